@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -7982,6 +7982,7 @@ pub struct UpdateServiceSettingResult {}
 
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -7993,6 +7994,8 @@ pub enum AddTagsToResourceError {
     TooManyTagsError(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -8000,26 +8003,37 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceId(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsError" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyTagsError(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::TooManyTagsError(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8034,12 +8048,14 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::InvalidResourceType(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::TooManyTagsError(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by CancelCommand
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelCommandError {
     /// <p>You cannot specify an instance ID in more than one association.</p>
     DuplicateInstanceId(String),
@@ -8049,6 +8065,8 @@ pub enum CancelCommandError {
     InvalidCommandId(String),
     /// <p>The following problems can cause this exception:</p> <p>You do not have permission to access the instance.</p> <p>SSM Agent is not running. Verify that SSM Agent is running.</p> <p>SSM Agent is not registered with the SSM endpoint. Try reinstalling SSM Agent.</p> <p>The instance is not in valid state. Valid states are: Running, Pending, Stopped, Stopping. Invalid states are: Shutting-down and Terminated.</p>
     InvalidInstanceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelCommandError {
@@ -8056,19 +8074,31 @@ impl CancelCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service(
+                        CancelCommandError::DuplicateInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelCommandError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CancelCommandError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidCommandId(err.msg))
+                    return RusotoError::service(
+                        CancelCommandError::InvalidCommandId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        CancelCommandError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelCommandError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8082,17 +8112,21 @@ impl fmt::Display for CancelCommandError {
             CancelCommandError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CancelCommandError::InvalidCommandId(ref cause) => write!(f, "{}", cause),
             CancelCommandError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
+            CancelCommandError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelCommandError {}
 /// Errors returned by CancelMaintenanceWindowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelMaintenanceWindowExecutionError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelMaintenanceWindowExecutionError {
@@ -8102,17 +8136,24 @@ impl CancelMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelMaintenanceWindowExecutionError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelMaintenanceWindowExecutionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CancelMaintenanceWindowExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8128,15 +8169,21 @@ impl fmt::Display for CancelMaintenanceWindowExecutionError {
             CancelMaintenanceWindowExecutionError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CancelMaintenanceWindowExecutionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CancelMaintenanceWindowExecutionError {}
 /// Errors returned by CreateActivation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateActivationError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateActivationError {
@@ -8144,12 +8191,13 @@ impl CreateActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateActivationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateActivationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateActivationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8160,12 +8208,14 @@ impl fmt::Display for CreateActivationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateActivationError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateActivationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateActivationError {}
 /// Errors returned by CreateAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAssociationError {
     /// <p>The specified association already exists.</p>
     AssociationAlreadyExists(String),
@@ -8189,6 +8239,8 @@ pub enum CreateAssociationError {
     InvalidTarget(String),
     /// <p>The document does not support the platform type of the given instance ID(s). For example, you sent an document for a Windows instance to a Linux instance.</p>
     UnsupportedPlatformType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAssociationError {
@@ -8196,52 +8248,73 @@ impl CreateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationAlreadyExists" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::AssociationAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::AssociationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidOutputLocation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidOutputLocation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidSchedule(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        CreateAssociationError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(CreateAssociationError::UnsupportedPlatformType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationError::UnsupportedPlatformType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8262,12 +8335,14 @@ impl fmt::Display for CreateAssociationError {
             CreateAssociationError::InvalidSchedule(ref cause) => write!(f, "{}", cause),
             CreateAssociationError::InvalidTarget(ref cause) => write!(f, "{}", cause),
             CreateAssociationError::UnsupportedPlatformType(ref cause) => write!(f, "{}", cause),
+            CreateAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAssociationError {}
 /// Errors returned by CreateAssociationBatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAssociationBatchError {
     /// <p>You can have at most 2,000 active associations.</p>
     AssociationLimitExceeded(String),
@@ -8291,6 +8366,8 @@ pub enum CreateAssociationBatchError {
     InvalidTarget(String),
     /// <p>The document does not support the platform type of the given instance ID(s). For example, you sent an document for a Windows instance to a Linux instance.</p>
     UnsupportedPlatformType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAssociationBatchError {
@@ -8298,62 +8375,73 @@ impl CreateAssociationBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssociationBatchError::AssociationLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::DuplicateInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::DuplicateInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssociationBatchError::InvalidDocumentVersion(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssociationBatchError::InvalidOutputLocation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidSchedule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InvalidSchedule(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidTarget(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAssociationBatchError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateAssociationBatchError::UnsupportedPlatformType(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAssociationBatchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8380,12 +8468,14 @@ impl fmt::Display for CreateAssociationBatchError {
             CreateAssociationBatchError::UnsupportedPlatformType(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAssociationBatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAssociationBatchError {}
 /// Errors returned by CreateDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDocumentError {
     /// <p>The specified document already exists.</p>
     DocumentAlreadyExists(String),
@@ -8399,6 +8489,8 @@ pub enum CreateDocumentError {
     InvalidDocumentSchemaVersion(String),
     /// <p>The size limit of a document is 64 KB.</p>
     MaxDocumentSizeExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDocumentError {
@@ -8406,35 +8498,43 @@ impl CreateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentAlreadyExists" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentError::DocumentAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentError::DocumentLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentError::InvalidDocumentContent(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentSchemaVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentError::InvalidDocumentSchemaVersion(err.msg),
+                        &res,
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::MaxDocumentSizeExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentError::MaxDocumentSizeExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8450,12 +8550,14 @@ impl fmt::Display for CreateDocumentError {
             CreateDocumentError::InvalidDocumentContent(ref cause) => write!(f, "{}", cause),
             CreateDocumentError::InvalidDocumentSchemaVersion(ref cause) => write!(f, "{}", cause),
             CreateDocumentError::MaxDocumentSizeExceeded(ref cause) => write!(f, "{}", cause),
+            CreateDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDocumentError {}
 /// Errors returned by CreateMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMaintenanceWindowError {
     /// <p>Error returned when an idempotent operation is retried and the parameters don't match the original call to the API with the same idempotency token. </p>
     IdempotentParameterMismatch(String),
@@ -8463,6 +8565,8 @@ pub enum CreateMaintenanceWindowError {
     InternalServerError(String),
     /// <p>Error returned when the caller has exceeded the default resource quotas. For example, too many maintenance windows or patch baselines have been created.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMaintenanceWindowError {
@@ -8470,22 +8574,25 @@ impl CreateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMaintenanceWindowError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMaintenanceWindowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8502,12 +8609,14 @@ impl fmt::Display for CreateMaintenanceWindowError {
             CreateMaintenanceWindowError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateMaintenanceWindowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMaintenanceWindowError {}
 /// Errors returned by CreateOpsItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOpsItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -8517,6 +8626,8 @@ pub enum CreateOpsItemError {
     OpsItemInvalidParameter(String),
     /// <p>The request caused OpsItems to exceed one or more quotas. For information about OpsItem quotas, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-learn-more.html#OpsCenter-learn-more-limits">What are the resource limits for OpsCenter?</a>.</p>
     OpsItemLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOpsItemError {
@@ -8524,21 +8635,31 @@ impl CreateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateOpsItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateOpsItemError::OpsItemAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateOpsItemError::OpsItemInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateOpsItemError::OpsItemLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateOpsItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8552,12 +8673,14 @@ impl fmt::Display for CreateOpsItemError {
             CreateOpsItemError::OpsItemAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateOpsItemError::OpsItemInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateOpsItemError::OpsItemLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateOpsItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOpsItemError {}
 /// Errors returned by CreatePatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePatchBaselineError {
     /// <p>Error returned when an idempotent operation is retried and the parameters don't match the original call to the API with the same idempotency token. </p>
     IdempotentParameterMismatch(String),
@@ -8565,6 +8688,8 @@ pub enum CreatePatchBaselineError {
     InternalServerError(String),
     /// <p>Error returned when the caller has exceeded the default resource quotas. For example, too many maintenance windows or patch baselines have been created.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePatchBaselineError {
@@ -8572,22 +8697,25 @@ impl CreatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePatchBaselineError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreatePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePatchBaselineError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreatePatchBaselineError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePatchBaselineError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePatchBaselineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8602,12 +8730,14 @@ impl fmt::Display for CreatePatchBaselineError {
             }
             CreatePatchBaselineError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreatePatchBaselineError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreatePatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePatchBaselineError {}
 /// Errors returned by CreateResourceDataSync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceDataSyncError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -8617,6 +8747,8 @@ pub enum CreateResourceDataSyncError {
     ResourceDataSyncCountExceeded(String),
     /// <p>The specified sync configuration is invalid.</p>
     ResourceDataSyncInvalidConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceDataSyncError {
@@ -8624,27 +8756,31 @@ impl CreateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceDataSyncError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDataSyncAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceDataSyncError::ResourceDataSyncAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDataSyncCountExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceDataSyncError::ResourceDataSyncCountExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceDataSyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8664,12 +8800,14 @@ impl fmt::Display for CreateResourceDataSyncError {
             CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateResourceDataSyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceDataSyncError {}
 /// Errors returned by DeleteActivation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteActivationError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -8679,6 +8817,8 @@ pub enum DeleteActivationError {
     InvalidActivationId(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteActivationError {
@@ -8686,23 +8826,31 @@ impl DeleteActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteActivationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteActivationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidActivation" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivation(err.msg))
+                    return RusotoError::service(
+                        DeleteActivationError::InvalidActivation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidActivationId" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivationId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteActivationError::InvalidActivationId(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteActivationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        DeleteActivationError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteActivationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8716,12 +8864,14 @@ impl fmt::Display for DeleteActivationError {
             DeleteActivationError::InvalidActivation(ref cause) => write!(f, "{}", cause),
             DeleteActivationError::InvalidActivationId(ref cause) => write!(f, "{}", cause),
             DeleteActivationError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            DeleteActivationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteActivationError {}
 /// Errors returned by DeleteAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssociationError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -8733,6 +8883,8 @@ pub enum DeleteAssociationError {
     InvalidInstanceId(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssociationError {
@@ -8740,26 +8892,37 @@ impl DeleteAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DeleteAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssociationError::AssociationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAssociationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        DeleteAssociationError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        DeleteAssociationError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        DeleteAssociationError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8774,12 +8937,14 @@ impl fmt::Display for DeleteAssociationError {
             DeleteAssociationError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             DeleteAssociationError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             DeleteAssociationError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            DeleteAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssociationError {}
 /// Errors returned by DeleteDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDocumentError {
     /// <p>You must disassociate a document from all instances before you can delete it.</p>
     AssociatedInstances(String),
@@ -8789,6 +8954,8 @@ pub enum DeleteDocumentError {
     InvalidDocument(String),
     /// <p>You attempted to delete a document while it is still shared. You must stop sharing the document before you can delete it.</p>
     InvalidDocumentOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDocumentError {
@@ -8796,21 +8963,31 @@ impl DeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedInstances" => {
-                    return RusotoError::Service(DeleteDocumentError::AssociatedInstances(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentError::AssociatedInstances(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocumentOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentError::InvalidDocumentOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8824,12 +9001,14 @@ impl fmt::Display for DeleteDocumentError {
             DeleteDocumentError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteDocumentError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             DeleteDocumentError::InvalidDocumentOperation(ref cause) => write!(f, "{}", cause),
+            DeleteDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDocumentError {}
 /// Errors returned by DeleteInventory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInventoryError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -8841,6 +9020,8 @@ pub enum DeleteInventoryError {
     InvalidOption(String),
     /// <p>The parameter type name is not valid.</p>
     InvalidTypeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInventoryError {
@@ -8848,26 +9029,34 @@ impl DeleteInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteInventoryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeleteInventoryParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteInventoryError::InvalidDeleteInventoryParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInventoryRequestException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidInventoryRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInventoryError::InvalidInventoryRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOptionException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidOption(err.msg))
+                    return RusotoError::service(DeleteInventoryError::InvalidOption(err.msg), &res)
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(
+                        DeleteInventoryError::InvalidTypeName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInventoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8884,15 +9073,19 @@ impl fmt::Display for DeleteInventoryError {
             DeleteInventoryError::InvalidInventoryRequest(ref cause) => write!(f, "{}", cause),
             DeleteInventoryError::InvalidOption(ref cause) => write!(f, "{}", cause),
             DeleteInventoryError::InvalidTypeName(ref cause) => write!(f, "{}", cause),
+            DeleteInventoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInventoryError {}
 /// Errors returned by DeleteMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMaintenanceWindowError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMaintenanceWindowError {
@@ -8900,12 +9093,13 @@ impl DeleteMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMaintenanceWindowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8916,17 +9110,21 @@ impl fmt::Display for DeleteMaintenanceWindowError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteMaintenanceWindowError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteMaintenanceWindowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMaintenanceWindowError {}
 /// Errors returned by DeleteParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteParameterError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The parameter could not be found. Verify the name and try again.</p>
     ParameterNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteParameterError {
@@ -8934,13 +9132,19 @@ impl DeleteParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteParameterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(DeleteParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteParameterError::ParameterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8952,15 +9156,19 @@ impl fmt::Display for DeleteParameterError {
         match *self {
             DeleteParameterError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteParameterError::ParameterNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteParameterError {}
 /// Errors returned by DeleteParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteParametersError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteParametersError {
@@ -8968,12 +9176,13 @@ impl DeleteParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParametersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteParametersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteParametersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8984,17 +9193,21 @@ impl fmt::Display for DeleteParametersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteParametersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteParametersError {}
 /// Errors returned by DeletePatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePatchBaselineError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>Error returned if an attempt is made to delete a patch baseline that is registered for a patch group.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePatchBaselineError {
@@ -9002,15 +9215,19 @@ impl DeletePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeletePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePatchBaselineError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePatchBaselineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeletePatchBaselineError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePatchBaselineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9022,12 +9239,14 @@ impl fmt::Display for DeletePatchBaselineError {
         match *self {
             DeletePatchBaselineError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeletePatchBaselineError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeletePatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePatchBaselineError {}
 /// Errors returned by DeleteResourceDataSync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceDataSyncError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9035,6 +9254,8 @@ pub enum DeleteResourceDataSyncError {
     ResourceDataSyncInvalidConfiguration(String),
     /// <p>The specified sync name was not found.</p>
     ResourceDataSyncNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceDataSyncError {
@@ -9042,22 +9263,25 @@ impl DeleteResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceDataSyncError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceDataSyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9074,17 +9298,21 @@ impl fmt::Display for DeleteResourceDataSyncError {
             DeleteResourceDataSyncError::ResourceDataSyncNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteResourceDataSyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceDataSyncError {}
 /// Errors returned by DeregisterManagedInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterManagedInstanceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The following problems can cause this exception:</p> <p>You do not have permission to access the instance.</p> <p>SSM Agent is not running. Verify that SSM Agent is running.</p> <p>SSM Agent is not registered with the SSM endpoint. Try reinstalling SSM Agent.</p> <p>The instance is not in valid state. Valid states are: Running, Pending, Stopped, Stopping. Invalid states are: Shutting-down and Terminated.</p>
     InvalidInstanceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterManagedInstanceError {
@@ -9092,17 +9320,21 @@ impl DeregisterManagedInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterManagedInstanceError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeregisterManagedInstanceError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterManagedInstanceError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeregisterManagedInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9116,17 +9348,21 @@ impl fmt::Display for DeregisterManagedInstanceError {
                 write!(f, "{}", cause)
             }
             DeregisterManagedInstanceError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
+            DeregisterManagedInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterManagedInstanceError {}
 /// Errors returned by DeregisterPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterPatchBaselineForPatchGroupError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The resource ID is not valid. Verify that you entered the correct ID and try again.</p>
     InvalidResourceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterPatchBaselineForPatchGroupError {
@@ -9136,17 +9372,24 @@ impl DeregisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterPatchBaselineForPatchGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9162,12 +9405,16 @@ impl fmt::Display for DeregisterPatchBaselineForPatchGroupError {
             DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterPatchBaselineForPatchGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeregisterPatchBaselineForPatchGroupError {}
 /// Errors returned by DeregisterTargetFromMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTargetFromMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -9175,6 +9422,8 @@ pub enum DeregisterTargetFromMaintenanceWindowError {
     InternalServerError(String),
     /// <p>You specified the <code>Safe</code> option for the DeregisterTargetFromMaintenanceWindow operation, but the target is still referenced in a task.</p>
     TargetInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTargetFromMaintenanceWindowError {
@@ -9184,22 +9433,30 @@ impl DeregisterTargetFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterTargetFromMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterTargetFromMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "TargetInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterTargetFromMaintenanceWindowError::TargetInUse(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterTargetFromMaintenanceWindowError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9218,17 +9475,23 @@ impl fmt::Display for DeregisterTargetFromMaintenanceWindowError {
             DeregisterTargetFromMaintenanceWindowError::TargetInUse(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterTargetFromMaintenanceWindowError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeregisterTargetFromMaintenanceWindowError {}
 /// Errors returned by DeregisterTaskFromMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTaskFromMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTaskFromMaintenanceWindowError {
@@ -9238,17 +9501,24 @@ impl DeregisterTaskFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterTaskFromMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterTaskFromMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterTaskFromMaintenanceWindowError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9264,12 +9534,16 @@ impl fmt::Display for DeregisterTaskFromMaintenanceWindowError {
             DeregisterTaskFromMaintenanceWindowError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterTaskFromMaintenanceWindowError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeregisterTaskFromMaintenanceWindowError {}
 /// Errors returned by DescribeActivations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeActivationsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9277,6 +9551,8 @@ pub enum DescribeActivationsError {
     InvalidFilter(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeActivationsError {
@@ -9284,20 +9560,25 @@ impl DescribeActivationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeActivationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeActivationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        DescribeActivationsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeActivationsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeActivationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9310,12 +9591,14 @@ impl fmt::Display for DescribeActivationsError {
             DescribeActivationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeActivationsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             DescribeActivationsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeActivationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeActivationsError {}
 /// Errors returned by DescribeAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssociationError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -9327,6 +9610,8 @@ pub enum DescribeAssociationError {
     InvalidDocument(String),
     /// <p>The following problems can cause this exception:</p> <p>You do not have permission to access the instance.</p> <p>SSM Agent is not running. Verify that SSM Agent is running.</p> <p>SSM Agent is not registered with the SSM endpoint. Try reinstalling SSM Agent.</p> <p>The instance is not in valid state. Valid states are: Running, Pending, Stopped, Stopping. Invalid states are: Shutting-down and Terminated.</p>
     InvalidInstanceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssociationError {
@@ -9334,30 +9619,37 @@ impl DescribeAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DescribeAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssociationError::AssociationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssociationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationError::InvalidAssociationVersion(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        DescribeAssociationError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAssociationError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9374,12 +9666,14 @@ impl fmt::Display for DescribeAssociationError {
             }
             DescribeAssociationError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             DescribeAssociationError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
+            DescribeAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAssociationError {}
 /// Errors returned by DescribeAssociationExecutionTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssociationExecutionTargetsError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -9389,6 +9683,8 @@ pub enum DescribeAssociationExecutionTargetsError {
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssociationExecutionTargetsError {
@@ -9398,29 +9694,38 @@ impl DescribeAssociationExecutionTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionTargetsError::AssociationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "AssociationExecutionDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionTargetsError::AssociationExecutionDoesNotExist(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionTargetsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionTargetsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAssociationExecutionTargetsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9442,12 +9747,16 @@ impl fmt::Display for DescribeAssociationExecutionTargetsError {
             DescribeAssociationExecutionTargetsError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAssociationExecutionTargetsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAssociationExecutionTargetsError {}
 /// Errors returned by DescribeAssociationExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAssociationExecutionsError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -9455,6 +9764,8 @@ pub enum DescribeAssociationExecutionsError {
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAssociationExecutionsError {
@@ -9464,22 +9775,30 @@ impl DescribeAssociationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionsError::AssociationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAssociationExecutionsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAssociationExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9498,12 +9817,16 @@ impl fmt::Display for DescribeAssociationExecutionsError {
             DescribeAssociationExecutionsError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAssociationExecutionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAssociationExecutionsError {}
 /// Errors returned by DescribeAutomationExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutomationExecutionsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9513,6 +9836,8 @@ pub enum DescribeAutomationExecutionsError {
     InvalidFilterValue(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutomationExecutionsError {
@@ -9522,27 +9847,36 @@ impl DescribeAutomationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationExecutionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationExecutionsError::InvalidFilterKey(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationExecutionsError::InvalidFilterValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationExecutionsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAutomationExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9564,12 +9898,14 @@ impl fmt::Display for DescribeAutomationExecutionsError {
             DescribeAutomationExecutionsError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAutomationExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAutomationExecutionsError {}
 /// Errors returned by DescribeAutomationStepExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutomationStepExecutionsError {
     /// <p>There is no automation execution information for the requested automation execution ID.</p>
     AutomationExecutionNotFound(String),
@@ -9581,6 +9917,8 @@ pub enum DescribeAutomationStepExecutionsError {
     InvalidFilterValue(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutomationStepExecutionsError {
@@ -9590,32 +9928,42 @@ impl DescribeAutomationStepExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationStepExecutionsError::AutomationExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationStepExecutionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationStepExecutionsError::InvalidFilterKey(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationStepExecutionsError::InvalidFilterValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAutomationStepExecutionsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAutomationStepExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9640,15 +9988,21 @@ impl fmt::Display for DescribeAutomationStepExecutionsError {
             DescribeAutomationStepExecutionsError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAutomationStepExecutionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAutomationStepExecutionsError {}
 /// Errors returned by DescribeAvailablePatches
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAvailablePatchesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAvailablePatchesError {
@@ -9656,12 +10010,13 @@ impl DescribeAvailablePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAvailablePatchesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAvailablePatchesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9672,12 +10027,14 @@ impl fmt::Display for DescribeAvailablePatchesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAvailablePatchesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeAvailablePatchesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAvailablePatchesError {}
 /// Errors returned by DescribeDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDocumentError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9685,6 +10042,8 @@ pub enum DescribeDocumentError {
     InvalidDocument(String),
     /// <p>The document version is not valid or does not exist.</p>
     InvalidDocumentVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDocumentError {
@@ -9692,20 +10051,25 @@ impl DescribeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeDocumentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        DescribeDocumentError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9718,12 +10082,14 @@ impl fmt::Display for DescribeDocumentError {
             DescribeDocumentError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeDocumentError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             DescribeDocumentError::InvalidDocumentVersion(ref cause) => write!(f, "{}", cause),
+            DescribeDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDocumentError {}
 /// Errors returned by DescribeDocumentPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDocumentPermissionError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9731,6 +10097,8 @@ pub enum DescribeDocumentPermissionError {
     InvalidDocument(String),
     /// <p>The permission type is not supported. <i>Share</i> is the only supported permission type.</p>
     InvalidPermissionType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDocumentPermissionError {
@@ -9740,22 +10108,27 @@ impl DescribeDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentPermissionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentPermissionError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentPermissionError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentPermissionError::InvalidPermissionType(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeDocumentPermissionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9772,12 +10145,14 @@ impl fmt::Display for DescribeDocumentPermissionError {
             DescribeDocumentPermissionError::InvalidPermissionType(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDocumentPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDocumentPermissionError {}
 /// Errors returned by DescribeEffectiveInstanceAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEffectiveInstanceAssociationsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9785,6 +10160,8 @@ pub enum DescribeEffectiveInstanceAssociationsError {
     InvalidInstanceId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEffectiveInstanceAssociationsError {
@@ -9794,22 +10171,30 @@ impl DescribeEffectiveInstanceAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectiveInstanceAssociationsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectiveInstanceAssociationsError::InvalidInstanceId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectiveInstanceAssociationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEffectiveInstanceAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9828,12 +10213,16 @@ impl fmt::Display for DescribeEffectiveInstanceAssociationsError {
             DescribeEffectiveInstanceAssociationsError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEffectiveInstanceAssociationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEffectiveInstanceAssociationsError {}
 /// Errors returned by DescribeEffectivePatchesForPatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEffectivePatchesForPatchBaselineError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -9843,6 +10232,8 @@ pub enum DescribeEffectivePatchesForPatchBaselineError {
     InvalidResourceId(String),
     /// <p>The operating systems you specified is not supported, or the operation is not supported for the operating system. Valid operating systems include: Windows, AmazonLinux, RedhatEnterpriseLinux, and Ubuntu.</p>
     UnsupportedOperatingSystem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEffectivePatchesForPatchBaselineError {
@@ -9852,29 +10243,38 @@ impl DescribeEffectivePatchesForPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePatchesForPatchBaselineError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePatchesForPatchBaselineError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePatchesForPatchBaselineError::InvalidResourceId(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEffectivePatchesForPatchBaselineError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9896,12 +10296,16 @@ impl fmt::Display for DescribeEffectivePatchesForPatchBaselineError {
             DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeEffectivePatchesForPatchBaselineError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEffectivePatchesForPatchBaselineError {}
 /// Errors returned by DescribeInstanceAssociationsStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstanceAssociationsStatusError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9909,6 +10313,8 @@ pub enum DescribeInstanceAssociationsStatusError {
     InvalidInstanceId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstanceAssociationsStatusError {
@@ -9918,22 +10324,30 @@ impl DescribeInstanceAssociationsStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceAssociationsStatusError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceAssociationsStatusError::InvalidInstanceId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceAssociationsStatusError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeInstanceAssociationsStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9952,12 +10366,16 @@ impl fmt::Display for DescribeInstanceAssociationsStatusError {
             DescribeInstanceAssociationsStatusError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeInstanceAssociationsStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeInstanceAssociationsStatusError {}
 /// Errors returned by DescribeInstanceInformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstanceInformationError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -9969,6 +10387,8 @@ pub enum DescribeInstanceInformationError {
     InvalidInstanceInformationFilterValue(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstanceInformationError {
@@ -9978,34 +10398,44 @@ impl DescribeInstanceInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceInformationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceInformationError::InvalidFilterKey(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceInformationError::InvalidInstanceId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceInformationFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceInformationError::InvalidInstanceInformationFilterValue(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstanceInformationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeInstanceInformationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10026,17 +10456,21 @@ impl fmt::Display for DescribeInstanceInformationError {
                 write!(f, "{}", cause)
             }
             DescribeInstanceInformationError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeInstanceInformationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstanceInformationError {}
 /// Errors returned by DescribeInstancePatchStates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancePatchStatesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancePatchStatesError {
@@ -10046,17 +10480,24 @@ impl DescribeInstancePatchStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstancePatchStatesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstancePatchStatesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeInstancePatchStatesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10070,12 +10511,14 @@ impl fmt::Display for DescribeInstancePatchStatesError {
                 write!(f, "{}", cause)
             }
             DescribeInstancePatchStatesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeInstancePatchStatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstancePatchStatesError {}
 /// Errors returned by DescribeInstancePatchStatesForPatchGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancePatchStatesForPatchGroupError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10083,6 +10526,8 @@ pub enum DescribeInstancePatchStatesForPatchGroupError {
     InvalidFilter(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancePatchStatesForPatchGroupError {
@@ -10092,22 +10537,30 @@ impl DescribeInstancePatchStatesForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstancePatchStatesForPatchGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstancePatchStatesForPatchGroupError::InvalidFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeInstancePatchStatesForPatchGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10126,12 +10579,16 @@ impl fmt::Display for DescribeInstancePatchStatesForPatchGroupError {
             DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeInstancePatchStatesForPatchGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeInstancePatchStatesForPatchGroupError {}
 /// Errors returned by DescribeInstancePatches
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancePatchesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10141,6 +10598,8 @@ pub enum DescribeInstancePatchesError {
     InvalidInstanceId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancePatchesError {
@@ -10148,27 +10607,31 @@ impl DescribeInstancePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInstancePatchesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInstancePatchesError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInstancePatchesError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInstancePatchesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInstancePatchesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10182,12 +10645,14 @@ impl fmt::Display for DescribeInstancePatchesError {
             DescribeInstancePatchesError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             DescribeInstancePatchesError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             DescribeInstancePatchesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeInstancePatchesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstancePatchesError {}
 /// Errors returned by DescribeInventoryDeletions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInventoryDeletionsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10195,6 +10660,8 @@ pub enum DescribeInventoryDeletionsError {
     InvalidDeletionId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInventoryDeletionsError {
@@ -10204,22 +10671,27 @@ impl DescribeInventoryDeletionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInventoryDeletionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeletionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeInventoryDeletionsError::InvalidDeletionId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInventoryDeletionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInventoryDeletionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeInventoryDeletionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10234,17 +10706,21 @@ impl fmt::Display for DescribeInventoryDeletionsError {
             }
             DescribeInventoryDeletionsError::InvalidDeletionId(ref cause) => write!(f, "{}", cause),
             DescribeInventoryDeletionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeInventoryDeletionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInventoryDeletionsError {}
 /// Errors returned by DescribeMaintenanceWindowExecutionTaskInvocations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowExecutionTaskInvocationsError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowExecutionTaskInvocationsError {
@@ -10254,21 +10730,28 @@ impl DescribeMaintenanceWindowExecutionTaskInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowExecutionTaskInvocationsError::DoesNotExist(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowExecutionTaskInvocationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10284,17 +10767,23 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionTaskInvocationsError {
             DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeMaintenanceWindowExecutionTaskInvocationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionTaskInvocationsError {}
 /// Errors returned by DescribeMaintenanceWindowExecutionTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowExecutionTasksError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowExecutionTasksError {
@@ -10304,17 +10793,24 @@ impl DescribeMaintenanceWindowExecutionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowExecutionTasksError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowExecutionTasksError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowExecutionTasksError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10330,15 +10826,21 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionTasksError {
             DescribeMaintenanceWindowExecutionTasksError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowExecutionTasksError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionTasksError {}
 /// Errors returned by DescribeMaintenanceWindowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowExecutionsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowExecutionsError {
@@ -10348,12 +10850,18 @@ impl DescribeMaintenanceWindowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowExecutionsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10366,17 +10874,23 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionsError {
             DescribeMaintenanceWindowExecutionsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowExecutionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionsError {}
 /// Errors returned by DescribeMaintenanceWindowSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowScheduleError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowScheduleError {
@@ -10386,17 +10900,24 @@ impl DescribeMaintenanceWindowScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowScheduleError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowScheduleError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowScheduleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10412,17 +10933,23 @@ impl fmt::Display for DescribeMaintenanceWindowScheduleError {
             DescribeMaintenanceWindowScheduleError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowScheduleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowScheduleError {}
 /// Errors returned by DescribeMaintenanceWindowTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowTargetsError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowTargetsError {
@@ -10432,17 +10959,24 @@ impl DescribeMaintenanceWindowTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowTargetsError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowTargetsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowTargetsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10458,17 +10992,23 @@ impl fmt::Display for DescribeMaintenanceWindowTargetsError {
             DescribeMaintenanceWindowTargetsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowTargetsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowTargetsError {}
 /// Errors returned by DescribeMaintenanceWindowTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowTasksError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowTasksError {
@@ -10478,17 +11018,24 @@ impl DescribeMaintenanceWindowTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(DescribeMaintenanceWindowTasksError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMaintenanceWindowTasksError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowTasksError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowTasksError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10502,15 +11049,21 @@ impl fmt::Display for DescribeMaintenanceWindowTasksError {
             DescribeMaintenanceWindowTasksError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowTasksError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowTasksError {}
 /// Errors returned by DescribeMaintenanceWindows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowsError {
@@ -10520,12 +11073,15 @@ impl DescribeMaintenanceWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeMaintenanceWindowsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10538,15 +11094,19 @@ impl fmt::Display for DescribeMaintenanceWindowsError {
             DescribeMaintenanceWindowsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMaintenanceWindowsError {}
 /// Errors returned by DescribeMaintenanceWindowsForTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceWindowsForTargetError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceWindowsForTargetError {
@@ -10556,12 +11116,18 @@ impl DescribeMaintenanceWindowsForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceWindowsForTargetError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceWindowsForTargetError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10574,15 +11140,21 @@ impl fmt::Display for DescribeMaintenanceWindowsForTargetError {
             DescribeMaintenanceWindowsForTargetError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceWindowsForTargetError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMaintenanceWindowsForTargetError {}
 /// Errors returned by DescribeOpsItems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOpsItemsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOpsItemsError {
@@ -10590,12 +11162,13 @@ impl DescribeOpsItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeOpsItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeOpsItemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeOpsItemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10606,12 +11179,14 @@ impl fmt::Display for DescribeOpsItemsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeOpsItemsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeOpsItemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeOpsItemsError {}
 /// Errors returned by DescribeParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeParametersError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10623,6 +11198,8 @@ pub enum DescribeParametersError {
     InvalidFilterValue(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeParametersError {
@@ -10630,28 +11207,37 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeParametersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeParametersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        DescribeParametersError::InvalidFilterKey(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeParametersError::InvalidFilterOption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeParametersError::InvalidFilterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeParametersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeParametersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10666,15 +11252,19 @@ impl fmt::Display for DescribeParametersError {
             DescribeParametersError::InvalidFilterOption(ref cause) => write!(f, "{}", cause),
             DescribeParametersError::InvalidFilterValue(ref cause) => write!(f, "{}", cause),
             DescribeParametersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeParametersError {}
 /// Errors returned by DescribePatchBaselines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePatchBaselinesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePatchBaselinesError {
@@ -10682,12 +11272,13 @@ impl DescribePatchBaselinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchBaselinesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePatchBaselinesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePatchBaselinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10698,17 +11289,21 @@ impl fmt::Display for DescribePatchBaselinesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribePatchBaselinesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribePatchBaselinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePatchBaselinesError {}
 /// Errors returned by DescribePatchGroupState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePatchGroupStateError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePatchGroupStateError {
@@ -10716,17 +11311,19 @@ impl DescribePatchGroupStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePatchGroupStateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePatchGroupStateError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePatchGroupStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10738,15 +11335,19 @@ impl fmt::Display for DescribePatchGroupStateError {
         match *self {
             DescribePatchGroupStateError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribePatchGroupStateError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribePatchGroupStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePatchGroupStateError {}
 /// Errors returned by DescribePatchGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePatchGroupsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePatchGroupsError {
@@ -10754,12 +11355,13 @@ impl DescribePatchGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePatchGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePatchGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10770,15 +11372,19 @@ impl fmt::Display for DescribePatchGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribePatchGroupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribePatchGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePatchGroupsError {}
 /// Errors returned by DescribePatchProperties
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePatchPropertiesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePatchPropertiesError {
@@ -10786,12 +11392,13 @@ impl DescribePatchPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchPropertiesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePatchPropertiesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePatchPropertiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10802,12 +11409,14 @@ impl fmt::Display for DescribePatchPropertiesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribePatchPropertiesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribePatchPropertiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePatchPropertiesError {}
 /// Errors returned by DescribeSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSessionsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10815,6 +11424,8 @@ pub enum DescribeSessionsError {
     InvalidFilterKey(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSessionsError {
@@ -10822,18 +11433,25 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSessionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSessionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        DescribeSessionsError::InvalidFilterKey(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeSessionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10846,17 +11464,21 @@ impl fmt::Display for DescribeSessionsError {
             DescribeSessionsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeSessionsError::InvalidFilterKey(ref cause) => write!(f, "{}", cause),
             DescribeSessionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSessionsError {}
 /// Errors returned by GetAutomationExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAutomationExecutionError {
     /// <p>There is no automation execution information for the requested automation execution ID.</p>
     AutomationExecutionNotFound(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAutomationExecutionError {
@@ -10864,17 +11486,19 @@ impl GetAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutomationExecutionError::AutomationExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetAutomationExecutionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAutomationExecutionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAutomationExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10888,12 +11512,14 @@ impl fmt::Display for GetAutomationExecutionError {
                 write!(f, "{}", cause)
             }
             GetAutomationExecutionError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetAutomationExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAutomationExecutionError {}
 /// Errors returned by GetCalendarState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCalendarStateError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10903,6 +11529,8 @@ pub enum GetCalendarStateError {
     InvalidDocumentType(String),
     /// <p>The calendar entry contained in the specified Systems Manager document is not supported.</p>
     UnsupportedCalendar(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCalendarStateError {
@@ -10910,25 +11538,31 @@ impl GetCalendarStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCalendarStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCalendarStateError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        GetCalendarStateError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentType" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocumentType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCalendarStateError::InvalidDocumentType(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedCalendarException" => {
-                    return RusotoError::Service(GetCalendarStateError::UnsupportedCalendar(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCalendarStateError::UnsupportedCalendar(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCalendarStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10942,12 +11576,14 @@ impl fmt::Display for GetCalendarStateError {
             GetCalendarStateError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             GetCalendarStateError::InvalidDocumentType(ref cause) => write!(f, "{}", cause),
             GetCalendarStateError::UnsupportedCalendar(ref cause) => write!(f, "{}", cause),
+            GetCalendarStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCalendarStateError {}
 /// Errors returned by GetCommandInvocation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCommandInvocationError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -10959,6 +11595,8 @@ pub enum GetCommandInvocationError {
     InvalidPluginName(String),
     /// <p>The command ID and instance ID you specified did not match any invocations. Verify the command ID and the instance ID and try again. </p>
     InvocationDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCommandInvocationError {
@@ -10966,32 +11604,37 @@ impl GetCommandInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCommandInvocationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommandInvocationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidCommandId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommandInvocationError::InvalidCommandId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommandInvocationError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPluginName" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidPluginName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommandInvocationError::InvalidPluginName(err.msg),
+                        &res,
+                    )
                 }
                 "InvocationDoesNotExist" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvocationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommandInvocationError::InvocationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCommandInvocationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11006,15 +11649,19 @@ impl fmt::Display for GetCommandInvocationError {
             GetCommandInvocationError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             GetCommandInvocationError::InvalidPluginName(ref cause) => write!(f, "{}", cause),
             GetCommandInvocationError::InvocationDoesNotExist(ref cause) => write!(f, "{}", cause),
+            GetCommandInvocationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCommandInvocationError {}
 /// Errors returned by GetConnectionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectionStatusError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectionStatusError {
@@ -11022,12 +11669,13 @@ impl GetConnectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetConnectionStatusError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetConnectionStatusError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectionStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11038,15 +11686,19 @@ impl fmt::Display for GetConnectionStatusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConnectionStatusError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetConnectionStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectionStatusError {}
 /// Errors returned by GetDefaultPatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDefaultPatchBaselineError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDefaultPatchBaselineError {
@@ -11054,12 +11706,13 @@ impl GetDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDefaultPatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDefaultPatchBaselineError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDefaultPatchBaselineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11070,12 +11723,14 @@ impl fmt::Display for GetDefaultPatchBaselineError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetDefaultPatchBaselineError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetDefaultPatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDefaultPatchBaselineError {}
 /// Errors returned by GetDeployablePatchSnapshotForInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeployablePatchSnapshotForInstanceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11083,6 +11738,8 @@ pub enum GetDeployablePatchSnapshotForInstanceError {
     UnsupportedFeatureRequired(String),
     /// <p>The operating systems you specified is not supported, or the operation is not supported for the operating system. Valid operating systems include: Windows, AmazonLinux, RedhatEnterpriseLinux, and Ubuntu.</p>
     UnsupportedOperatingSystem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeployablePatchSnapshotForInstanceError {
@@ -11092,26 +11749,34 @@ impl GetDeployablePatchSnapshotForInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeployablePatchSnapshotForInstanceError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedFeatureRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeployablePatchSnapshotForInstanceError::UnsupportedFeatureRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDeployablePatchSnapshotForInstanceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11130,12 +11795,16 @@ impl fmt::Display for GetDeployablePatchSnapshotForInstanceError {
             GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDeployablePatchSnapshotForInstanceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetDeployablePatchSnapshotForInstanceError {}
 /// Errors returned by GetDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11143,6 +11812,8 @@ pub enum GetDocumentError {
     InvalidDocument(String),
     /// <p>The document version is not valid or does not exist.</p>
     InvalidDocumentVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentError {
@@ -11150,16 +11821,22 @@ impl GetDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(GetDocumentError::InvalidDocument(err.msg), &res)
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service(
+                        GetDocumentError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11172,12 +11849,14 @@ impl fmt::Display for GetDocumentError {
             GetDocumentError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetDocumentError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             GetDocumentError::InvalidDocumentVersion(ref cause) => write!(f, "{}", cause),
+            GetDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentError {}
 /// Errors returned by GetInventory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInventoryError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11193,6 +11872,8 @@ pub enum GetInventoryError {
     InvalidResultAttribute(String),
     /// <p>The parameter type name is not valid.</p>
     InvalidTypeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInventoryError {
@@ -11200,28 +11881,40 @@ impl GetInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetInventoryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidAggregator(err.msg))
+                    return RusotoError::service(
+                        GetInventoryError::InvalidAggregator(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetInventoryError::InvalidFilter(err.msg))
+                    return RusotoError::service(GetInventoryError::InvalidFilter(err.msg), &res)
                 }
                 "InvalidInventoryGroupException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidInventoryGroup(err.msg))
+                    return RusotoError::service(
+                        GetInventoryError::InvalidInventoryGroup(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventoryError::InvalidNextToken(err.msg))
+                    return RusotoError::service(GetInventoryError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidResultAttributeException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidResultAttribute(err.msg))
+                    return RusotoError::service(
+                        GetInventoryError::InvalidResultAttribute(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(GetInventoryError::InvalidTypeName(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInventoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11238,12 +11931,14 @@ impl fmt::Display for GetInventoryError {
             GetInventoryError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetInventoryError::InvalidResultAttribute(ref cause) => write!(f, "{}", cause),
             GetInventoryError::InvalidTypeName(ref cause) => write!(f, "{}", cause),
+            GetInventoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInventoryError {}
 /// Errors returned by GetInventorySchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInventorySchemaError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11251,6 +11946,8 @@ pub enum GetInventorySchemaError {
     InvalidNextToken(String),
     /// <p>The parameter type name is not valid.</p>
     InvalidTypeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInventorySchemaError {
@@ -11258,18 +11955,25 @@ impl GetInventorySchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventorySchemaError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInventorySchemaError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetInventorySchemaError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidTypeName(err.msg))
+                    return RusotoError::service(
+                        GetInventorySchemaError::InvalidTypeName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInventorySchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11282,17 +11986,21 @@ impl fmt::Display for GetInventorySchemaError {
             GetInventorySchemaError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetInventorySchemaError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetInventorySchemaError::InvalidTypeName(ref cause) => write!(f, "{}", cause),
+            GetInventorySchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInventorySchemaError {}
 /// Errors returned by GetMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMaintenanceWindowError {
@@ -11300,15 +12008,19 @@ impl GetMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::DoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMaintenanceWindowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11320,17 +12032,21 @@ impl fmt::Display for GetMaintenanceWindowError {
         match *self {
             GetMaintenanceWindowError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             GetMaintenanceWindowError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetMaintenanceWindowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMaintenanceWindowError {}
 /// Errors returned by GetMaintenanceWindowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMaintenanceWindowExecutionError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMaintenanceWindowExecutionError {
@@ -11340,17 +12056,24 @@ impl GetMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowExecutionError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMaintenanceWindowExecutionError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowExecutionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetMaintenanceWindowExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11364,17 +12087,23 @@ impl fmt::Display for GetMaintenanceWindowExecutionError {
             GetMaintenanceWindowExecutionError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetMaintenanceWindowExecutionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetMaintenanceWindowExecutionError {}
 /// Errors returned by GetMaintenanceWindowExecutionTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMaintenanceWindowExecutionTaskError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMaintenanceWindowExecutionTaskError {
@@ -11384,17 +12113,24 @@ impl GetMaintenanceWindowExecutionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowExecutionTaskError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowExecutionTaskError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetMaintenanceWindowExecutionTaskError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11410,17 +12146,23 @@ impl fmt::Display for GetMaintenanceWindowExecutionTaskError {
             GetMaintenanceWindowExecutionTaskError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetMaintenanceWindowExecutionTaskError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetMaintenanceWindowExecutionTaskError {}
 /// Errors returned by GetMaintenanceWindowExecutionTaskInvocation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMaintenanceWindowExecutionTaskInvocationError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMaintenanceWindowExecutionTaskInvocationError {
@@ -11430,19 +12172,26 @@ impl GetMaintenanceWindowExecutionTaskInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowExecutionTaskInvocationError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetMaintenanceWindowExecutionTaskInvocationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11458,17 +12207,23 @@ impl fmt::Display for GetMaintenanceWindowExecutionTaskInvocationError {
             GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetMaintenanceWindowExecutionTaskInvocationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetMaintenanceWindowExecutionTaskInvocationError {}
 /// Errors returned by GetMaintenanceWindowTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMaintenanceWindowTaskError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMaintenanceWindowTaskError {
@@ -11476,17 +12231,19 @@ impl GetMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowTaskError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMaintenanceWindowTaskError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMaintenanceWindowTaskError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMaintenanceWindowTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11498,17 +12255,21 @@ impl fmt::Display for GetMaintenanceWindowTaskError {
         match *self {
             GetMaintenanceWindowTaskError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             GetMaintenanceWindowTaskError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            GetMaintenanceWindowTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMaintenanceWindowTaskError {}
 /// Errors returned by GetOpsItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOpsItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The specified OpsItem ID doesn't exist. Verify the ID and try again.</p>
     OpsItemNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOpsItemError {
@@ -11516,13 +12277,16 @@ impl GetOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetOpsItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(GetOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service(GetOpsItemError::OpsItemNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOpsItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11534,12 +12298,14 @@ impl fmt::Display for GetOpsItemError {
         match *self {
             GetOpsItemError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetOpsItemError::OpsItemNotFound(ref cause) => write!(f, "{}", cause),
+            GetOpsItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOpsItemError {}
 /// Errors returned by GetOpsSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOpsSummaryError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11553,6 +12319,8 @@ pub enum GetOpsSummaryError {
     InvalidTypeName(String),
     /// <p>The specified sync name was not found.</p>
     ResourceDataSyncNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOpsSummaryError {
@@ -11560,27 +12328,37 @@ impl GetOpsSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsSummaryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetOpsSummaryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidAggregator(err.msg))
+                    return RusotoError::service(
+                        GetOpsSummaryError::InvalidAggregator(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidFilter(err.msg))
+                    return RusotoError::service(GetOpsSummaryError::InvalidFilter(err.msg), &res)
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetOpsSummaryError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(GetOpsSummaryError::InvalidTypeName(err.msg), &res)
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(GetOpsSummaryError::ResourceDataSyncNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOpsSummaryError::ResourceDataSyncNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOpsSummaryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11596,12 +12374,14 @@ impl fmt::Display for GetOpsSummaryError {
             GetOpsSummaryError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetOpsSummaryError::InvalidTypeName(ref cause) => write!(f, "{}", cause),
             GetOpsSummaryError::ResourceDataSyncNotFound(ref cause) => write!(f, "{}", cause),
+            GetOpsSummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOpsSummaryError {}
 /// Errors returned by GetParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetParameterError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11611,6 +12391,8 @@ pub enum GetParameterError {
     ParameterNotFound(String),
     /// <p>The specified parameter version was not found. Verify the parameter name and version, and try again.</p>
     ParameterVersionNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetParameterError {
@@ -11618,21 +12400,28 @@ impl GetParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetParameterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service(GetParameterError::InvalidKeyId(err.msg), &res)
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service(
+                        GetParameterError::ParameterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterVersionNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParameterError::ParameterVersionNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11646,12 +12435,14 @@ impl fmt::Display for GetParameterError {
             GetParameterError::InvalidKeyId(ref cause) => write!(f, "{}", cause),
             GetParameterError::ParameterNotFound(ref cause) => write!(f, "{}", cause),
             GetParameterError::ParameterVersionNotFound(ref cause) => write!(f, "{}", cause),
+            GetParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetParameterError {}
 /// Errors returned by GetParameterHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetParameterHistoryError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11661,6 +12452,8 @@ pub enum GetParameterHistoryError {
     InvalidNextToken(String),
     /// <p>The parameter could not be found. Verify the name and try again.</p>
     ParameterNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetParameterHistoryError {
@@ -11668,25 +12461,31 @@ impl GetParameterHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterHistoryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParameterHistoryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidKeyId(err.msg))
+                    return RusotoError::service(
+                        GetParameterHistoryError::InvalidKeyId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParameterHistoryError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterHistoryError::ParameterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParameterHistoryError::ParameterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetParameterHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11700,17 +12499,21 @@ impl fmt::Display for GetParameterHistoryError {
             GetParameterHistoryError::InvalidKeyId(ref cause) => write!(f, "{}", cause),
             GetParameterHistoryError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetParameterHistoryError::ParameterNotFound(ref cause) => write!(f, "{}", cause),
+            GetParameterHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetParameterHistoryError {}
 /// Errors returned by GetParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetParametersError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The query key ID is not valid.</p>
     InvalidKeyId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetParametersError {
@@ -11718,13 +12521,16 @@ impl GetParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetParametersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersError::InvalidKeyId(err.msg))
+                    return RusotoError::service(GetParametersError::InvalidKeyId(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetParametersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11736,12 +12542,14 @@ impl fmt::Display for GetParametersError {
         match *self {
             GetParametersError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetParametersError::InvalidKeyId(ref cause) => write!(f, "{}", cause),
+            GetParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetParametersError {}
 /// Errors returned by GetParametersByPath
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetParametersByPathError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11755,6 +12563,8 @@ pub enum GetParametersByPathError {
     InvalidKeyId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetParametersByPathError {
@@ -11762,35 +12572,43 @@ impl GetParametersByPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersByPathError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersByPathError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterKey(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersByPathError::InvalidFilterKey(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersByPathError::InvalidFilterOption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersByPathError::InvalidFilterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidKeyId(err.msg))
+                    return RusotoError::service(
+                        GetParametersByPathError::InvalidKeyId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetParametersByPathError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetParametersByPathError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11806,12 +12624,14 @@ impl fmt::Display for GetParametersByPathError {
             GetParametersByPathError::InvalidFilterValue(ref cause) => write!(f, "{}", cause),
             GetParametersByPathError::InvalidKeyId(ref cause) => write!(f, "{}", cause),
             GetParametersByPathError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            GetParametersByPathError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetParametersByPathError {}
 /// Errors returned by GetPatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPatchBaselineError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -11819,6 +12639,8 @@ pub enum GetPatchBaselineError {
     InternalServerError(String),
     /// <p>The resource ID is not valid. Verify that you entered the correct ID and try again.</p>
     InvalidResourceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPatchBaselineError {
@@ -11826,18 +12648,22 @@ impl GetPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetPatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service(GetPatchBaselineError::DoesNotExist(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPatchBaselineError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(GetPatchBaselineError::InvalidResourceId(err.msg))
+                    return RusotoError::service(
+                        GetPatchBaselineError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPatchBaselineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11850,15 +12676,19 @@ impl fmt::Display for GetPatchBaselineError {
             GetPatchBaselineError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             GetPatchBaselineError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetPatchBaselineError::InvalidResourceId(ref cause) => write!(f, "{}", cause),
+            GetPatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPatchBaselineError {}
 /// Errors returned by GetPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPatchBaselineForPatchGroupError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPatchBaselineForPatchGroupError {
@@ -11868,12 +12698,18 @@ impl GetPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetPatchBaselineForPatchGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11886,17 +12722,23 @@ impl fmt::Display for GetPatchBaselineForPatchGroupError {
             GetPatchBaselineForPatchGroupError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetPatchBaselineForPatchGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetPatchBaselineForPatchGroupError {}
 /// Errors returned by GetServiceSetting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetServiceSettingError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The specified service setting was not found. Either the service name or the setting has not been provisioned by the AWS service team.</p>
     ServiceSettingNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetServiceSettingError {
@@ -11904,17 +12746,19 @@ impl GetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetServiceSettingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(GetServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetServiceSettingError::ServiceSettingNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetServiceSettingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11926,12 +12770,14 @@ impl fmt::Display for GetServiceSettingError {
         match *self {
             GetServiceSettingError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetServiceSettingError::ServiceSettingNotFound(ref cause) => write!(f, "{}", cause),
+            GetServiceSettingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetServiceSettingError {}
 /// Errors returned by LabelParameterVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LabelParameterVersionError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -11943,6 +12789,8 @@ pub enum LabelParameterVersionError {
     ParameterVersionNotFound(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LabelParameterVersionError {
@@ -11950,32 +12798,37 @@ impl LabelParameterVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(LabelParameterVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LabelParameterVersionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(LabelParameterVersionError::ParameterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LabelParameterVersionError::ParameterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterVersionLabelLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         LabelParameterVersionError::ParameterVersionLabelLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         LabelParameterVersionError::ParameterVersionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(LabelParameterVersionError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LabelParameterVersionError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LabelParameterVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11994,12 +12847,14 @@ impl fmt::Display for LabelParameterVersionError {
                 write!(f, "{}", cause)
             }
             LabelParameterVersionError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            LabelParameterVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LabelParameterVersionError {}
 /// Errors returned by ListAssociationVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssociationVersionsError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -12007,6 +12862,8 @@ pub enum ListAssociationVersionsError {
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssociationVersionsError {
@@ -12014,22 +12871,25 @@ impl ListAssociationVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAssociationVersionsError::AssociationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssociationVersionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssociationVersionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssociationVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12044,17 +12904,21 @@ impl fmt::Display for ListAssociationVersionsError {
             }
             ListAssociationVersionsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListAssociationVersionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListAssociationVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssociationVersionsError {}
 /// Errors returned by ListAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssociationsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssociationsError {
@@ -12062,15 +12926,19 @@ impl ListAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAssociationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListAssociationsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssociationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12082,12 +12950,14 @@ impl fmt::Display for ListAssociationsError {
         match *self {
             ListAssociationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListAssociationsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAssociationsError {}
 /// Errors returned by ListCommandInvocations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCommandInvocationsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12099,6 +12969,8 @@ pub enum ListCommandInvocationsError {
     InvalidInstanceId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCommandInvocationsError {
@@ -12106,32 +12978,37 @@ impl ListCommandInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCommandInvocationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidCommandId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCommandInvocationsError::InvalidCommandId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidFilterKey(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCommandInvocationsError::InvalidFilterKey(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCommandInvocationsError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCommandInvocationsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCommandInvocationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12146,12 +13023,14 @@ impl fmt::Display for ListCommandInvocationsError {
             ListCommandInvocationsError::InvalidFilterKey(ref cause) => write!(f, "{}", cause),
             ListCommandInvocationsError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             ListCommandInvocationsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListCommandInvocationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCommandInvocationsError {}
 /// Errors returned by ListCommands
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCommandsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12163,6 +13042,8 @@ pub enum ListCommandsError {
     InvalidInstanceId(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCommandsError {
@@ -12170,22 +13051,28 @@ impl ListCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListCommandsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidCommandId(err.msg))
+                    return RusotoError::service(ListCommandsError::InvalidCommandId(err.msg), &res)
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(ListCommandsError::InvalidFilterKey(err.msg), &res)
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        ListCommandsError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListCommandsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCommandsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12200,12 +13087,14 @@ impl fmt::Display for ListCommandsError {
             ListCommandsError::InvalidFilterKey(ref cause) => write!(f, "{}", cause),
             ListCommandsError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             ListCommandsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListCommandsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCommandsError {}
 /// Errors returned by ListComplianceItems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComplianceItemsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12217,6 +13106,8 @@ pub enum ListComplianceItemsError {
     InvalidResourceId(String),
     /// <p>The resource type is not valid. For example, if you are attempting to tag an instance, the instance must be a registered, managed instance.</p>
     InvalidResourceType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComplianceItemsError {
@@ -12224,30 +13115,37 @@ impl ListComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceItemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        ListComplianceItemsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceItemsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceItemsError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceItemsError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListComplianceItemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12262,12 +13160,14 @@ impl fmt::Display for ListComplianceItemsError {
             ListComplianceItemsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListComplianceItemsError::InvalidResourceId(ref cause) => write!(f, "{}", cause),
             ListComplianceItemsError::InvalidResourceType(ref cause) => write!(f, "{}", cause),
+            ListComplianceItemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComplianceItemsError {}
 /// Errors returned by ListComplianceSummaries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComplianceSummariesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12275,6 +13175,8 @@ pub enum ListComplianceSummariesError {
     InvalidFilter(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComplianceSummariesError {
@@ -12282,22 +13184,25 @@ impl ListComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceSummariesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceSummariesError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceSummariesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListComplianceSummariesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12310,12 +13215,14 @@ impl fmt::Display for ListComplianceSummariesError {
             ListComplianceSummariesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListComplianceSummariesError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListComplianceSummariesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListComplianceSummariesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComplianceSummariesError {}
 /// Errors returned by ListDocumentVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDocumentVersionsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12323,6 +13230,8 @@ pub enum ListDocumentVersionsError {
     InvalidDocument(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDocumentVersionsError {
@@ -12330,22 +13239,25 @@ impl ListDocumentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentVersionsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentVersionsError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentVersionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDocumentVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12358,12 +13270,14 @@ impl fmt::Display for ListDocumentVersionsError {
             ListDocumentVersionsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListDocumentVersionsError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             ListDocumentVersionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDocumentVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDocumentVersionsError {}
 /// Errors returned by ListDocuments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDocumentsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12371,6 +13285,8 @@ pub enum ListDocumentsError {
     InvalidFilterKey(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDocumentsError {
@@ -12378,16 +13294,25 @@ impl ListDocumentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListDocumentsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        ListDocumentsError::InvalidFilterKey(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDocumentsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDocumentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12400,12 +13325,14 @@ impl fmt::Display for ListDocumentsError {
             ListDocumentsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListDocumentsError::InvalidFilterKey(ref cause) => write!(f, "{}", cause),
             ListDocumentsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDocumentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDocumentsError {}
 /// Errors returned by ListInventoryEntries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInventoryEntriesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12417,6 +13344,8 @@ pub enum ListInventoryEntriesError {
     InvalidNextToken(String),
     /// <p>The parameter type name is not valid.</p>
     InvalidTypeName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInventoryEntriesError {
@@ -12424,30 +13353,37 @@ impl ListInventoryEntriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInventoryEntriesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        ListInventoryEntriesError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInventoryEntriesError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInventoryEntriesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidTypeName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListInventoryEntriesError::InvalidTypeName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInventoryEntriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12462,12 +13398,14 @@ impl fmt::Display for ListInventoryEntriesError {
             ListInventoryEntriesError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             ListInventoryEntriesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListInventoryEntriesError::InvalidTypeName(ref cause) => write!(f, "{}", cause),
+            ListInventoryEntriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInventoryEntriesError {}
 /// Errors returned by ListResourceComplianceSummaries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceComplianceSummariesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12475,6 +13413,8 @@ pub enum ListResourceComplianceSummariesError {
     InvalidFilter(String),
     /// <p>The specified token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceComplianceSummariesError {
@@ -12484,22 +13424,30 @@ impl ListResourceComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceComplianceSummariesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceComplianceSummariesError::InvalidFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceComplianceSummariesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListResourceComplianceSummariesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12518,12 +13466,16 @@ impl fmt::Display for ListResourceComplianceSummariesError {
             ListResourceComplianceSummariesError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListResourceComplianceSummariesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListResourceComplianceSummariesError {}
 /// Errors returned by ListResourceDataSync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceDataSyncError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12531,6 +13483,8 @@ pub enum ListResourceDataSyncError {
     InvalidNextToken(String),
     /// <p>The specified sync configuration is invalid.</p>
     ResourceDataSyncInvalidConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceDataSyncError {
@@ -12538,22 +13492,25 @@ impl ListResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceDataSyncError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceDataSyncError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourceDataSyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12568,12 +13525,14 @@ impl fmt::Display for ListResourceDataSyncError {
             ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListResourceDataSyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceDataSyncError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -12581,6 +13540,8 @@ pub enum ListTagsForResourceError {
     InvalidResourceId(String),
     /// <p>The resource type is not valid. For example, if you are attempting to tag an instance, the instance must be a registered, managed instance.</p>
     InvalidResourceType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -12588,22 +13549,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12616,12 +13580,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidResourceId(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidResourceType(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyDocumentPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDocumentPermissionError {
     /// <p>You can have at most 500 active Systems Manager documents.</p>
     DocumentLimitExceeded(String),
@@ -12633,6 +13599,8 @@ pub enum ModifyDocumentPermissionError {
     InvalidDocument(String),
     /// <p>The permission type is not supported. <i>Share</i> is the only supported permission type.</p>
     InvalidPermissionType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDocumentPermissionError {
@@ -12640,32 +13608,37 @@ impl ModifyDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyDocumentPermissionError::DocumentLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "DocumentPermissionLimit" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyDocumentPermissionError::DocumentPermissionLimit(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyDocumentPermissionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ModifyDocumentPermissionError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyDocumentPermissionError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyDocumentPermissionError::InvalidPermissionType(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyDocumentPermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12686,12 +13659,14 @@ impl fmt::Display for ModifyDocumentPermissionError {
             ModifyDocumentPermissionError::InvalidPermissionType(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDocumentPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDocumentPermissionError {}
 /// Errors returned by PutComplianceItems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutComplianceItemsError {
     /// <p>You specified too many custom compliance types. You can specify a maximum of 10 different types. </p>
     ComplianceTypeCountLimitExceeded(String),
@@ -12707,6 +13682,8 @@ pub enum PutComplianceItemsError {
     ItemSizeLimitExceeded(String),
     /// <p>The size of inventory data has exceeded the total size limit for the resource.</p>
     TotalSizeLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutComplianceItemsError {
@@ -12714,42 +13691,49 @@ impl PutComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ComplianceTypeCountLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutComplianceItemsError::ComplianceTypeCountLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutComplianceItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidItemContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::InvalidItemContent(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::ItemSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::ItemSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::TotalSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutComplianceItemsError::TotalSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutComplianceItemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12768,12 +13752,14 @@ impl fmt::Display for PutComplianceItemsError {
             PutComplianceItemsError::InvalidResourceType(ref cause) => write!(f, "{}", cause),
             PutComplianceItemsError::ItemSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             PutComplianceItemsError::TotalSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
+            PutComplianceItemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutComplianceItemsError {}
 /// Errors returned by PutInventory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutInventoryError {
     /// <p>You have exceeded the limit for custom schemas. Delete one or more custom schemas and try again.</p>
     CustomSchemaCountLimitExceeded(String),
@@ -12799,6 +13785,8 @@ pub enum PutInventoryError {
     UnsupportedInventoryItemContext(String),
     /// <p>Inventory item type schema version has to match supported versions in the service. Check output of GetInventorySchema to see the available schema version for each type.</p>
     UnsupportedInventorySchemaVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutInventoryError {
@@ -12806,53 +13794,76 @@ impl PutInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomSchemaCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::CustomSchemaCountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInventoryError::CustomSchemaCountLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInventoryItemContextException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInventoryItemContext(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInventoryError::InvalidInventoryItemContext(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidItemContent(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::InvalidItemContent(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(PutInventoryError::InvalidTypeName(err.msg), &res)
                 }
                 "ItemContentMismatchException" => {
-                    return RusotoError::Service(PutInventoryError::ItemContentMismatch(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::ItemContentMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::ItemSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::ItemSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "SubTypeCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::SubTypeCountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInventoryError::SubTypeCountLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::TotalSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutInventoryError::TotalSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedInventoryItemContextException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInventoryError::UnsupportedInventoryItemContext(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedInventorySchemaVersionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInventoryError::UnsupportedInventorySchemaVersion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutInventoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12876,12 +13887,14 @@ impl fmt::Display for PutInventoryError {
             PutInventoryError::UnsupportedInventorySchemaVersion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutInventoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutInventoryError {}
 /// Errors returned by PutParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutParameterError {
     /// <p>A hierarchy can have a maximum of 15 levels. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-parameter-name-constraints.html">Requirements and Constraints for Parameter Names</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     HierarchyLevelLimitExceeded(String),
@@ -12913,6 +13926,8 @@ pub enum PutParameterError {
     TooManyUpdates(String),
     /// <p>The parameter type is not supported.</p>
     UnsupportedParameterType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutParameterError {
@@ -12920,60 +13935,91 @@ impl PutParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "HierarchyLevelLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyLevelLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutParameterError::HierarchyLevelLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "HierarchyTypeMismatchException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyTypeMismatch(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::HierarchyTypeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatiblePolicyException" => {
-                    return RusotoError::Service(PutParameterError::IncompatiblePolicy(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::IncompatiblePolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAllowedPatternException" => {
-                    return RusotoError::Service(PutParameterError::InvalidAllowedPattern(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::InvalidAllowedPattern(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(PutParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service(PutParameterError::InvalidKeyId(err.msg), &res)
                 }
                 "InvalidPolicyAttributeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyAttribute(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::InvalidPolicyAttribute(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPolicyTypeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyType(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::InvalidPolicyType(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterAlreadyExists" => {
-                    return RusotoError::Service(PutParameterError::ParameterAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::ParameterAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterLimitExceeded" => {
-                    return RusotoError::Service(PutParameterError::ParameterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::ParameterLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterMaxVersionLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutParameterError::ParameterMaxVersionLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ParameterPatternMismatchException" => {
-                    return RusotoError::Service(PutParameterError::ParameterPatternMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutParameterError::ParameterPatternMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "PoliciesLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::PoliciesLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutParameterError::PoliciesLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(PutParameterError::TooManyUpdates(err.msg))
+                    return RusotoError::service(PutParameterError::TooManyUpdates(err.msg), &res)
                 }
                 "UnsupportedParameterType" => {
-                    return RusotoError::Service(PutParameterError::UnsupportedParameterType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutParameterError::UnsupportedParameterType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13000,12 +14046,14 @@ impl fmt::Display for PutParameterError {
             PutParameterError::PoliciesLimitExceeded(ref cause) => write!(f, "{}", cause),
             PutParameterError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
             PutParameterError::UnsupportedParameterType(ref cause) => write!(f, "{}", cause),
+            PutParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutParameterError {}
 /// Errors returned by RegisterDefaultPatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterDefaultPatchBaselineError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -13013,6 +14061,8 @@ pub enum RegisterDefaultPatchBaselineError {
     InternalServerError(String),
     /// <p>The resource ID is not valid. Verify that you entered the correct ID and try again.</p>
     InvalidResourceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterDefaultPatchBaselineError {
@@ -13022,22 +14072,30 @@ impl RegisterDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(RegisterDefaultPatchBaselineError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterDefaultPatchBaselineError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterDefaultPatchBaselineError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterDefaultPatchBaselineError::InvalidResourceId(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterDefaultPatchBaselineError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13054,12 +14112,14 @@ impl fmt::Display for RegisterDefaultPatchBaselineError {
             RegisterDefaultPatchBaselineError::InvalidResourceId(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterDefaultPatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterDefaultPatchBaselineError {}
 /// Errors returned by RegisterPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterPatchBaselineForPatchGroupError {
     /// <p>Error returned if an attempt is made to register a patch group with a patch baseline that is already registered with a different patch baseline.</p>
     AlreadyExists(String),
@@ -13071,6 +14131,8 @@ pub enum RegisterPatchBaselineForPatchGroupError {
     InvalidResourceId(String),
     /// <p>Error returned when the caller has exceeded the default resource quotas. For example, too many maintenance windows or patch baselines have been created.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterPatchBaselineForPatchGroupError {
@@ -13080,32 +14142,42 @@ impl RegisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterPatchBaselineForPatchGroupError::AlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterPatchBaselineForPatchGroupError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterPatchBaselineForPatchGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13130,12 +14202,16 @@ impl fmt::Display for RegisterPatchBaselineForPatchGroupError {
             RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterPatchBaselineForPatchGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterPatchBaselineForPatchGroupError {}
 /// Errors returned by RegisterTargetWithMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTargetWithMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -13145,6 +14221,8 @@ pub enum RegisterTargetWithMaintenanceWindowError {
     InternalServerError(String),
     /// <p>Error returned when the caller has exceeded the default resource quotas. For example, too many maintenance windows or patch baselines have been created.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTargetWithMaintenanceWindowError {
@@ -13154,29 +14232,38 @@ impl RegisterTargetWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTargetWithMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTargetWithMaintenanceWindowError::IdempotentParameterMismatch(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTargetWithMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterTargetWithMaintenanceWindowError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13198,12 +14285,16 @@ impl fmt::Display for RegisterTargetWithMaintenanceWindowError {
             RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterTargetWithMaintenanceWindowError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterTargetWithMaintenanceWindowError {}
 /// Errors returned by RegisterTaskWithMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTaskWithMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
@@ -13215,6 +14306,8 @@ pub enum RegisterTaskWithMaintenanceWindowError {
     InternalServerError(String),
     /// <p>Error returned when the caller has exceeded the default resource quotas. For example, too many maintenance windows or patch baselines have been created.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     ResourceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTaskWithMaintenanceWindowError {
@@ -13224,34 +14317,44 @@ impl RegisterTaskWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTaskWithMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "FeatureNotAvailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTaskWithMaintenanceWindowError::FeatureNotAvailable(err.msg),
+                        &res,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTaskWithMaintenanceWindowError::IdempotentParameterMismatch(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTaskWithMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterTaskWithMaintenanceWindowError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13276,12 +14379,16 @@ impl fmt::Display for RegisterTaskWithMaintenanceWindowError {
             RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterTaskWithMaintenanceWindowError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterTaskWithMaintenanceWindowError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -13291,6 +14398,8 @@ pub enum RemoveTagsFromResourceError {
     InvalidResourceType(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -13298,27 +14407,31 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InvalidResourceId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InvalidResourceType(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13332,12 +14445,14 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::InvalidResourceId(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::InvalidResourceType(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetServiceSetting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetServiceSettingError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -13345,6 +14460,8 @@ pub enum ResetServiceSettingError {
     ServiceSettingNotFound(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetServiceSettingError {
@@ -13352,20 +14469,25 @@ impl ResetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResetServiceSettingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(ResetServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResetServiceSettingError::ServiceSettingNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(ResetServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        ResetServiceSettingError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetServiceSettingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13378,17 +14500,21 @@ impl fmt::Display for ResetServiceSettingError {
             ResetServiceSettingError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ResetServiceSettingError::ServiceSettingNotFound(ref cause) => write!(f, "{}", cause),
             ResetServiceSettingError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            ResetServiceSettingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetServiceSettingError {}
 /// Errors returned by ResumeSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResumeSessionError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResumeSessionError {
@@ -13396,13 +14522,16 @@ impl ResumeSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(ResumeSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service(ResumeSessionError::DoesNotExist(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResumeSessionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ResumeSessionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResumeSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13414,12 +14543,14 @@ impl fmt::Display for ResumeSessionError {
         match *self {
             ResumeSessionError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             ResumeSessionError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ResumeSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResumeSessionError {}
 /// Errors returned by SendAutomationSignal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendAutomationSignalError {
     /// <p>There is no automation execution information for the requested automation execution ID.</p>
     AutomationExecutionNotFound(String),
@@ -13429,6 +14560,8 @@ pub enum SendAutomationSignalError {
     InternalServerError(String),
     /// <p>The signal is not valid for the current Automation execution.</p>
     InvalidAutomationSignal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendAutomationSignalError {
@@ -13436,27 +14569,31 @@ impl SendAutomationSignalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SendAutomationSignalError::AutomationExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "AutomationStepNotFoundException" => {
-                    return RusotoError::Service(SendAutomationSignalError::AutomationStepNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAutomationSignalError::AutomationStepNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendAutomationSignalError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendAutomationSignalError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAutomationSignalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SendAutomationSignalError::InvalidAutomationSignal(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendAutomationSignalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13472,12 +14609,14 @@ impl fmt::Display for SendAutomationSignalError {
             SendAutomationSignalError::AutomationStepNotFound(ref cause) => write!(f, "{}", cause),
             SendAutomationSignalError::InternalServerError(ref cause) => write!(f, "{}", cause),
             SendAutomationSignalError::InvalidAutomationSignal(ref cause) => write!(f, "{}", cause),
+            SendAutomationSignalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendAutomationSignalError {}
 /// Errors returned by SendCommand
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendCommandError {
     /// <p>You cannot specify an instance ID in more than one association.</p>
     DuplicateInstanceId(String),
@@ -13501,6 +14640,8 @@ pub enum SendCommandError {
     MaxDocumentSizeExceeded(String),
     /// <p>The document does not support the platform type of the given instance ID(s). For example, you sent an document for a Windows instance to a Linux instance.</p>
     UnsupportedPlatformType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendCommandError {
@@ -13508,42 +14649,61 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(SendCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::DuplicateInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendCommandError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocument(err.msg))
+                    return RusotoError::service(SendCommandError::InvalidDocument(err.msg), &res)
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(SendCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(SendCommandError::InvalidInstanceId(err.msg), &res)
                 }
                 "InvalidNotificationConfig" => {
-                    return RusotoError::Service(SendCommandError::InvalidNotificationConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendCommandError::InvalidNotificationConfig(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOutputFolder" => {
-                    return RusotoError::Service(SendCommandError::InvalidOutputFolder(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::InvalidOutputFolder(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(SendCommandError::InvalidParameters(err.msg))
+                    return RusotoError::service(SendCommandError::InvalidParameters(err.msg), &res)
                 }
                 "InvalidRole" => {
-                    return RusotoError::Service(SendCommandError::InvalidRole(err.msg))
+                    return RusotoError::service(SendCommandError::InvalidRole(err.msg), &res)
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(SendCommandError::MaxDocumentSizeExceeded(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::MaxDocumentSizeExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(SendCommandError::UnsupportedPlatformType(err.msg))
+                    return RusotoError::service(
+                        SendCommandError::UnsupportedPlatformType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendCommandError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13564,17 +14724,21 @@ impl fmt::Display for SendCommandError {
             SendCommandError::InvalidRole(ref cause) => write!(f, "{}", cause),
             SendCommandError::MaxDocumentSizeExceeded(ref cause) => write!(f, "{}", cause),
             SendCommandError::UnsupportedPlatformType(ref cause) => write!(f, "{}", cause),
+            SendCommandError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendCommandError {}
 /// Errors returned by StartAssociationsOnce
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAssociationsOnceError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
     /// <p>The association is not valid or does not exist. </p>
     InvalidAssociation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAssociationsOnceError {
@@ -13582,17 +14746,19 @@ impl StartAssociationsOnceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAssociationsOnceError::AssociationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAssociation" => {
-                    return RusotoError::Service(StartAssociationsOnceError::InvalidAssociation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAssociationsOnceError::InvalidAssociation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartAssociationsOnceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13606,12 +14772,14 @@ impl fmt::Display for StartAssociationsOnceError {
                 write!(f, "{}", cause)
             }
             StartAssociationsOnceError::InvalidAssociation(ref cause) => write!(f, "{}", cause),
+            StartAssociationsOnceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAssociationsOnceError {}
 /// Errors returned by StartAutomationExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAutomationExecutionError {
     /// <p>An Automation document with the specified name could not be found.</p>
     AutomationDefinitionNotFound(String),
@@ -13627,6 +14795,8 @@ pub enum StartAutomationExecutionError {
     InvalidAutomationExecutionParameters(String),
     /// <p>The target is not valid or does not exist. It might not be configured for EC2 Systems Manager or you might not have permission to perform the operation.</p>
     InvalidTarget(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAutomationExecutionError {
@@ -13634,44 +14804,51 @@ impl StartAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationDefinitionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::AutomationDefinitionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "AutomationDefinitionVersionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::AutomationDefinitionVersionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "AutomationExecutionLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::AutomationExecutionLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAutomationExecutionParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAutomationExecutionError::InvalidAutomationExecutionParameters(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(StartAutomationExecutionError::InvalidTarget(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartAutomationExecutionError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartAutomationExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13698,12 +14875,14 @@ impl fmt::Display for StartAutomationExecutionError {
                 write!(f, "{}", cause)
             }
             StartAutomationExecutionError::InvalidTarget(ref cause) => write!(f, "{}", cause),
+            StartAutomationExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAutomationExecutionError {}
 /// Errors returned by StartSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSessionError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -13711,6 +14890,8 @@ pub enum StartSessionError {
     InvalidDocument(String),
     /// <p>The specified target instance for the session is not fully configured for use with Session Manager. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-getting-started.html">Getting Started with Session Manager</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     TargetNotConnected(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSessionError {
@@ -13718,16 +14899,22 @@ impl StartSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartSessionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartSessionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(StartSessionError::InvalidDocument(err.msg))
+                    return RusotoError::service(StartSessionError::InvalidDocument(err.msg), &res)
                 }
                 "TargetNotConnected" => {
-                    return RusotoError::Service(StartSessionError::TargetNotConnected(err.msg))
+                    return RusotoError::service(
+                        StartSessionError::TargetNotConnected(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13740,12 +14927,14 @@ impl fmt::Display for StartSessionError {
             StartSessionError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StartSessionError::InvalidDocument(ref cause) => write!(f, "{}", cause),
             StartSessionError::TargetNotConnected(ref cause) => write!(f, "{}", cause),
+            StartSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSessionError {}
 /// Errors returned by StopAutomationExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopAutomationExecutionError {
     /// <p>There is no automation execution information for the requested automation execution ID.</p>
     AutomationExecutionNotFound(String),
@@ -13753,6 +14942,8 @@ pub enum StopAutomationExecutionError {
     InternalServerError(String),
     /// <p>The specified update status operation is not valid.</p>
     InvalidAutomationStatusUpdate(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopAutomationExecutionError {
@@ -13760,22 +14951,25 @@ impl StopAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopAutomationExecutionError::AutomationExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAutomationExecutionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopAutomationExecutionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAutomationStatusUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopAutomationExecutionError::InvalidAutomationStatusUpdate(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopAutomationExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13792,17 +14986,21 @@ impl fmt::Display for StopAutomationExecutionError {
             StopAutomationExecutionError::InvalidAutomationStatusUpdate(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopAutomationExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopAutomationExecutionError {}
 /// Errors returned by TerminateSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateSessionError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateSessionError {
@@ -13810,15 +15008,16 @@ impl TerminateSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(TerminateSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service(TerminateSessionError::DoesNotExist(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateSessionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TerminateSessionError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TerminateSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13830,12 +15029,14 @@ impl fmt::Display for TerminateSessionError {
         match *self {
             TerminateSessionError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             TerminateSessionError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            TerminateSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateSessionError {}
 /// Errors returned by UpdateAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAssociationError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -13861,6 +15062,8 @@ pub enum UpdateAssociationError {
     InvalidUpdate(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAssociationError {
@@ -13868,55 +15071,79 @@ impl UpdateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(UpdateAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationError::AssociationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "AssociationVersionLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAssociationError::AssociationVersionLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidAssociationVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidAssociationVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidOutputLocation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidOutputLocation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidParameters(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidSchedule(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUpdate" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidUpdate(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::InvalidUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        UpdateAssociationError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13940,12 +15167,14 @@ impl fmt::Display for UpdateAssociationError {
             UpdateAssociationError::InvalidTarget(ref cause) => write!(f, "{}", cause),
             UpdateAssociationError::InvalidUpdate(ref cause) => write!(f, "{}", cause),
             UpdateAssociationError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            UpdateAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAssociationError {}
 /// Errors returned by UpdateAssociationStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAssociationStatusError {
     /// <p>The specified association does not exist.</p>
     AssociationDoesNotExist(String),
@@ -13959,6 +15188,8 @@ pub enum UpdateAssociationStatusError {
     StatusUnchanged(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAssociationStatusError {
@@ -13966,37 +15197,43 @@ impl UpdateAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAssociationStatusError::AssociationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationStatusError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationStatusError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationStatusError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "StatusUnchanged" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::StatusUnchanged(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationStatusError::StatusUnchanged(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAssociationStatusError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAssociationStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14014,12 +15251,14 @@ impl fmt::Display for UpdateAssociationStatusError {
             UpdateAssociationStatusError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
             UpdateAssociationStatusError::StatusUnchanged(ref cause) => write!(f, "{}", cause),
             UpdateAssociationStatusError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            UpdateAssociationStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAssociationStatusError {}
 /// Errors returned by UpdateDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDocumentError {
     /// <p>The document has too many versions. Delete one or more document versions and try again.</p>
     DocumentVersionLimitExceeded(String),
@@ -14041,6 +15280,8 @@ pub enum UpdateDocumentError {
     InvalidDocumentVersion(String),
     /// <p>The size limit of a document is 64 KB.</p>
     MaxDocumentSizeExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDocumentError {
@@ -14048,53 +15289,67 @@ impl UpdateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentVersionLimitExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::DocumentVersionLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::DocumentVersionLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::DuplicateDocumentContent(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateDocumentVersionName" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentVersionName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::DuplicateDocumentVersionName(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentError::InvalidDocument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::InvalidDocumentContent(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::InvalidDocumentOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentSchemaVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::InvalidDocumentSchemaVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::InvalidDocumentVersion(err.msg),
+                        &res,
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::MaxDocumentSizeExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentError::MaxDocumentSizeExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14114,12 +15369,14 @@ impl fmt::Display for UpdateDocumentError {
             UpdateDocumentError::InvalidDocumentSchemaVersion(ref cause) => write!(f, "{}", cause),
             UpdateDocumentError::InvalidDocumentVersion(ref cause) => write!(f, "{}", cause),
             UpdateDocumentError::MaxDocumentSizeExceeded(ref cause) => write!(f, "{}", cause),
+            UpdateDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDocumentError {}
 /// Errors returned by UpdateDocumentDefaultVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDocumentDefaultVersionError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -14129,6 +15386,8 @@ pub enum UpdateDocumentDefaultVersionError {
     InvalidDocumentSchemaVersion(String),
     /// <p>The document version is not valid or does not exist.</p>
     InvalidDocumentVersion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDocumentDefaultVersionError {
@@ -14138,27 +15397,36 @@ impl UpdateDocumentDefaultVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDocumentDefaultVersionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDocumentDefaultVersionError::InvalidDocument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDocumentDefaultVersionError::InvalidDocumentSchemaVersion(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDocumentDefaultVersionError::InvalidDocumentVersion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDocumentDefaultVersionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -14178,17 +15446,21 @@ impl fmt::Display for UpdateDocumentDefaultVersionError {
             UpdateDocumentDefaultVersionError::InvalidDocumentVersion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDocumentDefaultVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDocumentDefaultVersionError {}
 /// Errors returned by UpdateMaintenanceWindow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMaintenanceWindowError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMaintenanceWindowError {
@@ -14196,17 +15468,19 @@ impl UpdateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMaintenanceWindowError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMaintenanceWindowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMaintenanceWindowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14218,17 +15492,21 @@ impl fmt::Display for UpdateMaintenanceWindowError {
         match *self {
             UpdateMaintenanceWindowError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateMaintenanceWindowError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdateMaintenanceWindowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMaintenanceWindowError {}
 /// Errors returned by UpdateMaintenanceWindowTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMaintenanceWindowTargetError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMaintenanceWindowTargetError {
@@ -14238,17 +15516,24 @@ impl UpdateMaintenanceWindowTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTargetError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMaintenanceWindowTargetError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMaintenanceWindowTargetError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateMaintenanceWindowTargetError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -14262,17 +15547,23 @@ impl fmt::Display for UpdateMaintenanceWindowTargetError {
             UpdateMaintenanceWindowTargetError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateMaintenanceWindowTargetError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateMaintenanceWindowTargetError {}
 /// Errors returned by UpdateMaintenanceWindowTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMaintenanceWindowTaskError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMaintenanceWindowTaskError {
@@ -14282,17 +15573,24 @@ impl UpdateMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTaskError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMaintenanceWindowTaskError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMaintenanceWindowTaskError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateMaintenanceWindowTaskError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -14306,17 +15604,21 @@ impl fmt::Display for UpdateMaintenanceWindowTaskError {
             UpdateMaintenanceWindowTaskError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateMaintenanceWindowTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMaintenanceWindowTaskError {}
 /// Errors returned by UpdateManagedInstanceRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateManagedInstanceRoleError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The following problems can cause this exception:</p> <p>You do not have permission to access the instance.</p> <p>SSM Agent is not running. Verify that SSM Agent is running.</p> <p>SSM Agent is not registered with the SSM endpoint. Try reinstalling SSM Agent.</p> <p>The instance is not in valid state. Valid states are: Running, Pending, Stopped, Stopping. Invalid states are: Shutting-down and Terminated.</p>
     InvalidInstanceId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateManagedInstanceRoleError {
@@ -14324,17 +15626,21 @@ impl UpdateManagedInstanceRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateManagedInstanceRoleError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateManagedInstanceRoleError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateManagedInstanceRoleError::InvalidInstanceId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateManagedInstanceRoleError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -14348,12 +15654,14 @@ impl fmt::Display for UpdateManagedInstanceRoleError {
                 write!(f, "{}", cause)
             }
             UpdateManagedInstanceRoleError::InvalidInstanceId(ref cause) => write!(f, "{}", cause),
+            UpdateManagedInstanceRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateManagedInstanceRoleError {}
 /// Errors returned by UpdateOpsItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateOpsItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -14365,6 +15673,8 @@ pub enum UpdateOpsItemError {
     OpsItemLimitExceeded(String),
     /// <p>The specified OpsItem ID doesn't exist. Verify the ID and try again.</p>
     OpsItemNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateOpsItemError {
@@ -14372,24 +15682,34 @@ impl UpdateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateOpsItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        UpdateOpsItemError::OpsItemAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateOpsItemError::OpsItemInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateOpsItemError::OpsItemLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service(UpdateOpsItemError::OpsItemNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateOpsItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14404,17 +15724,21 @@ impl fmt::Display for UpdateOpsItemError {
             UpdateOpsItemError::OpsItemInvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateOpsItemError::OpsItemLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateOpsItemError::OpsItemNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateOpsItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateOpsItemError {}
 /// Errors returned by UpdatePatchBaseline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePatchBaselineError {
     /// <p>Error returned when the ID specified for a resource, such as a maintenance window or Patch baseline, doesn't exist.</p> <p>For information about resource quotas in Systems Manager, see <a href="http://docs.aws.amazon.com/general/latest/gr/ssm.html#limits_ssm">Systems Manager Service Quotas</a> in the <i>AWS General Reference</i>.</p>
     DoesNotExist(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePatchBaselineError {
@@ -14422,15 +15746,19 @@ impl UpdatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UpdatePatchBaselineError::DoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePatchBaselineError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePatchBaselineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14442,12 +15770,14 @@ impl fmt::Display for UpdatePatchBaselineError {
         match *self {
             UpdatePatchBaselineError::DoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdatePatchBaselineError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UpdatePatchBaselineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePatchBaselineError {}
 /// Errors returned by UpdateResourceDataSync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceDataSyncError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -14457,6 +15787,8 @@ pub enum UpdateResourceDataSyncError {
     ResourceDataSyncInvalidConfiguration(String),
     /// <p>The specified sync name was not found.</p>
     ResourceDataSyncNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceDataSyncError {
@@ -14464,27 +15796,31 @@ impl UpdateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceDataSyncError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDataSyncConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateResourceDataSyncError::ResourceDataSyncConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceDataSyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14504,12 +15840,14 @@ impl fmt::Display for UpdateResourceDataSyncError {
             UpdateResourceDataSyncError::ResourceDataSyncNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateResourceDataSyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResourceDataSyncError {}
 /// Errors returned by UpdateServiceSetting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceSettingError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -14517,6 +15855,8 @@ pub enum UpdateServiceSettingError {
     ServiceSettingNotFound(String),
     /// <p>There are concurrent updates for a resource that supports one update at a time.</p>
     TooManyUpdates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceSettingError {
@@ -14524,20 +15864,25 @@ impl UpdateServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceSettingError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(UpdateServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServiceSettingError::ServiceSettingNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceSettingError::TooManyUpdates(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServiceSettingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14550,6 +15895,7 @@ impl fmt::Display for UpdateServiceSettingError {
             UpdateServiceSettingError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateServiceSettingError::ServiceSettingNotFound(ref cause) => write!(f, "{}", cause),
             UpdateServiceSettingError::TooManyUpdates(ref cause) => write!(f, "{}", cause),
+            UpdateServiceSettingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -15372,6 +16718,7 @@ impl SsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SsmClient {
         SsmClient {
             client: Client::shared(),
@@ -15397,600 +16744,1225 @@ impl SsmClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SsmClient {
         SsmClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SsmClient> {
+        rusoto_core::ClientBuilder::new(SsmClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SsmClient {
+        SsmClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ssm for SsmClient {
-    /// <p>Adds or overwrites one or more tags for the specified resource. Tags are metadata that you can assign to your documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. Each tag consists of a key and an optional value, both of which you define. For example, you could define a set of tags for your account's managed instances that helps you track each instance's owner and stack level. For example: Key=Owner and Value=DbAdmin, SysAdmin, or Dev. Or Key=Stack and Value=Production, Pre-Production, or Test.</p> <p>Each resource can have a maximum of 50 tags. </p> <p>We recommend that you devise a set of tag keys that meets your needs for each resource type. Using a consistent set of tag keys makes it easier for you to manage your resources. You can search and filter the resources based on the tags you add. Tags don't have any semantic meaning to Amazon EC2 and are interpreted strictly as a string of characters. </p> <p>For more information about tags, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon EC2 User Guide</i>.</p>
-    async fn add_tags_to_resource(
+impl SsmClient {
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
         &self,
         input: AddTagsToResourceRequest,
-    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(AddTagsToResourceResult, ResponseMetadata), RusotoError<AddTagsToResourceError>>
+    {
+        let request = self.build_add_tags_to_resource_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddTagsToResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AddTagsToResourceError::from_response(response))
         }
     }
 
-    /// <p>Attempts to cancel the command specified by the Command ID. There is no guarantee that the command will be terminated and the underlying process stopped.</p>
-    async fn cancel_command(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
-        input: CancelCommandRequest,
-    ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
+        request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_command`, also returning the metadata of the response.
+    pub async fn cancel_command_with_metadata(
+        &self,
+        input: CancelCommandRequest,
+    ) -> Result<(CancelCommandResult, ResponseMetadata), RusotoError<CancelCommandError>> {
+        let request = self.build_cancel_command_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelCommandResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelCommandResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CancelCommandError::from_response(response))
         }
     }
 
-    /// <p>Stops a maintenance window execution that is already in progress and cancels any tasks in the window that have not already starting running. (Tasks already in progress will continue to completion.)</p>
-    async fn cancel_maintenance_window_execution(
+    /// Presigns the request `cancel_command` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_command(
         &self,
-        input: CancelMaintenanceWindowExecutionRequest,
-    ) -> Result<
-        CancelMaintenanceWindowExecutionResult,
-        RusotoError<CancelMaintenanceWindowExecutionError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: CancelCommandRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_command_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_command_request(&self, input: CancelCommandRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelCommand");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
+        request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_maintenance_window_execution`, also returning the metadata of the response.
+    pub async fn cancel_maintenance_window_execution_with_metadata(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> Result<
+        (CancelMaintenanceWindowExecutionResult, ResponseMetadata),
+        RusotoError<CancelMaintenanceWindowExecutionError>,
+    > {
+        let request = self.build_cancel_maintenance_window_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelMaintenanceWindowExecutionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelMaintenanceWindowExecutionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CancelMaintenanceWindowExecutionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Generates an activation code and activation ID you can use to register your on-premises server or virtual machine (VM) with Systems Manager. Registering these machines with Systems Manager makes it possible to manage them using Systems Manager capabilities. You use the activation code and ID when installing SSM Agent on machines in your hybrid environment. For more information about requirements for managing on-premises instances and VMs using Systems Manager, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-managedinstances.html">Setting Up AWS Systems Manager for Hybrid Environments</a> in the <i>AWS Systems Manager User Guide</i>. </p> <note> <p>On-premises servers or VMs that are registered with Systems Manager and Amazon EC2 instances that you manage with Systems Manager are all called <i>managed instances</i>.</p> </note></p>
-    async fn create_activation(
+    /// Presigns the request `cancel_maintenance_window_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_maintenance_window_execution(
         &self,
-        input: CreateActivationRequest,
-    ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_maintenance_window_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_maintenance_window_execution_request(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelMaintenanceWindowExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
+        request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_activation`, also returning the metadata of the response.
+    pub async fn create_activation_with_metadata(
+        &self,
+        input: CreateActivationRequest,
+    ) -> Result<(CreateActivationResult, ResponseMetadata), RusotoError<CreateActivationError>>
+    {
+        let request = self.build_create_activation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateActivationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateActivationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateActivationError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
-    async fn create_association(
+    /// Presigns the request `create_activation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_activation(
         &self,
-        input: CreateAssociationRequest,
-    ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateActivationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_activation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_activation_request(&self, input: CreateActivationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateActivation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
+        request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_association`, also returning the metadata of the response.
+    pub async fn create_association_with_metadata(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> Result<(CreateAssociationResult, ResponseMetadata), RusotoError<CreateAssociationError>>
+    {
+        let request = self.build_create_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateAssociationError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
-    async fn create_association_batch(
+    /// Presigns the request `create_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_association(
         &self,
-        input: CreateAssociationBatchRequest,
-    ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateAssociationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_association_request(&self, input: CreateAssociationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
+        request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_association_batch`, also returning the metadata of the response.
+    pub async fn create_association_batch_with_metadata(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> Result<
+        (CreateAssociationBatchResult, ResponseMetadata),
+        RusotoError<CreateAssociationBatchError>,
+    > {
+        let request = self.build_create_association_batch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAssociationBatchResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAssociationBatchResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateAssociationBatchError::from_response(response))
         }
     }
 
-    /// <p>Creates a Systems Manager document.</p> <p>After you create a document, you can use CreateAssociation to associate it with one or more running instances.</p>
-    async fn create_document(
+    /// Presigns the request `create_association_batch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_association_batch(
         &self,
-        input: CreateDocumentRequest,
-    ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateAssociationBatchRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_association_batch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_association_batch_request(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociationBatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
+        request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_document`, also returning the metadata of the response.
+    pub async fn create_document_with_metadata(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> Result<(CreateDocumentResult, ResponseMetadata), RusotoError<CreateDocumentError>> {
+        let request = self.build_create_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDocumentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDocumentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateDocumentError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new maintenance window.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
-    async fn create_maintenance_window(
+    /// Presigns the request `create_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_document(
         &self,
-        input: CreateMaintenanceWindowRequest,
-    ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDocumentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_document_request(&self, input: CreateDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
+        request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_maintenance_window`, also returning the metadata of the response.
+    pub async fn create_maintenance_window_with_metadata(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> Result<
+        (CreateMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<CreateMaintenanceWindowError>,
+    > {
+        let request = self.build_create_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateMaintenanceWindowError::from_response(response))
         }
     }
 
-    /// <p>Creates a new OpsItem. You must have permission in AWS Identity and Access Management (IAM) to create a new OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
-    async fn create_ops_item(
+    /// Presigns the request `create_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_maintenance_window(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateMaintenanceWindowRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_maintenance_window_request(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `create_ops_item`, also returning the metadata of the response.
+    pub async fn create_ops_item_with_metadata(
         &self,
         input: CreateOpsItemRequest,
-    ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
+    ) -> Result<(CreateOpsItemResponse, ResponseMetadata), RusotoError<CreateOpsItemError>> {
+        let request = self.build_create_ops_item_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateOpsItemResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateOpsItemError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_ops_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ops_item(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateOpsItemRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ops_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ops_item_request(&self, input: CreateOpsItemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_patch_baseline`, also returning the metadata of the response.
+    pub async fn create_patch_baseline_with_metadata(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> Result<(CreatePatchBaselineResult, ResponseMetadata), RusotoError<CreatePatchBaselineError>>
+    {
+        let request = self.build_create_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateOpsItemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateOpsItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePatchBaselineError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a patch baseline.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
-    async fn create_patch_baseline(
+    /// Presigns the request `create_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePatchBaselineRequest,
-    ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_patch_baseline_request(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreatePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_resource_data_sync`, also returning the metadata of the response.
+    pub async fn create_resource_data_sync_with_metadata(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> Result<
+        (CreateResourceDataSyncResult, ResponseMetadata),
+        RusotoError<CreateResourceDataSyncError>,
+    > {
+        let request = self.build_create_resource_data_sync_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateResourceDataSyncResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateResourceDataSyncError::from_response(response))
         }
     }
 
-    /// <p><p>A resource data sync helps you view data from multiple sources in a single location. Systems Manager offers two types of resource data sync: <code>SyncToDestination</code> and <code>SyncFromSource</code>.</p> <p>You can configure Systems Manager Inventory to use the <code>SyncToDestination</code> type to synchronize Inventory data from multiple AWS Regions to a single Amazon S3 bucket. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-datasync.html">Configuring Resource Data Sync for Inventory</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>You can configure Systems Manager Explorer to use the <code>SyncToDestination</code> type to synchronize operational work items (OpsItems) and operational data (OpsData) from multiple AWS Regions to a single Amazon S3 bucket. You can also configure Explorer to use the <code>SyncFromSource</code> type. This type synchronizes OpsItems and OpsData from multiple AWS accounts and Regions by using AWS Organizations. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/Explorer-resource-data-sync.html">Setting Up Explorer to Display Data from Multiple Accounts and Regions</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>A resource data sync is an asynchronous operation that returns immediately. After a successful initial sync is completed, the system continuously syncs data. To check the status of a sync, use the <a>ListResourceDataSync</a>.</p> <note> <p>By default, data is not encrypted in Amazon S3. We strongly recommend that you enable encryption in Amazon S3 to ensure secure data storage. We also recommend that you secure access to the Amazon S3 bucket by creating a restrictive bucket policy. </p> </note></p>
-    async fn create_resource_data_sync(
+    /// Presigns the request `create_resource_data_sync` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_data_sync(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceDataSyncRequest,
-    ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_data_sync_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_data_sync_request(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_activation`, also returning the metadata of the response.
+    pub async fn delete_activation_with_metadata(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> Result<(DeleteActivationResult, ResponseMetadata), RusotoError<DeleteActivationError>>
+    {
+        let request = self.build_delete_activation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceDataSyncResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteActivationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceDataSyncError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteActivationError::from_response(response))
         }
     }
 
-    /// <p>Deletes an activation. You are not required to delete an activation. If you delete an activation, you can no longer use it to register additional managed instances. Deleting an activation does not de-register managed instances. You must manually de-register managed instances.</p>
-    async fn delete_activation(
+    /// Presigns the request `delete_activation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_activation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteActivationRequest,
-    ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_activation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_activation_request(&self, input: DeleteActivationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteActivation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_association`, also returning the metadata of the response.
+    pub async fn delete_association_with_metadata(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> Result<(DeleteAssociationResult, ResponseMetadata), RusotoError<DeleteAssociationError>>
+    {
+        let request = self.build_delete_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteActivationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteActivationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAssociationError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified Systems Manager document from the specified instance.</p> <p>When you disassociate a document from an instance, it does not change the configuration of the instance. To change the configuration state of an instance after you disassociate a document, you must create a new document with the desired configuration and associate it with the instance.</p>
-    async fn delete_association(
+    /// Presigns the request `delete_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssociationRequest,
-    ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_association_request(&self, input: DeleteAssociationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_document`, also returning the metadata of the response.
+    pub async fn delete_document_with_metadata(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<(DeleteDocumentResult, ResponseMetadata), RusotoError<DeleteDocumentError>> {
+        let request = self.build_delete_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDocumentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssociationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDocumentError::from_response(response))
         }
     }
 
-    /// <p>Deletes the Systems Manager document and all instance associations to the document.</p> <p>Before you delete the document, we recommend that you use <a>DeleteAssociation</a> to disassociate all instances that are associated with the document.</p>
-    async fn delete_document(
+    /// Presigns the request `delete_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDocumentRequest,
-    ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_document_request(&self, input: DeleteDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_inventory`, also returning the metadata of the response.
+    pub async fn delete_inventory_with_metadata(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> Result<(DeleteInventoryResult, ResponseMetadata), RusotoError<DeleteInventoryError>> {
+        let request = self.build_delete_inventory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDocumentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteInventoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInventoryError::from_response(response))
         }
     }
 
-    /// <p>Delete a custom inventory type, or the data associated with a custom Inventory type. Deleting a custom inventory type is also referred to as deleting a custom inventory schema.</p>
-    async fn delete_inventory(
+    /// Presigns the request `delete_inventory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_inventory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInventoryRequest,
-    ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_inventory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_inventory_request(&self, input: DeleteInventoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_maintenance_window`, also returning the metadata of the response.
+    pub async fn delete_maintenance_window_with_metadata(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> Result<
+        (DeleteMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<DeleteMaintenanceWindowError>,
+    > {
+        let request = self.build_delete_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteInventoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInventoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMaintenanceWindowError::from_response(response))
         }
     }
 
-    /// <p>Deletes a maintenance window.</p>
-    async fn delete_maintenance_window(
+    /// Presigns the request `delete_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMaintenanceWindowRequest,
-    ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_maintenance_window_request(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_parameter`, also returning the metadata of the response.
+    pub async fn delete_parameter_with_metadata(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> Result<(DeleteParameterResult, ResponseMetadata), RusotoError<DeleteParameterError>> {
+        let request = self.build_delete_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteParameterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMaintenanceWindowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteParameterError::from_response(response))
         }
     }
 
-    /// <p>Delete a parameter from the system.</p>
-    async fn delete_parameter(
+    /// Presigns the request `delete_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteParameterRequest,
-    ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_parameter_request(&self, input: DeleteParameterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_parameters`, also returning the metadata of the response.
+    pub async fn delete_parameters_with_metadata(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> Result<(DeleteParametersResult, ResponseMetadata), RusotoError<DeleteParametersError>>
+    {
+        let request = self.build_delete_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteParameterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteParametersError::from_response(response))
         }
     }
 
-    /// <p>Delete a list of parameters.</p>
-    async fn delete_parameters(
+    /// Presigns the request `delete_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteParametersRequest,
-    ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_parameters_request(&self, input: DeleteParametersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_patch_baseline`, also returning the metadata of the response.
+    pub async fn delete_patch_baseline_with_metadata(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> Result<(DeletePatchBaselineResult, ResponseMetadata), RusotoError<DeletePatchBaselineError>>
+    {
+        let request = self.build_delete_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteParametersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePatchBaselineError::from_response(response))
         }
     }
 
-    /// <p>Deletes a patch baseline.</p>
-    async fn delete_patch_baseline(
+    /// Presigns the request `delete_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePatchBaselineRequest,
-    ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_patch_baseline_request(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeletePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_data_sync`, also returning the metadata of the response.
+    pub async fn delete_resource_data_sync_with_metadata(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> Result<
+        (DeleteResourceDataSyncResult, ResponseMetadata),
+        RusotoError<DeleteResourceDataSyncError>,
+    > {
+        let request = self.build_delete_resource_data_sync_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResourceDataSyncResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourceDataSyncError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Resource Data Sync configuration. After the configuration is deleted, changes to data on managed instances are no longer synced to or from the target. Deleting a sync configuration does not delete data.</p>
-    async fn delete_resource_data_sync(
+    /// Presigns the request `delete_resource_data_sync` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_data_sync(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceDataSyncRequest,
-    ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_data_sync_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_data_sync_request(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_managed_instance`, also returning the metadata of the response.
+    pub async fn deregister_managed_instance_with_metadata(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> Result<
+        (DeregisterManagedInstanceResult, ResponseMetadata),
+        RusotoError<DeregisterManagedInstanceError>,
+    > {
+        let request = self.build_deregister_managed_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourceDataSyncResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterManagedInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceDataSyncError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterManagedInstanceError::from_response(response))
         }
     }
 
-    /// <p>Removes the server or virtual machine from the list of registered servers. You can reregister the instance again at any time. If you don't plan to use Run Command on the server, we suggest uninstalling SSM Agent first.</p>
-    async fn deregister_managed_instance(
+    /// Presigns the request `deregister_managed_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_managed_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterManagedInstanceRequest,
-    ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_managed_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_managed_instance_request(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterManagedInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_patch_baseline_for_patch_group`, also returning the metadata of the response.
+    pub async fn deregister_patch_baseline_for_patch_group_with_metadata(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        (DeregisterPatchBaselineForPatchGroupResult, ResponseMetadata),
+        RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+    > {
+        let request = self.build_deregister_patch_baseline_for_patch_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterManagedInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterPatchBaselineForPatchGroupResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterManagedInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterPatchBaselineForPatchGroupError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Removes a patch group from a patch baseline.</p>
-    async fn deregister_patch_baseline_for_patch_group(
+    /// Presigns the request `deregister_patch_baseline_for_patch_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_patch_baseline_for_patch_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterPatchBaselineForPatchGroupRequest,
-    ) -> Result<
-        DeregisterPatchBaselineForPatchGroupResult,
-        RusotoError<DeregisterPatchBaselineForPatchGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_patch_baseline_for_patch_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_patch_baseline_for_patch_group_request(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16000,33 +17972,63 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_target_from_maintenance_window`, also returning the metadata of the response.
+    pub async fn deregister_target_from_maintenance_window_with_metadata(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> Result<
+        (
+            DeregisterTargetFromMaintenanceWindowResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+    > {
+        let request = self.build_deregister_target_from_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterPatchBaselineForPatchGroupResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterTargetFromMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterPatchBaselineForPatchGroupError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterTargetFromMaintenanceWindowError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Removes a target from a maintenance window.</p>
-    async fn deregister_target_from_maintenance_window(
+    /// Presigns the request `deregister_target_from_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_target_from_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterTargetFromMaintenanceWindowRequest,
-    ) -> Result<
-        DeregisterTargetFromMaintenanceWindowResult,
-        RusotoError<DeregisterTargetFromMaintenanceWindowError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_target_from_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_target_from_maintenance_window_request(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTargetFromMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16036,33 +18038,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_task_from_maintenance_window`, also returning the metadata of the response.
+    pub async fn deregister_task_from_maintenance_window_with_metadata(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> Result<
+        (DeregisterTaskFromMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+    > {
+        let request = self.build_deregister_task_from_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterTargetFromMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterTaskFromMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterTargetFromMaintenanceWindowError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterTaskFromMaintenanceWindowError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Removes a task from a maintenance window.</p>
-    async fn deregister_task_from_maintenance_window(
+    /// Presigns the request `deregister_task_from_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_task_from_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterTaskFromMaintenanceWindowRequest,
-    ) -> Result<
-        DeregisterTaskFromMaintenanceWindowResult,
-        RusotoError<DeregisterTaskFromMaintenanceWindowError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_task_from_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_task_from_maintenance_window_request(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTaskFromMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16072,89 +18101,172 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_activations`, also returning the metadata of the response.
+    pub async fn describe_activations_with_metadata(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> Result<(DescribeActivationsResult, ResponseMetadata), RusotoError<DescribeActivationsError>>
+    {
+        let request = self.build_describe_activations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterTaskFromMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeActivationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterTaskFromMaintenanceWindowError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeActivationsError::from_response(response))
         }
     }
 
-    /// <p>Describes details about the activation, such as the date and time the activation was created, its expiration date, the IAM role assigned to the instances in the activation, and the number of instances registered by using this activation.</p>
-    async fn describe_activations(
+    /// Presigns the request `describe_activations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_activations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeActivationsRequest,
-    ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_activations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_activations_request(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeActivations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_association`, also returning the metadata of the response.
+    pub async fn describe_association_with_metadata(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> Result<(DescribeAssociationResult, ResponseMetadata), RusotoError<DescribeAssociationError>>
+    {
+        let request = self.build_describe_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeActivationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeActivationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssociationError::from_response(response))
         }
     }
 
-    /// <p>Describes the association for the specified target or instance. If you created the association by using the <code>Targets</code> parameter, then you must retrieve the association by using the association ID. If you created the association by specifying an instance ID and a Systems Manager document, then you retrieve the association by specifying the document name and the instance ID. </p>
-    async fn describe_association(
+    /// Presigns the request `describe_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssociationRequest,
-    ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_association_request(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_association_execution_targets`, also returning the metadata of the response.
+    pub async fn describe_association_execution_targets_with_metadata(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> Result<
+        (DescribeAssociationExecutionTargetsResult, ResponseMetadata),
+        RusotoError<DescribeAssociationExecutionTargetsError>,
+    > {
+        let request = self.build_describe_association_execution_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssociationExecutionTargetsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssociationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssociationExecutionTargetsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Use this API action to view information about a specific execution of a specific association.</p>
-    async fn describe_association_execution_targets(
+    /// Presigns the request `describe_association_execution_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_association_execution_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssociationExecutionTargetsRequest,
-    ) -> Result<
-        DescribeAssociationExecutionTargetsResult,
-        RusotoError<DescribeAssociationExecutionTargetsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_association_execution_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_association_execution_targets_request(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutionTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16164,208 +18276,408 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_association_executions`, also returning the metadata of the response.
+    pub async fn describe_association_executions_with_metadata(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> Result<
+        (DescribeAssociationExecutionsResult, ResponseMetadata),
+        RusotoError<DescribeAssociationExecutionsError>,
+    > {
+        let request = self.build_describe_association_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssociationExecutionTargetsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAssociationExecutionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssociationExecutionTargetsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAssociationExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Use this API action to view all executions for a specific association ID. </p>
-    async fn describe_association_executions(
+    /// Presigns the request `describe_association_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_association_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAssociationExecutionsRequest,
-    ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_association_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_association_executions_request(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_automation_executions`, also returning the metadata of the response.
+    pub async fn describe_automation_executions_with_metadata(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> Result<
+        (DescribeAutomationExecutionsResult, ResponseMetadata),
+        RusotoError<DescribeAutomationExecutionsError>,
+    > {
+        let request = self.build_describe_automation_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAssociationExecutionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAutomationExecutionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAssociationExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAutomationExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Provides details about all active and terminated Automation executions.</p>
-    async fn describe_automation_executions(
+    /// Presigns the request `describe_automation_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_automation_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAutomationExecutionsRequest,
-    ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_automation_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_automation_executions_request(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_automation_step_executions`, also returning the metadata of the response.
+    pub async fn describe_automation_step_executions_with_metadata(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> Result<
+        (DescribeAutomationStepExecutionsResult, ResponseMetadata),
+        RusotoError<DescribeAutomationStepExecutionsError>,
+    > {
+        let request = self.build_describe_automation_step_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAutomationExecutionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAutomationStepExecutionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAutomationExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAutomationStepExecutionsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Information about all active and terminated step executions in an Automation workflow.</p>
-    async fn describe_automation_step_executions(
+    /// Presigns the request `describe_automation_step_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_automation_step_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAutomationStepExecutionsRequest,
-    ) -> Result<
-        DescribeAutomationStepExecutionsResult,
-        RusotoError<DescribeAutomationStepExecutionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_automation_step_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_automation_step_executions_request(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationStepExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_available_patches`, also returning the metadata of the response.
+    pub async fn describe_available_patches_with_metadata(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> Result<
+        (DescribeAvailablePatchesResult, ResponseMetadata),
+        RusotoError<DescribeAvailablePatchesError>,
+    > {
+        let request = self.build_describe_available_patches_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAutomationStepExecutionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAvailablePatchesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAutomationStepExecutionsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAvailablePatchesError::from_response(response))
         }
     }
 
-    /// <p>Lists all patches eligible to be included in a patch baseline.</p>
-    async fn describe_available_patches(
+    /// Presigns the request `describe_available_patches` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_available_patches(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAvailablePatchesRequest,
-    ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_available_patches_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_available_patches_request(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAvailablePatches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_document`, also returning the metadata of the response.
+    pub async fn describe_document_with_metadata(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> Result<(DescribeDocumentResult, ResponseMetadata), RusotoError<DescribeDocumentError>>
+    {
+        let request = self.build_describe_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAvailablePatchesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDocumentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAvailablePatchesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDocumentError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified Systems Manager document.</p>
-    async fn describe_document(
+    /// Presigns the request `describe_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDocumentRequest,
-    ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_document_request(&self, input: DescribeDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_document_permission`, also returning the metadata of the response.
+    pub async fn describe_document_permission_with_metadata(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> Result<
+        (DescribeDocumentPermissionResponse, ResponseMetadata),
+        RusotoError<DescribeDocumentPermissionError>,
+    > {
+        let request = self.build_describe_document_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeDocumentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDocumentPermissionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDocumentPermissionError::from_response(response))
         }
     }
 
-    /// <p>Describes the permissions for a Systems Manager document. If you created the document, you are the owner. If a document is shared, it can either be shared privately (by specifying a user's AWS account ID) or publicly (<i>All</i>). </p>
-    async fn describe_document_permission(
+    /// Presigns the request `describe_document_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_document_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDocumentPermissionRequest,
-    ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_document_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_document_permission_request(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocumentPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_effective_instance_associations`, also returning the metadata of the response.
+    pub async fn describe_effective_instance_associations_with_metadata(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> Result<
+        (
+            DescribeEffectiveInstanceAssociationsResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEffectiveInstanceAssociationsError>,
+    > {
+        let request = self.build_describe_effective_instance_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDocumentPermissionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEffectiveInstanceAssociationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDocumentPermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEffectiveInstanceAssociationsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>All associations for the instance(s).</p>
-    async fn describe_effective_instance_associations(
+    /// Presigns the request `describe_effective_instance_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_effective_instance_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEffectiveInstanceAssociationsRequest,
-    ) -> Result<
-        DescribeEffectiveInstanceAssociationsResult,
-        RusotoError<DescribeEffectiveInstanceAssociationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_effective_instance_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_effective_instance_associations_request(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectiveInstanceAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16375,33 +18687,61 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_effective_patches_for_patch_baseline`, also returning the metadata of the response.
+    pub async fn describe_effective_patches_for_patch_baseline_with_metadata(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> Result<
+        (
+            DescribeEffectivePatchesForPatchBaselineResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+    > {
+        let request = self.build_describe_effective_patches_for_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEffectiveInstanceAssociationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEffectivePatchesForPatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEffectiveInstanceAssociationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEffectivePatchesForPatchBaselineError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current effective patches (the patch and the approval state) for the specified patch baseline. Note that this API applies only to Windows patch baselines.</p>
-    async fn describe_effective_patches_for_patch_baseline(
+    /// Presigns the request `describe_effective_patches_for_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_effective_patches_for_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEffectivePatchesForPatchBaselineRequest,
-    ) -> Result<
-        DescribeEffectivePatchesForPatchBaselineResult,
-        RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_effective_patches_for_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_effective_patches_for_patch_baseline_request(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectivePatchesForPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16411,31 +18751,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instance_associations_status`, also returning the metadata of the response.
+    pub async fn describe_instance_associations_status_with_metadata(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> Result<
+        (DescribeInstanceAssociationsStatusResult, ResponseMetadata),
+        RusotoError<DescribeInstanceAssociationsStatusError>,
+    > {
+        let request = self.build_describe_instance_associations_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEffectivePatchesForPatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstanceAssociationsStatusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEffectivePatchesForPatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstanceAssociationsStatusError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>The status of the associations for the instance(s).</p>
-    async fn describe_instance_associations_status(
+    /// Presigns the request `describe_instance_associations_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_associations_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstanceAssociationsStatusRequest,
-    ) -> Result<
-        DescribeInstanceAssociationsStatusResult,
-        RusotoError<DescribeInstanceAssociationsStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_associations_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_associations_status_request(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceAssociationsStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16445,91 +18814,177 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instance_information`, also returning the metadata of the response.
+    pub async fn describe_instance_information_with_metadata(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> Result<
+        (DescribeInstanceInformationResult, ResponseMetadata),
+        RusotoError<DescribeInstanceInformationError>,
+    > {
+        let request = self.build_describe_instance_information_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInstanceAssociationsStatusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstanceInformationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstanceAssociationsStatusError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstanceInformationError::from_response(response))
         }
     }
 
-    /// <p><p>Describes one or more of your instances. You can use this to get information about instances like the operating system platform, the SSM Agent version (Linux), status etc. If you specify one or more instance IDs, it returns information for those instances. If you do not specify instance IDs, it returns information for all your instances. If you specify an instance ID that is not valid or an instance that you do not own, you receive an error. </p> <note> <p>The IamRole field for this API action is the Amazon Identity and Access Management (IAM) role assigned to on-premises instances. This call does not return the IAM role for Amazon EC2 instances.</p> </note></p>
-    async fn describe_instance_information(
+    /// Presigns the request `describe_instance_information` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_information(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstanceInformationRequest,
-    ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_information_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_information_request(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `describe_instance_patch_states`, also returning the metadata of the response.
+    pub async fn describe_instance_patch_states_with_metadata(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> Result<
+        (DescribeInstancePatchStatesResult, ResponseMetadata),
+        RusotoError<DescribeInstancePatchStatesError>,
+    > {
+        let request = self.build_describe_instance_patch_states_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInstanceInformationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstancePatchStatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstanceInformationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstancePatchStatesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the high-level patch state of one or more instances.</p>
-    async fn describe_instance_patch_states(
+    /// Presigns the request `describe_instance_patch_states` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_patch_states(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstancePatchStatesRequest,
-    ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_patch_states_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_patch_states_request(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instance_patch_states_for_patch_group`, also returning the metadata of the response.
+    pub async fn describe_instance_patch_states_for_patch_group_with_metadata(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> Result<
+        (
+            DescribeInstancePatchStatesForPatchGroupResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+    > {
+        let request = self.build_describe_instance_patch_states_for_patch_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInstancePatchStatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstancePatchStatesForPatchGroupResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstancePatchStatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstancePatchStatesForPatchGroupError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the high-level patch state for the instances in the specified patch group.</p>
-    async fn describe_instance_patch_states_for_patch_group(
+    /// Presigns the request `describe_instance_patch_states_for_patch_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_patch_states_for_patch_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstancePatchStatesForPatchGroupRequest,
-    ) -> Result<
-        DescribeInstancePatchStatesForPatchGroupResult,
-        RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_patch_states_for_patch_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_patch_states_for_patch_group_request(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStatesForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16539,88 +18994,180 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instance_patches`, also returning the metadata of the response.
+    pub async fn describe_instance_patches_with_metadata(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> Result<
+        (DescribeInstancePatchesResult, ResponseMetadata),
+        RusotoError<DescribeInstancePatchesError>,
+    > {
+        let request = self.build_describe_instance_patches_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInstancePatchStatesForPatchGroupResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstancePatchesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstancePatchStatesForPatchGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstancePatchesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about the patches on the specified instance and their state relative to the patch baseline being used for the instance.</p>
-    async fn describe_instance_patches(
+    /// Presigns the request `describe_instance_patches` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_patches(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstancePatchesRequest,
-    ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_patches_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_patches_request(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_inventory_deletions`, also returning the metadata of the response.
+    pub async fn describe_inventory_deletions_with_metadata(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> Result<
+        (DescribeInventoryDeletionsResult, ResponseMetadata),
+        RusotoError<DescribeInventoryDeletionsError>,
+    > {
+        let request = self.build_describe_inventory_deletions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInstancePatchesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInventoryDeletionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstancePatchesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInventoryDeletionsError::from_response(response))
         }
     }
 
-    /// <p>Describes a specific delete inventory operation.</p>
-    async fn describe_inventory_deletions(
+    /// Presigns the request `describe_inventory_deletions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_inventory_deletions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInventoryDeletionsRequest,
-    ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_inventory_deletions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_inventory_deletions_request(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInventoryDeletions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_execution_task_invocations`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_execution_task_invocations_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> Result<
+        (
+            DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+    > {
+        let request =
+            self.build_describe_maintenance_window_execution_task_invocations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInventoryDeletionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowExecutionTaskInvocationsResult, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInventoryDeletionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowExecutionTaskInvocationsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the individual task executions (one per target) for a particular task run as part of a maintenance window execution.</p>
-    async fn describe_maintenance_window_execution_task_invocations(
+    /// Presigns the request `describe_maintenance_window_execution_task_invocations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_execution_task_invocations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
-        RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_describe_maintenance_window_execution_task_invocations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_execution_task_invocations_request(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTaskInvocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16630,31 +19177,63 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_execution_tasks`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_execution_tasks_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> Result<
+        (
+            DescribeMaintenanceWindowExecutionTasksResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+    > {
+        let request = self.build_describe_maintenance_window_execution_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowExecutionTaskInvocationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowExecutionTasksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowExecutionTaskInvocationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowExecutionTasksError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>For a given maintenance window execution, lists the tasks that were run.</p>
-    async fn describe_maintenance_window_execution_tasks(
+    /// Presigns the request `describe_maintenance_window_execution_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_execution_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowExecutionTasksRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowExecutionTasksResult,
-        RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_window_execution_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_execution_tasks_request(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16664,33 +19243,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_executions`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_executions_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowExecutionsResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowExecutionsError>,
+    > {
+        let request = self.build_describe_maintenance_window_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowExecutionTasksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowExecutionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowExecutionTasksError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowExecutionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Lists the executions of a maintenance window. This includes information about when the maintenance window was scheduled to be active, and information about tasks registered and run with the maintenance window.</p>
-    async fn describe_maintenance_window_executions(
+    /// Presigns the request `describe_maintenance_window_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowExecutionsRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowExecutionsResult,
-        RusotoError<DescribeMaintenanceWindowExecutionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_window_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_executions_request(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16700,33 +19306,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_schedule`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_schedule_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowScheduleResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowScheduleError>,
+    > {
+        let request = self.build_describe_maintenance_window_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowExecutionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowScheduleResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowExecutionsError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowScheduleError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Retrieves information about upcoming executions of a maintenance window.</p>
-    async fn describe_maintenance_window_schedule(
+    /// Presigns the request `describe_maintenance_window_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowScheduleRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowScheduleResult,
-        RusotoError<DescribeMaintenanceWindowScheduleError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_window_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_schedule_request(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16736,126 +19369,236 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_targets`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_targets_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowTargetsResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowTargetsError>,
+    > {
+        let request = self.build_describe_maintenance_window_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowScheduleResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowTargetsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowScheduleError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowTargetsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Lists the targets registered with the maintenance window.</p>
-    async fn describe_maintenance_window_targets(
+    /// Presigns the request `describe_maintenance_window_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowTargetsRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowTargetsResult,
-        RusotoError<DescribeMaintenanceWindowTargetsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_window_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_targets_request(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_window_tasks`, also returning the metadata of the response.
+    pub async fn describe_maintenance_window_tasks_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowTasksResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowTasksError>,
+    > {
+        let request = self.build_describe_maintenance_window_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowTargetsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowTasksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowTargetsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowTasksError::from_response(response))
         }
     }
 
-    /// <p>Lists the tasks in a maintenance window.</p>
-    async fn describe_maintenance_window_tasks(
+    /// Presigns the request `describe_maintenance_window_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_window_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowTasksRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowTasksResult,
-        RusotoError<DescribeMaintenanceWindowTasksError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_window_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_window_tasks_request(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_windows`, also returning the metadata of the response.
+    pub async fn describe_maintenance_windows_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowsResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowsError>,
+    > {
+        let request = self.build_describe_maintenance_windows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowTasksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the maintenance windows in an AWS account.</p>
-    async fn describe_maintenance_windows(
+    /// Presigns the request `describe_maintenance_windows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_windows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowsRequest,
-    ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_windows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_windows_request(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_windows_for_target`, also returning the metadata of the response.
+    pub async fn describe_maintenance_windows_for_target_with_metadata(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> Result<
+        (DescribeMaintenanceWindowsForTargetResult, ResponseMetadata),
+        RusotoError<DescribeMaintenanceWindowsForTargetError>,
+    > {
+        let request = self.build_describe_maintenance_windows_for_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceWindowsForTargetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceWindowsForTargetError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves information about the maintenance window targets or tasks that an instance is associated with.</p>
-    async fn describe_maintenance_windows_for_target(
+    /// Presigns the request `describe_maintenance_windows_for_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_windows_for_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceWindowsForTargetRequest,
-    ) -> Result<
-        DescribeMaintenanceWindowsForTargetResult,
-        RusotoError<DescribeMaintenanceWindowsForTargetError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_windows_for_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_windows_for_target_request(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowsForTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16865,369 +19608,737 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_ops_items`, also returning the metadata of the response.
+    pub async fn describe_ops_items_with_metadata(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> Result<(DescribeOpsItemsResponse, ResponseMetadata), RusotoError<DescribeOpsItemsError>>
+    {
+        let request = self.build_describe_ops_items_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceWindowsForTargetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOpsItemsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceWindowsForTargetError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOpsItemsError::from_response(response))
         }
     }
 
-    /// <p>Query a set of OpsItems. You must have permission in AWS Identity and Access Management (IAM) to query a list of OpsItems. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
-    async fn describe_ops_items(
+    /// Presigns the request `describe_ops_items` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ops_items(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOpsItemsRequest,
-    ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ops_items_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ops_items_request(&self, input: DescribeOpsItemsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeOpsItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `describe_parameters`, also returning the metadata of the response.
+    pub async fn describe_parameters_with_metadata(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<(DescribeParametersResult, ResponseMetadata), RusotoError<DescribeParametersError>>
+    {
+        let request = self.build_describe_parameters_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOpsItemsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOpsItemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeParametersError::from_response(response))
         }
     }
 
-    /// <p><p>Get information about a parameter.</p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
-    async fn describe_parameters(
+    /// Presigns the request `describe_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeParametersRequest,
-    ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_parameters_request(&self, input: DescribeParametersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_patch_baselines`, also returning the metadata of the response.
+    pub async fn describe_patch_baselines_with_metadata(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> Result<
+        (DescribePatchBaselinesResult, ResponseMetadata),
+        RusotoError<DescribePatchBaselinesError>,
+    > {
+        let request = self.build_describe_patch_baselines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeParametersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePatchBaselinesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePatchBaselinesError::from_response(response))
         }
     }
 
-    /// <p>Lists the patch baselines in your AWS account.</p>
-    async fn describe_patch_baselines(
+    /// Presigns the request `describe_patch_baselines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_patch_baselines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePatchBaselinesRequest,
-    ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_patch_baselines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_patch_baselines_request(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchBaselines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_patch_group_state`, also returning the metadata of the response.
+    pub async fn describe_patch_group_state_with_metadata(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> Result<
+        (DescribePatchGroupStateResult, ResponseMetadata),
+        RusotoError<DescribePatchGroupStateError>,
+    > {
+        let request = self.build_describe_patch_group_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePatchBaselinesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePatchGroupStateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePatchBaselinesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePatchGroupStateError::from_response(response))
         }
     }
 
-    /// <p>Returns high-level aggregated patch compliance state for a patch group.</p>
-    async fn describe_patch_group_state(
+    /// Presigns the request `describe_patch_group_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_patch_group_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePatchGroupStateRequest,
-    ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_patch_group_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_patch_group_state_request(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroupState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_patch_groups`, also returning the metadata of the response.
+    pub async fn describe_patch_groups_with_metadata(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> Result<(DescribePatchGroupsResult, ResponseMetadata), RusotoError<DescribePatchGroupsError>>
+    {
+        let request = self.build_describe_patch_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePatchGroupStateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePatchGroupsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePatchGroupStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePatchGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists all patch groups that have been registered with patch baselines.</p>
-    async fn describe_patch_groups(
+    /// Presigns the request `describe_patch_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_patch_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePatchGroupsRequest,
-    ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_patch_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_patch_groups_request(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_patch_properties`, also returning the metadata of the response.
+    pub async fn describe_patch_properties_with_metadata(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> Result<
+        (DescribePatchPropertiesResult, ResponseMetadata),
+        RusotoError<DescribePatchPropertiesError>,
+    > {
+        let request = self.build_describe_patch_properties_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePatchGroupsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePatchPropertiesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePatchGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePatchPropertiesError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the properties of available patches organized by product, product family, classification, severity, and other properties of available patches. You can use the reported properties in the filters you specify in requests for actions such as <a>CreatePatchBaseline</a>, <a>UpdatePatchBaseline</a>, <a>DescribeAvailablePatches</a>, and <a>DescribePatchBaselines</a>.</p> <p>The following section lists the properties that can be used in filters for each major operating system type:</p> <dl> <dt>WINDOWS</dt> <dd> <p>Valid properties: PRODUCT, PRODUCT<em>FAMILY, CLASSIFICATION, MSRC</em>SEVERITY</p> </dd> <dt>AMAZON<em>LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>AMAZON</em>LINUX<em>2</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>UBUNTU </dt> <dd> <p>Valid properties: PRODUCT, PRIORITY</p> </dd> <dt>REDHAT</em>ENTERPRISE_LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>SUSE</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>CENTOS</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> </dl></p>
-    async fn describe_patch_properties(
+    /// Presigns the request `describe_patch_properties` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_patch_properties(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePatchPropertiesRequest,
-    ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_patch_properties_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_patch_properties_request(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_sessions`, also returning the metadata of the response.
+    pub async fn describe_sessions_with_metadata(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<(DescribeSessionsResponse, ResponseMetadata), RusotoError<DescribeSessionsError>>
+    {
+        let request = self.build_describe_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePatchPropertiesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSessionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePatchPropertiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSessionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of all active sessions (both connected and disconnected) or terminated sessions from the past 30 days.</p>
-    async fn describe_sessions(
+    /// Presigns the request `describe_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSessionsRequest,
-    ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_sessions_request(&self, input: DescribeSessionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_automation_execution`, also returning the metadata of the response.
+    pub async fn get_automation_execution_with_metadata(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> Result<
+        (GetAutomationExecutionResult, ResponseMetadata),
+        RusotoError<GetAutomationExecutionError>,
+    > {
+        let request = self.build_get_automation_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSessionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAutomationExecutionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAutomationExecutionError::from_response(response))
         }
     }
 
-    /// <p>Get detailed information about a particular Automation execution.</p>
-    async fn get_automation_execution(
+    /// Presigns the request `get_automation_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_automation_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAutomationExecutionRequest,
-    ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_automation_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_automation_execution_request(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_calendar_state`, also returning the metadata of the response.
+    pub async fn get_calendar_state_with_metadata(
+        &self,
+        input: GetCalendarStateRequest,
+    ) -> Result<(GetCalendarStateResponse, ResponseMetadata), RusotoError<GetCalendarStateError>>
+    {
+        let request = self.build_get_calendar_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAutomationExecutionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCalendarStateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAutomationExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCalendarStateError::from_response(response))
         }
     }
 
-    /// <p>Gets the state of the AWS Systems Manager Change Calendar at an optional, specified time. If you specify a time, <code>GetCalendarState</code> returns the state of the calendar at a specific time, and returns the next time that the Change Calendar state will transition. If you do not specify a time, <code>GetCalendarState</code> assumes the current time. Change Calendar entries have two possible states: <code>OPEN</code> or <code>CLOSED</code>. For more information about Systems Manager Change Calendar, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-change-calendar.html">AWS Systems Manager Change Calendar</a> in the <i>AWS Systems Manager User Guide</i>.</p>
-    async fn get_calendar_state(
+    /// Presigns the request `get_calendar_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_calendar_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCalendarStateRequest,
-    ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_calendar_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_calendar_state_request(&self, input: GetCalendarStateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetCalendarState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetCalendarState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_command_invocation`, also returning the metadata of the response.
+    pub async fn get_command_invocation_with_metadata(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<
+        (GetCommandInvocationResult, ResponseMetadata),
+        RusotoError<GetCommandInvocationError>,
+    > {
+        let request = self.build_get_command_invocation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCalendarStateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCommandInvocationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCalendarStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCommandInvocationError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about command execution for an invocation or plugin. </p>
-    async fn get_command_invocation(
+    /// Presigns the request `get_command_invocation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_command_invocation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCommandInvocationRequest,
-    ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_command_invocation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_command_invocation_request(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetCommandInvocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_connection_status`, also returning the metadata of the response.
+    pub async fn get_connection_status_with_metadata(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> Result<
+        (GetConnectionStatusResponse, ResponseMetadata),
+        RusotoError<GetConnectionStatusError>,
+    > {
+        let request = self.build_get_connection_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCommandInvocationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConnectionStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCommandInvocationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConnectionStatusError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the Session Manager connection status for an instance to determine whether it is connected and ready to receive Session Manager connections.</p>
-    async fn get_connection_status(
+    /// Presigns the request `get_connection_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connection_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectionStatusRequest,
-    ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connection_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connection_status_request(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetConnectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_default_patch_baseline`, also returning the metadata of the response.
+    pub async fn get_default_patch_baseline_with_metadata(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> Result<
+        (GetDefaultPatchBaselineResult, ResponseMetadata),
+        RusotoError<GetDefaultPatchBaselineError>,
+    > {
+        let request = self.build_get_default_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConnectionStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDefaultPatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectionStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDefaultPatchBaselineError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the default patch baseline. Note that Systems Manager supports creating multiple default patch baselines. For example, you can create a default patch baseline for each operating system.</p> <p>If you do not specify an operating system value, the default patch baseline for Windows is returned.</p>
-    async fn get_default_patch_baseline(
+    /// Presigns the request `get_default_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_default_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDefaultPatchBaselineRequest,
-    ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_default_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_default_patch_baseline_request(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDefaultPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_deployable_patch_snapshot_for_instance`, also returning the metadata of the response.
+    pub async fn get_deployable_patch_snapshot_for_instance_with_metadata(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> Result<
+        (
+            GetDeployablePatchSnapshotForInstanceResult,
+            ResponseMetadata,
+        ),
+        RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+    > {
+        let request = self.build_get_deployable_patch_snapshot_for_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDefaultPatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeployablePatchSnapshotForInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDefaultPatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeployablePatchSnapshotForInstanceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the current snapshot for the patch baseline the instance uses. This API is primarily used by the AWS-RunPatchBaseline Systems Manager document. </p>
-    async fn get_deployable_patch_snapshot_for_instance(
+    /// Presigns the request `get_deployable_patch_snapshot_for_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployable_patch_snapshot_for_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeployablePatchSnapshotForInstanceRequest,
-    ) -> Result<
-        GetDeployablePatchSnapshotForInstanceResult,
-        RusotoError<GetDeployablePatchSnapshotForInstanceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployable_patch_snapshot_for_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployable_patch_snapshot_for_instance_request(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDeployablePatchSnapshotForInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17237,172 +20348,336 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_document`, also returning the metadata of the response.
+    pub async fn get_document_with_metadata(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<(GetDocumentResult, ResponseMetadata), RusotoError<GetDocumentError>> {
+        let request = self.build_get_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeployablePatchSnapshotForInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDocumentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeployablePatchSnapshotForInstanceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDocumentError::from_response(response))
         }
     }
 
-    /// <p>Gets the contents of the specified Systems Manager document.</p>
-    async fn get_document(
+    /// Presigns the request `get_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentRequest,
-    ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_document_request(&self, input: GetDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_inventory`, also returning the metadata of the response.
+    pub async fn get_inventory_with_metadata(
+        &self,
+        input: GetInventoryRequest,
+    ) -> Result<(GetInventoryResult, ResponseMetadata), RusotoError<GetInventoryError>> {
+        let request = self.build_get_inventory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDocumentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInventoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInventoryError::from_response(response))
         }
     }
 
-    /// <p>Query inventory information.</p>
-    async fn get_inventory(
+    /// Presigns the request `get_inventory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_inventory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInventoryRequest,
-    ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_inventory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_inventory_request(&self, input: GetInventoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_inventory_schema`, also returning the metadata of the response.
+    pub async fn get_inventory_schema_with_metadata(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> Result<(GetInventorySchemaResult, ResponseMetadata), RusotoError<GetInventorySchemaError>>
+    {
+        let request = self.build_get_inventory_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInventoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInventorySchemaResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInventoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInventorySchemaError::from_response(response))
         }
     }
 
-    /// <p>Return a list of inventory type names for the account, or return a list of attribute names for a specific Inventory item type. </p>
-    async fn get_inventory_schema(
+    /// Presigns the request `get_inventory_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_inventory_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInventorySchemaRequest,
-    ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_inventory_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_inventory_schema_request(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventorySchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_maintenance_window`, also returning the metadata of the response.
+    pub async fn get_maintenance_window_with_metadata(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> Result<
+        (GetMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<GetMaintenanceWindowError>,
+    > {
+        let request = self.build_get_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInventorySchemaResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInventorySchemaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMaintenanceWindowError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a maintenance window.</p>
-    async fn get_maintenance_window(
+    /// Presigns the request `get_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMaintenanceWindowRequest,
-    ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_maintenance_window_request(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_maintenance_window_execution`, also returning the metadata of the response.
+    pub async fn get_maintenance_window_execution_with_metadata(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> Result<
+        (GetMaintenanceWindowExecutionResult, ResponseMetadata),
+        RusotoError<GetMaintenanceWindowExecutionError>,
+    > {
+        let request = self.build_get_maintenance_window_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMaintenanceWindowExecutionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMaintenanceWindowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMaintenanceWindowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details about a specific a maintenance window execution.</p>
-    async fn get_maintenance_window_execution(
+    /// Presigns the request `get_maintenance_window_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_maintenance_window_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMaintenanceWindowExecutionRequest,
-    ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_maintenance_window_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_maintenance_window_execution_request(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_maintenance_window_execution_task`, also returning the metadata of the response.
+    pub async fn get_maintenance_window_execution_task_with_metadata(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> Result<
+        (GetMaintenanceWindowExecutionTaskResult, ResponseMetadata),
+        RusotoError<GetMaintenanceWindowExecutionTaskError>,
+    > {
+        let request = self.build_get_maintenance_window_execution_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMaintenanceWindowExecutionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMaintenanceWindowExecutionTaskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMaintenanceWindowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMaintenanceWindowExecutionTaskError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the details about a specific task run as part of a maintenance window execution.</p>
-    async fn get_maintenance_window_execution_task(
+    /// Presigns the request `get_maintenance_window_execution_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_maintenance_window_execution_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMaintenanceWindowExecutionTaskRequest,
-    ) -> Result<
-        GetMaintenanceWindowExecutionTaskResult,
-        RusotoError<GetMaintenanceWindowExecutionTaskError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_maintenance_window_execution_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_maintenance_window_execution_task_request(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17412,33 +20687,61 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_maintenance_window_execution_task_invocation`, also returning the metadata of the response.
+    pub async fn get_maintenance_window_execution_task_invocation_with_metadata(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> Result<
+        (
+            GetMaintenanceWindowExecutionTaskInvocationResult,
+            ResponseMetadata,
+        ),
+        RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+    > {
+        let request = self.build_get_maintenance_window_execution_task_invocation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMaintenanceWindowExecutionTaskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMaintenanceWindowExecutionTaskInvocationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMaintenanceWindowExecutionTaskError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMaintenanceWindowExecutionTaskInvocationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a specific task running on a specific target.</p>
-    async fn get_maintenance_window_execution_task_invocation(
+    /// Presigns the request `get_maintenance_window_execution_task_invocation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_maintenance_window_execution_task_invocation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMaintenanceWindowExecutionTaskInvocationRequest,
-    ) -> Result<
-        GetMaintenanceWindowExecutionTaskInvocationResult,
-        RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_maintenance_window_execution_task_invocation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_maintenance_window_execution_task_invocation_request(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTaskInvocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -17448,811 +20751,1613 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_maintenance_window_task`, also returning the metadata of the response.
+    pub async fn get_maintenance_window_task_with_metadata(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> Result<
+        (GetMaintenanceWindowTaskResult, ResponseMetadata),
+        RusotoError<GetMaintenanceWindowTaskError>,
+    > {
+        let request = self.build_get_maintenance_window_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMaintenanceWindowExecutionTaskInvocationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMaintenanceWindowTaskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMaintenanceWindowExecutionTaskInvocationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMaintenanceWindowTaskError::from_response(response))
         }
     }
 
-    /// <p>Lists the tasks in a maintenance window.</p>
-    async fn get_maintenance_window_task(
+    /// Presigns the request `get_maintenance_window_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_maintenance_window_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMaintenanceWindowTaskRequest,
-    ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_maintenance_window_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_maintenance_window_task_request(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ops_item`, also returning the metadata of the response.
+    pub async fn get_ops_item_with_metadata(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> Result<(GetOpsItemResponse, ResponseMetadata), RusotoError<GetOpsItemError>> {
+        let request = self.build_get_ops_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMaintenanceWindowTaskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOpsItemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMaintenanceWindowTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOpsItemError::from_response(response))
         }
     }
 
-    /// <p>Get information about an OpsItem by using the ID. You must have permission in AWS Identity and Access Management (IAM) to view information about an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
-    async fn get_ops_item(
+    /// Presigns the request `get_ops_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ops_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOpsItemRequest,
-    ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ops_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ops_item_request(&self, input: GetOpsItemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ops_summary`, also returning the metadata of the response.
+    pub async fn get_ops_summary_with_metadata(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> Result<(GetOpsSummaryResult, ResponseMetadata), RusotoError<GetOpsSummaryError>> {
+        let request = self.build_get_ops_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOpsItemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOpsSummaryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOpsItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOpsSummaryError::from_response(response))
         }
     }
 
-    /// <p>View a summary of OpsItems based on specified filters and aggregators.</p>
-    async fn get_ops_summary(
+    /// Presigns the request `get_ops_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ops_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOpsSummaryRequest,
-    ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ops_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ops_summary_request(&self, input: GetOpsSummaryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_parameter`, also returning the metadata of the response.
+    pub async fn get_parameter_with_metadata(
+        &self,
+        input: GetParameterRequest,
+    ) -> Result<(GetParameterResult, ResponseMetadata), RusotoError<GetParameterError>> {
+        let request = self.build_get_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOpsSummaryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetParameterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOpsSummaryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetParameterError::from_response(response))
         }
     }
 
-    /// <p>Get information about a parameter by using the parameter name. Don't confuse this API action with the <a>GetParameters</a> API action.</p>
-    async fn get_parameter(
+    /// Presigns the request `get_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetParameterRequest,
-    ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_parameter_request(&self, input: GetParameterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_parameter_history`, also returning the metadata of the response.
+    pub async fn get_parameter_history_with_metadata(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> Result<(GetParameterHistoryResult, ResponseMetadata), RusotoError<GetParameterHistoryError>>
+    {
+        let request = self.build_get_parameter_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetParameterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetParameterHistoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetParameterHistoryError::from_response(response))
         }
     }
 
-    /// <p>Query a list of all parameters used by the AWS account.</p>
-    async fn get_parameter_history(
+    /// Presigns the request `get_parameter_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_parameter_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetParameterHistoryRequest,
-    ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_parameter_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_parameter_history_request(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameterHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_parameters`, also returning the metadata of the response.
+    pub async fn get_parameters_with_metadata(
+        &self,
+        input: GetParametersRequest,
+    ) -> Result<(GetParametersResult, ResponseMetadata), RusotoError<GetParametersError>> {
+        let request = self.build_get_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetParameterHistoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetParametersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetParameterHistoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetParametersError::from_response(response))
         }
     }
 
-    /// <p>Get details of a parameter. Don't confuse this API action with the <a>GetParameter</a> API action.</p>
-    async fn get_parameters(
+    /// Presigns the request `get_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetParametersRequest,
-    ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_parameters_request(&self, input: GetParametersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_parameters_by_path`, also returning the metadata of the response.
+    pub async fn get_parameters_by_path_with_metadata(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> Result<(GetParametersByPathResult, ResponseMetadata), RusotoError<GetParametersByPathError>>
+    {
+        let request = self.build_get_parameters_by_path_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetParametersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetParametersByPathResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetParametersByPathError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieve information about one or more parameters in a specific hierarchy. </p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
-    async fn get_parameters_by_path(
+    /// Presigns the request `get_parameters_by_path` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_parameters_by_path(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetParametersByPathRequest,
-    ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_parameters_by_path_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_parameters_by_path_request(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParametersByPath");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_patch_baseline`, also returning the metadata of the response.
+    pub async fn get_patch_baseline_with_metadata(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> Result<(GetPatchBaselineResult, ResponseMetadata), RusotoError<GetPatchBaselineError>>
+    {
+        let request = self.build_get_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetParametersByPathResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetParametersByPathError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPatchBaselineError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a patch baseline.</p>
-    async fn get_patch_baseline(
+    /// Presigns the request `get_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPatchBaselineRequest,
-    ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_patch_baseline_request(&self, input: GetPatchBaselineRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_patch_baseline_for_patch_group`, also returning the metadata of the response.
+    pub async fn get_patch_baseline_for_patch_group_with_metadata(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        (GetPatchBaselineForPatchGroupResult, ResponseMetadata),
+        RusotoError<GetPatchBaselineForPatchGroupError>,
+    > {
+        let request = self.build_get_patch_baseline_for_patch_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPatchBaselineForPatchGroupResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPatchBaselineForPatchGroupError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the patch baseline that should be used for the specified patch group.</p>
-    async fn get_patch_baseline_for_patch_group(
+    /// Presigns the request `get_patch_baseline_for_patch_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_patch_baseline_for_patch_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPatchBaselineForPatchGroupRequest,
-    ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_patch_baseline_for_patch_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_patch_baseline_for_patch_group_request(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_service_setting`, also returning the metadata of the response.
+    pub async fn get_service_setting_with_metadata(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> Result<(GetServiceSettingResult, ResponseMetadata), RusotoError<GetServiceSettingError>>
+    {
+        let request = self.build_get_service_setting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPatchBaselineForPatchGroupResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetServiceSettingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPatchBaselineForPatchGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetServiceSettingError::from_response(response))
         }
     }
 
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Query the current service setting for the account. </p>
-    async fn get_service_setting(
+    /// Presigns the request `get_service_setting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_service_setting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetServiceSettingRequest,
-    ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_service_setting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_service_setting_request(&self, input: GetServiceSettingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `label_parameter_version`, also returning the metadata of the response.
+    pub async fn label_parameter_version_with_metadata(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> Result<
+        (LabelParameterVersionResult, ResponseMetadata),
+        RusotoError<LabelParameterVersionError>,
+    > {
+        let request = self.build_label_parameter_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetServiceSettingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<LabelParameterVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetServiceSettingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(LabelParameterVersionError::from_response(response))
         }
     }
 
-    /// <p><p>A parameter label is a user-defined alias to help you manage different versions of a parameter. When you modify a parameter, Systems Manager automatically saves a new version and increments the version number by one. A label can help you remember the purpose of a parameter when there are multiple versions. </p> <p>Parameter labels have the following requirements and restrictions.</p> <ul> <li> <p>A version of a parameter can have a maximum of 10 labels.</p> </li> <li> <p>You can&#39;t attach the same label to different versions of the same parameter. For example, if version 1 has the label Production, then you can&#39;t attach Production to version 2.</p> </li> <li> <p>You can move a label from one version of a parameter to another.</p> </li> <li> <p>You can&#39;t create a label when you create a new parameter. You must attach a label to a specific version of a parameter.</p> </li> <li> <p>You can&#39;t delete a parameter label. If you no longer want to use a parameter label, then you must move it to a different version of a parameter.</p> </li> <li> <p>A label can have a maximum of 100 characters.</p> </li> <li> <p>Labels can contain letters (case sensitive), numbers, periods (.), hyphens (-), or underscores (_).</p> </li> <li> <p>Labels can&#39;t begin with a number, &quot;aws,&quot; or &quot;ssm&quot; (not case sensitive). If a label fails to meet these requirements, then the label is not associated with a parameter and the system displays it in the list of InvalidLabels.</p> </li> </ul></p>
-    async fn label_parameter_version(
+    /// Presigns the request `label_parameter_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_label_parameter_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LabelParameterVersionRequest,
-    ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_label_parameter_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_label_parameter_version_request(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("LabelParameterVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_association_versions`, also returning the metadata of the response.
+    pub async fn list_association_versions_with_metadata(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> Result<
+        (ListAssociationVersionsResult, ResponseMetadata),
+        RusotoError<ListAssociationVersionsError>,
+    > {
+        let request = self.build_list_association_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<LabelParameterVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociationVersionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(LabelParameterVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociationVersionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all versions of an association for a specific association ID.</p>
-    async fn list_association_versions(
+    /// Presigns the request `list_association_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_association_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociationVersionsRequest,
-    ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_association_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_association_versions_request(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociationVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_associations`, also returning the metadata of the response.
+    pub async fn list_associations_with_metadata(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> Result<(ListAssociationsResult, ResponseMetadata), RusotoError<ListAssociationsError>>
+    {
+        let request = self.build_list_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssociationVersionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociationVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Returns all State Manager associations in the current AWS account and Region. You can limit the results to a specific State Manager association document or instance by specifying a filter.</p>
-    async fn list_associations(
+    /// Presigns the request `list_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociationsRequest,
-    ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_associations_request(&self, input: ListAssociationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_command_invocations`, also returning the metadata of the response.
+    pub async fn list_command_invocations_with_metadata(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> Result<
+        (ListCommandInvocationsResult, ResponseMetadata),
+        RusotoError<ListCommandInvocationsError>,
+    > {
+        let request = self.build_list_command_invocations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAssociationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCommandInvocationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCommandInvocationsError::from_response(response))
         }
     }
 
-    /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. ListCommandInvocations provide status about command execution.</p>
-    async fn list_command_invocations(
+    /// Presigns the request `list_command_invocations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_command_invocations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCommandInvocationsRequest,
-    ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_command_invocations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_command_invocations_request(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommandInvocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `list_commands`, also returning the metadata of the response.
+    pub async fn list_commands_with_metadata(
+        &self,
+        input: ListCommandsRequest,
+    ) -> Result<(ListCommandsResult, ResponseMetadata), RusotoError<ListCommandsError>> {
+        let request = self.build_list_commands_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCommandInvocationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCommandsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCommandInvocationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCommandsError::from_response(response))
         }
     }
 
-    /// <p>Lists the commands requested by users of the AWS account.</p>
-    async fn list_commands(
+    /// Presigns the request `list_commands` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_commands(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCommandsRequest,
-    ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_commands_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_commands_request(&self, input: ListCommandsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommands");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_compliance_items`, also returning the metadata of the response.
+    pub async fn list_compliance_items_with_metadata(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> Result<(ListComplianceItemsResult, ResponseMetadata), RusotoError<ListComplianceItemsError>>
+    {
+        let request = self.build_list_compliance_items_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListCommandsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListComplianceItemsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCommandsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListComplianceItemsError::from_response(response))
         }
     }
 
-    /// <p>For a specified resource ID, this API action returns a list of compliance statuses for different resource types. Currently, you can only specify one resource ID per call. List results depend on the criteria specified in the filter. </p>
-    async fn list_compliance_items(
+    /// Presigns the request `list_compliance_items` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_compliance_items(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComplianceItemsRequest,
-    ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_compliance_items_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_compliance_items_request(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_compliance_summaries`, also returning the metadata of the response.
+    pub async fn list_compliance_summaries_with_metadata(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> Result<
+        (ListComplianceSummariesResult, ResponseMetadata),
+        RusotoError<ListComplianceSummariesError>,
+    > {
+        let request = self.build_list_compliance_summaries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListComplianceItemsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListComplianceSummariesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComplianceItemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListComplianceSummariesError::from_response(response))
         }
     }
 
-    /// <p>Returns a summary count of compliant and non-compliant resources for a compliance type. For example, this call can return State Manager associations, patches, or custom compliance types according to the filter criteria that you specify. </p>
-    async fn list_compliance_summaries(
+    /// Presigns the request `list_compliance_summaries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_compliance_summaries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComplianceSummariesRequest,
-    ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_compliance_summaries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_compliance_summaries_request(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_document_versions`, also returning the metadata of the response.
+    pub async fn list_document_versions_with_metadata(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> Result<
+        (ListDocumentVersionsResult, ResponseMetadata),
+        RusotoError<ListDocumentVersionsError>,
+    > {
+        let request = self.build_list_document_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListComplianceSummariesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDocumentVersionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComplianceSummariesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDocumentVersionsError::from_response(response))
         }
     }
 
-    /// <p>List all versions for a document.</p>
-    async fn list_document_versions(
+    /// Presigns the request `list_document_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_document_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDocumentVersionsRequest,
-    ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_document_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_document_versions_request(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocumentVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_documents`, also returning the metadata of the response.
+    pub async fn list_documents_with_metadata(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> Result<(ListDocumentsResult, ResponseMetadata), RusotoError<ListDocumentsError>> {
+        let request = self.build_list_documents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDocumentVersionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDocumentsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDocumentVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDocumentsError::from_response(response))
         }
     }
 
-    /// <p>Returns all Systems Manager (SSM) documents in the current AWS account and Region. You can limit the results of this request by using a filter.</p>
-    async fn list_documents(
+    /// Presigns the request `list_documents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_documents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDocumentsRequest,
-    ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_documents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_documents_request(&self, input: ListDocumentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocuments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_inventory_entries`, also returning the metadata of the response.
+    pub async fn list_inventory_entries_with_metadata(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> Result<
+        (ListInventoryEntriesResult, ResponseMetadata),
+        RusotoError<ListInventoryEntriesError>,
+    > {
+        let request = self.build_list_inventory_entries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDocumentsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInventoryEntriesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDocumentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInventoryEntriesError::from_response(response))
         }
     }
 
-    /// <p>A list of inventory items returned by the request.</p>
-    async fn list_inventory_entries(
+    /// Presigns the request `list_inventory_entries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_inventory_entries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInventoryEntriesRequest,
-    ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_inventory_entries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_inventory_entries_request(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListInventoryEntries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resource_compliance_summaries`, also returning the metadata of the response.
+    pub async fn list_resource_compliance_summaries_with_metadata(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> Result<
+        (ListResourceComplianceSummariesResult, ResponseMetadata),
+        RusotoError<ListResourceComplianceSummariesError>,
+    > {
+        let request = self.build_list_resource_compliance_summaries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInventoryEntriesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourceComplianceSummariesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInventoryEntriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourceComplianceSummariesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a resource-level summary count. The summary includes information about compliant and non-compliant statuses and detailed compliance-item severity counts, according to the filter criteria you specify.</p>
-    async fn list_resource_compliance_summaries(
+    /// Presigns the request `list_resource_compliance_summaries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_compliance_summaries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceComplianceSummariesRequest,
-    ) -> Result<
-        ListResourceComplianceSummariesResult,
-        RusotoError<ListResourceComplianceSummariesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_compliance_summaries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_compliance_summaries_request(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceComplianceSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resource_data_sync`, also returning the metadata of the response.
+    pub async fn list_resource_data_sync_with_metadata(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> Result<
+        (ListResourceDataSyncResult, ResponseMetadata),
+        RusotoError<ListResourceDataSyncError>,
+    > {
+        let request = self.build_list_resource_data_sync_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceComplianceSummariesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourceDataSyncResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceComplianceSummariesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourceDataSyncError::from_response(response))
         }
     }
 
-    /// <p>Lists your resource data sync configurations. Includes information about the last time a sync attempted to start, the last sync status, and the last time a sync successfully completed.</p> <p>The number of sync configurations might be too large to return using a single call to <code>ListResourceDataSync</code>. You can limit the number of sync configurations returned by using the <code>MaxResults</code> parameter. To determine whether there are more sync configurations to list, check the value of <code>NextToken</code> in the output. If there are more sync configurations to list, you can request them by specifying the <code>NextToken</code> returned in the call to the parameter of a subsequent call. </p>
-    async fn list_resource_data_sync(
+    /// Presigns the request `list_resource_data_sync` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_data_sync(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceDataSyncRequest,
-    ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_data_sync_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_data_sync_request(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<(ListTagsForResourceResult, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceDataSyncResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceDataSyncError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the tags assigned to the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_document_permission`, also returning the metadata of the response.
+    pub async fn modify_document_permission_with_metadata(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> Result<
+        (ModifyDocumentPermissionResponse, ResponseMetadata),
+        RusotoError<ModifyDocumentPermissionError>,
+    > {
+        let request = self.build_modify_document_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyDocumentPermissionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyDocumentPermissionError::from_response(response))
         }
     }
 
-    /// <p>Shares a Systems Manager document publicly or privately. If you share a document privately, you must specify the AWS user account IDs for those people who can use the document. If you share a document publicly, you must specify <i>All</i> as the account ID.</p>
-    async fn modify_document_permission(
+    /// Presigns the request `modify_document_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_document_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyDocumentPermissionRequest,
-    ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_document_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_document_permission_request(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ModifyDocumentPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_compliance_items`, also returning the metadata of the response.
+    pub async fn put_compliance_items_with_metadata(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> Result<(PutComplianceItemsResult, ResponseMetadata), RusotoError<PutComplianceItemsError>>
+    {
+        let request = self.build_put_compliance_items_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyDocumentPermissionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutComplianceItemsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyDocumentPermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutComplianceItemsError::from_response(response))
         }
     }
 
-    /// <p><p>Registers a compliance type and other compliance details on a designated resource. This action lets you register custom compliance details with a resource. This call overwrites existing compliance information on the resource, so you must provide a full list of compliance items each time that you send the request.</p> <p>ComplianceType can be one of the following:</p> <ul> <li> <p>ExecutionId: The execution ID when the patch, association, or custom compliance item was applied.</p> </li> <li> <p>ExecutionType: Specify patch, association, or Custom:<code>string</code>.</p> </li> <li> <p>ExecutionTime. The time the patch, association, or custom compliance item was applied to the instance.</p> </li> <li> <p>Id: The patch, association, or custom compliance ID.</p> </li> <li> <p>Title: A title.</p> </li> <li> <p>Status: The status of the compliance item. For example, <code>approved</code> for patches, or <code>Failed</code> for associations.</p> </li> <li> <p>Severity: A patch severity. For example, <code>critical</code>.</p> </li> <li> <p>DocumentName: A SSM document name. For example, AWS-RunPatchBaseline.</p> </li> <li> <p>DocumentVersion: An SSM document version number. For example, 4.</p> </li> <li> <p>Classification: A patch classification. For example, <code>security updates</code>.</p> </li> <li> <p>PatchBaselineId: A patch baseline ID.</p> </li> <li> <p>PatchSeverity: A patch severity. For example, <code>Critical</code>.</p> </li> <li> <p>PatchState: A patch state. For example, <code>InstancesWithFailedPatches</code>.</p> </li> <li> <p>PatchGroup: The name of a patch group.</p> </li> <li> <p>InstalledTime: The time the association, patch, or custom compliance item was applied to the resource. Specify the time by using the following format: yyyy-MM-dd&#39;T&#39;HH:mm:ss&#39;Z&#39;</p> </li> </ul></p>
-    async fn put_compliance_items(
+    /// Presigns the request `put_compliance_items` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_compliance_items(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutComplianceItemsRequest,
-    ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_compliance_items_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_compliance_items_request(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutComplianceItems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_inventory`, also returning the metadata of the response.
+    pub async fn put_inventory_with_metadata(
+        &self,
+        input: PutInventoryRequest,
+    ) -> Result<(PutInventoryResult, ResponseMetadata), RusotoError<PutInventoryError>> {
+        let request = self.build_put_inventory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutComplianceItemsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutInventoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutComplianceItemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutInventoryError::from_response(response))
         }
     }
 
-    /// <p>Bulk update custom inventory items on one more instance. The request adds an inventory item, if it doesn't already exist, or updates an inventory item, if it does exist.</p>
-    async fn put_inventory(
+    /// Presigns the request `put_inventory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_inventory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutInventoryRequest,
-    ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_inventory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_inventory_request(&self, input: PutInventoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_parameter`, also returning the metadata of the response.
+    pub async fn put_parameter_with_metadata(
+        &self,
+        input: PutParameterRequest,
+    ) -> Result<(PutParameterResult, ResponseMetadata), RusotoError<PutParameterError>> {
+        let request = self.build_put_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutInventoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutParameterResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutInventoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutParameterError::from_response(response))
         }
     }
 
-    /// <p>Add a parameter to the system.</p>
-    async fn put_parameter(
+    /// Presigns the request `put_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutParameterRequest,
-    ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_parameter_request(&self, input: PutParameterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.PutParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_default_patch_baseline`, also returning the metadata of the response.
+    pub async fn register_default_patch_baseline_with_metadata(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> Result<
+        (RegisterDefaultPatchBaselineResult, ResponseMetadata),
+        RusotoError<RegisterDefaultPatchBaselineError>,
+    > {
+        let request = self.build_register_default_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutParameterResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterDefaultPatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterDefaultPatchBaselineError::from_response(response))
         }
     }
 
-    /// <p>Defines the default patch baseline for the relevant operating system.</p> <p>To reset the AWS predefined patch baseline as the default, specify the full patch baseline ARN as the baseline ID value. For example, for CentOS, specify <code>arn:aws:ssm:us-east-2:733109147000:patchbaseline/pb-0574b43a65ea646ed</code> instead of <code>pb-0574b43a65ea646ed</code>.</p>
-    async fn register_default_patch_baseline(
+    /// Presigns the request `register_default_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_default_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterDefaultPatchBaselineRequest,
-    ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_default_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_default_patch_baseline_request(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterDefaultPatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_patch_baseline_for_patch_group`, also returning the metadata of the response.
+    pub async fn register_patch_baseline_for_patch_group_with_metadata(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        (RegisterPatchBaselineForPatchGroupResult, ResponseMetadata),
+        RusotoError<RegisterPatchBaselineForPatchGroupError>,
+    > {
+        let request = self.build_register_patch_baseline_for_patch_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterDefaultPatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterPatchBaselineForPatchGroupResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterDefaultPatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterPatchBaselineForPatchGroupError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Registers a patch baseline for a patch group.</p>
-    async fn register_patch_baseline_for_patch_group(
+    /// Presigns the request `register_patch_baseline_for_patch_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_patch_baseline_for_patch_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterPatchBaselineForPatchGroupRequest,
-    ) -> Result<
-        RegisterPatchBaselineForPatchGroupResult,
-        RusotoError<RegisterPatchBaselineForPatchGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_patch_baseline_for_patch_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_patch_baseline_for_patch_group_request(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterPatchBaselineForPatchGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18262,33 +22367,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_target_with_maintenance_window`, also returning the metadata of the response.
+    pub async fn register_target_with_maintenance_window_with_metadata(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> Result<
+        (RegisterTargetWithMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<RegisterTargetWithMaintenanceWindowError>,
+    > {
+        let request = self.build_register_target_with_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterPatchBaselineForPatchGroupResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterTargetWithMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterPatchBaselineForPatchGroupError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterTargetWithMaintenanceWindowError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Registers a target with a maintenance window.</p>
-    async fn register_target_with_maintenance_window(
+    /// Presigns the request `register_target_with_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_target_with_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterTargetWithMaintenanceWindowRequest,
-    ) -> Result<
-        RegisterTargetWithMaintenanceWindowResult,
-        RusotoError<RegisterTargetWithMaintenanceWindowError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_target_with_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_target_with_maintenance_window_request(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTargetWithMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18298,33 +22430,60 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_task_with_maintenance_window`, also returning the metadata of the response.
+    pub async fn register_task_with_maintenance_window_with_metadata(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> Result<
+        (RegisterTaskWithMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<RegisterTaskWithMaintenanceWindowError>,
+    > {
+        let request = self.build_register_task_with_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterTargetWithMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterTaskWithMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterTargetWithMaintenanceWindowError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterTaskWithMaintenanceWindowError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Adds a new task to a maintenance window.</p>
-    async fn register_task_with_maintenance_window(
+    /// Presigns the request `register_task_with_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_task_with_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterTaskWithMaintenanceWindowRequest,
-    ) -> Result<
-        RegisterTaskWithMaintenanceWindowResult,
-        RusotoError<RegisterTaskWithMaintenanceWindowError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_task_with_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_task_with_maintenance_window_request(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTaskWithMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -18334,634 +22493,4424 @@ impl Ssm for SsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<
+        (RemoveTagsFromResourceResult, ResponseMetadata),
+        RusotoError<RemoveTagsFromResourceError>,
+    > {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterTaskWithMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsFromResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterTaskWithMaintenanceWindowError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tag keys from the specified resource.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromResourceRequest,
-    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reset_service_setting`, also returning the metadata of the response.
+    pub async fn reset_service_setting_with_metadata(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> Result<(ResetServiceSettingResult, ResponseMetadata), RusotoError<ResetServiceSettingError>>
+    {
+        let request = self.build_reset_service_setting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveTagsFromResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResetServiceSettingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResetServiceSettingError::from_response(response))
         }
     }
 
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Use the <a>UpdateServiceSetting</a> API action to change the default setting. </p> <p>Reset the service setting for the account to the default value as provisioned by the AWS service team. </p>
-    async fn reset_service_setting(
+    /// Presigns the request `reset_service_setting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_service_setting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResetServiceSettingRequest,
-    ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_service_setting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_service_setting_request(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResetServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resume_session`, also returning the metadata of the response.
+    pub async fn resume_session_with_metadata(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> Result<(ResumeSessionResponse, ResponseMetadata), RusotoError<ResumeSessionError>> {
+        let request = self.build_resume_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResetServiceSettingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResumeSessionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetServiceSettingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResumeSessionError::from_response(response))
         }
     }
 
-    /// <p><p>Reconnects a session to an instance after it has been disconnected. Connections can be resumed for disconnected sessions, but not terminated sessions.</p> <note> <p>This command is primarily for use by client machines to automatically reconnect during intermittent network issues. It is not intended for any other use.</p> </note></p>
-    async fn resume_session(
+    /// Presigns the request `resume_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resume_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResumeSessionRequest,
-    ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resume_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resume_session_request(&self, input: ResumeSessionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResumeSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_automation_signal`, also returning the metadata of the response.
+    pub async fn send_automation_signal_with_metadata(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> Result<
+        (SendAutomationSignalResult, ResponseMetadata),
+        RusotoError<SendAutomationSignalError>,
+    > {
+        let request = self.build_send_automation_signal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResumeSessionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendAutomationSignalResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResumeSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendAutomationSignalError::from_response(response))
         }
     }
 
-    /// <p>Sends a signal to an Automation execution to change the current behavior or status of the execution. </p>
-    async fn send_automation_signal(
+    /// Presigns the request `send_automation_signal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_automation_signal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendAutomationSignalRequest,
-    ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_automation_signal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_automation_signal_request(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendAutomationSignal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_command`, also returning the metadata of the response.
+    pub async fn send_command_with_metadata(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<(SendCommandResult, ResponseMetadata), RusotoError<SendCommandError>> {
+        let request = self.build_send_command_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendAutomationSignalResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendCommandResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendAutomationSignalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendCommandError::from_response(response))
         }
     }
 
-    /// <p>Runs commands on one or more managed instances.</p>
-    async fn send_command(
+    /// Presigns the request `send_command` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_command(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendCommandRequest,
-    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_command_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_command_request(&self, input: SendCommandRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendCommand");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.SendCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_associations_once`, also returning the metadata of the response.
+    pub async fn start_associations_once_with_metadata(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> Result<
+        (StartAssociationsOnceResult, ResponseMetadata),
+        RusotoError<StartAssociationsOnceError>,
+    > {
+        let request = self.build_start_associations_once_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendCommandResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAssociationsOnceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendCommandError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAssociationsOnceError::from_response(response))
         }
     }
 
-    /// <p>Use this API action to run an association immediately and only one time. This action can be helpful when troubleshooting associations.</p>
-    async fn start_associations_once(
+    /// Presigns the request `start_associations_once` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_associations_once(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAssociationsOnceRequest,
-    ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_associations_once_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_associations_once_request(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAssociationsOnce");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_automation_execution`, also returning the metadata of the response.
+    pub async fn start_automation_execution_with_metadata(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> Result<
+        (StartAutomationExecutionResult, ResponseMetadata),
+        RusotoError<StartAutomationExecutionError>,
+    > {
+        let request = self.build_start_automation_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAssociationsOnceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAutomationExecutionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAssociationsOnceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAutomationExecutionError::from_response(response))
         }
     }
 
-    /// <p>Initiates execution of an Automation document.</p>
-    async fn start_automation_execution(
+    /// Presigns the request `start_automation_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_automation_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAutomationExecutionRequest,
-    ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_automation_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_automation_execution_request(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_session`, also returning the metadata of the response.
+    pub async fn start_session_with_metadata(
+        &self,
+        input: StartSessionRequest,
+    ) -> Result<(StartSessionResponse, ResponseMetadata), RusotoError<StartSessionError>> {
+        let request = self.build_start_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAutomationExecutionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartSessionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAutomationExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartSessionError::from_response(response))
         }
     }
 
-    /// <p><p>Initiates a connection to a target (for example, an instance) for a Session Manager session. Returns a URL and token that can be used to open a WebSocket connection for sending input and receiving outputs.</p> <note> <p>AWS CLI usage: <code>start-session</code> is an interactive command that requires the Session Manager plugin to be installed on the client machine making the call. For information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html"> Install the Session Manager Plugin for the AWS CLI</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>AWS Tools for PowerShell usage: Start-SSMSession is not currently supported by AWS Tools for PowerShell on Windows local machines.</p> </note></p>
-    async fn start_session(
+    /// Presigns the request `start_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSessionRequest,
-    ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_session_request(&self, input: StartSessionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StartSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_automation_execution`, also returning the metadata of the response.
+    pub async fn stop_automation_execution_with_metadata(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> Result<
+        (StopAutomationExecutionResult, ResponseMetadata),
+        RusotoError<StopAutomationExecutionError>,
+    > {
+        let request = self.build_stop_automation_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartSessionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopAutomationExecutionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopAutomationExecutionError::from_response(response))
         }
     }
 
-    /// <p>Stop an Automation that is currently running.</p>
-    async fn stop_automation_execution(
+    /// Presigns the request `stop_automation_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_automation_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopAutomationExecutionRequest,
-    ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_automation_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_automation_execution_request(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StopAutomationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `terminate_session`, also returning the metadata of the response.
+    pub async fn terminate_session_with_metadata(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> Result<(TerminateSessionResponse, ResponseMetadata), RusotoError<TerminateSessionError>>
+    {
+        let request = self.build_terminate_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopAutomationExecutionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TerminateSessionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopAutomationExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TerminateSessionError::from_response(response))
         }
     }
 
-    /// <p>Permanently ends a session and closes the data connection between the Session Manager client and SSM Agent on the instance. A terminated session cannot be resumed.</p>
-    async fn terminate_session(
+    /// Presigns the request `terminate_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TerminateSessionRequest,
-    ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_session_request(&self, input: TerminateSessionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("TerminateSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_association`, also returning the metadata of the response.
+    pub async fn update_association_with_metadata(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> Result<(UpdateAssociationResult, ResponseMetadata), RusotoError<UpdateAssociationError>>
+    {
+        let request = self.build_update_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TerminateSessionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TerminateSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAssociationError::from_response(response))
         }
     }
 
-    /// <p><p>Updates an association. You can update the association name and version, the document version, schedule, parameters, and Amazon S3 output. </p> <p>In order to call this API action, your IAM user account, group, or role must be configured with permission to call the <a>DescribeAssociation</a> API action. If you don&#39;t have permission to call DescribeAssociation, then you receive the following error: <code>An error occurred (AccessDeniedException) when calling the UpdateAssociation operation: User: &lt;user<em>arn&gt; is not authorized to perform: ssm:DescribeAssociation on resource: &lt;resource</em>arn&gt;</code> </p> <important> <p>When you update an association, the association immediately runs against the specified targets.</p> </important></p>
-    async fn update_association(
+    /// Presigns the request `update_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAssociationRequest,
-    ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_association_request(&self, input: UpdateAssociationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_association_status`, also returning the metadata of the response.
+    pub async fn update_association_status_with_metadata(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> Result<
+        (UpdateAssociationStatusResult, ResponseMetadata),
+        RusotoError<UpdateAssociationStatusError>,
+    > {
+        let request = self.build_update_association_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAssociationStatusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAssociationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAssociationStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the status of the Systems Manager document associated with the specified instance.</p>
-    async fn update_association_status(
+    /// Presigns the request `update_association_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_association_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAssociationStatusRequest,
-    ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_association_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_association_status_request(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_document`, also returning the metadata of the response.
+    pub async fn update_document_with_metadata(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<(UpdateDocumentResult, ResponseMetadata), RusotoError<UpdateDocumentError>> {
+        let request = self.build_update_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAssociationStatusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDocumentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAssociationStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDocumentError::from_response(response))
         }
     }
 
-    /// <p>Updates one or more values for an SSM document.</p>
-    async fn update_document(
+    /// Presigns the request `update_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDocumentRequest,
-    ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_document_request(&self, input: UpdateDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_document_default_version`, also returning the metadata of the response.
+    pub async fn update_document_default_version_with_metadata(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> Result<
+        (UpdateDocumentDefaultVersionResult, ResponseMetadata),
+        RusotoError<UpdateDocumentDefaultVersionError>,
+    > {
+        let request = self.build_update_document_default_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDocumentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDocumentDefaultVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDocumentDefaultVersionError::from_response(response))
         }
     }
 
-    /// <p>Set the default version of a document. </p>
-    async fn update_document_default_version(
+    /// Presigns the request `update_document_default_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_document_default_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDocumentDefaultVersionRequest,
-    ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_document_default_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_document_default_version_request(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocumentDefaultVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_maintenance_window`, also returning the metadata of the response.
+    pub async fn update_maintenance_window_with_metadata(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> Result<
+        (UpdateMaintenanceWindowResult, ResponseMetadata),
+        RusotoError<UpdateMaintenanceWindowError>,
+    > {
+        let request = self.build_update_maintenance_window_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDocumentDefaultVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMaintenanceWindowResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDocumentDefaultVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMaintenanceWindowError::from_response(response))
         }
     }
 
-    /// <p><p>Updates an existing maintenance window. Only specified parameters are modified.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
-    async fn update_maintenance_window(
+    /// Presigns the request `update_maintenance_window` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_maintenance_window(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMaintenanceWindowRequest,
-    ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_maintenance_window_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_maintenance_window_request(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_maintenance_window_target`, also returning the metadata of the response.
+    pub async fn update_maintenance_window_target_with_metadata(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> Result<
+        (UpdateMaintenanceWindowTargetResult, ResponseMetadata),
+        RusotoError<UpdateMaintenanceWindowTargetError>,
+    > {
+        let request = self.build_update_maintenance_window_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMaintenanceWindowResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMaintenanceWindowTargetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMaintenanceWindowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMaintenanceWindowTargetError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies the target of an existing maintenance window. You can change the following:</p> <ul> <li> <p>Name</p> </li> <li> <p>Description</p> </li> <li> <p>Owner</p> </li> <li> <p>IDs for an ID target</p> </li> <li> <p>Tags for a Tag target</p> </li> <li> <p>From any supported tag type to another. The three supported tag types are ID target, Tag target, and resource group. For more information, see <a>Target</a>.</p> </li> </ul> <note> <p>If a parameter is null, then the corresponding field is not modified.</p> </note></p>
-    async fn update_maintenance_window_target(
+    /// Presigns the request `update_maintenance_window_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_maintenance_window_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMaintenanceWindowTargetRequest,
-    ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_maintenance_window_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_maintenance_window_target_request(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_maintenance_window_task`, also returning the metadata of the response.
+    pub async fn update_maintenance_window_task_with_metadata(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> Result<
+        (UpdateMaintenanceWindowTaskResult, ResponseMetadata),
+        RusotoError<UpdateMaintenanceWindowTaskError>,
+    > {
+        let request = self.build_update_maintenance_window_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMaintenanceWindowTargetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMaintenanceWindowTaskResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMaintenanceWindowTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMaintenanceWindowTaskError::from_response(response))
         }
     }
 
-    /// <p>Modifies a task assigned to a maintenance window. You can't change the task type, but you can change the following values:</p> <ul> <li> <p>TaskARN. For example, you can change a RUN_COMMAND task from AWS-RunPowerShellScript to AWS-RunShellScript.</p> </li> <li> <p>ServiceRoleArn</p> </li> <li> <p>TaskInvocationParameters</p> </li> <li> <p>Priority</p> </li> <li> <p>MaxConcurrency</p> </li> <li> <p>MaxErrors</p> </li> </ul> <p>If a parameter is null, then the corresponding field is not modified. Also, if you set Replace to true, then all fields required by the <a>RegisterTaskWithMaintenanceWindow</a> action are required for this request. Optional fields that aren't specified are set to null.</p>
-    async fn update_maintenance_window_task(
+    /// Presigns the request `update_maintenance_window_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_maintenance_window_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMaintenanceWindowTaskRequest,
-    ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_maintenance_window_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_maintenance_window_task_request(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_managed_instance_role`, also returning the metadata of the response.
+    pub async fn update_managed_instance_role_with_metadata(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> Result<
+        (UpdateManagedInstanceRoleResult, ResponseMetadata),
+        RusotoError<UpdateManagedInstanceRoleError>,
+    > {
+        let request = self.build_update_managed_instance_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMaintenanceWindowTaskResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateManagedInstanceRoleResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMaintenanceWindowTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateManagedInstanceRoleError::from_response(response))
         }
     }
 
-    /// <p>Assigns or changes an Amazon Identity and Access Management (IAM) role for the managed instance.</p>
-    async fn update_managed_instance_role(
+    /// Presigns the request `update_managed_instance_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_managed_instance_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateManagedInstanceRoleRequest,
-    ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_managed_instance_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_managed_instance_role_request(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateManagedInstanceRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_ops_item`, also returning the metadata of the response.
+    pub async fn update_ops_item_with_metadata(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> Result<(UpdateOpsItemResponse, ResponseMetadata), RusotoError<UpdateOpsItemError>> {
+        let request = self.build_update_ops_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateManagedInstanceRoleResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateOpsItemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateManagedInstanceRoleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateOpsItemError::from_response(response))
         }
     }
 
-    /// <p>Edit or change an OpsItem. You must have permission in AWS Identity and Access Management (IAM) to update an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
-    async fn update_ops_item(
+    /// Presigns the request `update_ops_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ops_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateOpsItemRequest,
-    ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ops_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ops_item_request(&self, input: UpdateOpsItemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateOpsItem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_patch_baseline`, also returning the metadata of the response.
+    pub async fn update_patch_baseline_with_metadata(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> Result<(UpdatePatchBaselineResult, ResponseMetadata), RusotoError<UpdatePatchBaselineError>>
+    {
+        let request = self.build_update_patch_baseline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateOpsItemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePatchBaselineResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateOpsItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePatchBaselineError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies an existing patch baseline. Fields not specified in the request are left unchanged.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
-    async fn update_patch_baseline(
+    /// Presigns the request `update_patch_baseline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_patch_baseline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePatchBaselineRequest,
-    ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_patch_baseline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_patch_baseline_request(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdatePatchBaseline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_resource_data_sync`, also returning the metadata of the response.
+    pub async fn update_resource_data_sync_with_metadata(
+        &self,
+        input: UpdateResourceDataSyncRequest,
+    ) -> Result<
+        (UpdateResourceDataSyncResult, ResponseMetadata),
+        RusotoError<UpdateResourceDataSyncError>,
+    > {
+        let request = self.build_update_resource_data_sync_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePatchBaselineResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateResourceDataSyncResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePatchBaselineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateResourceDataSyncError::from_response(response))
         }
     }
 
-    /// <p>Update a resource data sync. After you create a resource data sync for a Region, you can't change the account options for that sync. For example, if you create a sync in the us-east-2 (Ohio) Region and you choose the Include only the current account option, you can't edit that sync later and choose the Include all accounts from my AWS Organizations configuration option. Instead, you must delete the first resource data sync, and create a new one.</p>
-    async fn update_resource_data_sync(
+    /// Presigns the request `update_resource_data_sync` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource_data_sync(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceDataSyncRequest,
-    ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_data_sync_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_data_sync_request(
+        &self,
+        input: UpdateResourceDataSyncRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateResourceDataSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service_setting`, also returning the metadata of the response.
+    pub async fn update_service_setting_with_metadata(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> Result<
+        (UpdateServiceSettingResult, ResponseMetadata),
+        RusotoError<UpdateServiceSettingError>,
+    > {
+        let request = self.build_update_service_setting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResourceDataSyncResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServiceSettingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceDataSyncError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServiceSettingError::from_response(response))
         }
     }
 
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Update the service setting for the account. </p>
-    async fn update_service_setting(
+    /// Presigns the request `update_service_setting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service_setting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServiceSettingRequest,
-    ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_setting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_setting_request(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateServiceSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateServiceSettingResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServiceSettingError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeActivations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_activations_pages(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_activations(input).await },
+            |input: &DescribeActivationsRequest, output: &DescribeActivationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeActivationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ActivationList` of every page of `DescribeActivations` results for `input`, as `describe_activations_pages` requests them.
+    pub fn describe_activations_stream(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> impl futures::Stream<Item = Result<Activation, RusotoError<DescribeActivationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_activations_pages(input), |page| {
+            page.activation_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeAssociationExecutionTargets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_association_execution_targets_pages(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAssociationExecutionTargetsResult,
+            RusotoError<DescribeAssociationExecutionTargetsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_association_execution_targets(input).await
+            },
+            |input: &DescribeAssociationExecutionTargetsRequest,
+             output: &DescribeAssociationExecutionTargetsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAssociationExecutionTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AssociationExecutionTargets` of every page of `DescribeAssociationExecutionTargets` results for `input`, as `describe_association_execution_targets_pages` requests them.
+    pub fn describe_association_execution_targets_stream(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AssociationExecutionTarget,
+            RusotoError<DescribeAssociationExecutionTargetsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_association_execution_targets_pages(input),
+            |page| page.association_execution_targets.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeAssociationExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_association_executions_pages(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAssociationExecutionsResult,
+            RusotoError<DescribeAssociationExecutionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_association_executions(input).await
+            },
+            |input: &DescribeAssociationExecutionsRequest,
+             output: &DescribeAssociationExecutionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAssociationExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AssociationExecutions` of every page of `DescribeAssociationExecutions` results for `input`, as `describe_association_executions_pages` requests them.
+    pub fn describe_association_executions_stream(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AssociationExecution, RusotoError<DescribeAssociationExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_association_executions_pages(input), |page| {
+            page.association_executions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeAutomationExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_automation_executions_pages(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAutomationExecutionsResult,
+            RusotoError<DescribeAutomationExecutionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_automation_executions(input).await },
+            |input: &DescribeAutomationExecutionsRequest,
+             output: &DescribeAutomationExecutionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAutomationExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AutomationExecutionMetadataList` of every page of `DescribeAutomationExecutions` results for `input`, as `describe_automation_executions_pages` requests them.
+    pub fn describe_automation_executions_stream(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AutomationExecutionMetadata, RusotoError<DescribeAutomationExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_automation_executions_pages(input), |page| {
+            page.automation_execution_metadata_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeAutomationStepExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_automation_step_executions_pages(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAutomationStepExecutionsResult,
+            RusotoError<DescribeAutomationStepExecutionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_automation_step_executions(input).await
+            },
+            |input: &DescribeAutomationStepExecutionsRequest,
+             output: &DescribeAutomationStepExecutionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAutomationStepExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StepExecutions` of every page of `DescribeAutomationStepExecutions` results for `input`, as `describe_automation_step_executions_pages` requests them.
+    pub fn describe_automation_step_executions_stream(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<StepExecution, RusotoError<DescribeAutomationStepExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_automation_step_executions_pages(input),
+            |page| page.step_executions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeAvailablePatches` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_available_patches_pages(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_available_patches(input).await },
+            |input: &DescribeAvailablePatchesRequest, output: &DescribeAvailablePatchesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAvailablePatchesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Patches` of every page of `DescribeAvailablePatches` results for `input`, as `describe_available_patches_pages` requests them.
+    pub fn describe_available_patches_stream(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> impl futures::Stream<Item = Result<Patch, RusotoError<DescribeAvailablePatchesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_available_patches_pages(input), |page| {
+            page.patches.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEffectiveInstanceAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_effective_instance_associations_pages(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEffectiveInstanceAssociationsResult,
+            RusotoError<DescribeEffectiveInstanceAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_effective_instance_associations(input).await
+            },
+            |input: &DescribeEffectiveInstanceAssociationsRequest,
+             output: &DescribeEffectiveInstanceAssociationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEffectiveInstanceAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Associations` of every page of `DescribeEffectiveInstanceAssociations` results for `input`, as `describe_effective_instance_associations_pages` requests them.
+    pub fn describe_effective_instance_associations_stream(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<InstanceAssociation, RusotoError<DescribeEffectiveInstanceAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_effective_instance_associations_pages(input),
+            |page| page.associations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeEffectivePatchesForPatchBaseline` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_effective_patches_for_patch_baseline_pages(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEffectivePatchesForPatchBaselineResult,
+            RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client
+                    .describe_effective_patches_for_patch_baseline(input)
+                    .await
+            },
+            |input: &DescribeEffectivePatchesForPatchBaselineRequest,
+             output: &DescribeEffectivePatchesForPatchBaselineResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEffectivePatchesForPatchBaselineRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EffectivePatches` of every page of `DescribeEffectivePatchesForPatchBaseline` results for `input`, as `describe_effective_patches_for_patch_baseline_pages` requests them.
+    pub fn describe_effective_patches_for_patch_baseline_stream(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EffectivePatch, RusotoError<DescribeEffectivePatchesForPatchBaselineError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_effective_patches_for_patch_baseline_pages(input),
+            |page| page.effective_patches.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeInstanceAssociationsStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_instance_associations_status_pages(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeInstanceAssociationsStatusResult,
+            RusotoError<DescribeInstanceAssociationsStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_instance_associations_status(input).await
+            },
+            |input: &DescribeInstanceAssociationsStatusRequest,
+             output: &DescribeInstanceAssociationsStatusResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstanceAssociationsStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstanceAssociationStatusInfos` of every page of `DescribeInstanceAssociationsStatus` results for `input`, as `describe_instance_associations_status_pages` requests them.
+    pub fn describe_instance_associations_status_stream(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            InstanceAssociationStatusInfo,
+            RusotoError<DescribeInstanceAssociationsStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_instance_associations_status_pages(input),
+            |page| page.instance_association_status_infos.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeInstanceInformation` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_instance_information_pages(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeInstanceInformationResult,
+            RusotoError<DescribeInstanceInformationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_instance_information(input).await },
+            |input: &DescribeInstanceInformationRequest,
+             output: &DescribeInstanceInformationResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstanceInformationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstanceInformationList` of every page of `DescribeInstanceInformation` results for `input`, as `describe_instance_information_pages` requests them.
+    pub fn describe_instance_information_stream(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<InstanceInformation, RusotoError<DescribeInstanceInformationError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_instance_information_pages(input), |page| {
+            page.instance_information_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeInstancePatchStates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_instance_patch_states_pages(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeInstancePatchStatesResult,
+            RusotoError<DescribeInstancePatchStatesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_instance_patch_states(input).await },
+            |input: &DescribeInstancePatchStatesRequest,
+             output: &DescribeInstancePatchStatesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstancePatchStatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstancePatchStates` of every page of `DescribeInstancePatchStates` results for `input`, as `describe_instance_patch_states_pages` requests them.
+    pub fn describe_instance_patch_states_stream(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<InstancePatchState, RusotoError<DescribeInstancePatchStatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_instance_patch_states_pages(input), |page| {
+            page.instance_patch_states.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeInstancePatchStatesForPatchGroup` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_instance_patch_states_for_patch_group_pages(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeInstancePatchStatesForPatchGroupResult,
+            RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client
+                    .describe_instance_patch_states_for_patch_group(input)
+                    .await
+            },
+            |input: &DescribeInstancePatchStatesForPatchGroupRequest,
+             output: &DescribeInstancePatchStatesForPatchGroupResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstancePatchStatesForPatchGroupRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstancePatchStates` of every page of `DescribeInstancePatchStatesForPatchGroup` results for `input`, as `describe_instance_patch_states_for_patch_group_pages` requests them.
+    pub fn describe_instance_patch_states_for_patch_group_stream(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            InstancePatchState,
+            RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_instance_patch_states_for_patch_group_pages(input),
+            |page| page.instance_patch_states.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeInstancePatches` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_instance_patches_pages(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_instance_patches(input).await },
+            |input: &DescribeInstancePatchesRequest, output: &DescribeInstancePatchesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstancePatchesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Patches` of every page of `DescribeInstancePatches` results for `input`, as `describe_instance_patches_pages` requests them.
+    pub fn describe_instance_patches_stream(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PatchComplianceData, RusotoError<DescribeInstancePatchesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_instance_patches_pages(input), |page| {
+            page.patches.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeInventoryDeletions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_inventory_deletions_pages(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeInventoryDeletionsResult,
+            RusotoError<DescribeInventoryDeletionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_inventory_deletions(input).await },
+            |input: &DescribeInventoryDeletionsRequest,
+             output: &DescribeInventoryDeletionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInventoryDeletionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InventoryDeletions` of every page of `DescribeInventoryDeletions` results for `input`, as `describe_inventory_deletions_pages` requests them.
+    pub fn describe_inventory_deletions_stream(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<InventoryDeletionStatusItem, RusotoError<DescribeInventoryDeletionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_inventory_deletions_pages(input), |page| {
+            page.inventory_deletions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMaintenanceWindowExecutionTaskInvocations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_execution_task_invocations_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+            RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client
+                    .describe_maintenance_window_execution_task_invocations(input)
+                    .await
+            },
+            |input: &DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+             output: &DescribeMaintenanceWindowExecutionTaskInvocationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowExecutionTaskInvocationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WindowExecutionTaskInvocationIdentities` of every page of `DescribeMaintenanceWindowExecutionTaskInvocations` results for `input`, as `describe_maintenance_window_execution_task_invocations_pages` requests them.
+    pub fn describe_maintenance_window_execution_task_invocations_stream(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MaintenanceWindowExecutionTaskInvocationIdentity,
+            RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_execution_task_invocations_pages(input),
+            |page| {
+                page.window_execution_task_invocation_identities
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindowExecutionTasks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_execution_tasks_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowExecutionTasksResult,
+            RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client
+                    .describe_maintenance_window_execution_tasks(input)
+                    .await
+            },
+            |input: &DescribeMaintenanceWindowExecutionTasksRequest,
+             output: &DescribeMaintenanceWindowExecutionTasksResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowExecutionTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WindowExecutionTaskIdentities` of every page of `DescribeMaintenanceWindowExecutionTasks` results for `input`, as `describe_maintenance_window_execution_tasks_pages` requests them.
+    pub fn describe_maintenance_window_execution_tasks_stream(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MaintenanceWindowExecutionTaskIdentity,
+            RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_execution_tasks_pages(input),
+            |page| page.window_execution_task_identities.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindowExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_executions_pages(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowExecutionsResult,
+            RusotoError<DescribeMaintenanceWindowExecutionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_maintenance_window_executions(input).await
+            },
+            |input: &DescribeMaintenanceWindowExecutionsRequest,
+             output: &DescribeMaintenanceWindowExecutionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WindowExecutions` of every page of `DescribeMaintenanceWindowExecutions` results for `input`, as `describe_maintenance_window_executions_pages` requests them.
+    pub fn describe_maintenance_window_executions_stream(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MaintenanceWindowExecution,
+            RusotoError<DescribeMaintenanceWindowExecutionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_executions_pages(input),
+            |page| page.window_executions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindowSchedule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_schedule_pages(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowScheduleResult,
+            RusotoError<DescribeMaintenanceWindowScheduleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_maintenance_window_schedule(input).await
+            },
+            |input: &DescribeMaintenanceWindowScheduleRequest,
+             output: &DescribeMaintenanceWindowScheduleResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowScheduleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScheduledWindowExecutions` of every page of `DescribeMaintenanceWindowSchedule` results for `input`, as `describe_maintenance_window_schedule_pages` requests them.
+    pub fn describe_maintenance_window_schedule_stream(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ScheduledWindowExecution,
+            RusotoError<DescribeMaintenanceWindowScheduleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_schedule_pages(input),
+            |page| page.scheduled_window_executions.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindowTargets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_targets_pages(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowTargetsResult,
+            RusotoError<DescribeMaintenanceWindowTargetsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_maintenance_window_targets(input).await
+            },
+            |input: &DescribeMaintenanceWindowTargetsRequest,
+             output: &DescribeMaintenanceWindowTargetsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Targets` of every page of `DescribeMaintenanceWindowTargets` results for `input`, as `describe_maintenance_window_targets_pages` requests them.
+    pub fn describe_maintenance_window_targets_stream(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MaintenanceWindowTarget, RusotoError<DescribeMaintenanceWindowTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_targets_pages(input),
+            |page| page.targets.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindowTasks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_window_tasks_pages(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowTasksResult,
+            RusotoError<DescribeMaintenanceWindowTasksError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_maintenance_window_tasks(input).await
+            },
+            |input: &DescribeMaintenanceWindowTasksRequest,
+             output: &DescribeMaintenanceWindowTasksResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tasks` of every page of `DescribeMaintenanceWindowTasks` results for `input`, as `describe_maintenance_window_tasks_pages` requests them.
+    pub fn describe_maintenance_window_tasks_stream(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MaintenanceWindowTask, RusotoError<DescribeMaintenanceWindowTasksError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_window_tasks_pages(input),
+            |page| page.tasks.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMaintenanceWindows` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_windows_pages(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowsResult,
+            RusotoError<DescribeMaintenanceWindowsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_maintenance_windows(input).await },
+            |input: &DescribeMaintenanceWindowsRequest,
+             output: &DescribeMaintenanceWindowsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WindowIdentities` of every page of `DescribeMaintenanceWindows` results for `input`, as `describe_maintenance_windows_pages` requests them.
+    pub fn describe_maintenance_windows_stream(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MaintenanceWindowIdentity, RusotoError<DescribeMaintenanceWindowsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_maintenance_windows_pages(input), |page| {
+            page.window_identities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMaintenanceWindowsForTarget` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_maintenance_windows_for_target_pages(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMaintenanceWindowsForTargetResult,
+            RusotoError<DescribeMaintenanceWindowsForTargetError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.describe_maintenance_windows_for_target(input).await
+            },
+            |input: &DescribeMaintenanceWindowsForTargetRequest,
+             output: &DescribeMaintenanceWindowsForTargetResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMaintenanceWindowsForTargetRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `WindowIdentities` of every page of `DescribeMaintenanceWindowsForTarget` results for `input`, as `describe_maintenance_windows_for_target_pages` requests them.
+    pub fn describe_maintenance_windows_for_target_stream(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MaintenanceWindowIdentityForTarget,
+            RusotoError<DescribeMaintenanceWindowsForTargetError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_maintenance_windows_for_target_pages(input),
+            |page| page.window_identities.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeOpsItems` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_ops_items_pages(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_ops_items(input).await },
+            |input: &DescribeOpsItemsRequest, output: &DescribeOpsItemsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeOpsItemsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OpsItemSummaries` of every page of `DescribeOpsItems` results for `input`, as `describe_ops_items_pages` requests them.
+    pub fn describe_ops_items_stream(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> impl futures::Stream<Item = Result<OpsItemSummary, RusotoError<DescribeOpsItemsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_ops_items_pages(input), |page| {
+            page.ops_item_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeParameters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_parameters_pages(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeParametersResult, RusotoError<DescribeParametersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_parameters(input).await },
+            |input: &DescribeParametersRequest, output: &DescribeParametersResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeParametersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeParameters` results for `input`, as `describe_parameters_pages` requests them.
+    pub fn describe_parameters_stream(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> impl futures::Stream<Item = Result<ParameterMetadata, RusotoError<DescribeParametersError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribePatchBaselines` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_patch_baselines_pages(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_patch_baselines(input).await },
+            |input: &DescribePatchBaselinesRequest, output: &DescribePatchBaselinesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePatchBaselinesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BaselineIdentities` of every page of `DescribePatchBaselines` results for `input`, as `describe_patch_baselines_pages` requests them.
+    pub fn describe_patch_baselines_stream(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PatchBaselineIdentity, RusotoError<DescribePatchBaselinesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_patch_baselines_pages(input), |page| {
+            page.baseline_identities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribePatchGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_patch_groups_pages(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_patch_groups(input).await },
+            |input: &DescribePatchGroupsRequest, output: &DescribePatchGroupsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePatchGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Mappings` of every page of `DescribePatchGroups` results for `input`, as `describe_patch_groups_pages` requests them.
+    pub fn describe_patch_groups_stream(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PatchGroupPatchBaselineMapping, RusotoError<DescribePatchGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_patch_groups_pages(input), |page| {
+            page.mappings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribePatchProperties` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_patch_properties_pages(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_patch_properties(input).await },
+            |input: &DescribePatchPropertiesRequest, output: &DescribePatchPropertiesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePatchPropertiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Properties` of every page of `DescribePatchProperties` results for `input`, as `describe_patch_properties_pages` requests them.
+    pub fn describe_patch_properties_stream(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ::std::collections::HashMap<String, String>,
+            RusotoError<DescribePatchPropertiesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_patch_properties_pages(input), |page| {
+            page.properties.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSessions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_sessions_pages(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.describe_sessions(input).await },
+            |input: &DescribeSessionsRequest, output: &DescribeSessionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSessionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Sessions` of every page of `DescribeSessions` results for `input`, as `describe_sessions_pages` requests them.
+    pub fn describe_sessions_stream(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> impl futures::Stream<Item = Result<Session, RusotoError<DescribeSessionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_sessions_pages(input), |page| {
+            page.sessions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetInventory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_inventory_pages(
+        &self,
+        input: GetInventoryRequest,
+    ) -> impl futures::Stream<Item = Result<GetInventoryResult, RusotoError<GetInventoryError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.get_inventory(input).await },
+            |input: &GetInventoryRequest, output: &GetInventoryResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetInventoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Entities` of every page of `GetInventory` results for `input`, as `get_inventory_pages` requests them.
+    pub fn get_inventory_stream(
+        &self,
+        input: GetInventoryRequest,
+    ) -> impl futures::Stream<Item = Result<InventoryResultEntity, RusotoError<GetInventoryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_inventory_pages(input), |page| {
+            page.entities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetInventorySchema` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_inventory_schema_pages(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.get_inventory_schema(input).await },
+            |input: &GetInventorySchemaRequest, output: &GetInventorySchemaResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetInventorySchemaRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Schemas` of every page of `GetInventorySchema` results for `input`, as `get_inventory_schema_pages` requests them.
+    pub fn get_inventory_schema_stream(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> impl futures::Stream<Item = Result<InventoryItemSchema, RusotoError<GetInventorySchemaError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_inventory_schema_pages(input), |page| {
+            page.schemas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetOpsSummary` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_ops_summary_pages(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> impl futures::Stream<Item = Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.get_ops_summary(input).await },
+            |input: &GetOpsSummaryRequest, output: &GetOpsSummaryResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetOpsSummaryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Entities` of every page of `GetOpsSummary` results for `input`, as `get_ops_summary_pages` requests them.
+    pub fn get_ops_summary_stream(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> impl futures::Stream<Item = Result<OpsEntity, RusotoError<GetOpsSummaryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_ops_summary_pages(input), |page| {
+            page.entities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetParameterHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_parameter_history_pages(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.get_parameter_history(input).await },
+            |input: &GetParameterHistoryRequest, output: &GetParameterHistoryResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetParameterHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `GetParameterHistory` results for `input`, as `get_parameter_history_pages` requests them.
+    pub fn get_parameter_history_stream(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> impl futures::Stream<Item = Result<ParameterHistory, RusotoError<GetParameterHistoryError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_parameter_history_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetParametersByPath` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_parameters_by_path_pages(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.get_parameters_by_path(input).await },
+            |input: &GetParametersByPathRequest, output: &GetParametersByPathResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetParametersByPathRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `GetParametersByPath` results for `input`, as `get_parameters_by_path_pages` requests them.
+    pub fn get_parameters_by_path_stream(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<GetParametersByPathError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_parameters_by_path_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAssociationVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_association_versions_pages(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_association_versions(input).await },
+            |input: &ListAssociationVersionsRequest, output: &ListAssociationVersionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssociationVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AssociationVersions` of every page of `ListAssociationVersions` results for `input`, as `list_association_versions_pages` requests them.
+    pub fn list_association_versions_stream(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AssociationVersionInfo, RusotoError<ListAssociationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_association_versions_pages(input), |page| {
+            page.association_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_associations_pages(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssociationsResult, RusotoError<ListAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_associations(input).await },
+            |input: &ListAssociationsRequest, output: &ListAssociationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Associations` of every page of `ListAssociations` results for `input`, as `list_associations_pages` requests them.
+    pub fn list_associations_stream(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> impl futures::Stream<Item = Result<Association, RusotoError<ListAssociationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_associations_pages(input), |page| {
+            page.associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCommandInvocations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_command_invocations_pages(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_command_invocations(input).await },
+            |input: &ListCommandInvocationsRequest, output: &ListCommandInvocationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCommandInvocationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CommandInvocations` of every page of `ListCommandInvocations` results for `input`, as `list_command_invocations_pages` requests them.
+    pub fn list_command_invocations_stream(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CommandInvocation, RusotoError<ListCommandInvocationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_command_invocations_pages(input), |page| {
+            page.command_invocations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCommands` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_commands_pages(
+        &self,
+        input: ListCommandsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCommandsResult, RusotoError<ListCommandsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_commands(input).await },
+            |input: &ListCommandsRequest, output: &ListCommandsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCommandsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Commands` of every page of `ListCommands` results for `input`, as `list_commands_pages` requests them.
+    pub fn list_commands_stream(
+        &self,
+        input: ListCommandsRequest,
+    ) -> impl futures::Stream<Item = Result<Command, RusotoError<ListCommandsError>>> + Send {
+        rusoto_core::pagination::items(self.list_commands_pages(input), |page| {
+            page.commands.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListComplianceItems` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_compliance_items_pages(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_compliance_items(input).await },
+            |input: &ListComplianceItemsRequest, output: &ListComplianceItemsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListComplianceItemsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ComplianceItems` of every page of `ListComplianceItems` results for `input`, as `list_compliance_items_pages` requests them.
+    pub fn list_compliance_items_stream(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> impl futures::Stream<Item = Result<ComplianceItem, RusotoError<ListComplianceItemsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_compliance_items_pages(input), |page| {
+            page.compliance_items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListComplianceSummaries` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_compliance_summaries_pages(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_compliance_summaries(input).await },
+            |input: &ListComplianceSummariesRequest, output: &ListComplianceSummariesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListComplianceSummariesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ComplianceSummaryItems` of every page of `ListComplianceSummaries` results for `input`, as `list_compliance_summaries_pages` requests them.
+    pub fn list_compliance_summaries_stream(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ComplianceSummaryItem, RusotoError<ListComplianceSummariesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_compliance_summaries_pages(input), |page| {
+            page.compliance_summary_items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDocumentVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_document_versions_pages(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_document_versions(input).await },
+            |input: &ListDocumentVersionsRequest, output: &ListDocumentVersionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDocumentVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DocumentVersions` of every page of `ListDocumentVersions` results for `input`, as `list_document_versions_pages` requests them.
+    pub fn list_document_versions_stream(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentVersionInfo, RusotoError<ListDocumentVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_document_versions_pages(input), |page| {
+            page.document_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDocuments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_documents_pages(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDocumentsResult, RusotoError<ListDocumentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_documents(input).await },
+            |input: &ListDocumentsRequest, output: &ListDocumentsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDocumentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DocumentIdentifiers` of every page of `ListDocuments` results for `input`, as `list_documents_pages` requests them.
+    pub fn list_documents_stream(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> impl futures::Stream<Item = Result<DocumentIdentifier, RusotoError<ListDocumentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_documents_pages(input), |page| {
+            page.document_identifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResourceComplianceSummaries` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_compliance_summaries_pages(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListResourceComplianceSummariesResult,
+            RusotoError<ListResourceComplianceSummariesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move {
+                client.list_resource_compliance_summaries(input).await
+            },
+            |input: &ListResourceComplianceSummariesRequest,
+             output: &ListResourceComplianceSummariesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceComplianceSummariesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceComplianceSummaryItems` of every page of `ListResourceComplianceSummaries` results for `input`, as `list_resource_compliance_summaries_pages` requests them.
+    pub fn list_resource_compliance_summaries_stream(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ResourceComplianceSummaryItem,
+            RusotoError<ListResourceComplianceSummariesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_resource_compliance_summaries_pages(input),
+            |page| page.resource_compliance_summary_items.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListResourceDataSync` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_data_sync_pages(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsmClient, input| async move { client.list_resource_data_sync(input).await },
+            |input: &ListResourceDataSyncRequest, output: &ListResourceDataSyncResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceDataSyncRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceDataSyncItems` of every page of `ListResourceDataSync` results for `input`, as `list_resource_data_sync_pages` requests them.
+    pub fn list_resource_data_sync_stream(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceDataSyncItem, RusotoError<ListResourceDataSyncError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_resource_data_sync_pages(input), |page| {
+            page.resource_data_sync_items.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `CommandExecuted`, polling `GetCommandInvocation` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_command_executed(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCommandInvocationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_command_executed_with(input, &waiter).await
+    }
+
+    /// Waits for `CommandExecuted` like `wait_until_command_executed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_command_executed_with(
+        &self,
+        input: GetCommandInvocationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCommandInvocationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_command_invocation(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Pending"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "InProgress"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Delayed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Success"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Cancelled"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "TimedOut"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Cancelling"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InvocationDoesNotExist")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Ssm for SsmClient {
+    /// <p>Adds or overwrites one or more tags for the specified resource. Tags are metadata that you can assign to your documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. Each tag consists of a key and an optional value, both of which you define. For example, you could define a set of tags for your account's managed instances that helps you track each instance's owner and stack level. For example: Key=Owner and Value=DbAdmin, SysAdmin, or Dev. Or Key=Stack and Value=Production, Pre-Production, or Test.</p> <p>Each resource can have a maximum of 50 tags. </p> <p>We recommend that you devise a set of tag keys that meets your needs for each resource type. Using a consistent set of tag keys makes it easier for you to manage your resources. You can search and filter the resources based on the tags you add. Tags don't have any semantic meaning to Amazon EC2 and are interpreted strictly as a string of characters. </p> <p>For more information about tags, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon EC2 User Guide</i>.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to cancel the command specified by the Command ID. There is no guarantee that the command will be terminated and the underlying process stopped.</p>
+    async fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
+        self.cancel_command_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a maintenance window execution that is already in progress and cancels any tasks in the window that have not already starting running. (Tasks already in progress will continue to completion.)</p>
+    async fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> Result<
+        CancelMaintenanceWindowExecutionResult,
+        RusotoError<CancelMaintenanceWindowExecutionError>,
+    > {
+        self.cancel_maintenance_window_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Generates an activation code and activation ID you can use to register your on-premises server or virtual machine (VM) with Systems Manager. Registering these machines with Systems Manager makes it possible to manage them using Systems Manager capabilities. You use the activation code and ID when installing SSM Agent on machines in your hybrid environment. For more information about requirements for managing on-premises instances and VMs using Systems Manager, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-managedinstances.html">Setting Up AWS Systems Manager for Hybrid Environments</a> in the <i>AWS Systems Manager User Guide</i>. </p> <note> <p>On-premises servers or VMs that are registered with Systems Manager and Amazon EC2 instances that you manage with Systems Manager are all called <i>managed instances</i>.</p> </note></p>
+    async fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
+        self.create_activation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
+    async fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
+        self.create_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
+    async fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
+        self.create_association_batch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Systems Manager document.</p> <p>After you create a document, you can use CreateAssociation to associate it with one or more running instances.</p>
+    async fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
+        self.create_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new maintenance window.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
+    async fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
+        self.create_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new OpsItem. You must have permission in AWS Identity and Access Management (IAM) to create a new OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    async fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
+        self.create_ops_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a patch baseline.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    async fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
+        self.create_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>A resource data sync helps you view data from multiple sources in a single location. Systems Manager offers two types of resource data sync: <code>SyncToDestination</code> and <code>SyncFromSource</code>.</p> <p>You can configure Systems Manager Inventory to use the <code>SyncToDestination</code> type to synchronize Inventory data from multiple AWS Regions to a single Amazon S3 bucket. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-datasync.html">Configuring Resource Data Sync for Inventory</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>You can configure Systems Manager Explorer to use the <code>SyncToDestination</code> type to synchronize operational work items (OpsItems) and operational data (OpsData) from multiple AWS Regions to a single Amazon S3 bucket. You can also configure Explorer to use the <code>SyncFromSource</code> type. This type synchronizes OpsItems and OpsData from multiple AWS accounts and Regions by using AWS Organizations. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/Explorer-resource-data-sync.html">Setting Up Explorer to Display Data from Multiple Accounts and Regions</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>A resource data sync is an asynchronous operation that returns immediately. After a successful initial sync is completed, the system continuously syncs data. To check the status of a sync, use the <a>ListResourceDataSync</a>.</p> <note> <p>By default, data is not encrypted in Amazon S3. We strongly recommend that you enable encryption in Amazon S3 to ensure secure data storage. We also recommend that you secure access to the Amazon S3 bucket by creating a restrictive bucket policy. </p> </note></p>
+    async fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
+        self.create_resource_data_sync_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an activation. You are not required to delete an activation. If you delete an activation, you can no longer use it to register additional managed instances. Deleting an activation does not de-register managed instances. You must manually de-register managed instances.</p>
+    async fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
+        self.delete_activation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified Systems Manager document from the specified instance.</p> <p>When you disassociate a document from an instance, it does not change the configuration of the instance. To change the configuration state of an instance after you disassociate a document, you must create a new document with the desired configuration and associate it with the instance.</p>
+    async fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
+        self.delete_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the Systems Manager document and all instance associations to the document.</p> <p>Before you delete the document, we recommend that you use <a>DeleteAssociation</a> to disassociate all instances that are associated with the document.</p>
+    async fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
+        self.delete_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a custom inventory type, or the data associated with a custom Inventory type. Deleting a custom inventory type is also referred to as deleting a custom inventory schema.</p>
+    async fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
+        self.delete_inventory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a maintenance window.</p>
+    async fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
+        self.delete_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a parameter from the system.</p>
+    async fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
+        self.delete_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a list of parameters.</p>
+    async fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
+        self.delete_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a patch baseline.</p>
+    async fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
+        self.delete_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Resource Data Sync configuration. After the configuration is deleted, changes to data on managed instances are no longer synced to or from the target. Deleting a sync configuration does not delete data.</p>
+    async fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
+        self.delete_resource_data_sync_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the server or virtual machine from the list of registered servers. You can reregister the instance again at any time. If you don't plan to use Run Command on the server, we suggest uninstalling SSM Agent first.</p>
+    async fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
+        self.deregister_managed_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a patch group from a patch baseline.</p>
+    async fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        DeregisterPatchBaselineForPatchGroupResult,
+        RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+    > {
+        self.deregister_patch_baseline_for_patch_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a target from a maintenance window.</p>
+    async fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTargetFromMaintenanceWindowResult,
+        RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+    > {
+        self.deregister_target_from_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a task from a maintenance window.</p>
+    async fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTaskFromMaintenanceWindowResult,
+        RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+    > {
+        self.deregister_task_from_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes details about the activation, such as the date and time the activation was created, its expiration date, the IAM role assigned to the instances in the activation, and the number of instances registered by using this activation.</p>
+    async fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
+        self.describe_activations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the association for the specified target or instance. If you created the association by using the <code>Targets</code> parameter, then you must retrieve the association by using the association ID. If you created the association by specifying an instance ID and a Systems Manager document, then you retrieve the association by specifying the document name and the instance ID. </p>
+    async fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
+        self.describe_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this API action to view information about a specific execution of a specific association.</p>
+    async fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> Result<
+        DescribeAssociationExecutionTargetsResult,
+        RusotoError<DescribeAssociationExecutionTargetsError>,
+    > {
+        self.describe_association_execution_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this API action to view all executions for a specific association ID. </p>
+    async fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
+    {
+        self.describe_association_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides details about all active and terminated Automation executions.</p>
+    async fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
+    {
+        self.describe_automation_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Information about all active and terminated step executions in an Automation workflow.</p>
+    async fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> Result<
+        DescribeAutomationStepExecutionsResult,
+        RusotoError<DescribeAutomationStepExecutionsError>,
+    > {
+        self.describe_automation_step_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all patches eligible to be included in a patch baseline.</p>
+    async fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
+        self.describe_available_patches_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified Systems Manager document.</p>
+    async fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
+        self.describe_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the permissions for a Systems Manager document. If you created the document, you are the owner. If a document is shared, it can either be shared privately (by specifying a user's AWS account ID) or publicly (<i>All</i>). </p>
+    async fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
+    {
+        self.describe_document_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>All associations for the instance(s).</p>
+    async fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> Result<
+        DescribeEffectiveInstanceAssociationsResult,
+        RusotoError<DescribeEffectiveInstanceAssociationsError>,
+    > {
+        self.describe_effective_instance_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current effective patches (the patch and the approval state) for the specified patch baseline. Note that this API applies only to Windows patch baselines.</p>
+    async fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> Result<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+    > {
+        self.describe_effective_patches_for_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The status of the associations for the instance(s).</p>
+    async fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> Result<
+        DescribeInstanceAssociationsStatusResult,
+        RusotoError<DescribeInstanceAssociationsStatusError>,
+    > {
+        self.describe_instance_associations_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes one or more of your instances. You can use this to get information about instances like the operating system platform, the SSM Agent version (Linux), status etc. If you specify one or more instance IDs, it returns information for those instances. If you do not specify instance IDs, it returns information for all your instances. If you specify an instance ID that is not valid or an instance that you do not own, you receive an error. </p> <note> <p>The IamRole field for this API action is the Amazon Identity and Access Management (IAM) role assigned to on-premises instances. This call does not return the IAM role for Amazon EC2 instances.</p> </note></p>
+    async fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
+    {
+        self.describe_instance_information_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the high-level patch state of one or more instances.</p>
+    async fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
+    {
+        self.describe_instance_patch_states_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the high-level patch state for the instances in the specified patch group.</p>
+    async fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> Result<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+    > {
+        self.describe_instance_patch_states_for_patch_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the patches on the specified instance and their state relative to the patch baseline being used for the instance.</p>
+    async fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
+        self.describe_instance_patches_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a specific delete inventory operation.</p>
+    async fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
+    {
+        self.describe_inventory_deletions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the individual task executions (one per target) for a particular task run as part of a maintenance window execution.</p>
+    async fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+    > {
+        self.describe_maintenance_window_execution_task_invocations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a given maintenance window execution, lists the tasks that were run.</p>
+    async fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+    > {
+        self.describe_maintenance_window_execution_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the executions of a maintenance window. This includes information about when the maintenance window was scheduled to be active, and information about tasks registered and run with the maintenance window.</p>
+    async fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionsError>,
+    > {
+        self.describe_maintenance_window_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about upcoming executions of a maintenance window.</p>
+    async fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowScheduleResult,
+        RusotoError<DescribeMaintenanceWindowScheduleError>,
+    > {
+        self.describe_maintenance_window_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the targets registered with the maintenance window.</p>
+    async fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTargetsResult,
+        RusotoError<DescribeMaintenanceWindowTargetsError>,
+    > {
+        self.describe_maintenance_window_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    async fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTasksResult,
+        RusotoError<DescribeMaintenanceWindowTasksError>,
+    > {
+        self.describe_maintenance_window_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the maintenance windows in an AWS account.</p>
+    async fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
+    {
+        self.describe_maintenance_windows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the maintenance window targets or tasks that an instance is associated with.</p>
+    async fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowsForTargetResult,
+        RusotoError<DescribeMaintenanceWindowsForTargetError>,
+    > {
+        self.describe_maintenance_windows_for_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Query a set of OpsItems. You must have permission in AWS Identity and Access Management (IAM) to query a list of OpsItems. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    async fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
+        self.describe_ops_items_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Get information about a parameter.</p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
+    async fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
+        self.describe_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the patch baselines in your AWS account.</p>
+    async fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
+        self.describe_patch_baselines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns high-level aggregated patch compliance state for a patch group.</p>
+    async fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
+        self.describe_patch_group_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all patch groups that have been registered with patch baselines.</p>
+    async fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
+        self.describe_patch_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the properties of available patches organized by product, product family, classification, severity, and other properties of available patches. You can use the reported properties in the filters you specify in requests for actions such as <a>CreatePatchBaseline</a>, <a>UpdatePatchBaseline</a>, <a>DescribeAvailablePatches</a>, and <a>DescribePatchBaselines</a>.</p> <p>The following section lists the properties that can be used in filters for each major operating system type:</p> <dl> <dt>WINDOWS</dt> <dd> <p>Valid properties: PRODUCT, PRODUCT<em>FAMILY, CLASSIFICATION, MSRC</em>SEVERITY</p> </dd> <dt>AMAZON<em>LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>AMAZON</em>LINUX<em>2</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>UBUNTU </dt> <dd> <p>Valid properties: PRODUCT, PRIORITY</p> </dd> <dt>REDHAT</em>ENTERPRISE_LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>SUSE</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>CENTOS</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> </dl></p>
+    async fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
+        self.describe_patch_properties_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of all active sessions (both connected and disconnected) or terminated sessions from the past 30 days.</p>
+    async fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
+        self.describe_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get detailed information about a particular Automation execution.</p>
+    async fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
+        self.get_automation_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the state of the AWS Systems Manager Change Calendar at an optional, specified time. If you specify a time, <code>GetCalendarState</code> returns the state of the calendar at a specific time, and returns the next time that the Change Calendar state will transition. If you do not specify a time, <code>GetCalendarState</code> assumes the current time. Change Calendar entries have two possible states: <code>OPEN</code> or <code>CLOSED</code>. For more information about Systems Manager Change Calendar, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-change-calendar.html">AWS Systems Manager Change Calendar</a> in the <i>AWS Systems Manager User Guide</i>.</p>
+    async fn get_calendar_state(
+        &self,
+        input: GetCalendarStateRequest,
+    ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
+        self.get_calendar_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about command execution for an invocation or plugin. </p>
+    async fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
+        self.get_command_invocation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the Session Manager connection status for an instance to determine whether it is connected and ready to receive Session Manager connections.</p>
+    async fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
+        self.get_connection_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the default patch baseline. Note that Systems Manager supports creating multiple default patch baselines. For example, you can create a default patch baseline for each operating system.</p> <p>If you do not specify an operating system value, the default patch baseline for Windows is returned.</p>
+    async fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
+        self.get_default_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current snapshot for the patch baseline the instance uses. This API is primarily used by the AWS-RunPatchBaseline Systems Manager document. </p>
+    async fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> Result<
+        GetDeployablePatchSnapshotForInstanceResult,
+        RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+    > {
+        self.get_deployable_patch_snapshot_for_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the contents of the specified Systems Manager document.</p>
+    async fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
+        self.get_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Query inventory information.</p>
+    async fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
+        self.get_inventory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Return a list of inventory type names for the account, or return a list of attribute names for a specific Inventory item type. </p>
+    async fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
+        self.get_inventory_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a maintenance window.</p>
+    async fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
+        self.get_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details about a specific a maintenance window execution.</p>
+    async fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
+    {
+        self.get_maintenance_window_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the details about a specific task run as part of a maintenance window execution.</p>
+    async fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskError>,
+    > {
+        self.get_maintenance_window_execution_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a specific task running on a specific target.</p>
+    async fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+    > {
+        self.get_maintenance_window_execution_task_invocation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    async fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
+        self.get_maintenance_window_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get information about an OpsItem by using the ID. You must have permission in AWS Identity and Access Management (IAM) to view information about an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    async fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
+        self.get_ops_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>View a summary of OpsItems based on specified filters and aggregators.</p>
+    async fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
+        self.get_ops_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get information about a parameter by using the parameter name. Don't confuse this API action with the <a>GetParameters</a> API action.</p>
+    async fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
+        self.get_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Query a list of all parameters used by the AWS account.</p>
+    async fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
+        self.get_parameter_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get details of a parameter. Don't confuse this API action with the <a>GetParameter</a> API action.</p>
+    async fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
+        self.get_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieve information about one or more parameters in a specific hierarchy. </p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
+    async fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
+        self.get_parameters_by_path_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a patch baseline.</p>
+    async fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
+        self.get_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the patch baseline that should be used for the specified patch group.</p>
+    async fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
+    {
+        self.get_patch_baseline_for_patch_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Query the current service setting for the account. </p>
+    async fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
+        self.get_service_setting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>A parameter label is a user-defined alias to help you manage different versions of a parameter. When you modify a parameter, Systems Manager automatically saves a new version and increments the version number by one. A label can help you remember the purpose of a parameter when there are multiple versions. </p> <p>Parameter labels have the following requirements and restrictions.</p> <ul> <li> <p>A version of a parameter can have a maximum of 10 labels.</p> </li> <li> <p>You can&#39;t attach the same label to different versions of the same parameter. For example, if version 1 has the label Production, then you can&#39;t attach Production to version 2.</p> </li> <li> <p>You can move a label from one version of a parameter to another.</p> </li> <li> <p>You can&#39;t create a label when you create a new parameter. You must attach a label to a specific version of a parameter.</p> </li> <li> <p>You can&#39;t delete a parameter label. If you no longer want to use a parameter label, then you must move it to a different version of a parameter.</p> </li> <li> <p>A label can have a maximum of 100 characters.</p> </li> <li> <p>Labels can contain letters (case sensitive), numbers, periods (.), hyphens (-), or underscores (_).</p> </li> <li> <p>Labels can&#39;t begin with a number, &quot;aws,&quot; or &quot;ssm&quot; (not case sensitive). If a label fails to meet these requirements, then the label is not associated with a parameter and the system displays it in the list of InvalidLabels.</p> </li> </ul></p>
+    async fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
+        self.label_parameter_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all versions of an association for a specific association ID.</p>
+    async fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
+        self.list_association_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all State Manager associations in the current AWS account and Region. You can limit the results to a specific State Manager association document or instance by specifying a filter.</p>
+    async fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
+        self.list_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. ListCommandInvocations provide status about command execution.</p>
+    async fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
+        self.list_command_invocations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the commands requested by users of the AWS account.</p>
+    async fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
+        self.list_commands_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a specified resource ID, this API action returns a list of compliance statuses for different resource types. Currently, you can only specify one resource ID per call. List results depend on the criteria specified in the filter. </p>
+    async fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
+        self.list_compliance_items_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a summary count of compliant and non-compliant resources for a compliance type. For example, this call can return State Manager associations, patches, or custom compliance types according to the filter criteria that you specify. </p>
+    async fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
+        self.list_compliance_summaries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all versions for a document.</p>
+    async fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
+        self.list_document_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all Systems Manager (SSM) documents in the current AWS account and Region. You can limit the results of this request by using a filter.</p>
+    async fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
+        self.list_documents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A list of inventory items returned by the request.</p>
+    async fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
+        self.list_inventory_entries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a resource-level summary count. The summary includes information about compliant and non-compliant statuses and detailed compliance-item severity counts, according to the filter criteria you specify.</p>
+    async fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> Result<
+        ListResourceComplianceSummariesResult,
+        RusotoError<ListResourceComplianceSummariesError>,
+    > {
+        self.list_resource_compliance_summaries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists your resource data sync configurations. Includes information about the last time a sync attempted to start, the last sync status, and the last time a sync successfully completed.</p> <p>The number of sync configurations might be too large to return using a single call to <code>ListResourceDataSync</code>. You can limit the number of sync configurations returned by using the <code>MaxResults</code> parameter. To determine whether there are more sync configurations to list, check the value of <code>NextToken</code> in the output. If there are more sync configurations to list, you can request them by specifying the <code>NextToken</code> returned in the call to the parameter of a subsequent call. </p>
+    async fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
+        self.list_resource_data_sync_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Shares a Systems Manager document publicly or privately. If you share a document privately, you must specify the AWS user account IDs for those people who can use the document. If you share a document publicly, you must specify <i>All</i> as the account ID.</p>
+    async fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
+        self.modify_document_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Registers a compliance type and other compliance details on a designated resource. This action lets you register custom compliance details with a resource. This call overwrites existing compliance information on the resource, so you must provide a full list of compliance items each time that you send the request.</p> <p>ComplianceType can be one of the following:</p> <ul> <li> <p>ExecutionId: The execution ID when the patch, association, or custom compliance item was applied.</p> </li> <li> <p>ExecutionType: Specify patch, association, or Custom:<code>string</code>.</p> </li> <li> <p>ExecutionTime. The time the patch, association, or custom compliance item was applied to the instance.</p> </li> <li> <p>Id: The patch, association, or custom compliance ID.</p> </li> <li> <p>Title: A title.</p> </li> <li> <p>Status: The status of the compliance item. For example, <code>approved</code> for patches, or <code>Failed</code> for associations.</p> </li> <li> <p>Severity: A patch severity. For example, <code>critical</code>.</p> </li> <li> <p>DocumentName: A SSM document name. For example, AWS-RunPatchBaseline.</p> </li> <li> <p>DocumentVersion: An SSM document version number. For example, 4.</p> </li> <li> <p>Classification: A patch classification. For example, <code>security updates</code>.</p> </li> <li> <p>PatchBaselineId: A patch baseline ID.</p> </li> <li> <p>PatchSeverity: A patch severity. For example, <code>Critical</code>.</p> </li> <li> <p>PatchState: A patch state. For example, <code>InstancesWithFailedPatches</code>.</p> </li> <li> <p>PatchGroup: The name of a patch group.</p> </li> <li> <p>InstalledTime: The time the association, patch, or custom compliance item was applied to the resource. Specify the time by using the following format: yyyy-MM-dd&#39;T&#39;HH:mm:ss&#39;Z&#39;</p> </li> </ul></p>
+    async fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
+        self.put_compliance_items_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Bulk update custom inventory items on one more instance. The request adds an inventory item, if it doesn't already exist, or updates an inventory item, if it does exist.</p>
+    async fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
+        self.put_inventory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add a parameter to the system.</p>
+    async fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
+        self.put_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines the default patch baseline for the relevant operating system.</p> <p>To reset the AWS predefined patch baseline as the default, specify the full patch baseline ARN as the baseline ID value. For example, for CentOS, specify <code>arn:aws:ssm:us-east-2:733109147000:patchbaseline/pb-0574b43a65ea646ed</code> instead of <code>pb-0574b43a65ea646ed</code>.</p>
+    async fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
+    {
+        self.register_default_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a patch baseline for a patch group.</p>
+    async fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        RegisterPatchBaselineForPatchGroupResult,
+        RusotoError<RegisterPatchBaselineForPatchGroupError>,
+    > {
+        self.register_patch_baseline_for_patch_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a target with a maintenance window.</p>
+    async fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTargetWithMaintenanceWindowResult,
+        RusotoError<RegisterTargetWithMaintenanceWindowError>,
+    > {
+        self.register_target_with_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a new task to a maintenance window.</p>
+    async fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTaskWithMaintenanceWindowResult,
+        RusotoError<RegisterTaskWithMaintenanceWindowError>,
+    > {
+        self.register_task_with_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tag keys from the specified resource.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Use the <a>UpdateServiceSetting</a> API action to change the default setting. </p> <p>Reset the service setting for the account to the default value as provisioned by the AWS service team. </p>
+    async fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
+        self.reset_service_setting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Reconnects a session to an instance after it has been disconnected. Connections can be resumed for disconnected sessions, but not terminated sessions.</p> <note> <p>This command is primarily for use by client machines to automatically reconnect during intermittent network issues. It is not intended for any other use.</p> </note></p>
+    async fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
+        self.resume_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends a signal to an Automation execution to change the current behavior or status of the execution. </p>
+    async fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
+        self.send_automation_signal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Runs commands on one or more managed instances.</p>
+    async fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.send_command_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this API action to run an association immediately and only one time. This action can be helpful when troubleshooting associations.</p>
+    async fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
+        self.start_associations_once_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates execution of an Automation document.</p>
+    async fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
+        self.start_automation_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Initiates a connection to a target (for example, an instance) for a Session Manager session. Returns a URL and token that can be used to open a WebSocket connection for sending input and receiving outputs.</p> <note> <p>AWS CLI usage: <code>start-session</code> is an interactive command that requires the Session Manager plugin to be installed on the client machine making the call. For information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html"> Install the Session Manager Plugin for the AWS CLI</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>AWS Tools for PowerShell usage: Start-SSMSession is not currently supported by AWS Tools for PowerShell on Windows local machines.</p> </note></p>
+    async fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
+        self.start_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stop an Automation that is currently running.</p>
+    async fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
+        self.stop_automation_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently ends a session and closes the data connection between the Session Manager client and SSM Agent on the instance. A terminated session cannot be resumed.</p>
+    async fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
+        self.terminate_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates an association. You can update the association name and version, the document version, schedule, parameters, and Amazon S3 output. </p> <p>In order to call this API action, your IAM user account, group, or role must be configured with permission to call the <a>DescribeAssociation</a> API action. If you don&#39;t have permission to call DescribeAssociation, then you receive the following error: <code>An error occurred (AccessDeniedException) when calling the UpdateAssociation operation: User: &lt;user<em>arn&gt; is not authorized to perform: ssm:DescribeAssociation on resource: &lt;resource</em>arn&gt;</code> </p> <important> <p>When you update an association, the association immediately runs against the specified targets.</p> </important></p>
+    async fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
+        self.update_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status of the Systems Manager document associated with the specified instance.</p>
+    async fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
+        self.update_association_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates one or more values for an SSM document.</p>
+    async fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
+        self.update_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Set the default version of a document. </p>
+    async fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
+    {
+        self.update_document_default_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates an existing maintenance window. Only specified parameters are modified.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
+    async fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
+        self.update_maintenance_window_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the target of an existing maintenance window. You can change the following:</p> <ul> <li> <p>Name</p> </li> <li> <p>Description</p> </li> <li> <p>Owner</p> </li> <li> <p>IDs for an ID target</p> </li> <li> <p>Tags for a Tag target</p> </li> <li> <p>From any supported tag type to another. The three supported tag types are ID target, Tag target, and resource group. For more information, see <a>Target</a>.</p> </li> </ul> <note> <p>If a parameter is null, then the corresponding field is not modified.</p> </note></p>
+    async fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
+    {
+        self.update_maintenance_window_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a task assigned to a maintenance window. You can't change the task type, but you can change the following values:</p> <ul> <li> <p>TaskARN. For example, you can change a RUN_COMMAND task from AWS-RunPowerShellScript to AWS-RunShellScript.</p> </li> <li> <p>ServiceRoleArn</p> </li> <li> <p>TaskInvocationParameters</p> </li> <li> <p>Priority</p> </li> <li> <p>MaxConcurrency</p> </li> <li> <p>MaxErrors</p> </li> </ul> <p>If a parameter is null, then the corresponding field is not modified. Also, if you set Replace to true, then all fields required by the <a>RegisterTaskWithMaintenanceWindow</a> action are required for this request. Optional fields that aren't specified are set to null.</p>
+    async fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
+    {
+        self.update_maintenance_window_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns or changes an Amazon Identity and Access Management (IAM) role for the managed instance.</p>
+    async fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
+        self.update_managed_instance_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Edit or change an OpsItem. You must have permission in AWS Identity and Access Management (IAM) to update an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    async fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
+        self.update_ops_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies an existing patch baseline. Fields not specified in the request are left unchanged.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    async fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
+        self.update_patch_baseline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a resource data sync. After you create a resource data sync for a Region, you can't change the account options for that sync. For example, if you create a sync in the us-east-2 (Ohio) Region and you choose the Include only the current account option, you can't edit that sync later and choose the Include all accounts from my AWS Organizations configuration option. Instead, you must delete the first resource data sync, and create a new one.</p>
+    async fn update_resource_data_sync(
+        &self,
+        input: UpdateResourceDataSyncRequest,
+    ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
+        self.update_resource_data_sync_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Update the service setting for the account. </p>
+    async fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
+        self.update_service_setting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -171,6 +171,7 @@ pub struct StartDeviceAuthorizationResponse {
 
 /// Errors returned by CreateToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTokenError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -194,6 +195,8 @@ pub enum CreateTokenError {
     UnauthorizedClient(String),
     /// <p>Indicates that the grant type in the request is not supported by the service.</p>
     UnsupportedGrantType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTokenError {
@@ -201,40 +204,49 @@ impl CreateTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTokenError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateTokenError::AccessDenied(err.msg), &res)
                 }
                 "AuthorizationPendingException" => {
-                    return RusotoError::Service(CreateTokenError::AuthorizationPending(err.msg))
+                    return RusotoError::service(
+                        CreateTokenError::AuthorizationPending(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(CreateTokenError::ExpiredToken(err.msg))
+                    return RusotoError::service(CreateTokenError::ExpiredToken(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateTokenError::InternalServer(err.msg))
+                    return RusotoError::service(CreateTokenError::InternalServer(err.msg), &res)
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidClient(err.msg))
+                    return RusotoError::service(CreateTokenError::InvalidClient(err.msg), &res)
                 }
                 "InvalidGrantException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidGrant(err.msg))
+                    return RusotoError::service(CreateTokenError::InvalidGrant(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateTokenError::InvalidRequest(err.msg), &res)
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidScope(err.msg))
+                    return RusotoError::service(CreateTokenError::InvalidScope(err.msg), &res)
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(CreateTokenError::SlowDown(err.msg))
+                    return RusotoError::service(CreateTokenError::SlowDown(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateTokenError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        CreateTokenError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedGrantTypeException" => {
-                    return RusotoError::Service(CreateTokenError::UnsupportedGrantType(err.msg))
+                    return RusotoError::service(
+                        CreateTokenError::UnsupportedGrantType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -255,12 +267,14 @@ impl fmt::Display for CreateTokenError {
             CreateTokenError::SlowDown(ref cause) => write!(f, "{}", cause),
             CreateTokenError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
             CreateTokenError::UnsupportedGrantType(ref cause) => write!(f, "{}", cause),
+            CreateTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTokenError {}
 /// Errors returned by RegisterClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterClientError {
     /// <p>Indicates that an error from the service occurred while trying to process a request.</p>
     InternalServer(String),
@@ -270,6 +284,8 @@ pub enum RegisterClientError {
     InvalidRequest(String),
     /// <p>Indicates that the scope provided in the request is invalid.</p>
     InvalidScope(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterClientError {
@@ -277,21 +293,22 @@ impl RegisterClientError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterClientError::InternalServer(err.msg))
+                    return RusotoError::service(RegisterClientError::InternalServer(err.msg), &res)
                 }
                 "InvalidClientMetadataException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidClientMetadata(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterClientError::InvalidClientMetadata(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(RegisterClientError::InvalidRequest(err.msg), &res)
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidScope(err.msg))
+                    return RusotoError::service(RegisterClientError::InvalidScope(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -305,12 +322,14 @@ impl fmt::Display for RegisterClientError {
             RegisterClientError::InvalidClientMetadata(ref cause) => write!(f, "{}", cause),
             RegisterClientError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RegisterClientError::InvalidScope(ref cause) => write!(f, "{}", cause),
+            RegisterClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterClientError {}
 /// Errors returned by StartDeviceAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDeviceAuthorizationError {
     /// <p>Indicates that an error from the service occurred while trying to process a request.</p>
     InternalServer(String),
@@ -322,6 +341,8 @@ pub enum StartDeviceAuthorizationError {
     SlowDown(String),
     /// <p>Indicates that the client is not currently authorized to make the request. This can happen when a <code>clientId</code> is not issued for a public client.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDeviceAuthorizationError {
@@ -329,30 +350,37 @@ impl StartDeviceAuthorizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeviceAuthorizationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeviceAuthorizationError::InvalidClient(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeviceAuthorizationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::SlowDown(err.msg))
+                    return RusotoError::service(
+                        StartDeviceAuthorizationError::SlowDown(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeviceAuthorizationError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDeviceAuthorizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -367,6 +395,7 @@ impl fmt::Display for StartDeviceAuthorizationError {
             StartDeviceAuthorizationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartDeviceAuthorizationError::SlowDown(ref cause) => write!(f, "{}", cause),
             StartDeviceAuthorizationError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            StartDeviceAuthorizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -403,6 +432,7 @@ impl SsoOidcClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SsoOidcClient {
         SsoOidcClient {
             client: Client::shared(),
@@ -428,100 +458,225 @@ impl SsoOidcClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SsoOidcClient {
         SsoOidcClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SsoOidcClient> {
+        rusoto_core::ClientBuilder::new(SsoOidcClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SsoOidcClient {
+        SsoOidcClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl SsoOidc for SsoOidcClient {
-    /// <p>Creates and returns an access token for the authorized client. The access token issued will be used to fetch short-term credentials for the assigned roles in the AWS account.</p>
-    async fn create_token(
+impl SsoOidcClient {
+    /// Calls `create_token`, also returning the metadata of the response.
+    pub async fn create_token_with_metadata(
         &self,
         input: CreateTokenRequest,
-    ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
-        let request_uri = "/token";
-
-        let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("oidc".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<(CreateTokenResponse, ResponseMetadata), RusotoError<CreateTokenError>> {
+        let request = self.build_create_token_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateTokenResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateTokenError::from_response(response))
         }
     }
 
-    /// <p>Registers a client with AWS SSO. This allows clients to initiate device authorization. The output should be persisted for reuse through many authentication requests.</p>
-    async fn register_client(
+    /// Presigns the request `create_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_token(
         &self,
-        input: RegisterClientRequest,
-    ) -> Result<RegisterClientResponse, RusotoError<RegisterClientError>> {
-        let request_uri = "/client/register";
+        presigner: &rusoto_core::Presigner,
+        input: CreateTokenRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_token_request(&self, input: CreateTokenRequest) -> SignedRequest {
+        let request_uri = "/token";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("CreateToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `register_client`, also returning the metadata of the response.
+    pub async fn register_client_with_metadata(
+        &self,
+        input: RegisterClientRequest,
+    ) -> Result<(RegisterClientResponse, ResponseMetadata), RusotoError<RegisterClientError>> {
+        let request = self.build_register_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterClientResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RegisterClientError::from_response(response))
         }
     }
 
-    /// <p>Initiates device authorization by requesting a pair of verification codes from the authorization service.</p>
-    async fn start_device_authorization(
+    /// Presigns the request `register_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_client(
         &self,
-        input: StartDeviceAuthorizationRequest,
-    ) -> Result<StartDeviceAuthorizationResponse, RusotoError<StartDeviceAuthorizationError>> {
-        let request_uri = "/device_authorization";
+        presigner: &rusoto_core::Presigner,
+        input: RegisterClientRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_client_request(&self, input: RegisterClientRequest) -> SignedRequest {
+        let request_uri = "/client/register";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("RegisterClient");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_device_authorization`, also returning the metadata of the response.
+    pub async fn start_device_authorization_with_metadata(
+        &self,
+        input: StartDeviceAuthorizationRequest,
+    ) -> Result<
+        (StartDeviceAuthorizationResponse, ResponseMetadata),
+        RusotoError<StartDeviceAuthorizationError>,
+    > {
+        let request = self.build_start_device_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartDeviceAuthorizationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(StartDeviceAuthorizationError::from_response(response))
         }
     }
+
+    /// Presigns the request `start_device_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_device_authorization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: StartDeviceAuthorizationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_device_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_device_authorization_request(
+        &self,
+        input: StartDeviceAuthorizationRequest,
+    ) -> SignedRequest {
+        let request_uri = "/device_authorization";
+
+        let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation("StartDeviceAuthorization");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("oidc".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl SsoOidc for SsoOidcClient {
+    /// <p>Creates and returns an access token for the authorized client. The access token issued will be used to fetch short-term credentials for the assigned roles in the AWS account.</p>
+    async fn create_token(
+        &self,
+        input: CreateTokenRequest,
+    ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
+        self.create_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a client with AWS SSO. This allows clients to initiate device authorization. The output should be persisted for reuse through many authentication requests.</p>
+    async fn register_client(
+        &self,
+        input: RegisterClientRequest,
+    ) -> Result<RegisterClientResponse, RusotoError<RegisterClientError>> {
+        self.register_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates device authorization by requesting a pair of verification codes from the authorization service.</p>
+    async fn start_device_authorization(
+        &self,
+        input: StartDeviceAuthorizationRequest,
+    ) -> Result<StartDeviceAuthorizationResponse, RusotoError<StartDeviceAuthorizationError>> {
+        self.start_device_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
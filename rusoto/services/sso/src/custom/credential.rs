@@ -0,0 +1,433 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, SecondsFormat, TimeZone, Utc};
+use futures::lock::Mutex;
+use serde_json::{json, Map, Value};
+use sha1::{Digest, Sha1};
+
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, ProfileProvider, ProvideAwsCredentials,
+};
+use rusoto_core::request::HttpClient;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{Client, Region};
+
+use crate::{GetRoleCredentialsRequest, RoleCredentials, Sso, SsoClient};
+
+/// Provides the credentials of a role in an account from AWS SSO, using the access token that
+/// `aws sso login` caches under `~/.aws/sso/cache`.
+///
+/// The role credentials are cached and fetched again once they have expired. Access tokens of
+/// an SSO session, configured in an `[sso-session <name>]` section, are refreshed with
+/// `sso-oidc:CreateToken` shortly before they expire, and the refreshed token is written back to
+/// the cache. Other access tokens can't be refreshed, once they have expired `aws sso login` has
+/// to be run again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_sso::SsoProvider;
+///
+/// // A profile with the `sso_account_id` and `sso_role_name` keys, and either an `sso_session`
+/// // or the `sso_start_url` and `sso_region` keys.
+/// let provider = SsoProvider::from_profile("my-sso-profile").unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SsoProvider {
+    client: Client,
+    region: Region,
+    start_url: String,
+    sso_session: Option<String>,
+    account_id: String,
+    role_name: String,
+    cache_dir: Option<PathBuf>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl SsoProvider {
+    /// Creates a new `SsoProvider` for the role `role_name` in the account `account_id`, logged
+    /// in to at `start_url` of the AWS SSO instance in `region`.
+    ///
+    /// The `Client` should not sign requests, which are authorized by the access token instead,
+    /// see `Client::new_not_signing`.
+    pub fn new(
+        client: Client,
+        region: Region,
+        start_url: String,
+        account_id: String,
+        role_name: String,
+    ) -> SsoProvider {
+        SsoProvider {
+            client,
+            region,
+            start_url,
+            sso_session: None,
+            account_id,
+            role_name,
+            cache_dir: None,
+            credentials: Default::default(),
+        }
+    }
+
+    /// Creates a new `SsoProvider` from the `sso_account_id` and `sso_role_name` keys of
+    /// `profile` in the AWS config file. The `sso_start_url` and `sso_region` keys are read from
+    /// the `[sso-session <name>]` section named by the `sso_session` key, or else from the profile.
+    pub fn from_profile(profile: &str) -> Result<SsoProvider, CredentialsError> {
+        let value = |key: &str| {
+            ProfileProvider::profile_config_value(profile, key)?.ok_or_else(|| {
+                CredentialsError::new(format!("Profile '{}' is missing '{}'", profile, key))
+            })
+        };
+        let sso_session = ProfileProvider::profile_config_value(profile, "sso_session")?;
+        let session_value = |key: &str| match sso_session {
+            Some(ref session) => ProfileProvider::sso_session_config_value(session, key)?
+                .ok_or_else(|| {
+                    CredentialsError::new(format!("SSO session '{}' is missing '{}'", session, key))
+                }),
+            None => value(key),
+        };
+        let start_url = session_value("sso_start_url")?;
+        let region =
+            Region::from_str(&session_value("sso_region")?).map_err(CredentialsError::new)?;
+        let dispatcher = HttpClient::new().map_err(CredentialsError::new)?;
+        let mut provider = SsoProvider::new(
+            Client::new_not_signing(dispatcher),
+            region,
+            start_url,
+            value("sso_account_id")?,
+            value("sso_role_name")?,
+        );
+        if let Some(session) = sso_session {
+            provider.set_sso_session(session);
+        }
+        Ok(provider)
+    }
+
+    /// Use the access token of the SSO session `sso_session`, which is cached by its name instead
+    /// of the start URL and refreshed when it expires.
+    pub fn set_sso_session<S>(&mut self, sso_session: S)
+    where
+        S: Into<String>,
+    {
+        self.sso_session = Some(sso_session.into());
+    }
+
+    /// Read cached access tokens from `cache_dir` instead of `~/.aws/sso/cache`.
+    pub fn set_cache_dir<P>(&mut self, cache_dir: P)
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+    }
+
+    fn cache_dir(&self) -> Result<PathBuf, CredentialsError> {
+        match self.cache_dir {
+            Some(ref cache_dir) => Ok(cache_dir.clone()),
+            None => dirs::home_dir()
+                .map(|home| home.join(".aws").join("sso").join("cache"))
+                .ok_or_else(|| CredentialsError::new("Failed to determine home directory.")),
+        }
+    }
+
+    /// The unexpired access token from the cache, refreshed first if it's about to expire.
+    async fn access_token(&self) -> Result<String, CredentialsError> {
+        let key = self.sso_session.as_ref().unwrap_or(&self.start_url);
+        let path = cached_token_path(&self.cache_dir()?, key);
+        let mut token = read_cached_token(&path)?;
+        let expires_at = token_expires_at(&token)?;
+        if expires_at > Utc::now() + Duration::minutes(5) {
+            return token_field(&token, "accessToken");
+        }
+        if self.sso_session.is_some() && token.contains_key("refreshToken") {
+            match self.refresh_token(&token).await {
+                Ok(refreshed) => {
+                    token.extend(refreshed);
+                    write_cached_token(&path, &token)?;
+                    return token_field(&token, "accessToken");
+                }
+                // the current token is still used as long as it's valid
+                Err(err) if expires_at <= Utc::now() => return Err(err),
+                Err(_) => {}
+            }
+        }
+        if expires_at <= Utc::now() {
+            return Err(CredentialsError::new(
+                "The SSO access token has expired, run `aws sso login`",
+            ));
+        }
+        token_field(&token, "accessToken")
+    }
+
+    /// The fields of `token` changed by refreshing it with `sso-oidc:CreateToken`.
+    async fn refresh_token(
+        &self,
+        token: &Map<String, Value>,
+    ) -> Result<Map<String, Value>, CredentialsError> {
+        let body = json!({
+            "clientId": token_field(token, "clientId")?,
+            "clientSecret": token_field(token, "clientSecret")?,
+            "grantType": "refresh_token",
+            "refreshToken": token_field(token, "refreshToken")?,
+        });
+        let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, "/token");
+        request.set_operation("CreateToken");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_endpoint_prefix("oidc".to_owned());
+        request.set_payload(Some(serde_json::to_vec(&body)?));
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!("Could not refresh SSO token: {:?}", err))
+            })?;
+        let status = response.status;
+        let response = response.buffer().await.map_err(CredentialsError::new)?;
+        if !status.is_success() {
+            return Err(CredentialsError::new(format!(
+                "Could not refresh SSO token: {}",
+                String::from_utf8_lossy(&response.body)
+            )));
+        }
+        let created: Map<String, Value> = serde_json::from_slice(&response.body)?;
+        let expires_in = created
+            .get("expiresIn")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| CredentialsError::new("No expiresIn in refreshed SSO token"))?;
+        let mut refreshed = Map::new();
+        refreshed.insert(
+            "accessToken".to_owned(),
+            json!(token_field(&created, "accessToken")?),
+        );
+        refreshed.insert(
+            "expiresAt".to_owned(),
+            json!((Utc::now() + Duration::seconds(expires_in))
+                .to_rfc3339_opts(SecondsFormat::Secs, true)),
+        );
+        if let Some(refresh_token) = created.get("refreshToken") {
+            refreshed.insert("refreshToken".to_owned(), refresh_token.clone());
+        }
+        Ok(refreshed)
+    }
+
+    async fn role_credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let access_token = self.access_token().await?;
+        let response = SsoClient::new_with_client(self.client.clone(), self.region.clone())
+            .get_role_credentials(GetRoleCredentialsRequest {
+                access_token,
+                account_id: self.account_id.clone(),
+                role_name: self.role_name.clone(),
+            })
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!("Could not get SSO role credentials: {}", err))
+            })?;
+        response
+            .role_credentials
+            .ok_or_else(|| CredentialsError::new("No role credentials in SSO response"))
+            .and_then(aws_credentials)
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for SsoProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref credentials) = *cached {
+            if !is_expiring(credentials) {
+                return Ok(credentials.clone());
+            }
+        }
+        let credentials = self.role_credentials().await?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+/// Credentials are fetched again this long before they expire.
+fn is_expiring(credentials: &AwsCredentials) -> bool {
+    match credentials.expires_at() {
+        Some(expires_at) => *expires_at < Utc::now() + Duration::seconds(60),
+        None => false,
+    }
+}
+
+fn aws_credentials(role_credentials: RoleCredentials) -> Result<AwsCredentials, CredentialsError> {
+    let missing = |field| CredentialsError::new(format!("No {} in SSO role credentials", field));
+    let expires_at = match role_credentials.expiration {
+        Some(millis) => Some(
+            Utc.timestamp_millis_opt(millis)
+                .single()
+                .ok_or_else(|| missing("valid expiration"))?,
+        ),
+        None => None,
+    };
+    Ok(AwsCredentials::new(
+        role_credentials
+            .access_key_id
+            .ok_or_else(|| missing("access key ID"))?,
+        role_credentials
+            .secret_access_key
+            .ok_or_else(|| missing("secret access key"))?,
+        role_credentials.session_token,
+        expires_at,
+    ))
+}
+
+/// The path of the cached token of `key`, the start URL or the name of the SSO session.
+fn cached_token_path(cache_dir: &Path, key: &str) -> PathBuf {
+    let digest = Sha1::digest(key.as_bytes());
+    let name: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    cache_dir.join(format!("{}.json", name))
+}
+
+/// The access token cached by `aws sso login` at `path`. Its fields are kept as they are, so
+/// that fields unknown here survive writing it back.
+fn read_cached_token(path: &Path) -> Result<Map<String, Value>, CredentialsError> {
+    let contents = fs::read_to_string(path).map_err(|err| {
+        CredentialsError::new(format!(
+            "Could not read SSO token cache '{}', run `aws sso login`: {}",
+            path.display(),
+            err
+        ))
+    })?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+fn write_cached_token(path: &Path, token: &Map<String, Value>) -> Result<(), CredentialsError> {
+    fs::write(path, serde_json::to_vec(token)?).map_err(|err| {
+        CredentialsError::new(format!(
+            "Could not write SSO token cache '{}': {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+fn token_field(token: &Map<String, Value>, field: &str) -> Result<String, CredentialsError> {
+    token
+        .get(field)
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| CredentialsError::new(format!("No {} in SSO token", field)))
+}
+
+fn token_expires_at(token: &Map<String, Value>) -> Result<DateTime<Utc>, CredentialsError> {
+    let expires_at = token_field(token, "expiresAt")?;
+    // Older versions of the AWS CLI wrote `2019-11-14T04:05:45UTC`.
+    let expires_at = match expires_at.strip_suffix("UTC") {
+        Some(timestamp) => format!("{}Z", timestamp),
+        None => expires_at,
+    };
+    Ok(expires_at.parse::<DateTime<Utc>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::sync::Mutex as StdMutex;
+
+    const START_URL: &str = "https://my-sso-portal.awsapps.com/start";
+    // Answers both `sso-oidc:CreateToken` and `sso:GetRoleCredentials`.
+    const RESPONSE: &str = r#"{"accessToken": "refreshed", "expiresIn": 28800,
+        "refreshToken": "new-refresh-token", "roleCredentials": {"accessKeyId": "key",
+        "secretAccessKey": "secret", "sessionToken": "session", "expiration": 4102444800000}}"#;
+
+    fn cache_dir_with_token(name: &str, key: &str, expires_at: DateTime<Utc>) -> PathBuf {
+        let cache_dir = std::env::temp_dir().join(name);
+        fs::create_dir_all(&cache_dir).unwrap();
+        let token = format!(
+            r#"{{"startUrl": "{}", "region": "us-east-1", "accessToken": "token",
+                "expiresAt": "{}", "clientId": "client", "clientSecret": "secret",
+                "refreshToken": "refresh-token"}}"#,
+            START_URL,
+            expires_at.format("%Y-%m-%dT%H:%M:%SZ")
+        );
+        fs::write(cached_token_path(&cache_dir, key), token).unwrap();
+        cache_dir
+    }
+
+    /// A provider reading tokens from `cache_dir`, and the paths of the requests it sends.
+    fn provider(cache_dir: &Path) -> (SsoProvider, Arc<StdMutex<Vec<String>>>) {
+        let paths = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = paths.clone();
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(RESPONSE)
+            .with_request_checker(move |request: &SignedRequest| {
+                recorded.lock().unwrap().push(request.path().to_owned());
+            });
+        let mut provider = SsoProvider::new(
+            Client::new_with(MockCredentialsProvider, dispatcher),
+            Region::UsEast1,
+            START_URL.to_owned(),
+            "111122223333".to_owned(),
+            "ReadOnly".to_owned(),
+        );
+        provider.set_cache_dir(cache_dir);
+        (provider, paths)
+    }
+
+    #[test]
+    fn names_cached_tokens_by_sha1() {
+        assert_eq!(
+            cached_token_path(Path::new("/cache"), START_URL),
+            Path::new("/cache/c7aaaf71fcc8777ae2475525ed049d39fe16c484.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_role_credentials_with_cached_token() {
+        let expires_at = Utc::now() + Duration::hours(1);
+        let cache_dir = cache_dir_with_token("rusoto-sso-valid", START_URL, expires_at);
+        let (provider, paths) = provider(&cache_dir);
+        let credentials = provider.credentials().await.unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert_eq!(credentials.token().as_deref(), Some("session"));
+        assert_eq!(
+            credentials.expires_at().map(|at| at.timestamp()),
+            Some(4_102_444_800)
+        );
+        assert_eq!(*paths.lock().unwrap(), vec!["/federation/credentials"]);
+    }
+
+    #[tokio::test]
+    async fn fails_with_expired_token() {
+        let expires_at = Utc::now() - Duration::hours(1);
+        let cache_dir = cache_dir_with_token("rusoto-sso-expired", START_URL, expires_at);
+        let result = provider(&cache_dir).0.credentials().await;
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert!(result.unwrap_err().message.contains("aws sso login"));
+    }
+
+    #[tokio::test]
+    async fn refreshes_expiring_tokens_of_sso_sessions() {
+        let expires_at = Utc::now() + Duration::minutes(1);
+        let cache_dir = cache_dir_with_token("rusoto-sso-session", "my-sso", expires_at);
+        let (mut provider, paths) = provider(&cache_dir);
+        provider.set_sso_session("my-sso");
+        let credentials = provider.credentials().await.unwrap();
+        let token = read_cached_token(&cached_token_path(&cache_dir, "my-sso")).unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert_eq!(
+            *paths.lock().unwrap(),
+            vec!["/token", "/federation/credentials"]
+        );
+        assert_eq!(token_field(&token, "accessToken").unwrap(), "refreshed");
+        assert_eq!(
+            token_field(&token, "refreshToken").unwrap(),
+            "new-refresh-token"
+        );
+        assert_eq!(token_field(&token, "clientId").unwrap(), "client");
+        assert!(token_expires_at(&token).unwrap() > Utc::now() + Duration::hours(7));
+    }
+}
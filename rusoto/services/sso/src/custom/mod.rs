@@ -0,0 +1,2 @@
+mod credential;
+pub use self::credential::SsoProvider;
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -173,6 +173,7 @@ pub struct RoleInfo {
 
 /// Errors returned by GetRoleCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRoleCredentialsError {
     /// <p>Indicates that a problem occurred with the input to the request. For example, a required parameter might be missing or out of range.</p>
     InvalidRequest(String),
@@ -182,6 +183,8 @@ pub enum GetRoleCredentialsError {
     TooManyRequests(String),
     /// <p>Indicates that the request is not authorized. This can happen due to an invalid access token in the request.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRoleCredentialsError {
@@ -189,19 +192,31 @@ impl GetRoleCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetRoleCredentialsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetRoleCredentialsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetRoleCredentialsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetRoleCredentialsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRoleCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -215,12 +230,14 @@ impl fmt::Display for GetRoleCredentialsError {
             GetRoleCredentialsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetRoleCredentialsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetRoleCredentialsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetRoleCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRoleCredentialsError {}
 /// Errors returned by ListAccountRoles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountRolesError {
     /// <p>Indicates that a problem occurred with the input to the request. For example, a required parameter might be missing or out of range.</p>
     InvalidRequest(String),
@@ -230,6 +247,8 @@ pub enum ListAccountRolesError {
     TooManyRequests(String),
     /// <p>Indicates that the request is not authorized. This can happen due to an invalid access token in the request.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountRolesError {
@@ -237,19 +256,28 @@ impl ListAccountRolesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAccountRolesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListAccountRolesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAccountRolesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListAccountRolesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountRolesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListAccountRolesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAccountRolesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListAccountRolesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountRolesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -263,12 +291,14 @@ impl fmt::Display for ListAccountRolesError {
             ListAccountRolesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAccountRolesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListAccountRolesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListAccountRolesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountRolesError {}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountsError {
     /// <p>Indicates that a problem occurred with the input to the request. For example, a required parameter might be missing or out of range.</p>
     InvalidRequest(String),
@@ -278,6 +308,8 @@ pub enum ListAccountsError {
     TooManyRequests(String),
     /// <p>Indicates that the request is not authorized. This can happen due to an invalid access token in the request.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountsError {
@@ -285,19 +317,19 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAccountsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListAccountsError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListAccountsError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListAccountsError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAccountsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListAccountsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -311,12 +343,14 @@ impl fmt::Display for ListAccountsError {
             ListAccountsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAccountsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListAccountsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListAccountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountsError {}
 /// Errors returned by Logout
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LogoutError {
     /// <p>Indicates that a problem occurred with the input to the request. For example, a required parameter might be missing or out of range.</p>
     InvalidRequest(String),
@@ -324,6 +358,8 @@ pub enum LogoutError {
     TooManyRequests(String),
     /// <p>Indicates that the request is not authorized. This can happen due to an invalid access token in the request.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LogoutError {
@@ -331,16 +367,16 @@ impl LogoutError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(LogoutError::InvalidRequest(err.msg))
+                    return RusotoError::service(LogoutError::InvalidRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LogoutError::TooManyRequests(err.msg))
+                    return RusotoError::service(LogoutError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(LogoutError::Unauthorized(err.msg))
+                    return RusotoError::service(LogoutError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LogoutError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -353,6 +389,7 @@ impl fmt::Display for LogoutError {
             LogoutError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             LogoutError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             LogoutError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            LogoutError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -392,6 +429,7 @@ impl SsoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SsoClient {
         SsoClient {
             client: Client::shared(),
@@ -417,18 +455,74 @@ impl SsoClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SsoClient {
         SsoClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SsoClient> {
+        rusoto_core::ClientBuilder::new(SsoClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SsoClient {
+        SsoClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Sso for SsoClient {
-    /// <p>Returns the STS short-term credentials for a given role name that is assigned to the user.</p>
-    async fn get_role_credentials(
+impl SsoClient {
+    /// Calls `get_role_credentials`, also returning the metadata of the response.
+    pub async fn get_role_credentials_with_metadata(
         &self,
         input: GetRoleCredentialsRequest,
-    ) -> Result<GetRoleCredentialsResponse, RusotoError<GetRoleCredentialsError>> {
+    ) -> Result<(GetRoleCredentialsResponse, ResponseMetadata), RusotoError<GetRoleCredentialsError>>
+    {
+        let request = self.build_get_role_credentials_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRoleCredentialsResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRoleCredentialsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `get_role_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_role_credentials(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetRoleCredentialsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_role_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_role_credentials_request(
+        &self,
+        input: GetRoleCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = "/federation/credentials";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("GetRoleCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -439,31 +533,56 @@ impl Sso for SsoClient {
         params.put("role_name", &input.role_name);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_account_roles`, also returning the metadata of the response.
+    pub async fn list_account_roles_with_metadata(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> Result<(ListAccountRolesResponse, ResponseMetadata), RusotoError<ListAccountRolesError>>
+    {
+        let request = self.build_list_account_roles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRoleCredentialsResponse, _>()?;
+                .deserialize::<ListAccountRolesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRoleCredentialsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAccountRolesError::from_response(response))
         }
     }
 
-    /// <p>Lists all roles that are assigned to the user for a given AWS account.</p>
-    async fn list_account_roles(
+    /// Presigns the request `list_account_roles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_account_roles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountRolesRequest,
-    ) -> Result<ListAccountRolesResponse, RusotoError<ListAccountRolesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_account_roles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_account_roles_request(&self, input: ListAccountRolesRequest) -> SignedRequest {
         let request_uri = "/assignment/roles";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccountRoles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -479,31 +598,55 @@ impl Sso for SsoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_accounts`, also returning the metadata of the response.
+    pub async fn list_accounts_with_metadata(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<(ListAccountsResponse, ResponseMetadata), RusotoError<ListAccountsError>> {
+        let request = self.build_list_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAccountRolesResponse, _>()?;
+                .deserialize::<ListAccountsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountRolesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAccountsError::from_response(response))
         }
     }
 
-    /// <p>Lists all AWS accounts assigned to the user. These AWS accounts are assigned by the administrator of the account. For more information, see <a href="https://docs.aws.amazon.com/singlesignon/latest/userguide/useraccess.html#assignusers">Assign User Access</a> in the <i>AWS SSO User Guide</i>. This operation returns a paginated response.</p>
-    async fn list_accounts(
+    /// Presigns the request `list_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountsRequest,
-    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accounts_request(&self, input: ListAccountsRequest) -> SignedRequest {
         let request_uri = "/assignment/accounts";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -518,47 +661,173 @@ impl Sso for SsoClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `logout`, also returning the metadata of the response.
+    pub async fn logout_with_metadata(
+        &self,
+        input: LogoutRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<LogoutError>> {
+        let request = self.build_logout_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAccountsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(LogoutError::from_response(response))
         }
     }
 
-    /// <p>Removes the client- and server-side session that is associated with the user.</p>
-    async fn logout(&self, input: LogoutRequest) -> Result<(), RusotoError<LogoutError>> {
+    /// Presigns the request `logout` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_logout(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: LogoutRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_logout_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_logout_request(&self, input: LogoutRequest) -> SignedRequest {
         let request_uri = "/logout";
 
         let mut request = SignedRequest::new("POST", "awsssoportal", &self.region, &request_uri);
+        request.set_operation("Logout");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
 
         request.add_header("x-amz-sso_bearer_token", &input.access_token);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAccountRoles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_account_roles_pages(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAccountRolesResponse, RusotoError<ListAccountRolesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsoClient, input| async move { client.list_account_roles(input).await },
+            |input: &ListAccountRolesRequest, output: &ListAccountRolesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountRolesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `roleList` of every page of `ListAccountRoles` results for `input`, as `list_account_roles_pages` requests them.
+    pub fn list_account_roles_stream(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> impl futures::Stream<Item = Result<RoleInfo, RusotoError<ListAccountRolesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_account_roles_pages(input), |page| {
+            page.role_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAccounts` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAccountsResponse, RusotoError<ListAccountsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SsoClient, input| async move { client.list_accounts(input).await },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `accountList` of every page of `ListAccounts` results for `input`, as `list_accounts_pages` requests them.
+    pub fn list_accounts_stream(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<AccountInfo, RusotoError<ListAccountsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_accounts_pages(input), |page| {
+            page.account_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Sso for SsoClient {
+    /// <p>Returns the STS short-term credentials for a given role name that is assigned to the user.</p>
+    async fn get_role_credentials(
+        &self,
+        input: GetRoleCredentialsRequest,
+    ) -> Result<GetRoleCredentialsResponse, RusotoError<GetRoleCredentialsError>> {
+        self.get_role_credentials_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(LogoutError::from_response(response))
-        }
+    /// <p>Lists all roles that are assigned to the user for a given AWS account.</p>
+    async fn list_account_roles(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> Result<ListAccountRolesResponse, RusotoError<ListAccountRolesError>> {
+        self.list_account_roles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all AWS accounts assigned to the user. These AWS accounts are assigned by the administrator of the account. For more information, see <a href="https://docs.aws.amazon.com/singlesignon/latest/userguide/useraccess.html#assignusers">Assign User Access</a> in the <i>AWS SSO User Guide</i>. This operation returns a paginated response.</p>
+    async fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.list_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the client- and server-side session that is associated with the user.</p>
+    async fn logout(&self, input: LogoutRequest) -> Result<(), RusotoError<LogoutError>> {
+        self.logout_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
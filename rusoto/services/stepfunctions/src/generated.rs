@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1216,6 +1216,7 @@ pub struct UpdateStateMachineOutput {
 
 /// Errors returned by CreateActivity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateActivityError {
     /// <p>The maximum number of activities has been reached. Existing activities must be deleted before a new activity can be created.</p>
     ActivityLimitExceeded(String),
@@ -1223,6 +1224,8 @@ pub enum CreateActivityError {
     InvalidName(String),
     /// <p>You've exceeded the number of tags allowed for a resource. See the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/limits.html"> Limits Topic</a> in the AWS Step Functions Developer Guide.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateActivityError {
@@ -1230,18 +1233,19 @@ impl CreateActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityLimitExceeded" => {
-                    return RusotoError::Service(CreateActivityError::ActivityLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateActivityError::ActivityLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateActivityError::InvalidName(err.msg))
+                    return RusotoError::service(CreateActivityError::InvalidName(err.msg), &res)
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateActivityError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateActivityError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateActivityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1254,12 +1258,14 @@ impl fmt::Display for CreateActivityError {
             CreateActivityError::ActivityLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateActivityError::InvalidName(ref cause) => write!(f, "{}", cause),
             CreateActivityError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateActivityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateActivityError {}
 /// Errors returned by CreateStateMachine
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStateMachineError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
@@ -1279,6 +1285,8 @@ pub enum CreateStateMachineError {
     StateMachineTypeNotSupported(String),
     /// <p>You've exceeded the number of tags allowed for a resource. See the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/limits.html"> Limits Topic</a> in the AWS Step Functions Developer Guide.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStateMachineError {
@@ -1286,46 +1294,58 @@ impl CreateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateStateMachineError::InvalidArn(err.msg), &res)
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidDefinition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStateMachineError::InvalidDefinition(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStateMachineError::InvalidLoggingConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidName(err.msg))
+                    return RusotoError::service(
+                        CreateStateMachineError::InvalidName(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStateMachineError::StateMachineAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(CreateStateMachineError::StateMachineDeleting(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStateMachineError::StateMachineDeleting(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStateMachineError::StateMachineLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStateMachineError::StateMachineTypeNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateStateMachineError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        CreateStateMachineError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStateMachineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1348,15 +1368,19 @@ impl fmt::Display for CreateStateMachineError {
                 write!(f, "{}", cause)
             }
             CreateStateMachineError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateStateMachineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStateMachineError {}
 /// Errors returned by DeleteActivity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteActivityError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteActivityError {
@@ -1364,10 +1388,10 @@ impl DeleteActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteActivityError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteActivityError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteActivityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1378,15 +1402,19 @@ impl fmt::Display for DeleteActivityError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteActivityError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            DeleteActivityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteActivityError {}
 /// Errors returned by DeleteStateMachine
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStateMachineError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStateMachineError {
@@ -1394,10 +1422,10 @@ impl DeleteStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteStateMachineError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStateMachineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1408,17 +1436,21 @@ impl fmt::Display for DeleteStateMachineError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteStateMachineError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            DeleteStateMachineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStateMachineError {}
 /// Errors returned by DescribeActivity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeActivityError {
     /// <p>The specified activity does not exist.</p>
     ActivityDoesNotExist(String),
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeActivityError {
@@ -1426,15 +1458,16 @@ impl DescribeActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(DescribeActivityError::ActivityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeActivityError::ActivityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeActivityError::InvalidArn(err.msg))
+                    return RusotoError::service(DescribeActivityError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeActivityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1446,17 +1479,21 @@ impl fmt::Display for DescribeActivityError {
         match *self {
             DescribeActivityError::ActivityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DescribeActivityError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            DescribeActivityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeActivityError {}
 /// Errors returned by DescribeExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExecutionError {
     /// <p>The specified execution does not exist.</p>
     ExecutionDoesNotExist(String),
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExecutionError {
@@ -1464,15 +1501,16 @@ impl DescribeExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(DescribeExecutionError::ExecutionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExecutionError::ExecutionDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(DescribeExecutionError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1484,17 +1522,21 @@ impl fmt::Display for DescribeExecutionError {
         match *self {
             DescribeExecutionError::ExecutionDoesNotExist(ref cause) => write!(f, "{}", cause),
             DescribeExecutionError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            DescribeExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExecutionError {}
 /// Errors returned by DescribeStateMachine
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStateMachineError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
     /// <p>The specified state machine does not exist.</p>
     StateMachineDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStateMachineError {
@@ -1502,15 +1544,19 @@ impl DescribeStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        DescribeStateMachineError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStateMachineError::StateMachineDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStateMachineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1524,17 +1570,21 @@ impl fmt::Display for DescribeStateMachineError {
             DescribeStateMachineError::StateMachineDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeStateMachineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStateMachineError {}
 /// Errors returned by DescribeStateMachineForExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStateMachineForExecutionError {
     /// <p>The specified execution does not exist.</p>
     ExecutionDoesNotExist(String),
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStateMachineForExecutionError {
@@ -1544,17 +1594,24 @@ impl DescribeStateMachineForExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStateMachineForExecutionError::ExecutionDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineForExecutionError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeStateMachineForExecutionError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeStateMachineForExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1568,12 +1625,16 @@ impl fmt::Display for DescribeStateMachineForExecutionError {
                 write!(f, "{}", cause)
             }
             DescribeStateMachineForExecutionError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            DescribeStateMachineForExecutionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeStateMachineForExecutionError {}
 /// Errors returned by GetActivityTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetActivityTaskError {
     /// <p>The specified activity does not exist.</p>
     ActivityDoesNotExist(String),
@@ -1581,6 +1642,8 @@ pub enum GetActivityTaskError {
     ActivityWorkerLimitExceeded(String),
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetActivityTaskError {
@@ -1588,20 +1651,22 @@ impl GetActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetActivityTaskError::ActivityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ActivityWorkerLimitExceeded" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityWorkerLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetActivityTaskError::ActivityWorkerLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetActivityTaskError::InvalidArn(err.msg))
+                    return RusotoError::service(GetActivityTaskError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetActivityTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1614,12 +1679,14 @@ impl fmt::Display for GetActivityTaskError {
             GetActivityTaskError::ActivityDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetActivityTaskError::ActivityWorkerLimitExceeded(ref cause) => write!(f, "{}", cause),
             GetActivityTaskError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            GetActivityTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetActivityTaskError {}
 /// Errors returned by GetExecutionHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExecutionHistoryError {
     /// <p>The specified execution does not exist.</p>
     ExecutionDoesNotExist(String),
@@ -1627,6 +1694,8 @@ pub enum GetExecutionHistoryError {
     InvalidArn(String),
     /// <p>The provided token is invalid.</p>
     InvalidToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExecutionHistoryError {
@@ -1634,18 +1703,25 @@ impl GetExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(GetExecutionHistoryError::ExecutionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetExecutionHistoryError::ExecutionDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetExecutionHistoryError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        GetExecutionHistoryError::InvalidToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExecutionHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1658,15 +1734,19 @@ impl fmt::Display for GetExecutionHistoryError {
             GetExecutionHistoryError::ExecutionDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetExecutionHistoryError::InvalidArn(ref cause) => write!(f, "{}", cause),
             GetExecutionHistoryError::InvalidToken(ref cause) => write!(f, "{}", cause),
+            GetExecutionHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExecutionHistoryError {}
 /// Errors returned by ListActivities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListActivitiesError {
     /// <p>The provided token is invalid.</p>
     InvalidToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListActivitiesError {
@@ -1674,10 +1754,10 @@ impl ListActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListActivitiesError::InvalidToken(err.msg))
+                    return RusotoError::service(ListActivitiesError::InvalidToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListActivitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1688,12 +1768,14 @@ impl fmt::Display for ListActivitiesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListActivitiesError::InvalidToken(ref cause) => write!(f, "{}", cause),
+            ListActivitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListActivitiesError {}
 /// Errors returned by ListExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListExecutionsError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
@@ -1703,6 +1785,8 @@ pub enum ListExecutionsError {
     StateMachineDoesNotExist(String),
     /// <p><p/></p>
     StateMachineTypeNotSupported(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListExecutionsError {
@@ -1710,23 +1794,25 @@ impl ListExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListExecutionsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidToken(err.msg))
+                    return RusotoError::service(ListExecutionsError::InvalidToken(err.msg), &res)
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListExecutionsError::StateMachineDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineTypeNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListExecutionsError::StateMachineTypeNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1740,15 +1826,19 @@ impl fmt::Display for ListExecutionsError {
             ListExecutionsError::InvalidToken(ref cause) => write!(f, "{}", cause),
             ListExecutionsError::StateMachineDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListExecutionsError::StateMachineTypeNotSupported(ref cause) => write!(f, "{}", cause),
+            ListExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListExecutionsError {}
 /// Errors returned by ListStateMachines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStateMachinesError {
     /// <p>The provided token is invalid.</p>
     InvalidToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStateMachinesError {
@@ -1756,10 +1846,13 @@ impl ListStateMachinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListStateMachinesError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        ListStateMachinesError::InvalidToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStateMachinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1770,17 +1863,21 @@ impl fmt::Display for ListStateMachinesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListStateMachinesError::InvalidToken(ref cause) => write!(f, "{}", cause),
+            ListStateMachinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStateMachinesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
     /// <p>Could not find the referenced resource. Only state machine and activity ARNs are supported.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1788,15 +1885,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1808,12 +1909,14 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by SendTaskFailure
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendTaskFailureError {
     /// <p>The provided token is invalid.</p>
     InvalidToken(String),
@@ -1821,6 +1924,8 @@ pub enum SendTaskFailureError {
     TaskDoesNotExist(String),
 
     TaskTimedOut(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendTaskFailureError {
@@ -1828,16 +1933,19 @@ impl SendTaskFailureError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskFailureError::InvalidToken(err.msg))
+                    return RusotoError::service(SendTaskFailureError::InvalidToken(err.msg), &res)
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        SendTaskFailureError::TaskDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskTimedOut(err.msg))
+                    return RusotoError::service(SendTaskFailureError::TaskTimedOut(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendTaskFailureError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1850,12 +1958,14 @@ impl fmt::Display for SendTaskFailureError {
             SendTaskFailureError::InvalidToken(ref cause) => write!(f, "{}", cause),
             SendTaskFailureError::TaskDoesNotExist(ref cause) => write!(f, "{}", cause),
             SendTaskFailureError::TaskTimedOut(ref cause) => write!(f, "{}", cause),
+            SendTaskFailureError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendTaskFailureError {}
 /// Errors returned by SendTaskHeartbeat
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendTaskHeartbeatError {
     /// <p>The provided token is invalid.</p>
     InvalidToken(String),
@@ -1863,6 +1973,8 @@ pub enum SendTaskHeartbeatError {
     TaskDoesNotExist(String),
 
     TaskTimedOut(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendTaskHeartbeatError {
@@ -1870,16 +1982,25 @@ impl SendTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        SendTaskHeartbeatError::InvalidToken(err.msg),
+                        &res,
+                    )
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        SendTaskHeartbeatError::TaskDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskTimedOut(err.msg))
+                    return RusotoError::service(
+                        SendTaskHeartbeatError::TaskTimedOut(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendTaskHeartbeatError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1892,12 +2013,14 @@ impl fmt::Display for SendTaskHeartbeatError {
             SendTaskHeartbeatError::InvalidToken(ref cause) => write!(f, "{}", cause),
             SendTaskHeartbeatError::TaskDoesNotExist(ref cause) => write!(f, "{}", cause),
             SendTaskHeartbeatError::TaskTimedOut(ref cause) => write!(f, "{}", cause),
+            SendTaskHeartbeatError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendTaskHeartbeatError {}
 /// Errors returned by SendTaskSuccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendTaskSuccessError {
     /// <p>The provided JSON output data is invalid.</p>
     InvalidOutput(String),
@@ -1907,6 +2030,8 @@ pub enum SendTaskSuccessError {
     TaskDoesNotExist(String),
 
     TaskTimedOut(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendTaskSuccessError {
@@ -1914,19 +2039,22 @@ impl SendTaskSuccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOutput" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidOutput(err.msg))
+                    return RusotoError::service(SendTaskSuccessError::InvalidOutput(err.msg), &res)
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidToken(err.msg))
+                    return RusotoError::service(SendTaskSuccessError::InvalidToken(err.msg), &res)
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        SendTaskSuccessError::TaskDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskTimedOut(err.msg))
+                    return RusotoError::service(SendTaskSuccessError::TaskTimedOut(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendTaskSuccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1940,12 +2068,14 @@ impl fmt::Display for SendTaskSuccessError {
             SendTaskSuccessError::InvalidToken(ref cause) => write!(f, "{}", cause),
             SendTaskSuccessError::TaskDoesNotExist(ref cause) => write!(f, "{}", cause),
             SendTaskSuccessError::TaskTimedOut(ref cause) => write!(f, "{}", cause),
+            SendTaskSuccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendTaskSuccessError {}
 /// Errors returned by StartExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartExecutionError {
     /// <p><p>The execution has the same <code>name</code> as another execution (but a different <code>input</code>).</p> <note> <p>Executions with the same <code>name</code> and <code>input</code> are considered idempotent.</p> </note></p>
     ExecutionAlreadyExists(String),
@@ -1961,6 +2091,8 @@ pub enum StartExecutionError {
     StateMachineDeleting(String),
     /// <p>The specified state machine does not exist.</p>
     StateMachineDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartExecutionError {
@@ -1968,36 +2100,43 @@ impl StartExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionAlreadyExists" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExecutionError::ExecutionAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ExecutionLimitExceeded" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExecutionError::ExecutionLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StartExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(StartExecutionError::InvalidArn(err.msg), &res)
                 }
                 "InvalidExecutionInput" => {
-                    return RusotoError::Service(StartExecutionError::InvalidExecutionInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExecutionError::InvalidExecutionInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(StartExecutionError::InvalidName(err.msg))
+                    return RusotoError::service(StartExecutionError::InvalidName(err.msg), &res)
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDeleting(err.msg))
+                    return RusotoError::service(
+                        StartExecutionError::StateMachineDeleting(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExecutionError::StateMachineDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2014,17 +2153,21 @@ impl fmt::Display for StartExecutionError {
             StartExecutionError::InvalidName(ref cause) => write!(f, "{}", cause),
             StartExecutionError::StateMachineDeleting(ref cause) => write!(f, "{}", cause),
             StartExecutionError::StateMachineDoesNotExist(ref cause) => write!(f, "{}", cause),
+            StartExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartExecutionError {}
 /// Errors returned by StopExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopExecutionError {
     /// <p>The specified execution does not exist.</p>
     ExecutionDoesNotExist(String),
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopExecutionError {
@@ -2032,13 +2175,16 @@ impl StopExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(StopExecutionError::ExecutionDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        StopExecutionError::ExecutionDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StopExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(StopExecutionError::InvalidArn(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2050,12 +2196,14 @@ impl fmt::Display for StopExecutionError {
         match *self {
             StopExecutionError::ExecutionDoesNotExist(ref cause) => write!(f, "{}", cause),
             StopExecutionError::InvalidArn(ref cause) => write!(f, "{}", cause),
+            StopExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
@@ -2063,21 +2211,25 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>You've exceeded the number of tags allowed for a resource. See the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/limits.html"> Limits Topic</a> in the AWS Step Functions Developer Guide.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidArn" => return RusotoError::Service(TagResourceError::InvalidArn(err.msg)),
+                "InvalidArn" => {
+                    return RusotoError::service(TagResourceError::InvalidArn(err.msg), &res)
+                }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2090,17 +2242,21 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
     /// <p>Could not find the referenced resource. Only state machine and activity ARNs are supported.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2108,13 +2264,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArn(err.msg), &res)
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2126,12 +2285,14 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateStateMachine
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStateMachineError {
     /// <p>The provided Amazon Resource Name (ARN) is invalid.</p>
     InvalidArn(String),
@@ -2145,6 +2306,8 @@ pub enum UpdateStateMachineError {
     StateMachineDeleting(String),
     /// <p>The specified state machine does not exist.</p>
     StateMachineDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStateMachineError {
@@ -2152,35 +2315,40 @@ impl UpdateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(UpdateStateMachineError::InvalidArn(err.msg), &res)
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidDefinition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStateMachineError::InvalidDefinition(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateStateMachineError::InvalidLoggingConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "MissingRequiredParameter" => {
-                    return RusotoError::Service(UpdateStateMachineError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStateMachineError::MissingRequiredParameter(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDeleting(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStateMachineError::StateMachineDeleting(err.msg),
+                        &res,
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStateMachineError::StateMachineDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStateMachineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2198,6 +2366,7 @@ impl fmt::Display for UpdateStateMachineError {
             UpdateStateMachineError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
             UpdateStateMachineError::StateMachineDeleting(ref cause) => write!(f, "{}", cause),
             UpdateStateMachineError::StateMachineDoesNotExist(ref cause) => write!(f, "{}", cause),
+            UpdateStateMachineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2351,6 +2520,7 @@ impl StepFunctionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> StepFunctionsClient {
         StepFunctionsClient {
             client: Client::shared(),
@@ -2376,211 +2546,449 @@ impl StepFunctionsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
         StepFunctionsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<StepFunctionsClient> {
+        rusoto_core::ClientBuilder::new(StepFunctionsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> StepFunctionsClient {
+        StepFunctionsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl StepFunctions for StepFunctionsClient {
-    /// <p><p>Creates an activity. An activity is a task that you write in any programming language and host on any machine that has access to AWS Step Functions. Activities must poll Step Functions using the <code>GetActivityTask</code> API action and respond using <code>SendTask*</code> API actions. This function lets Step Functions know the existence of your activity and returns an identifier for use in a state machine and when polling from the activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateActivity</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateActivity</code>&#39;s idempotency check is based on the activity <code>name</code>. If a following request has different <code>tags</code> values, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>tags</code> will not be updated, even if they are different.</p> </note></p>
-    async fn create_activity(
+impl StepFunctionsClient {
+    /// Calls `create_activity`, also returning the metadata of the response.
+    pub async fn create_activity_with_metadata(
         &self,
         input: CreateActivityInput,
-    ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
+    ) -> Result<(CreateActivityOutput, ResponseMetadata), RusotoError<CreateActivityError>> {
+        let request = self.build_create_activity_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateActivityOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateActivityError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_activity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_activity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateActivityInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_activity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_activity_request(&self, input: CreateActivityInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_state_machine`, also returning the metadata of the response.
+    pub async fn create_state_machine_with_metadata(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> Result<(CreateStateMachineOutput, ResponseMetadata), RusotoError<CreateStateMachineError>>
+    {
+        let request = self.build_create_state_machine_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateActivityOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStateMachineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateActivityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStateMachineError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a state machine. A state machine consists of a collection of states that can do work (<code>Task</code> states), determine to which states to transition next (<code>Choice</code> states), stop an execution with an error (<code>Fail</code> states), and so on. State machines are specified using a JSON-based, structured language.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateStateMachine</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateStateMachine</code>&#39;s idempotency check is based on the state machine <code>name</code> and <code>definition</code>. If a following request has a different <code>roleArn</code> or <code>tags</code>, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>roleArn</code> and <code>tags</code> will not be updated, even if they are different.</p> </note></p>
-    async fn create_state_machine(
+    /// Presigns the request `create_state_machine` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_state_machine(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStateMachineInput,
-    ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_state_machine_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_state_machine_request(&self, input: CreateStateMachineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_activity`, also returning the metadata of the response.
+    pub async fn delete_activity_with_metadata(
+        &self,
+        input: DeleteActivityInput,
+    ) -> Result<(DeleteActivityOutput, ResponseMetadata), RusotoError<DeleteActivityError>> {
+        let request = self.build_delete_activity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateStateMachineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteActivityOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStateMachineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteActivityError::from_response(response))
         }
     }
 
-    /// <p>Deletes an activity.</p>
-    async fn delete_activity(
+    /// Presigns the request `delete_activity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_activity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteActivityInput,
-    ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_activity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_activity_request(&self, input: DeleteActivityInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_state_machine`, also returning the metadata of the response.
+    pub async fn delete_state_machine_with_metadata(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> Result<(DeleteStateMachineOutput, ResponseMetadata), RusotoError<DeleteStateMachineError>>
+    {
+        let request = self.build_delete_state_machine_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteActivityOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteStateMachineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteActivityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteStateMachineError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a state machine. This is an asynchronous operation: It sets the state machine&#39;s status to <code>DELETING</code> and begins the deletion process. Each state machine execution is deleted the next time it makes a state transition.</p> <note> <p>The state machine itself is deleted after all executions are completed or deleted.</p> </note></p>
-    async fn delete_state_machine(
+    /// Presigns the request `delete_state_machine` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_state_machine(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStateMachineInput,
-    ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_state_machine_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_state_machine_request(&self, input: DeleteStateMachineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_activity`, also returning the metadata of the response.
+    pub async fn describe_activity_with_metadata(
+        &self,
+        input: DescribeActivityInput,
+    ) -> Result<(DescribeActivityOutput, ResponseMetadata), RusotoError<DescribeActivityError>>
+    {
+        let request = self.build_describe_activity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteStateMachineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeActivityOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStateMachineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeActivityError::from_response(response))
         }
     }
 
-    /// <p><p>Describes an activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn describe_activity(
+    /// Presigns the request `describe_activity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_activity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeActivityInput,
-    ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_activity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_activity_request(&self, input: DescribeActivityInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_execution`, also returning the metadata of the response.
+    pub async fn describe_execution_with_metadata(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> Result<(DescribeExecutionOutput, ResponseMetadata), RusotoError<DescribeExecutionError>>
+    {
+        let request = self.build_describe_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeActivityOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeActivityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExecutionError::from_response(response))
         }
     }
 
-    /// <p><p>Describes an execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn describe_execution(
+    /// Presigns the request `describe_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExecutionInput,
-    ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_execution_request(&self, input: DescribeExecutionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_state_machine`, also returning the metadata of the response.
+    pub async fn describe_state_machine_with_metadata(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> Result<
+        (DescribeStateMachineOutput, ResponseMetadata),
+        RusotoError<DescribeStateMachineError>,
+    > {
+        let request = self.build_describe_state_machine_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStateMachineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStateMachineError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a state machine.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn describe_state_machine(
+    /// Presigns the request `describe_state_machine` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_state_machine(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStateMachineInput,
-    ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_state_machine_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_state_machine_request(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_state_machine_for_execution`, also returning the metadata of the response.
+    pub async fn describe_state_machine_for_execution_with_metadata(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> Result<
+        (DescribeStateMachineForExecutionOutput, ResponseMetadata),
+        RusotoError<DescribeStateMachineForExecutionError>,
+    > {
+        let request = self.build_describe_state_machine_for_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStateMachineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStateMachineForExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStateMachineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStateMachineForExecutionError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Describes the state machine associated with a specific execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn describe_state_machine_for_execution(
+    /// Presigns the request `describe_state_machine_for_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_state_machine_for_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStateMachineForExecutionInput,
-    ) -> Result<
-        DescribeStateMachineForExecutionOutput,
-        RusotoError<DescribeStateMachineForExecutionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_state_machine_for_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_state_machine_for_execution_request(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachineForExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -2590,402 +2998,1113 @@ impl StepFunctions for StepFunctionsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_activity_task`, also returning the metadata of the response.
+    pub async fn get_activity_task_with_metadata(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> Result<(GetActivityTaskOutput, ResponseMetadata), RusotoError<GetActivityTaskError>> {
+        let request = self.build_get_activity_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStateMachineForExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetActivityTaskOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStateMachineForExecutionError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetActivityTaskError::from_response(response))
         }
     }
 
-    /// <p><p>Used by workers to retrieve a task (with the specified activity ARN) which has been scheduled for execution by a running state machine. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available (i.e. an execution of a task of this type is needed.) The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns a <code>taskToken</code> with a null string.</p> <important> <p>Workers should set their client side socket timeout to at least 65 seconds (5 seconds higher than the maximum time the service may hold the poll request).</p> <p>Polling with <code>GetActivityTask</code> can cause latency in some implementations. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/bp-activity-pollers.html">Avoid Latency When Polling for Activity Tasks</a> in the Step Functions Developer Guide.</p> </important></p>
-    async fn get_activity_task(
+    /// Presigns the request `get_activity_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_activity_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetActivityTaskInput,
-    ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_activity_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_activity_task_request(&self, input: GetActivityTaskInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetActivityTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_execution_history`, also returning the metadata of the response.
+    pub async fn get_execution_history_with_metadata(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> Result<(GetExecutionHistoryOutput, ResponseMetadata), RusotoError<GetExecutionHistoryError>>
+    {
+        let request = self.build_get_execution_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetActivityTaskOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetExecutionHistoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetActivityTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetExecutionHistoryError::from_response(response))
         }
     }
 
-    /// <p>Returns the history of the specified execution as a list of events. By default, the results are returned in ascending order of the <code>timeStamp</code> of the events. Use the <code>reverseOrder</code> parameter to get the latest events first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p>
-    async fn get_execution_history(
+    /// Presigns the request `get_execution_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_execution_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExecutionHistoryInput,
-    ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_execution_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_execution_history_request(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetExecutionHistory");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetExecutionHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_activities`, also returning the metadata of the response.
+    pub async fn list_activities_with_metadata(
+        &self,
+        input: ListActivitiesInput,
+    ) -> Result<(ListActivitiesOutput, ResponseMetadata), RusotoError<ListActivitiesError>> {
+        let request = self.build_list_activities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetExecutionHistoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListActivitiesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExecutionHistoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListActivitiesError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the existing activities.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn list_activities(
+    /// Presigns the request `list_activities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_activities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListActivitiesInput,
-    ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_activities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_activities_request(&self, input: ListActivitiesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListActivities");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListActivities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_executions`, also returning the metadata of the response.
+    pub async fn list_executions_with_metadata(
+        &self,
+        input: ListExecutionsInput,
+    ) -> Result<(ListExecutionsOutput, ResponseMetadata), RusotoError<ListExecutionsError>> {
+        let request = self.build_list_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListActivitiesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListExecutionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListActivitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListExecutionsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the executions of a state machine that meet the filtering criteria. Results are sorted by time, with the most recent execution first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn list_executions(
+    /// Presigns the request `list_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListExecutionsInput,
-    ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_executions_request(&self, input: ListExecutionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_state_machines`, also returning the metadata of the response.
+    pub async fn list_state_machines_with_metadata(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> Result<(ListStateMachinesOutput, ResponseMetadata), RusotoError<ListStateMachinesError>>
+    {
+        let request = self.build_list_state_machines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListExecutionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStateMachinesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStateMachinesError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the existing state machines.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
-    async fn list_state_machines(
+    /// Presigns the request `list_state_machines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_state_machines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStateMachinesInput,
-    ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_state_machines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_state_machines_request(&self, input: ListStateMachinesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListStateMachines");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListStateMachines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListStateMachinesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStateMachinesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List tags for a given resource.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_task_failure`, also returning the metadata of the response.
+    pub async fn send_task_failure_with_metadata(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> Result<(SendTaskFailureOutput, ResponseMetadata), RusotoError<SendTaskFailureError>> {
+        let request = self.build_send_task_failure_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendTaskFailureOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendTaskFailureError::from_response(response))
         }
     }
 
-    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> failed.</p>
-    async fn send_task_failure(
+    /// Presigns the request `send_task_failure` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_task_failure(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendTaskFailureInput,
-    ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_task_failure_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_task_failure_request(&self, input: SendTaskFailureInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskFailure");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskFailure");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_task_heartbeat`, also returning the metadata of the response.
+    pub async fn send_task_heartbeat_with_metadata(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> Result<(SendTaskHeartbeatOutput, ResponseMetadata), RusotoError<SendTaskHeartbeatError>>
+    {
+        let request = self.build_send_task_heartbeat_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendTaskFailureOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendTaskHeartbeatOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendTaskFailureError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendTaskHeartbeatError::from_response(response))
         }
     }
 
-    /// <p><p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report to Step Functions that the task represented by the specified <code>taskToken</code> is still making progress. This action resets the <code>Heartbeat</code> clock. The <code>Heartbeat</code> threshold is specified in the state machine&#39;s Amazon States Language definition (<code>HeartbeatSeconds</code>). This action does not in itself create an event in the execution history. However, if the task times out, the execution history contains an <code>ActivityTimedOut</code> entry for activities, or a <code>TaskTimedOut</code> entry for for tasks using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-sync">job run</a> or <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern.</p> <note> <p>The <code>Timeout</code> of a task, defined in the state machine&#39;s Amazon States Language definition, is its maximum allowed duration, regardless of the number of <a>SendTaskHeartbeat</a> requests received. Use <code>HeartbeatSeconds</code> to configure the timeout interval for heartbeats.</p> </note></p>
-    async fn send_task_heartbeat(
+    /// Presigns the request `send_task_heartbeat` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_task_heartbeat(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendTaskHeartbeatInput,
-    ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_task_heartbeat_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_task_heartbeat_request(&self, input: SendTaskHeartbeatInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_task_success`, also returning the metadata of the response.
+    pub async fn send_task_success_with_metadata(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> Result<(SendTaskSuccessOutput, ResponseMetadata), RusotoError<SendTaskSuccessError>> {
+        let request = self.build_send_task_success_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendTaskHeartbeatOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendTaskSuccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendTaskHeartbeatError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendTaskSuccessError::from_response(response))
         }
     }
 
-    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> completed successfully.</p>
-    async fn send_task_success(
+    /// Presigns the request `send_task_success` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_task_success(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendTaskSuccessInput,
-    ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_task_success_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_task_success_request(&self, input: SendTaskSuccessInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskSuccess");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskSuccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_execution`, also returning the metadata of the response.
+    pub async fn start_execution_with_metadata(
+        &self,
+        input: StartExecutionInput,
+    ) -> Result<(StartExecutionOutput, ResponseMetadata), RusotoError<StartExecutionError>> {
+        let request = self.build_start_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendTaskSuccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendTaskSuccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartExecutionError::from_response(response))
         }
     }
 
-    /// <p><p>Starts a state machine execution.</p> <note> <p> <code>StartExecution</code> is idempotent. If <code>StartExecution</code> is called with the same name and input as a running execution, the call will succeed and return the same response as the original request. If the execution is closed or if the input is different, it will return a 400 <code>ExecutionAlreadyExists</code> error. Names can be reused after 90 days. </p> </note></p>
-    async fn start_execution(
+    /// Presigns the request `start_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartExecutionInput,
-    ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_execution_request(&self, input: StartExecutionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StartExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StartExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_execution`, also returning the metadata of the response.
+    pub async fn stop_execution_with_metadata(
+        &self,
+        input: StopExecutionInput,
+    ) -> Result<(StopExecutionOutput, ResponseMetadata), RusotoError<StopExecutionError>> {
+        let request = self.build_stop_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopExecutionError::from_response(response))
         }
     }
 
-    /// <p>Stops an execution.</p>
-    async fn stop_execution(
+    /// Presigns the request `stop_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopExecutionInput,
-    ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_execution_request(&self, input: StopExecutionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StopExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StopExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Add a tag to a Step Functions resource.</p> <p>An array of key-value pairs. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>, and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_iam-tags.html">Controlling Access Using IAM Tags</a>.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove a tag from a Step Functions resource</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_state_machine`, also returning the metadata of the response.
+    pub async fn update_state_machine_with_metadata(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> Result<(UpdateStateMachineOutput, ResponseMetadata), RusotoError<UpdateStateMachineError>>
+    {
+        let request = self.build_update_state_machine_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateStateMachineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateStateMachineError::from_response(response))
         }
     }
 
-    /// <p><p>Updates an existing state machine by modifying its <code>definition</code> and/or <code>roleArn</code>. Running executions will continue to use the previous <code>definition</code> and <code>roleArn</code>. You must include at least one of <code>definition</code> or <code>roleArn</code> or you will receive a <code>MissingRequiredParameter</code> error.</p> <note> <p>All <code>StartExecution</code> calls within a few seconds will use the updated <code>definition</code> and <code>roleArn</code>. Executions started immediately after calling <code>UpdateStateMachine</code> may use the previous state machine <code>definition</code> and <code>roleArn</code>. </p> </note></p>
-    async fn update_state_machine(
+    /// Presigns the request `update_state_machine` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_state_machine(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStateMachineInput,
-    ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_state_machine_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_state_machine_request(&self, input: UpdateStateMachineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UpdateStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UpdateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetExecutionHistory` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_execution_history_pages(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> impl futures::Stream<
+        Item = Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input| async move {
+                client.get_execution_history(input).await
+            },
+            |input: &GetExecutionHistoryInput, output: &GetExecutionHistoryOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetExecutionHistoryInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `events` of every page of `GetExecutionHistory` results for `input`, as `get_execution_history_pages` requests them.
+    pub fn get_execution_history_stream(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> impl futures::Stream<Item = Result<HistoryEvent, RusotoError<GetExecutionHistoryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_execution_history_pages(input), |page| page.events)
+    }
+
+    /// The pages of `ListActivities` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_activities_pages(
+        &self,
+        input: ListActivitiesInput,
+    ) -> impl futures::Stream<Item = Result<ListActivitiesOutput, RusotoError<ListActivitiesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input| async move { client.list_activities(input).await },
+            |input: &ListActivitiesInput, output: &ListActivitiesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListActivitiesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `activities` of every page of `ListActivities` results for `input`, as `list_activities_pages` requests them.
+    pub fn list_activities_stream(
+        &self,
+        input: ListActivitiesInput,
+    ) -> impl futures::Stream<Item = Result<ActivityListItem, RusotoError<ListActivitiesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_activities_pages(input), |page| page.activities)
+    }
+
+    /// The pages of `ListExecutions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_executions_pages(
+        &self,
+        input: ListExecutionsInput,
+    ) -> impl futures::Stream<Item = Result<ListExecutionsOutput, RusotoError<ListExecutionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input| async move { client.list_executions(input).await },
+            |input: &ListExecutionsInput, output: &ListExecutionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListExecutionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `executions` of every page of `ListExecutions` results for `input`, as `list_executions_pages` requests them.
+    pub fn list_executions_stream(
+        &self,
+        input: ListExecutionsInput,
+    ) -> impl futures::Stream<Item = Result<ExecutionListItem, RusotoError<ListExecutionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_executions_pages(input), |page| page.executions)
+    }
+
+    /// The pages of `ListStateMachines` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_state_machines_pages(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StepFunctionsClient, input| async move { client.list_state_machines(input).await },
+            |input: &ListStateMachinesInput, output: &ListStateMachinesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStateMachinesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `stateMachines` of every page of `ListStateMachines` results for `input`, as `list_state_machines_pages` requests them.
+    pub fn list_state_machines_stream(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> impl futures::Stream<Item = Result<StateMachineListItem, RusotoError<ListStateMachinesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_state_machines_pages(input), |page| {
+            page.state_machines
+        })
+    }
+}
+
+#[async_trait]
+impl StepFunctions for StepFunctionsClient {
+    /// <p><p>Creates an activity. An activity is a task that you write in any programming language and host on any machine that has access to AWS Step Functions. Activities must poll Step Functions using the <code>GetActivityTask</code> API action and respond using <code>SendTask*</code> API actions. This function lets Step Functions know the existence of your activity and returns an identifier for use in a state machine and when polling from the activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateActivity</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateActivity</code>&#39;s idempotency check is based on the activity <code>name</code>. If a following request has different <code>tags</code> values, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    async fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
+        self.create_activity_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateStateMachineOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStateMachineError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a state machine. A state machine consists of a collection of states that can do work (<code>Task</code> states), determine to which states to transition next (<code>Choice</code> states), stop an execution with an error (<code>Fail</code> states), and so on. State machines are specified using a JSON-based, structured language.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateStateMachine</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateStateMachine</code>&#39;s idempotency check is based on the state machine <code>name</code> and <code>definition</code>. If a following request has a different <code>roleArn</code> or <code>tags</code>, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>roleArn</code> and <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    async fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
+        self.create_state_machine_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an activity.</p>
+    async fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
+        self.delete_activity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a state machine. This is an asynchronous operation: It sets the state machine&#39;s status to <code>DELETING</code> and begins the deletion process. Each state machine execution is deleted the next time it makes a state transition.</p> <note> <p>The state machine itself is deleted after all executions are completed or deleted.</p> </note></p>
+    async fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
+        self.delete_state_machine_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes an activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
+        self.describe_activity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes an execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
+        self.describe_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a state machine.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
+        self.describe_state_machine_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes the state machine associated with a specific execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> Result<
+        DescribeStateMachineForExecutionOutput,
+        RusotoError<DescribeStateMachineForExecutionError>,
+    > {
+        self.describe_state_machine_for_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Used by workers to retrieve a task (with the specified activity ARN) which has been scheduled for execution by a running state machine. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available (i.e. an execution of a task of this type is needed.) The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns a <code>taskToken</code> with a null string.</p> <important> <p>Workers should set their client side socket timeout to at least 65 seconds (5 seconds higher than the maximum time the service may hold the poll request).</p> <p>Polling with <code>GetActivityTask</code> can cause latency in some implementations. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/bp-activity-pollers.html">Avoid Latency When Polling for Activity Tasks</a> in the Step Functions Developer Guide.</p> </important></p>
+    async fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
+        self.get_activity_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the history of the specified execution as a list of events. By default, the results are returned in ascending order of the <code>timeStamp</code> of the events. Use the <code>reverseOrder</code> parameter to get the latest events first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p>
+    async fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
+        self.get_execution_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the existing activities.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
+        self.list_activities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the executions of a state machine that meet the filtering criteria. Results are sorted by time, with the most recent execution first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
+        self.list_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the existing state machines.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    async fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
+        self.list_state_machines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List tags for a given resource.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> failed.</p>
+    async fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
+        self.send_task_failure_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report to Step Functions that the task represented by the specified <code>taskToken</code> is still making progress. This action resets the <code>Heartbeat</code> clock. The <code>Heartbeat</code> threshold is specified in the state machine&#39;s Amazon States Language definition (<code>HeartbeatSeconds</code>). This action does not in itself create an event in the execution history. However, if the task times out, the execution history contains an <code>ActivityTimedOut</code> entry for activities, or a <code>TaskTimedOut</code> entry for for tasks using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-sync">job run</a> or <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern.</p> <note> <p>The <code>Timeout</code> of a task, defined in the state machine&#39;s Amazon States Language definition, is its maximum allowed duration, regardless of the number of <a>SendTaskHeartbeat</a> requests received. Use <code>HeartbeatSeconds</code> to configure the timeout interval for heartbeats.</p> </note></p>
+    async fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
+        self.send_task_heartbeat_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> completed successfully.</p>
+    async fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
+        self.send_task_success_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts a state machine execution.</p> <note> <p> <code>StartExecution</code> is idempotent. If <code>StartExecution</code> is called with the same name and input as a running execution, the call will succeed and return the same response as the original request. If the execution is closed or if the input is different, it will return a 400 <code>ExecutionAlreadyExists</code> error. Names can be reused after 90 days. </p> </note></p>
+    async fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
+        self.start_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops an execution.</p>
+    async fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
+        self.stop_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add a tag to a Step Functions resource.</p> <p>An array of key-value pairs. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>, and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_iam-tags.html">Controlling Access Using IAM Tags</a>.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove a tag from a Step Functions resource</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates an existing state machine by modifying its <code>definition</code> and/or <code>roleArn</code>. Running executions will continue to use the previous <code>definition</code> and <code>roleArn</code>. You must include at least one of <code>definition</code> or <code>roleArn</code> or you will receive a <code>MissingRequiredParameter</code> error.</p> <note> <p>All <code>StartExecution</code> calls within a few seconds will use the updated <code>definition</code> and <code>roleArn</code>. Executions started immediately after calling <code>UpdateStateMachine</code> may use the previous state machine <code>definition</code> and <code>roleArn</code>. </p> </note></p>
+    async fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
+        self.update_state_machine_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2927,11 +2927,14 @@ pub struct VolumeiSCSIAttributes {
 
 /// Errors returned by ActivateGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ActivateGatewayError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ActivateGatewayError {
@@ -2939,15 +2942,19 @@ impl ActivateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ActivateGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ActivateGatewayError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ActivateGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ActivateGatewayError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ActivateGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2959,17 +2966,21 @@ impl fmt::Display for ActivateGatewayError {
         match *self {
             ActivateGatewayError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ActivateGatewayError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ActivateGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ActivateGatewayError {}
 /// Errors returned by AddCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddCacheError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddCacheError {
@@ -2977,13 +2988,16 @@ impl AddCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(AddCacheError::InternalServerError(err.msg), &res)
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        AddCacheError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2995,17 +3009,21 @@ impl fmt::Display for AddCacheError {
         match *self {
             AddCacheError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AddCacheError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AddCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddCacheError {}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -3013,17 +3031,19 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3035,17 +3055,21 @@ impl fmt::Display for AddTagsToResourceError {
         match *self {
             AddTagsToResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by AddUploadBuffer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddUploadBufferError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddUploadBufferError {
@@ -3053,15 +3077,19 @@ impl AddUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddUploadBufferError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AddUploadBufferError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddUploadBufferError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddUploadBufferError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddUploadBufferError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3073,17 +3101,21 @@ impl fmt::Display for AddUploadBufferError {
         match *self {
             AddUploadBufferError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AddUploadBufferError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AddUploadBufferError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddUploadBufferError {}
 /// Errors returned by AddWorkingStorage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddWorkingStorageError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddWorkingStorageError {
@@ -3091,17 +3123,19 @@ impl AddWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddWorkingStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddWorkingStorageError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddWorkingStorageError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddWorkingStorageError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddWorkingStorageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3113,17 +3147,21 @@ impl fmt::Display for AddWorkingStorageError {
         match *self {
             AddWorkingStorageError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AddWorkingStorageError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AddWorkingStorageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddWorkingStorageError {}
 /// Errors returned by AssignTapePool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssignTapePoolError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssignTapePoolError {
@@ -3131,15 +3169,19 @@ impl AssignTapePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AssignTapePoolError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AssignTapePoolError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AssignTapePoolError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssignTapePoolError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssignTapePoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3151,17 +3193,21 @@ impl fmt::Display for AssignTapePoolError {
         match *self {
             AssignTapePoolError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AssignTapePoolError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AssignTapePoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssignTapePoolError {}
 /// Errors returned by AttachVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachVolumeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachVolumeError {
@@ -3169,13 +3215,19 @@ impl AttachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AttachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AttachVolumeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AttachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        AttachVolumeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3187,17 +3239,21 @@ impl fmt::Display for AttachVolumeError {
         match *self {
             AttachVolumeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             AttachVolumeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            AttachVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachVolumeError {}
 /// Errors returned by CancelArchival
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelArchivalError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelArchivalError {
@@ -3205,15 +3261,19 @@ impl CancelArchivalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelArchivalError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CancelArchivalError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelArchivalError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelArchivalError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelArchivalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3225,17 +3285,21 @@ impl fmt::Display for CancelArchivalError {
         match *self {
             CancelArchivalError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CancelArchivalError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CancelArchivalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelArchivalError {}
 /// Errors returned by CancelRetrieval
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelRetrievalError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelRetrievalError {
@@ -3243,15 +3307,19 @@ impl CancelRetrievalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelRetrievalError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CancelRetrievalError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelRetrievalError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelRetrievalError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelRetrievalError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3263,17 +3331,21 @@ impl fmt::Display for CancelRetrievalError {
         match *self {
             CancelRetrievalError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CancelRetrievalError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CancelRetrievalError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelRetrievalError {}
 /// Errors returned by CreateCachediSCSIVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCachediSCSIVolumeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCachediSCSIVolumeError {
@@ -3281,17 +3353,19 @@ impl CreateCachediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCachediSCSIVolumeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCachediSCSIVolumeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCachediSCSIVolumeError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCachediSCSIVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3305,17 +3379,21 @@ impl fmt::Display for CreateCachediSCSIVolumeError {
             CreateCachediSCSIVolumeError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCachediSCSIVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCachediSCSIVolumeError {}
 /// Errors returned by CreateNFSFileShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNFSFileShareError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNFSFileShareError {
@@ -3323,17 +3401,19 @@ impl CreateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNFSFileShareError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNFSFileShareError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNFSFileShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3345,17 +3425,21 @@ impl fmt::Display for CreateNFSFileShareError {
         match *self {
             CreateNFSFileShareError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateNFSFileShareError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CreateNFSFileShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNFSFileShareError {}
 /// Errors returned by CreateSMBFileShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSMBFileShareError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSMBFileShareError {
@@ -3363,17 +3447,19 @@ impl CreateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSMBFileShareError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSMBFileShareError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSMBFileShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3385,12 +3471,14 @@ impl fmt::Display for CreateSMBFileShareError {
         match *self {
             CreateSMBFileShareError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateSMBFileShareError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CreateSMBFileShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSMBFileShareError {}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
@@ -3398,6 +3486,8 @@ pub enum CreateSnapshotError {
     InvalidGatewayRequest(String),
     /// <p>An internal server error has occurred because the service is unavailable. For more information, see the error and message fields.</p>
     ServiceUnavailableError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotError {
@@ -3405,20 +3495,25 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSnapshotError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateSnapshotError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSnapshotError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CreateSnapshotError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSnapshotError::ServiceUnavailableError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3431,12 +3526,14 @@ impl fmt::Display for CreateSnapshotError {
             CreateSnapshotError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateSnapshotError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
             CreateSnapshotError::ServiceUnavailableError(ref cause) => write!(f, "{}", cause),
+            CreateSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSnapshotError {}
 /// Errors returned by CreateSnapshotFromVolumeRecoveryPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotFromVolumeRecoveryPointError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
@@ -3444,6 +3541,8 @@ pub enum CreateSnapshotFromVolumeRecoveryPointError {
     InvalidGatewayRequest(String),
     /// <p>An internal server error has occurred because the service is unavailable. For more information, see the error and message fields.</p>
     ServiceUnavailableError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotFromVolumeRecoveryPointError {
@@ -3453,24 +3552,32 @@ impl CreateSnapshotFromVolumeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSnapshotFromVolumeRecoveryPointError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSnapshotFromVolumeRecoveryPointError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSnapshotFromVolumeRecoveryPointError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3489,17 +3596,23 @@ impl fmt::Display for CreateSnapshotFromVolumeRecoveryPointError {
             CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateSnapshotFromVolumeRecoveryPointError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateSnapshotFromVolumeRecoveryPointError {}
 /// Errors returned by CreateStorediSCSIVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStorediSCSIVolumeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStorediSCSIVolumeError {
@@ -3507,17 +3620,19 @@ impl CreateStorediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStorediSCSIVolumeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStorediSCSIVolumeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStorediSCSIVolumeError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStorediSCSIVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3531,17 +3646,21 @@ impl fmt::Display for CreateStorediSCSIVolumeError {
             CreateStorediSCSIVolumeError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateStorediSCSIVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStorediSCSIVolumeError {}
 /// Errors returned by CreateTapeWithBarcode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTapeWithBarcodeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTapeWithBarcodeError {
@@ -3549,17 +3668,19 @@ impl CreateTapeWithBarcodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTapeWithBarcodeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTapeWithBarcodeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTapeWithBarcodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3571,17 +3692,21 @@ impl fmt::Display for CreateTapeWithBarcodeError {
         match *self {
             CreateTapeWithBarcodeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateTapeWithBarcodeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CreateTapeWithBarcodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTapeWithBarcodeError {}
 /// Errors returned by CreateTapes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTapesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTapesError {
@@ -3589,13 +3714,19 @@ impl CreateTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateTapesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        CreateTapesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTapesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3607,17 +3738,21 @@ impl fmt::Display for CreateTapesError {
         match *self {
             CreateTapesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateTapesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            CreateTapesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTapesError {}
 /// Errors returned by DeleteBandwidthRateLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBandwidthRateLimitError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBandwidthRateLimitError {
@@ -3625,17 +3760,19 @@ impl DeleteBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBandwidthRateLimitError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBandwidthRateLimitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3649,17 +3786,21 @@ impl fmt::Display for DeleteBandwidthRateLimitError {
             DeleteBandwidthRateLimitError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteBandwidthRateLimitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBandwidthRateLimitError {}
 /// Errors returned by DeleteChapCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteChapCredentialsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteChapCredentialsError {
@@ -3667,17 +3808,19 @@ impl DeleteChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteChapCredentialsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteChapCredentialsError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteChapCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3689,17 +3832,21 @@ impl fmt::Display for DeleteChapCredentialsError {
         match *self {
             DeleteChapCredentialsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteChapCredentialsError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteChapCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteChapCredentialsError {}
 /// Errors returned by DeleteFileShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFileShareError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFileShareError {
@@ -3707,15 +3854,19 @@ impl DeleteFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileShareError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteFileShareError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileShareError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFileShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3727,17 +3878,21 @@ impl fmt::Display for DeleteFileShareError {
         match *self {
             DeleteFileShareError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteFileShareError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteFileShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFileShareError {}
 /// Errors returned by DeleteGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGatewayError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGatewayError {
@@ -3745,13 +3900,19 @@ impl DeleteGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3763,17 +3924,21 @@ impl fmt::Display for DeleteGatewayError {
         match *self {
             DeleteGatewayError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteGatewayError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGatewayError {}
 /// Errors returned by DeleteSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSnapshotScheduleError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSnapshotScheduleError {
@@ -3781,17 +3946,19 @@ impl DeleteSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteSnapshotScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSnapshotScheduleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSnapshotScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3803,17 +3970,21 @@ impl fmt::Display for DeleteSnapshotScheduleError {
         match *self {
             DeleteSnapshotScheduleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteSnapshotScheduleError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteSnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSnapshotScheduleError {}
 /// Errors returned by DeleteTape
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTapeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTapeError {
@@ -3821,13 +3992,19 @@ impl DeleteTapeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteTapeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteTapeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTapeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3839,17 +4016,21 @@ impl fmt::Display for DeleteTapeError {
         match *self {
             DeleteTapeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteTapeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteTapeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTapeError {}
 /// Errors returned by DeleteTapeArchive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTapeArchiveError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTapeArchiveError {
@@ -3857,17 +4038,19 @@ impl DeleteTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTapeArchiveError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTapeArchiveError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTapeArchiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3879,17 +4062,21 @@ impl fmt::Display for DeleteTapeArchiveError {
         match *self {
             DeleteTapeArchiveError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteTapeArchiveError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteTapeArchiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTapeArchiveError {}
 /// Errors returned by DeleteVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVolumeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVolumeError {
@@ -3897,13 +4084,19 @@ impl DeleteVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteVolumeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteVolumeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3915,17 +4108,21 @@ impl fmt::Display for DeleteVolumeError {
         match *self {
             DeleteVolumeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteVolumeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DeleteVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVolumeError {}
 /// Errors returned by DescribeAvailabilityMonitorTest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAvailabilityMonitorTestError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAvailabilityMonitorTestError {
@@ -3935,17 +4132,24 @@ impl DescribeAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAvailabilityMonitorTestError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAvailabilityMonitorTestError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3961,17 +4165,23 @@ impl fmt::Display for DescribeAvailabilityMonitorTestError {
             DescribeAvailabilityMonitorTestError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAvailabilityMonitorTestError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAvailabilityMonitorTestError {}
 /// Errors returned by DescribeBandwidthRateLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBandwidthRateLimitError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBandwidthRateLimitError {
@@ -3981,17 +4191,21 @@ impl DescribeBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBandwidthRateLimitError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeBandwidthRateLimitError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4007,17 +4221,21 @@ impl fmt::Display for DescribeBandwidthRateLimitError {
             DescribeBandwidthRateLimitError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeBandwidthRateLimitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBandwidthRateLimitError {}
 /// Errors returned by DescribeCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheError {
@@ -4025,13 +4243,19 @@ impl DescribeCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeCacheError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeCacheError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4043,17 +4267,21 @@ impl fmt::Display for DescribeCacheError {
         match *self {
             DescribeCacheError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeCacheError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheError {}
 /// Errors returned by DescribeCachediSCSIVolumes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCachediSCSIVolumesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCachediSCSIVolumesError {
@@ -4063,17 +4291,21 @@ impl DescribeCachediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCachediSCSIVolumesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCachediSCSIVolumesError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeCachediSCSIVolumesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4089,17 +4321,21 @@ impl fmt::Display for DescribeCachediSCSIVolumesError {
             DescribeCachediSCSIVolumesError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCachediSCSIVolumesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCachediSCSIVolumesError {}
 /// Errors returned by DescribeChapCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChapCredentialsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChapCredentialsError {
@@ -4107,17 +4343,19 @@ impl DescribeChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeChapCredentialsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeChapCredentialsError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeChapCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4131,17 +4369,21 @@ impl fmt::Display for DescribeChapCredentialsError {
             DescribeChapCredentialsError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeChapCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChapCredentialsError {}
 /// Errors returned by DescribeGatewayInformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGatewayInformationError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGatewayInformationError {
@@ -4151,17 +4393,21 @@ impl DescribeGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGatewayInformationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGatewayInformationError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeGatewayInformationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4177,17 +4423,21 @@ impl fmt::Display for DescribeGatewayInformationError {
             DescribeGatewayInformationError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeGatewayInformationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGatewayInformationError {}
 /// Errors returned by DescribeMaintenanceStartTime
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMaintenanceStartTimeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMaintenanceStartTimeError {
@@ -4197,17 +4447,24 @@ impl DescribeMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceStartTimeError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMaintenanceStartTimeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4223,17 +4480,21 @@ impl fmt::Display for DescribeMaintenanceStartTimeError {
             DescribeMaintenanceStartTimeError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMaintenanceStartTimeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMaintenanceStartTimeError {}
 /// Errors returned by DescribeNFSFileShares
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNFSFileSharesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNFSFileSharesError {
@@ -4241,17 +4502,19 @@ impl DescribeNFSFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNFSFileSharesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNFSFileSharesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeNFSFileSharesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4263,17 +4526,21 @@ impl fmt::Display for DescribeNFSFileSharesError {
         match *self {
             DescribeNFSFileSharesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeNFSFileSharesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeNFSFileSharesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeNFSFileSharesError {}
 /// Errors returned by DescribeSMBFileShares
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSMBFileSharesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSMBFileSharesError {
@@ -4281,17 +4548,19 @@ impl DescribeSMBFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSMBFileSharesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSMBFileSharesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSMBFileSharesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4303,17 +4572,21 @@ impl fmt::Display for DescribeSMBFileSharesError {
         match *self {
             DescribeSMBFileSharesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeSMBFileSharesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeSMBFileSharesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSMBFileSharesError {}
 /// Errors returned by DescribeSMBSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSMBSettingsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSMBSettingsError {
@@ -4321,17 +4594,19 @@ impl DescribeSMBSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSMBSettingsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSMBSettingsError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSMBSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4343,17 +4618,21 @@ impl fmt::Display for DescribeSMBSettingsError {
         match *self {
             DescribeSMBSettingsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeSMBSettingsError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeSMBSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSMBSettingsError {}
 /// Errors returned by DescribeSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSnapshotScheduleError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSnapshotScheduleError {
@@ -4361,17 +4640,19 @@ impl DescribeSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSnapshotScheduleError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSnapshotScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4385,17 +4666,21 @@ impl fmt::Display for DescribeSnapshotScheduleError {
             DescribeSnapshotScheduleError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSnapshotScheduleError {}
 /// Errors returned by DescribeStorediSCSIVolumes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStorediSCSIVolumesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStorediSCSIVolumesError {
@@ -4405,17 +4690,21 @@ impl DescribeStorediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStorediSCSIVolumesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeStorediSCSIVolumesError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeStorediSCSIVolumesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4431,17 +4720,21 @@ impl fmt::Display for DescribeStorediSCSIVolumesError {
             DescribeStorediSCSIVolumesError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeStorediSCSIVolumesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStorediSCSIVolumesError {}
 /// Errors returned by DescribeTapeArchives
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTapeArchivesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTapeArchivesError {
@@ -4449,17 +4742,19 @@ impl DescribeTapeArchivesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTapeArchivesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTapeArchivesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTapeArchivesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4471,17 +4766,21 @@ impl fmt::Display for DescribeTapeArchivesError {
         match *self {
             DescribeTapeArchivesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeTapeArchivesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeTapeArchivesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTapeArchivesError {}
 /// Errors returned by DescribeTapeRecoveryPoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTapeRecoveryPointsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTapeRecoveryPointsError {
@@ -4491,17 +4790,21 @@ impl DescribeTapeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTapeRecoveryPointsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTapeRecoveryPointsError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeTapeRecoveryPointsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4517,17 +4820,21 @@ impl fmt::Display for DescribeTapeRecoveryPointsError {
             DescribeTapeRecoveryPointsError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTapeRecoveryPointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTapeRecoveryPointsError {}
 /// Errors returned by DescribeTapes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTapesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTapesError {
@@ -4535,13 +4842,19 @@ impl DescribeTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeTapesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeTapesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTapesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4553,17 +4866,21 @@ impl fmt::Display for DescribeTapesError {
         match *self {
             DescribeTapesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeTapesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeTapesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTapesError {}
 /// Errors returned by DescribeUploadBuffer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUploadBufferError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUploadBufferError {
@@ -4571,17 +4888,19 @@ impl DescribeUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUploadBufferError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUploadBufferError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUploadBufferError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4593,17 +4912,21 @@ impl fmt::Display for DescribeUploadBufferError {
         match *self {
             DescribeUploadBufferError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeUploadBufferError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeUploadBufferError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUploadBufferError {}
 /// Errors returned by DescribeVTLDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVTLDevicesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVTLDevicesError {
@@ -4611,17 +4934,19 @@ impl DescribeVTLDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVTLDevicesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVTLDevicesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVTLDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4633,17 +4958,21 @@ impl fmt::Display for DescribeVTLDevicesError {
         match *self {
             DescribeVTLDevicesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeVTLDevicesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeVTLDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVTLDevicesError {}
 /// Errors returned by DescribeWorkingStorage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeWorkingStorageError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeWorkingStorageError {
@@ -4651,17 +4980,19 @@ impl DescribeWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeWorkingStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeWorkingStorageError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeWorkingStorageError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeWorkingStorageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4673,17 +5004,21 @@ impl fmt::Display for DescribeWorkingStorageError {
         match *self {
             DescribeWorkingStorageError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeWorkingStorageError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DescribeWorkingStorageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeWorkingStorageError {}
 /// Errors returned by DetachVolume
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachVolumeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachVolumeError {
@@ -4691,13 +5026,19 @@ impl DetachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DetachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DetachVolumeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DetachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        DetachVolumeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachVolumeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4709,17 +5050,21 @@ impl fmt::Display for DetachVolumeError {
         match *self {
             DetachVolumeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DetachVolumeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DetachVolumeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachVolumeError {}
 /// Errors returned by DisableGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableGatewayError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableGatewayError {
@@ -4727,15 +5072,19 @@ impl DisableGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DisableGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DisableGatewayError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DisableGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableGatewayError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4747,17 +5096,21 @@ impl fmt::Display for DisableGatewayError {
         match *self {
             DisableGatewayError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DisableGatewayError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            DisableGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableGatewayError {}
 /// Errors returned by JoinDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum JoinDomainError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl JoinDomainError {
@@ -4765,13 +5118,19 @@ impl JoinDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(JoinDomainError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        JoinDomainError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(JoinDomainError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        JoinDomainError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(JoinDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4783,17 +5142,21 @@ impl fmt::Display for JoinDomainError {
         match *self {
             JoinDomainError::InternalServerError(ref cause) => write!(f, "{}", cause),
             JoinDomainError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            JoinDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for JoinDomainError {}
 /// Errors returned by ListFileShares
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFileSharesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFileSharesError {
@@ -4801,15 +5164,19 @@ impl ListFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFileSharesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListFileSharesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFileSharesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFileSharesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4821,17 +5188,21 @@ impl fmt::Display for ListFileSharesError {
         match *self {
             ListFileSharesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListFileSharesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListFileSharesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFileSharesError {}
 /// Errors returned by ListGateways
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGatewaysError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGatewaysError {
@@ -4839,13 +5210,19 @@ impl ListGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGatewaysError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListGatewaysError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListGatewaysError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        ListGatewaysError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGatewaysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4857,17 +5234,21 @@ impl fmt::Display for ListGatewaysError {
         match *self {
             ListGatewaysError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListGatewaysError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListGatewaysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGatewaysError {}
 /// Errors returned by ListLocalDisks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLocalDisksError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLocalDisksError {
@@ -4875,15 +5256,19 @@ impl ListLocalDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListLocalDisksError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListLocalDisksError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListLocalDisksError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLocalDisksError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLocalDisksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4895,17 +5280,21 @@ impl fmt::Display for ListLocalDisksError {
         match *self {
             ListLocalDisksError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListLocalDisksError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListLocalDisksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLocalDisksError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4913,17 +5302,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4935,17 +5326,21 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTapes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTapesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTapesError {
@@ -4953,13 +5348,16 @@ impl ListTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(ListTapesError::InternalServerError(err.msg), &res)
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        ListTapesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTapesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4971,17 +5369,21 @@ impl fmt::Display for ListTapesError {
         match *self {
             ListTapesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTapesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListTapesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTapesError {}
 /// Errors returned by ListVolumeInitiators
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVolumeInitiatorsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVolumeInitiatorsError {
@@ -4989,17 +5391,19 @@ impl ListVolumeInitiatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVolumeInitiatorsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVolumeInitiatorsError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVolumeInitiatorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5011,17 +5415,21 @@ impl fmt::Display for ListVolumeInitiatorsError {
         match *self {
             ListVolumeInitiatorsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListVolumeInitiatorsError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListVolumeInitiatorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVolumeInitiatorsError {}
 /// Errors returned by ListVolumeRecoveryPoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVolumeRecoveryPointsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVolumeRecoveryPointsError {
@@ -5029,17 +5437,19 @@ impl ListVolumeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVolumeRecoveryPointsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVolumeRecoveryPointsError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVolumeRecoveryPointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5053,17 +5463,21 @@ impl fmt::Display for ListVolumeRecoveryPointsError {
             ListVolumeRecoveryPointsError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListVolumeRecoveryPointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVolumeRecoveryPointsError {}
 /// Errors returned by ListVolumes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVolumesError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVolumesError {
@@ -5071,13 +5485,19 @@ impl ListVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListVolumesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        ListVolumesError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVolumesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5089,17 +5509,21 @@ impl fmt::Display for ListVolumesError {
         match *self {
             ListVolumesError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListVolumesError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ListVolumesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVolumesError {}
 /// Errors returned by NotifyWhenUploaded
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum NotifyWhenUploadedError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl NotifyWhenUploadedError {
@@ -5107,17 +5531,19 @@ impl NotifyWhenUploadedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyWhenUploadedError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        NotifyWhenUploadedError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(NotifyWhenUploadedError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5129,17 +5555,21 @@ impl fmt::Display for NotifyWhenUploadedError {
         match *self {
             NotifyWhenUploadedError::InternalServerError(ref cause) => write!(f, "{}", cause),
             NotifyWhenUploadedError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            NotifyWhenUploadedError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for NotifyWhenUploadedError {}
 /// Errors returned by RefreshCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RefreshCacheError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RefreshCacheError {
@@ -5147,13 +5577,19 @@ impl RefreshCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RefreshCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        RefreshCacheError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RefreshCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        RefreshCacheError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RefreshCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5165,17 +5601,21 @@ impl fmt::Display for RefreshCacheError {
         match *self {
             RefreshCacheError::InternalServerError(ref cause) => write!(f, "{}", cause),
             RefreshCacheError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            RefreshCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RefreshCacheError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -5183,17 +5623,19 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromResourceError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5205,17 +5647,21 @@ impl fmt::Display for RemoveTagsFromResourceError {
         match *self {
             RemoveTagsFromResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetCacheError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetCacheError {
@@ -5223,13 +5669,19 @@ impl ResetCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ResetCacheError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ResetCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        ResetCacheError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5241,17 +5693,21 @@ impl fmt::Display for ResetCacheError {
         match *self {
             ResetCacheError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ResetCacheError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ResetCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetCacheError {}
 /// Errors returned by RetrieveTapeArchive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RetrieveTapeArchiveError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RetrieveTapeArchiveError {
@@ -5259,17 +5715,19 @@ impl RetrieveTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RetrieveTapeArchiveError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RetrieveTapeArchiveError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RetrieveTapeArchiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5281,17 +5739,21 @@ impl fmt::Display for RetrieveTapeArchiveError {
         match *self {
             RetrieveTapeArchiveError::InternalServerError(ref cause) => write!(f, "{}", cause),
             RetrieveTapeArchiveError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            RetrieveTapeArchiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RetrieveTapeArchiveError {}
 /// Errors returned by RetrieveTapeRecoveryPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RetrieveTapeRecoveryPointError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RetrieveTapeRecoveryPointError {
@@ -5299,17 +5761,21 @@ impl RetrieveTapeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RetrieveTapeRecoveryPointError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RetrieveTapeRecoveryPointError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RetrieveTapeRecoveryPointError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5325,17 +5791,21 @@ impl fmt::Display for RetrieveTapeRecoveryPointError {
             RetrieveTapeRecoveryPointError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RetrieveTapeRecoveryPointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RetrieveTapeRecoveryPointError {}
 /// Errors returned by SetLocalConsolePassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetLocalConsolePasswordError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetLocalConsolePasswordError {
@@ -5343,17 +5813,19 @@ impl SetLocalConsolePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetLocalConsolePasswordError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetLocalConsolePasswordError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetLocalConsolePasswordError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetLocalConsolePasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5367,17 +5839,21 @@ impl fmt::Display for SetLocalConsolePasswordError {
             SetLocalConsolePasswordError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetLocalConsolePasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetLocalConsolePasswordError {}
 /// Errors returned by SetSMBGuestPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetSMBGuestPasswordError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetSMBGuestPasswordError {
@@ -5385,17 +5861,19 @@ impl SetSMBGuestPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetSMBGuestPasswordError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetSMBGuestPasswordError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetSMBGuestPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5407,17 +5885,21 @@ impl fmt::Display for SetSMBGuestPasswordError {
         match *self {
             SetSMBGuestPasswordError::InternalServerError(ref cause) => write!(f, "{}", cause),
             SetSMBGuestPasswordError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            SetSMBGuestPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetSMBGuestPasswordError {}
 /// Errors returned by ShutdownGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ShutdownGatewayError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ShutdownGatewayError {
@@ -5425,15 +5907,19 @@ impl ShutdownGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ShutdownGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ShutdownGatewayError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ShutdownGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ShutdownGatewayError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ShutdownGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5445,17 +5931,21 @@ impl fmt::Display for ShutdownGatewayError {
         match *self {
             ShutdownGatewayError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ShutdownGatewayError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            ShutdownGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ShutdownGatewayError {}
 /// Errors returned by StartAvailabilityMonitorTest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartAvailabilityMonitorTestError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartAvailabilityMonitorTestError {
@@ -5465,17 +5955,24 @@ impl StartAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAvailabilityMonitorTestError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartAvailabilityMonitorTestError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5491,17 +5988,21 @@ impl fmt::Display for StartAvailabilityMonitorTestError {
             StartAvailabilityMonitorTestError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartAvailabilityMonitorTestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartAvailabilityMonitorTestError {}
 /// Errors returned by StartGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartGatewayError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartGatewayError {
@@ -5509,13 +6010,19 @@ impl StartGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        StartGatewayError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(StartGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        StartGatewayError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5527,17 +6034,21 @@ impl fmt::Display for StartGatewayError {
         match *self {
             StartGatewayError::InternalServerError(ref cause) => write!(f, "{}", cause),
             StartGatewayError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            StartGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartGatewayError {}
 /// Errors returned by UpdateBandwidthRateLimit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBandwidthRateLimitError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBandwidthRateLimitError {
@@ -5545,17 +6056,19 @@ impl UpdateBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateBandwidthRateLimitError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBandwidthRateLimitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5569,17 +6082,21 @@ impl fmt::Display for UpdateBandwidthRateLimitError {
             UpdateBandwidthRateLimitError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateBandwidthRateLimitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBandwidthRateLimitError {}
 /// Errors returned by UpdateChapCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateChapCredentialsError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateChapCredentialsError {
@@ -5587,17 +6104,19 @@ impl UpdateChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateChapCredentialsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateChapCredentialsError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateChapCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5609,17 +6128,21 @@ impl fmt::Display for UpdateChapCredentialsError {
         match *self {
             UpdateChapCredentialsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateChapCredentialsError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            UpdateChapCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateChapCredentialsError {}
 /// Errors returned by UpdateGatewayInformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGatewayInformationError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGatewayInformationError {
@@ -5627,17 +6150,19 @@ impl UpdateGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGatewayInformationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGatewayInformationError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGatewayInformationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5651,17 +6176,21 @@ impl fmt::Display for UpdateGatewayInformationError {
             UpdateGatewayInformationError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateGatewayInformationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGatewayInformationError {}
 /// Errors returned by UpdateGatewaySoftwareNow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGatewaySoftwareNowError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGatewaySoftwareNowError {
@@ -5669,17 +6198,19 @@ impl UpdateGatewaySoftwareNowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGatewaySoftwareNowError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGatewaySoftwareNowError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGatewaySoftwareNowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5693,17 +6224,21 @@ impl fmt::Display for UpdateGatewaySoftwareNowError {
             UpdateGatewaySoftwareNowError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateGatewaySoftwareNowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGatewaySoftwareNowError {}
 /// Errors returned by UpdateMaintenanceStartTime
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMaintenanceStartTimeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMaintenanceStartTimeError {
@@ -5713,17 +6248,21 @@ impl UpdateMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMaintenanceStartTimeError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateMaintenanceStartTimeError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5739,17 +6278,21 @@ impl fmt::Display for UpdateMaintenanceStartTimeError {
             UpdateMaintenanceStartTimeError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateMaintenanceStartTimeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMaintenanceStartTimeError {}
 /// Errors returned by UpdateNFSFileShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNFSFileShareError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNFSFileShareError {
@@ -5757,17 +6300,19 @@ impl UpdateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNFSFileShareError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNFSFileShareError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNFSFileShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5779,17 +6324,21 @@ impl fmt::Display for UpdateNFSFileShareError {
         match *self {
             UpdateNFSFileShareError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateNFSFileShareError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            UpdateNFSFileShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNFSFileShareError {}
 /// Errors returned by UpdateSMBFileShare
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSMBFileShareError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSMBFileShareError {
@@ -5797,17 +6346,19 @@ impl UpdateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSMBFileShareError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSMBFileShareError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSMBFileShareError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5819,17 +6370,21 @@ impl fmt::Display for UpdateSMBFileShareError {
         match *self {
             UpdateSMBFileShareError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateSMBFileShareError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            UpdateSMBFileShareError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSMBFileShareError {}
 /// Errors returned by UpdateSMBSecurityStrategy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSMBSecurityStrategyError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSMBSecurityStrategyError {
@@ -5837,17 +6392,21 @@ impl UpdateSMBSecurityStrategyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSMBSecurityStrategyError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSMBSecurityStrategyError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateSMBSecurityStrategyError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5863,17 +6422,21 @@ impl fmt::Display for UpdateSMBSecurityStrategyError {
             UpdateSMBSecurityStrategyError::InvalidGatewayRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateSMBSecurityStrategyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSMBSecurityStrategyError {}
 /// Errors returned by UpdateSnapshotSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSnapshotScheduleError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSnapshotScheduleError {
@@ -5881,17 +6444,19 @@ impl UpdateSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSnapshotScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSnapshotScheduleError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSnapshotScheduleError::InvalidGatewayRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSnapshotScheduleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5903,17 +6468,21 @@ impl fmt::Display for UpdateSnapshotScheduleError {
         match *self {
             UpdateSnapshotScheduleError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateSnapshotScheduleError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            UpdateSnapshotScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSnapshotScheduleError {}
 /// Errors returned by UpdateVTLDeviceType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVTLDeviceTypeError {
     /// <p>An internal server error has occurred during the request. For more information, see the error and message fields.</p>
     InternalServerError(String),
     /// <p>An exception occurred because an invalid gateway request was issued to the service. For more information, see the error and message fields.</p>
     InvalidGatewayRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVTLDeviceTypeError {
@@ -5921,17 +6490,19 @@ impl UpdateVTLDeviceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVTLDeviceTypeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVTLDeviceTypeError::InvalidGatewayRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVTLDeviceTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5943,6 +6514,7 @@ impl fmt::Display for UpdateVTLDeviceTypeError {
         match *self {
             UpdateVTLDeviceTypeError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateVTLDeviceTypeError::InvalidGatewayRequest(ref cause) => write!(f, "{}", cause),
+            UpdateVTLDeviceTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6417,6 +6989,7 @@ impl StorageGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> StorageGatewayClient {
         StorageGatewayClient {
             client: Client::shared(),
@@ -6442,259 +7015,543 @@ impl StorageGatewayClient {
     pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
         StorageGatewayClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<StorageGatewayClient> {
+        rusoto_core::ClientBuilder::new(StorageGatewayClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> StorageGatewayClient {
+        StorageGatewayClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl StorageGateway for StorageGatewayClient {
-    /// <p><p>Activates the gateway you previously deployed on your host. In the activation process, you specify information such as the AWS Region that you want to use for storing snapshots or tapes, the time zone for scheduled snapshots the gateway snapshot schedule window, an activation key, and a name for your gateway. The activation process also associates your gateway with your account; for more information, see <a>UpdateGatewayInformation</a>.</p> <note> <p>You must turn on the gateway VM before you can activate your gateway.</p> </note></p>
-    async fn activate_gateway(
+impl StorageGatewayClient {
+    /// Calls `activate_gateway`, also returning the metadata of the response.
+    pub async fn activate_gateway_with_metadata(
         &self,
         input: ActivateGatewayInput,
-    ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
+    ) -> Result<(ActivateGatewayOutput, ResponseMetadata), RusotoError<ActivateGatewayError>> {
+        let request = self.build_activate_gateway_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ActivateGatewayOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ActivateGatewayError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `activate_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_activate_gateway(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ActivateGatewayInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_activate_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_activate_gateway_request(&self, input: ActivateGatewayInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ActivateGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_cache`, also returning the metadata of the response.
+    pub async fn add_cache_with_metadata(
+        &self,
+        input: AddCacheInput,
+    ) -> Result<(AddCacheOutput, ResponseMetadata), RusotoError<AddCacheError>> {
+        let request = self.build_add_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivateGatewayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddCacheOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ActivateGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddCacheError::from_response(response))
         }
     }
 
-    /// <p>Configures one or more gateway local disks as cache for a gateway. This operation is only supported in the cached volume, tape and file gateway type (see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/StorageGatewayConcepts.html">Storage Gateway Concepts</a>).</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add cache, and one or more disk IDs that you want to configure as cache.</p>
-    async fn add_cache(
+    /// Presigns the request `add_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddCacheInput,
-    ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_cache_request(&self, input: AddCacheInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> Result<(AddTagsToResourceOutput, ResponseMetadata), RusotoError<AddTagsToResourceError>>
+    {
+        let request = self.build_add_tags_to_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddCacheOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddTagsToResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddCacheError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to the specified resource. You use tags to add metadata to resources, which you can use to categorize these resources. For example, you can categorize resources by purpose, owner, environment, or team. Each tag consists of a key and a value, which you define. You can add tags to the following AWS Storage Gateway resources:</p> <ul> <li> <p>Storage gateways of all types</p> </li> <li> <p>Storage volumes</p> </li> <li> <p>Virtual tapes</p> </li> <li> <p>NFS and SMB file shares</p> </li> </ul> <p>You can create a maximum of 50 tags for each resource. Virtual tapes and storage volumes that are recovered to a new gateway maintain their tags.</p>
-    async fn add_tags_to_resource(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddTagsToResourceInput,
-    ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_upload_buffer`, also returning the metadata of the response.
+    pub async fn add_upload_buffer_with_metadata(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> Result<(AddUploadBufferOutput, ResponseMetadata), RusotoError<AddUploadBufferError>> {
+        let request = self.build_add_upload_buffer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddUploadBufferOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddUploadBufferError::from_response(response))
         }
     }
 
-    /// <p>Configures one or more gateway local disks as upload buffer for a specified gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add upload buffer, and one or more disk IDs that you want to configure as upload buffer.</p>
-    async fn add_upload_buffer(
+    /// Presigns the request `add_upload_buffer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_upload_buffer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddUploadBufferInput,
-    ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_upload_buffer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_upload_buffer_request(&self, input: AddUploadBufferInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddUploadBuffer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_working_storage`, also returning the metadata of the response.
+    pub async fn add_working_storage_with_metadata(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> Result<(AddWorkingStorageOutput, ResponseMetadata), RusotoError<AddWorkingStorageError>>
+    {
+        let request = self.build_add_working_storage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddUploadBufferOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddWorkingStorageOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddUploadBufferError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddWorkingStorageError::from_response(response))
         }
     }
 
-    /// <p>Configures one or more gateway local disks as working storage for a gateway. This operation is only supported in the stored volume gateway type. This operation is deprecated in cached volume API version 20120630. Use <a>AddUploadBuffer</a> instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the <a>AddUploadBuffer</a> operation to add upload buffer to a stored volume gateway.</p> </note> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add working storage, and one or more disk IDs that you want to configure as working storage.</p>
-    async fn add_working_storage(
+    /// Presigns the request `add_working_storage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_working_storage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddWorkingStorageInput,
-    ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_working_storage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_working_storage_request(&self, input: AddWorkingStorageInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddWorkingStorage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `assign_tape_pool`, also returning the metadata of the response.
+    pub async fn assign_tape_pool_with_metadata(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> Result<(AssignTapePoolOutput, ResponseMetadata), RusotoError<AssignTapePoolError>> {
+        let request = self.build_assign_tape_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddWorkingStorageOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssignTapePoolOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddWorkingStorageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssignTapePoolError::from_response(response))
         }
     }
 
-    /// <p>Assigns a tape to a tape pool for archiving. The tape assigned to a pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the S3 storage class (Glacier or Deep Archive) that corresponds to the pool.</p> <p>Valid values: "GLACIER", "DEEP_ARCHIVE"</p>
-    async fn assign_tape_pool(
+    /// Presigns the request `assign_tape_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_assign_tape_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssignTapePoolInput,
-    ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_assign_tape_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_assign_tape_pool_request(&self, input: AssignTapePoolInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AssignTapePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `attach_volume`, also returning the metadata of the response.
+    pub async fn attach_volume_with_metadata(
+        &self,
+        input: AttachVolumeInput,
+    ) -> Result<(AttachVolumeOutput, ResponseMetadata), RusotoError<AttachVolumeError>> {
+        let request = self.build_attach_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AssignTapePoolOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AttachVolumeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssignTapePoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AttachVolumeError::from_response(response))
         }
     }
 
-    /// <p>Connects a volume to an iSCSI connection and then attaches the volume to the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
-    async fn attach_volume(
+    /// Presigns the request `attach_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachVolumeInput,
-    ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_volume_request(&self, input: AttachVolumeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AttachVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_archival`, also returning the metadata of the response.
+    pub async fn cancel_archival_with_metadata(
+        &self,
+        input: CancelArchivalInput,
+    ) -> Result<(CancelArchivalOutput, ResponseMetadata), RusotoError<CancelArchivalError>> {
+        let request = self.build_cancel_archival_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AttachVolumeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelArchivalOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelArchivalError::from_response(response))
         }
     }
 
-    /// <p>Cancels archiving of a virtual tape to the virtual tape shelf (VTS) after the archiving process is initiated. This operation is only supported in the tape gateway type.</p>
-    async fn cancel_archival(
+    /// Presigns the request `cancel_archival` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_archival(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelArchivalInput,
-    ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_archival_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_archival_request(&self, input: CancelArchivalInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelArchival");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_retrieval`, also returning the metadata of the response.
+    pub async fn cancel_retrieval_with_metadata(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> Result<(CancelRetrievalOutput, ResponseMetadata), RusotoError<CancelRetrievalError>> {
+        let request = self.build_cancel_retrieval_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelArchivalOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelRetrievalOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelArchivalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelRetrievalError::from_response(response))
         }
     }
 
-    /// <p>Cancels retrieval of a virtual tape from the virtual tape shelf (VTS) to a gateway after the retrieval process is initiated. The virtual tape is returned to the VTS. This operation is only supported in the tape gateway type.</p>
-    async fn cancel_retrieval(
+    /// Presigns the request `cancel_retrieval` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_retrieval(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelRetrievalInput,
-    ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_retrieval_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_retrieval_request(&self, input: CancelRetrievalInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelRetrieval");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_cachedi_scsi_volume`, also returning the metadata of the response.
+    pub async fn create_cachedi_scsi_volume_with_metadata(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> Result<
+        (CreateCachediSCSIVolumeOutput, ResponseMetadata),
+        RusotoError<CreateCachediSCSIVolumeError>,
+    > {
+        let request = self.build_create_cachedi_scsi_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelRetrievalOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCachediSCSIVolumeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelRetrievalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCachediSCSIVolumeError::from_response(response))
         }
     }
 
-    /// <p>Creates a cached volume on a specified cached volume gateway. This operation is only supported in the cached volume gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a cached volume. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note> <p>In the request, you must specify the gateway, size of the volume in bytes, the iSCSI target name, an IP address on which to expose the target, and a unique client token. In response, the gateway creates the volume and returns information about it. This information includes the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p> <p>Optionally, you can provide the ARN for an existing volume as the <code>SourceVolumeARN</code> for this cached volume, which creates an exact copy of the existing volume’s latest recovery point. The <code>VolumeSizeInBytes</code> value must be equal to or larger than the size of the copied volume, in bytes.</p>
-    async fn create_cachedi_scsi_volume(
+    /// Presigns the request `create_cachedi_scsi_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cachedi_scsi_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCachediSCSIVolumeInput,
-    ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cachedi_scsi_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cachedi_scsi_volume_request(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateCachediSCSIVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6704,114 +7561,221 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_nfs_file_share`, also returning the metadata of the response.
+    pub async fn create_nfs_file_share_with_metadata(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> Result<(CreateNFSFileShareOutput, ResponseMetadata), RusotoError<CreateNFSFileShareError>>
+    {
+        let request = self.build_create_nfs_file_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCachediSCSIVolumeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNFSFileShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCachediSCSIVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNFSFileShareError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a Network File System (NFS) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway exposes file shares using a NFS interface. This operation is only supported for file gateways.</p> <important> <p>File gateway requires AWS Security Token Service (AWS STS) to be activated to enable you create a file share. Make sure AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in the AWS Region, activate it. For information about how to activate AWS STS, see Activating and Deactivating AWS STS in an AWS Region in the AWS Identity and Access Management User Guide. </p> <p>File gateway does not support creating hard or symbolic links on a file share.</p> </important></p>
-    async fn create_nfs_file_share(
+    /// Presigns the request `create_nfs_file_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_nfs_file_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNFSFileShareInput,
-    ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_nfs_file_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_nfs_file_share_request(&self, input: CreateNFSFileShareInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateNFSFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_smb_file_share`, also returning the metadata of the response.
+    pub async fn create_smb_file_share_with_metadata(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> Result<(CreateSMBFileShareOutput, ResponseMetadata), RusotoError<CreateSMBFileShareError>>
+    {
+        let request = self.build_create_smb_file_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNFSFileShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSMBFileShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNFSFileShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSMBFileShareError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a Server Message Block (SMB) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway expose file shares using a SMB interface. This operation is only supported for file gateways.</p> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
-    async fn create_smb_file_share(
+    /// Presigns the request `create_smb_file_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_smb_file_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSMBFileShareInput,
-    ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_smb_file_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_smb_file_share_request(&self, input: CreateSMBFileShareInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSMBFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_snapshot`, also returning the metadata of the response.
+    pub async fn create_snapshot_with_metadata(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> Result<(CreateSnapshotOutput, ResponseMetadata), RusotoError<CreateSnapshotError>> {
+        let request = self.build_create_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSMBFileShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSnapshotOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSMBFileShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSnapshotError::from_response(response))
         }
     }
 
-    /// <p><p>Initiates a snapshot of a volume.</p> <p>AWS Storage Gateway provides the ability to back up point-in-time snapshots of your data to Amazon Simple Storage (S3) for durable off-site recovery, as well as import the data to an Amazon Elastic Block Store (EBS) volume in Amazon Elastic Compute Cloud (EC2). You can take snapshots of your gateway volume on a scheduled or ad hoc basis. This API enables you to take ad-hoc snapshot. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/managing-volumes.html#SchedulingSnapshot">Editing a Snapshot Schedule</a>.</p> <p>In the CreateSnapshot request you identify the volume by providing its Amazon Resource Name (ARN). You must also provide description for the snapshot. When AWS Storage Gateway takes the snapshot of specified volume, the snapshot and description appears in the AWS Storage Gateway Console. In response, AWS Storage Gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot. This operation is only supported in stored and cached volume gateway type.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see DescribeSnapshots or DeleteSnapshot in the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_Operations.html">EC2 API reference</a>.</p> </note> <important> <p>Volume and snapshot IDs are changing to a longer length ID format. For more information, see the important note on the <a href="https://docs.aws.amazon.com/storagegateway/latest/APIReference/Welcome.html">Welcome</a> page.</p> </important></p>
-    async fn create_snapshot(
+    /// Presigns the request `create_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSnapshotInput,
-    ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_request(&self, input: CreateSnapshotInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_snapshot_from_volume_recovery_point`, also returning the metadata of the response.
+    pub async fn create_snapshot_from_volume_recovery_point_with_metadata(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> Result<
+        (
+            CreateSnapshotFromVolumeRecoveryPointOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+    > {
+        let request = self.build_create_snapshot_from_volume_recovery_point_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateSnapshotOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSnapshotFromVolumeRecoveryPointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSnapshotFromVolumeRecoveryPointError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Initiates a snapshot of a gateway from a volume recovery point. This operation is only supported in the cached volume gateway type.</p> <p>A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot. To get a list of volume recovery point for cached volume gateway, use <a>ListVolumeRecoveryPoints</a>.</p> <p>In the <code>CreateSnapshotFromVolumeRecoveryPoint</code> request, you identify the volume by providing its Amazon Resource Name (ARN). You must also provide a description for the snapshot. When the gateway takes a snapshot of the specified volume, the snapshot and its description appear in the AWS Storage Gateway console. In response, the gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
-    async fn create_snapshot_from_volume_recovery_point(
+    /// Presigns the request `create_snapshot_from_volume_recovery_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot_from_volume_recovery_point(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSnapshotFromVolumeRecoveryPointInput,
-    ) -> Result<
-        CreateSnapshotFromVolumeRecoveryPointOutput,
-        RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_from_volume_recovery_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_from_volume_recovery_point_request(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshotFromVolumeRecoveryPoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6821,30 +7785,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_storedi_scsi_volume`, also returning the metadata of the response.
+    pub async fn create_storedi_scsi_volume_with_metadata(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> Result<
+        (CreateStorediSCSIVolumeOutput, ResponseMetadata),
+        RusotoError<CreateStorediSCSIVolumeError>,
+    > {
+        let request = self.build_create_storedi_scsi_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSnapshotFromVolumeRecoveryPointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStorediSCSIVolumeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSnapshotFromVolumeRecoveryPointError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStorediSCSIVolumeError::from_response(response))
         }
     }
 
-    /// <p>Creates a volume on a specified gateway. This operation is only supported in the stored volume gateway type.</p> <p>The size of the volume to create is inferred from the disk size. You can choose to preserve existing data on the disk, create volume from an existing snapshot, or create an empty volume. If you choose to create an empty gateway volume, then any existing data on the disk is erased.</p> <p>In the request you must specify the gateway and the disk information on which you are creating the volume. In response, the gateway creates the volume and returns volume information such as the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p>
-    async fn create_storedi_scsi_volume(
+    /// Presigns the request `create_storedi_scsi_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_storedi_scsi_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStorediSCSIVolumeInput,
-    ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_storedi_scsi_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_storedi_scsi_volume_request(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateStorediSCSIVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6854,28 +7846,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_tape_with_barcode`, also returning the metadata of the response.
+    pub async fn create_tape_with_barcode_with_metadata(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> Result<
+        (CreateTapeWithBarcodeOutput, ResponseMetadata),
+        RusotoError<CreateTapeWithBarcodeError>,
+    > {
+        let request = self.build_create_tape_with_barcode_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateStorediSCSIVolumeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTapeWithBarcodeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStorediSCSIVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTapeWithBarcodeError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a virtual tape by using your own barcode. You write data to the virtual tape and then archive the tape. A barcode is unique and can not be reused if it has already been used on a tape . This applies to barcodes used on deleted tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a virtual tape. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
-    async fn create_tape_with_barcode(
+    /// Presigns the request `create_tape_with_barcode` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tape_with_barcode(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTapeWithBarcodeInput,
-    ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tape_with_barcode_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tape_with_barcode_request(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapeWithBarcode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6885,55 +7907,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_tapes`, also returning the metadata of the response.
+    pub async fn create_tapes_with_metadata(
+        &self,
+        input: CreateTapesInput,
+    ) -> Result<(CreateTapesOutput, ResponseMetadata), RusotoError<CreateTapesError>> {
+        let request = self.build_create_tapes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTapeWithBarcodeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTapesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTapeWithBarcodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTapesError::from_response(response))
         }
     }
 
-    /// <p><p>Creates one or more virtual tapes. You write data to the virtual tapes and then archive the tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create virtual tapes. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note></p>
-    async fn create_tapes(
+    /// Presigns the request `create_tapes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tapes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTapesInput,
-    ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tapes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tapes_request(&self, input: CreateTapesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_bandwidth_rate_limit`, also returning the metadata of the response.
+    pub async fn delete_bandwidth_rate_limit_with_metadata(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> Result<
+        (DeleteBandwidthRateLimitOutput, ResponseMetadata),
+        RusotoError<DeleteBandwidthRateLimitError>,
+    > {
+        let request = self.build_delete_bandwidth_rate_limit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTapesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBandwidthRateLimitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTapesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBandwidthRateLimitError::from_response(response))
         }
     }
 
-    /// <p>Deletes the bandwidth rate limits of a gateway. You can delete either the upload and download bandwidth rate limit, or you can delete both. If you delete only one of the limits, the other limit remains unchanged. To specify which gateway to work with, use the Amazon Resource Name (ARN) of the gateway in your request. This operation is supported for the stored volume, cached volume and tape gateway types.</p>
-    async fn delete_bandwidth_rate_limit(
+    /// Presigns the request `delete_bandwidth_rate_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bandwidth_rate_limit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBandwidthRateLimitInput,
-    ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bandwidth_rate_limit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bandwidth_rate_limit_request(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6943,28 +8020,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_chap_credentials`, also returning the metadata of the response.
+    pub async fn delete_chap_credentials_with_metadata(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> Result<
+        (DeleteChapCredentialsOutput, ResponseMetadata),
+        RusotoError<DeleteChapCredentialsError>,
+    > {
+        let request = self.build_delete_chap_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBandwidthRateLimitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteChapCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBandwidthRateLimitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteChapCredentialsError::from_response(response))
         }
     }
 
-    /// <p>Deletes Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target and initiator pair. This operation is supported in volume and tape gateway types.</p>
-    async fn delete_chap_credentials(
+    /// Presigns the request `delete_chap_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_chap_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteChapCredentialsInput,
-    ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_chap_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_chap_credentials_request(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6974,82 +8081,162 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_file_share`, also returning the metadata of the response.
+    pub async fn delete_file_share_with_metadata(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> Result<(DeleteFileShareOutput, ResponseMetadata), RusotoError<DeleteFileShareError>> {
+        let request = self.build_delete_file_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteChapCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFileShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteChapCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFileShareError::from_response(response))
         }
     }
 
-    /// <p>Deletes a file share from a file gateway. This operation is only supported for file gateways.</p>
-    async fn delete_file_share(
+    /// Presigns the request `delete_file_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_file_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFileShareInput,
-    ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_file_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_file_share_request(&self, input: DeleteFileShareInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_gateway`, also returning the metadata of the response.
+    pub async fn delete_gateway_with_metadata(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> Result<(DeleteGatewayOutput, ResponseMetadata), RusotoError<DeleteGatewayError>> {
+        let request = self.build_delete_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteFileShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteGatewayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFileShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGatewayError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a gateway. To specify which gateway to delete, use the Amazon Resource Name (ARN) of the gateway in your request. The operation deletes the gateway; however, it does not delete the gateway virtual machine (VM) from your host computer.</p> <p>After you delete a gateway, you cannot reactivate it. Completed snapshots of the gateway volumes are not deleted upon deleting the gateway, however, pending snapshots will not complete. After you delete a gateway, your next step is to remove it from your environment.</p> <important> <p>You no longer pay software charges after the gateway is deleted; however, your existing Amazon EBS snapshots persist and you will continue to be billed for these snapshots. You can choose to remove all remaining Amazon EBS snapshots by canceling your Amazon EC2 subscription.  If you prefer not to cancel your Amazon EC2 subscription, you can delete your snapshots using the Amazon EC2 console. For more information, see the <a href="http://aws.amazon.com/storagegateway"> AWS Storage Gateway Detail Page</a>. </p> </important></p>
-    async fn delete_gateway(
+    /// Presigns the request `delete_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGatewayInput,
-    ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_gateway_request(&self, input: DeleteGatewayInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn delete_snapshot_schedule_with_metadata(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> Result<
+        (DeleteSnapshotScheduleOutput, ResponseMetadata),
+        RusotoError<DeleteSnapshotScheduleError>,
+    > {
+        let request = self.build_delete_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteGatewayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSnapshotScheduleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSnapshotScheduleError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a snapshot of a volume.</p> <p>You can take snapshots of your gateway volumes on a scheduled or ad hoc basis. This API action enables you to delete a snapshot schedule for a volume. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/WorkingWithSnapshots.html">Working with Snapshots</a>. In the <code>DeleteSnapshotSchedule</code> request, you identify the volume by providing its Amazon Resource Name (ARN). This operation is only supported in stored and cached volume gateway types.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
-    async fn delete_snapshot_schedule(
+    /// Presigns the request `delete_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_snapshot_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSnapshotScheduleInput,
-    ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_snapshot_schedule_request(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7059,112 +8246,217 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tape`, also returning the metadata of the response.
+    pub async fn delete_tape_with_metadata(
+        &self,
+        input: DeleteTapeInput,
+    ) -> Result<(DeleteTapeOutput, ResponseMetadata), RusotoError<DeleteTapeError>> {
+        let request = self.build_delete_tape_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSnapshotScheduleOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTapeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSnapshotScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTapeError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified virtual tape. This operation is only supported in the tape gateway type.</p>
-    async fn delete_tape(
+    /// Presigns the request `delete_tape` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tape(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTapeInput,
-    ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tape_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tape_request(&self, input: DeleteTapeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTape");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tape_archive`, also returning the metadata of the response.
+    pub async fn delete_tape_archive_with_metadata(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> Result<(DeleteTapeArchiveOutput, ResponseMetadata), RusotoError<DeleteTapeArchiveError>>
+    {
+        let request = self.build_delete_tape_archive_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTapeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTapeArchiveOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTapeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTapeArchiveError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified virtual tape from the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p>
-    async fn delete_tape_archive(
+    /// Presigns the request `delete_tape_archive` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tape_archive(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTapeArchiveInput,
-    ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tape_archive_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tape_archive_request(&self, input: DeleteTapeArchiveInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTapeArchive");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_volume`, also returning the metadata of the response.
+    pub async fn delete_volume_with_metadata(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> Result<(DeleteVolumeOutput, ResponseMetadata), RusotoError<DeleteVolumeError>> {
+        let request = self.build_delete_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTapeArchiveOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteVolumeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTapeArchiveError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVolumeError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified storage volume that you previously created using the <a>CreateCachediSCSIVolume</a> or <a>CreateStorediSCSIVolume</a> API. This operation is only supported in the cached volume and stored volume types. For stored volume gateways, the local disk that was configured as the storage volume is not deleted. You can reuse the local disk to create another storage volume. </p> <p>Before you delete a volume, make sure there are no iSCSI connections to the volume you are deleting. You should also make sure there is no snapshot in progress. You can use the Amazon Elastic Compute Cloud (Amazon EC2) API to query snapshots on the volume you are deleting and check the snapshot status. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/ApiReference-query-DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> <p>In the request, you must provide the Amazon Resource Name (ARN) of the storage volume you want to delete.</p>
-    async fn delete_volume(
+    /// Presigns the request `delete_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVolumeInput,
-    ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_volume_request(&self, input: DeleteVolumeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_availability_monitor_test`, also returning the metadata of the response.
+    pub async fn describe_availability_monitor_test_with_metadata(
+        &self,
+        input: DescribeAvailabilityMonitorTestInput,
+    ) -> Result<
+        (DescribeAvailabilityMonitorTestOutput, ResponseMetadata),
+        RusotoError<DescribeAvailabilityMonitorTestError>,
+    > {
+        let request = self.build_describe_availability_monitor_test_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteVolumeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAvailabilityMonitorTestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAvailabilityMonitorTestError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns information about the most recent High Availability monitoring test that was performed on the host in a cluster. If a test isn't performed, the status and start time in the response would be null.</p>
-    async fn describe_availability_monitor_test(
+    /// Presigns the request `describe_availability_monitor_test` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_availability_monitor_test(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAvailabilityMonitorTestInput,
-    ) -> Result<
-        DescribeAvailabilityMonitorTestOutput,
-        RusotoError<DescribeAvailabilityMonitorTestError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_availability_monitor_test_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_availability_monitor_test_request(
+        &self,
+        input: DescribeAvailabilityMonitorTestInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeAvailabilityMonitorTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7174,31 +8466,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_bandwidth_rate_limit`, also returning the metadata of the response.
+    pub async fn describe_bandwidth_rate_limit_with_metadata(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> Result<
+        (DescribeBandwidthRateLimitOutput, ResponseMetadata),
+        RusotoError<DescribeBandwidthRateLimitError>,
+    > {
+        let request = self.build_describe_bandwidth_rate_limit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAvailabilityMonitorTestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBandwidthRateLimitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAvailabilityMonitorTestError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBandwidthRateLimitError::from_response(response))
         }
     }
 
-    /// <p>Returns the bandwidth rate limits of a gateway. By default, these limits are not set, which means no bandwidth rate limiting is in effect. This operation is supported for the stored volume, cached volume and tape gateway types.'</p> <p>This operation only returns a value for a bandwidth rate limit only if the limit is set. If no limits are set for the gateway, then this operation returns only the gateway ARN in the response body. To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
-    async fn describe_bandwidth_rate_limit(
+    /// Presigns the request `describe_bandwidth_rate_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_bandwidth_rate_limit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBandwidthRateLimitInput,
-    ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_bandwidth_rate_limit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_bandwidth_rate_limit_request(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7208,56 +8527,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cache`, also returning the metadata of the response.
+    pub async fn describe_cache_with_metadata(
+        &self,
+        input: DescribeCacheInput,
+    ) -> Result<(DescribeCacheOutput, ResponseMetadata), RusotoError<DescribeCacheError>> {
+        let request = self.build_describe_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBandwidthRateLimitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCacheOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBandwidthRateLimitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCacheError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the cache of a gateway. This operation is only supported in the cached volume, tape and file gateway types.</p> <p>The response includes disk IDs that are configured as cache, and it includes the amount of cache allocated and used.</p>
-    async fn describe_cache(
+    /// Presigns the request `describe_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCacheInput,
-    ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_request(&self, input: DescribeCacheInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cachedi_scsi_volumes`, also returning the metadata of the response.
+    pub async fn describe_cachedi_scsi_volumes_with_metadata(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> Result<
+        (DescribeCachediSCSIVolumesOutput, ResponseMetadata),
+        RusotoError<DescribeCachediSCSIVolumesError>,
+    > {
+        let request = self.build_describe_cachedi_scsi_volumes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeCacheOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCachediSCSIVolumesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCacheError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCachediSCSIVolumesError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of the gateway volumes specified in the request. This operation is only supported in the cached volume gateway types.</p> <p>The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume Amazon Resource Name (ARN).</p>
-    async fn describe_cachedi_scsi_volumes(
+    /// Presigns the request `describe_cachedi_scsi_volumes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cachedi_scsi_volumes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCachediSCSIVolumesInput,
-    ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cachedi_scsi_volumes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cachedi_scsi_volumes_request(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCachediSCSIVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7267,28 +8640,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_chap_credentials`, also returning the metadata of the response.
+    pub async fn describe_chap_credentials_with_metadata(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> Result<
+        (DescribeChapCredentialsOutput, ResponseMetadata),
+        RusotoError<DescribeChapCredentialsError>,
+    > {
+        let request = self.build_describe_chap_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCachediSCSIVolumesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeChapCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCachediSCSIVolumesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeChapCredentialsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of Challenge-Handshake Authentication Protocol (CHAP) credentials information for a specified iSCSI target, one for each target-initiator pair. This operation is supported in the volume and tape gateway types.</p>
-    async fn describe_chap_credentials(
+    /// Presigns the request `describe_chap_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_chap_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeChapCredentialsInput,
-    ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_chap_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_chap_credentials_request(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7298,29 +8701,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_gateway_information`, also returning the metadata of the response.
+    pub async fn describe_gateway_information_with_metadata(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> Result<
+        (DescribeGatewayInformationOutput, ResponseMetadata),
+        RusotoError<DescribeGatewayInformationError>,
+    > {
+        let request = self.build_describe_gateway_information_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeChapCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGatewayInformationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeChapCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGatewayInformationError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata about a gateway such as its name, network interfaces, configured time zone, and the state (whether the gateway is running or not). To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
-    async fn describe_gateway_information(
+    /// Presigns the request `describe_gateway_information` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_gateway_information(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGatewayInformationInput,
-    ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_gateway_information_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_gateway_information_request(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeGatewayInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7330,29 +8762,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_maintenance_start_time`, also returning the metadata of the response.
+    pub async fn describe_maintenance_start_time_with_metadata(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> Result<
+        (DescribeMaintenanceStartTimeOutput, ResponseMetadata),
+        RusotoError<DescribeMaintenanceStartTimeError>,
+    > {
+        let request = self.build_describe_maintenance_start_time_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGatewayInformationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMaintenanceStartTimeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGatewayInformationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMaintenanceStartTimeError::from_response(response))
         }
     }
 
-    /// <p>Returns your gateway's weekly maintenance start time including the day and time of the week. Note that values are in terms of the gateway's time zone.</p>
-    async fn describe_maintenance_start_time(
+    /// Presigns the request `describe_maintenance_start_time` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_maintenance_start_time(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMaintenanceStartTimeInput,
-    ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_maintenance_start_time_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_maintenance_start_time_request(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeMaintenanceStartTime");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7362,28 +8823,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_nfs_file_shares`, also returning the metadata of the response.
+    pub async fn describe_nfs_file_shares_with_metadata(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> Result<
+        (DescribeNFSFileSharesOutput, ResponseMetadata),
+        RusotoError<DescribeNFSFileSharesError>,
+    > {
+        let request = self.build_describe_nfs_file_shares_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMaintenanceStartTimeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNFSFileSharesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMaintenanceStartTimeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNFSFileSharesError::from_response(response))
         }
     }
 
-    /// <p>Gets a description for one or more Network File System (NFS) file shares from a file gateway. This operation is only supported for file gateways.</p>
-    async fn describe_nfs_file_shares(
+    /// Presigns the request `describe_nfs_file_shares` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_nfs_file_shares(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNFSFileSharesInput,
-    ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_nfs_file_shares_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_nfs_file_shares_request(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeNFSFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7393,28 +8884,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_smb_file_shares`, also returning the metadata of the response.
+    pub async fn describe_smb_file_shares_with_metadata(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> Result<
+        (DescribeSMBFileSharesOutput, ResponseMetadata),
+        RusotoError<DescribeSMBFileSharesError>,
+    > {
+        let request = self.build_describe_smb_file_shares_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNFSFileSharesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSMBFileSharesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNFSFileSharesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSMBFileSharesError::from_response(response))
         }
     }
 
-    /// <p>Gets a description for one or more Server Message Block (SMB) file shares from a file gateway. This operation is only supported for file gateways.</p>
-    async fn describe_smb_file_shares(
+    /// Presigns the request `describe_smb_file_shares` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_smb_file_shares(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSMBFileSharesInput,
-    ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_smb_file_shares_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_smb_file_shares_request(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7424,28 +8945,56 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_smb_settings`, also returning the metadata of the response.
+    pub async fn describe_smb_settings_with_metadata(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> Result<(DescribeSMBSettingsOutput, ResponseMetadata), RusotoError<DescribeSMBSettingsError>>
+    {
+        let request = self.build_describe_smb_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSMBFileSharesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSMBSettingsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSMBFileSharesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSMBSettingsError::from_response(response))
         }
     }
 
-    /// <p>Gets a description of a Server Message Block (SMB) file share settings from a file gateway. This operation is only supported for file gateways.</p>
-    async fn describe_smb_settings(
+    /// Presigns the request `describe_smb_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_smb_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSMBSettingsInput,
-    ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_smb_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_smb_settings_request(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7455,28 +9004,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn describe_snapshot_schedule_with_metadata(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> Result<
+        (DescribeSnapshotScheduleOutput, ResponseMetadata),
+        RusotoError<DescribeSnapshotScheduleError>,
+    > {
+        let request = self.build_describe_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSMBSettingsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSnapshotScheduleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSMBSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSnapshotScheduleError::from_response(response))
         }
     }
 
-    /// <p>Describes the snapshot schedule for the specified gateway volume. The snapshot schedule information includes intervals at which snapshots are automatically initiated on the volume. This operation is only supported in the cached volume and stored volume types.</p>
-    async fn describe_snapshot_schedule(
+    /// Presigns the request `describe_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_snapshot_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSnapshotScheduleInput,
-    ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_snapshot_schedule_request(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7486,29 +9065,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_storedi_scsi_volumes`, also returning the metadata of the response.
+    pub async fn describe_storedi_scsi_volumes_with_metadata(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> Result<
+        (DescribeStorediSCSIVolumesOutput, ResponseMetadata),
+        RusotoError<DescribeStorediSCSIVolumesError>,
+    > {
+        let request = self.build_describe_storedi_scsi_volumes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSnapshotScheduleOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStorediSCSIVolumesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSnapshotScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStorediSCSIVolumesError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of the gateway volumes specified in the request. The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume ARNs. This operation is only supported in stored volume gateway type.</p>
-    async fn describe_storedi_scsi_volumes(
+    /// Presigns the request `describe_storedi_scsi_volumes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_storedi_scsi_volumes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStorediSCSIVolumesInput,
-    ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_storedi_scsi_volumes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_storedi_scsi_volumes_request(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeStorediSCSIVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7518,28 +9126,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tape_archives`, also returning the metadata of the response.
+    pub async fn describe_tape_archives_with_metadata(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> Result<
+        (DescribeTapeArchivesOutput, ResponseMetadata),
+        RusotoError<DescribeTapeArchivesError>,
+    > {
+        let request = self.build_describe_tape_archives_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeStorediSCSIVolumesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTapeArchivesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStorediSCSIVolumesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTapeArchivesError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of specified virtual tapes in the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p> <p>If a specific <code>TapeARN</code> is not specified, AWS Storage Gateway returns a description of all virtual tapes found in the VTS associated with your account.</p>
-    async fn describe_tape_archives(
+    /// Presigns the request `describe_tape_archives` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tape_archives(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTapeArchivesInput,
-    ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tape_archives_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tape_archives_request(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeArchives");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7549,29 +9187,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tape_recovery_points`, also returning the metadata of the response.
+    pub async fn describe_tape_recovery_points_with_metadata(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> Result<
+        (DescribeTapeRecoveryPointsOutput, ResponseMetadata),
+        RusotoError<DescribeTapeRecoveryPointsError>,
+    > {
+        let request = self.build_describe_tape_recovery_points_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTapeArchivesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTapeRecoveryPointsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTapeArchivesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTapeRecoveryPointsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of virtual tape recovery points that are available for the specified tape gateway.</p> <p>A recovery point is a point-in-time view of a virtual tape at which all the data on the virtual tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway. This operation is only supported in the tape gateway type.</p>
-    async fn describe_tape_recovery_points(
+    /// Presigns the request `describe_tape_recovery_points` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tape_recovery_points(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTapeRecoveryPointsInput,
-    ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tape_recovery_points_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tape_recovery_points_request(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeRecoveryPoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7581,55 +9248,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tapes`, also returning the metadata of the response.
+    pub async fn describe_tapes_with_metadata(
+        &self,
+        input: DescribeTapesInput,
+    ) -> Result<(DescribeTapesOutput, ResponseMetadata), RusotoError<DescribeTapesError>> {
+        let request = self.build_describe_tapes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTapeRecoveryPointsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTapesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTapeRecoveryPointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTapesError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of the specified Amazon Resource Name (ARN) of virtual tapes. If a <code>TapeARN</code> is not specified, returns a description of all virtual tapes associated with the specified gateway. This operation is only supported in the tape gateway type.</p>
-    async fn describe_tapes(
+    /// Presigns the request `describe_tapes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tapes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTapesInput,
-    ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tapes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tapes_request(&self, input: DescribeTapesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_upload_buffer`, also returning the metadata of the response.
+    pub async fn describe_upload_buffer_with_metadata(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> Result<
+        (DescribeUploadBufferOutput, ResponseMetadata),
+        RusotoError<DescribeUploadBufferError>,
+    > {
+        let request = self.build_describe_upload_buffer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTapesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUploadBufferOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTapesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUploadBufferError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the upload buffer of a gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>The response includes disk IDs that are configured as upload buffer space, and it includes the amount of upload buffer space allocated and used.</p>
-    async fn describe_upload_buffer(
+    /// Presigns the request `describe_upload_buffer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_upload_buffer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUploadBufferInput,
-    ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_upload_buffer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_upload_buffer_request(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeUploadBuffer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7639,56 +9361,111 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_vtl_devices`, also returning the metadata of the response.
+    pub async fn describe_vtl_devices_with_metadata(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> Result<(DescribeVTLDevicesOutput, ResponseMetadata), RusotoError<DescribeVTLDevicesError>>
+    {
+        let request = self.build_describe_vtl_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUploadBufferOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeVTLDevicesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUploadBufferError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVTLDevicesError::from_response(response))
         }
     }
 
-    /// <p>Returns a description of virtual tape library (VTL) devices for the specified tape gateway. In the response, AWS Storage Gateway returns VTL device information.</p> <p>This operation is only supported in the tape gateway type.</p>
-    async fn describe_vtl_devices(
+    /// Presigns the request `describe_vtl_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_vtl_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVTLDevicesInput,
-    ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_vtl_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_vtl_devices_request(&self, input: DescribeVTLDevicesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeVTLDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_working_storage`, also returning the metadata of the response.
+    pub async fn describe_working_storage_with_metadata(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> Result<
+        (DescribeWorkingStorageOutput, ResponseMetadata),
+        RusotoError<DescribeWorkingStorageError>,
+    > {
+        let request = self.build_describe_working_storage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVTLDevicesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeWorkingStorageOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVTLDevicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeWorkingStorageError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the working storage of a gateway. This operation is only supported in the stored volumes gateway type. This operation is deprecated in cached volumes API version (20120630). Use DescribeUploadBuffer instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the DescribeUploadBuffer operation to add upload buffer to a stored volume gateway.</p> </note> <p>The response includes disk IDs that are configured as working storage, and it includes the amount of working storage allocated and used.</p>
-    async fn describe_working_storage(
+    /// Presigns the request `describe_working_storage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_working_storage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeWorkingStorageInput,
-    ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_working_storage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_working_storage_request(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeWorkingStorage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7698,190 +9475,368 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detach_volume`, also returning the metadata of the response.
+    pub async fn detach_volume_with_metadata(
+        &self,
+        input: DetachVolumeInput,
+    ) -> Result<(DetachVolumeOutput, ResponseMetadata), RusotoError<DetachVolumeError>> {
+        let request = self.build_detach_volume_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeWorkingStorageOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetachVolumeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeWorkingStorageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetachVolumeError::from_response(response))
         }
     }
 
-    /// <p>Disconnects a volume from an iSCSI connection and then detaches the volume from the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance. This operation is only supported in the volume gateway type.</p>
-    async fn detach_volume(
+    /// Presigns the request `detach_volume` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_volume(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachVolumeInput,
-    ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_volume_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_volume_request(&self, input: DetachVolumeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DetachVolume");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_gateway`, also returning the metadata of the response.
+    pub async fn disable_gateway_with_metadata(
+        &self,
+        input: DisableGatewayInput,
+    ) -> Result<(DisableGatewayOutput, ResponseMetadata), RusotoError<DisableGatewayError>> {
+        let request = self.build_disable_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetachVolumeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableGatewayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachVolumeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableGatewayError::from_response(response))
         }
     }
 
-    /// <p><p>Disables a tape gateway when the gateway is no longer functioning. For example, if your gateway VM is damaged, you can disable the gateway so you can recover virtual tapes.</p> <p>Use this operation for a tape gateway that is not reachable or not functioning. This operation is only supported in the tape gateway type.</p> <important> <p>Once a gateway is disabled it cannot be enabled.</p> </important></p>
-    async fn disable_gateway(
+    /// Presigns the request `disable_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableGatewayInput,
-    ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_gateway_request(&self, input: DisableGatewayInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DisableGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `join_domain`, also returning the metadata of the response.
+    pub async fn join_domain_with_metadata(
+        &self,
+        input: JoinDomainInput,
+    ) -> Result<(JoinDomainOutput, ResponseMetadata), RusotoError<JoinDomainError>> {
+        let request = self.build_join_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableGatewayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<JoinDomainOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(JoinDomainError::from_response(response))
         }
     }
 
-    /// <p>Adds a file gateway to an Active Directory domain. This operation is only supported for file gateways that support the SMB file protocol.</p>
-    async fn join_domain(
+    /// Presigns the request `join_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_join_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: JoinDomainInput,
-    ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_join_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_join_domain_request(&self, input: JoinDomainInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("JoinDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_file_shares`, also returning the metadata of the response.
+    pub async fn list_file_shares_with_metadata(
+        &self,
+        input: ListFileSharesInput,
+    ) -> Result<(ListFileSharesOutput, ResponseMetadata), RusotoError<ListFileSharesError>> {
+        let request = self.build_list_file_shares_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<JoinDomainOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFileSharesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(JoinDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFileSharesError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the file shares for a specific file gateway, or the list of file shares that belong to the calling user account. This operation is only supported for file gateways.</p>
-    async fn list_file_shares(
+    /// Presigns the request `list_file_shares` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_file_shares(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFileSharesInput,
-    ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_file_shares_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_file_shares_request(&self, input: ListFileSharesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListFileShares");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_gateways`, also returning the metadata of the response.
+    pub async fn list_gateways_with_metadata(
+        &self,
+        input: ListGatewaysInput,
+    ) -> Result<(ListGatewaysOutput, ResponseMetadata), RusotoError<ListGatewaysError>> {
+        let request = self.build_list_gateways_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListFileSharesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGatewaysOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFileSharesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGatewaysError::from_response(response))
         }
     }
 
-    /// <p>Lists gateways owned by an AWS account in an AWS Region specified in the request. The returned list is ordered by gateway Amazon Resource Name (ARN).</p> <p>By default, the operation returns a maximum of 100 gateways. This operation supports pagination that allows you to optionally reduce the number of gateways returned in a response.</p> <p>If you have more gateways than are returned in a response (that is, the response returns only a truncated list of your gateways), the response contains a marker that you can specify in your next request to fetch the next page of gateways.</p>
-    async fn list_gateways(
+    /// Presigns the request `list_gateways` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_gateways(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGatewaysInput,
-    ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_gateways_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_gateways_request(&self, input: ListGatewaysInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_local_disks`, also returning the metadata of the response.
+    pub async fn list_local_disks_with_metadata(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> Result<(ListLocalDisksOutput, ResponseMetadata), RusotoError<ListLocalDisksError>> {
+        let request = self.build_list_local_disks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGatewaysOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLocalDisksOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGatewaysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLocalDisksError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the gateway's local disks. To specify which gateway to describe, you use the Amazon Resource Name (ARN) of the gateway in the body of the request.</p> <p>The request returns a list of all disks, specifying which are configured as working storage, cache storage, or stored volume or not configured at all. The response includes a <code>DiskStatus</code> field. This field can have a value of present (the disk is available to use), missing (the disk is no longer connected to the gateway), or mismatch (the disk node is occupied by a disk that has incorrect metadata or the disk content is corrupted).</p>
-    async fn list_local_disks(
+    /// Presigns the request `list_local_disks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_local_disks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLocalDisksInput,
-    ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_local_disks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_local_disks_request(&self, input: ListLocalDisksInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListLocalDisks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListLocalDisksOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLocalDisksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags that have been added to the specified resource. This operation is supported in storage gateways of all types.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7891,55 +9846,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tapes`, also returning the metadata of the response.
+    pub async fn list_tapes_with_metadata(
+        &self,
+        input: ListTapesInput,
+    ) -> Result<(ListTapesOutput, ResponseMetadata), RusotoError<ListTapesError>> {
+        let request = self.build_list_tapes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListTapesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTapesError::from_response(response))
         }
     }
 
-    /// <p>Lists virtual tapes in your virtual tape library (VTL) and your virtual tape shelf (VTS). You specify the tapes to list by specifying one or more tape Amazon Resource Names (ARNs). If you don't specify a tape ARN, the operation lists all virtual tapes in both your VTL and VTS.</p> <p>This operation supports pagination. By default, the operation returns a maximum of up to 100 tapes. You can optionally specify the <code>Limit</code> parameter in the body to limit the number of tapes in the response. If the number of tapes returned in the response is truncated, the response includes a <code>Marker</code> element that you can use in your subsequent request to retrieve the next set of tapes. This operation is only supported in the tape gateway type.</p>
-    async fn list_tapes(
+    /// Presigns the request `list_tapes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tapes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTapesInput,
-    ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tapes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tapes_request(&self, input: ListTapesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTapes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_volume_initiators`, also returning the metadata of the response.
+    pub async fn list_volume_initiators_with_metadata(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> Result<
+        (ListVolumeInitiatorsOutput, ResponseMetadata),
+        RusotoError<ListVolumeInitiatorsError>,
+    > {
+        let request = self.build_list_volume_initiators_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTapesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVolumeInitiatorsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTapesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVolumeInitiatorsError::from_response(response))
         }
     }
 
-    /// <p>Lists iSCSI initiators that are connected to a volume. You can use this operation to determine whether a volume is being used or not. This operation is only supported in the cached volume and stored volume gateway types.</p>
-    async fn list_volume_initiators(
+    /// Presigns the request `list_volume_initiators` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_volume_initiators(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVolumeInitiatorsInput,
-    ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_volume_initiators_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_volume_initiators_request(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeInitiators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7949,28 +9959,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_volume_recovery_points`, also returning the metadata of the response.
+    pub async fn list_volume_recovery_points_with_metadata(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> Result<
+        (ListVolumeRecoveryPointsOutput, ResponseMetadata),
+        RusotoError<ListVolumeRecoveryPointsError>,
+    > {
+        let request = self.build_list_volume_recovery_points_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVolumeInitiatorsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVolumeRecoveryPointsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVolumeInitiatorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVolumeRecoveryPointsError::from_response(response))
         }
     }
 
-    /// <p>Lists the recovery points for a specified gateway. This operation is only supported in the cached volume gateway type.</p> <p>Each cache volume has one recovery point. A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot or clone a new cached volume from a source volume. To create a snapshot from a volume recovery point use the <a>CreateSnapshotFromVolumeRecoveryPoint</a> operation.</p>
-    async fn list_volume_recovery_points(
+    /// Presigns the request `list_volume_recovery_points` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_volume_recovery_points(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVolumeRecoveryPointsInput,
-    ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_volume_recovery_points_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_volume_recovery_points_request(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeRecoveryPoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7980,110 +10020,215 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_volumes`, also returning the metadata of the response.
+    pub async fn list_volumes_with_metadata(
+        &self,
+        input: ListVolumesInput,
+    ) -> Result<(ListVolumesOutput, ResponseMetadata), RusotoError<ListVolumesError>> {
+        let request = self.build_list_volumes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVolumeRecoveryPointsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVolumesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVolumeRecoveryPointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVolumesError::from_response(response))
         }
     }
 
-    /// <p>Lists the iSCSI stored volumes of a gateway. Results are sorted by volume ARN. The response includes only the volume ARNs. If you want additional volume information, use the <a>DescribeStorediSCSIVolumes</a> or the <a>DescribeCachediSCSIVolumes</a> API.</p> <p>The operation supports pagination. By default, the operation returns a maximum of up to 100 volumes. You can optionally specify the <code>Limit</code> field in the body to limit the number of volumes in the response. If the number of volumes returned in the response is truncated, the response includes a Marker field. You can use this Marker value in your subsequent request to retrieve the next set of volumes. This operation is only supported in the cached volume and stored volume gateway types.</p>
-    async fn list_volumes(
+    /// Presigns the request `list_volumes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_volumes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVolumesInput,
-    ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_volumes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_volumes_request(&self, input: ListVolumesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `notify_when_uploaded`, also returning the metadata of the response.
+    pub async fn notify_when_uploaded_with_metadata(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> Result<(NotifyWhenUploadedOutput, ResponseMetadata), RusotoError<NotifyWhenUploadedError>>
+    {
+        let request = self.build_notify_when_uploaded_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListVolumesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<NotifyWhenUploadedOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVolumesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(NotifyWhenUploadedError::from_response(response))
         }
     }
 
-    /// <p>Sends you notification through CloudWatch Events when all files written to your file share have been uploaded to Amazon S3.</p> <p>AWS Storage Gateway can send a notification through Amazon CloudWatch Events when all files written to your file share up to that point in time have been uploaded to Amazon S3. These files include files written to the file share up to the time that you make a request for notification. When the upload is done, Storage Gateway sends you notification through an Amazon CloudWatch Event. You can configure CloudWatch Events to send the notification through event targets such as Amazon SNS or AWS Lambda function. This operation is only supported for file gateways.</p> <p>For more information, see Getting File Upload Notification in the Storage Gateway User Guide (https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-upload-notification). </p>
-    async fn notify_when_uploaded(
+    /// Presigns the request `notify_when_uploaded` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_notify_when_uploaded(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: NotifyWhenUploadedInput,
-    ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_notify_when_uploaded_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_notify_when_uploaded_request(&self, input: NotifyWhenUploadedInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("NotifyWhenUploaded");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `refresh_cache`, also returning the metadata of the response.
+    pub async fn refresh_cache_with_metadata(
+        &self,
+        input: RefreshCacheInput,
+    ) -> Result<(RefreshCacheOutput, ResponseMetadata), RusotoError<RefreshCacheError>> {
+        let request = self.build_refresh_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<NotifyWhenUploadedOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RefreshCacheOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(NotifyWhenUploadedError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RefreshCacheError::from_response(response))
         }
     }
 
-    /// <p>Refreshes the cache for the specified file share. This operation finds objects in the Amazon S3 bucket that were added, removed or replaced since the gateway last listed the bucket's contents and cached the results. This operation is only supported in the file gateway type. You can subscribe to be notified through an Amazon CloudWatch event when your RefreshCache operation completes. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>When this API is called, it only initiates the refresh operation. When the API call completes and returns a success code, it doesn't necessarily mean that the file refresh has completed. You should use the refresh-complete notification to determine that the operation has completed before you check for new files on the gateway file share. You can subscribe to be notified through an CloudWatch event when your <code>RefreshCache</code> operation completes. </p> <p>Throttle limit: This API is asynchronous so the gateway will accept no more than two refreshes at any time. We recommend using the refresh-complete CloudWatch event notification before issuing additional requests. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>If you invoke the RefreshCache API when two requests are already being processed, any new request will cause an <code>InvalidGatewayRequestException</code> error because too many requests were sent to the server.</p> <p>For more information, see "https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification".</p>
-    async fn refresh_cache(
+    /// Presigns the request `refresh_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_refresh_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RefreshCacheInput,
-    ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_refresh_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_refresh_cache_request(&self, input: RefreshCacheInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RefreshCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> Result<
+        (RemoveTagsFromResourceOutput, ResponseMetadata),
+        RusotoError<RemoveTagsFromResourceError>,
+    > {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RefreshCacheOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsFromResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RefreshCacheError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from the specified resource. This operation is supported in storage gateways of all types.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromResourceInput,
-    ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8093,55 +10238,108 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reset_cache`, also returning the metadata of the response.
+    pub async fn reset_cache_with_metadata(
+        &self,
+        input: ResetCacheInput,
+    ) -> Result<(ResetCacheOutput, ResponseMetadata), RusotoError<ResetCacheError>> {
+        let request = self.build_reset_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveTagsFromResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResetCacheOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResetCacheError::from_response(response))
         }
     }
 
-    /// <p><p>Resets all cache disks that have encountered a error and makes the disks available for reconfiguration as cache storage. If your cache disk encounters a error, the gateway prevents read and write operations on virtual tapes in the gateway. For example, an error can occur when a disk is corrupted or removed from the gateway. When a cache is reset, the gateway loses its cache storage. At this point you can reconfigure the disks as cache disks. This operation is only supported in the cached volume and tape types.</p> <important> <p>If the cache disk you are resetting contains data that has not been uploaded to Amazon S3 yet, that data can be lost. After you reset cache disks, there will be no configured cache disks left in the gateway, so you must configure at least one new cache disk for your gateway to function properly.</p> </important></p>
-    async fn reset_cache(
+    /// Presigns the request `reset_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResetCacheInput,
-    ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_cache_request(&self, input: ResetCacheInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ResetCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `retrieve_tape_archive`, also returning the metadata of the response.
+    pub async fn retrieve_tape_archive_with_metadata(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> Result<(RetrieveTapeArchiveOutput, ResponseMetadata), RusotoError<RetrieveTapeArchiveError>>
+    {
+        let request = self.build_retrieve_tape_archive_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResetCacheOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RetrieveTapeArchiveOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetCacheError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RetrieveTapeArchiveError::from_response(response))
         }
     }
 
-    /// <p>Retrieves an archived virtual tape from the virtual tape shelf (VTS) to a tape gateway. Virtual tapes archived in the VTS are not associated with any gateway. However after a tape is retrieved, it is associated with a gateway, even though it is also listed in the VTS, that is, archive. This operation is only supported in the tape gateway type.</p> <p>Once a tape is successfully retrieved to a gateway, it cannot be retrieved again to another gateway. You must archive the tape again before you can retrieve it to another gateway. This operation is only supported in the tape gateway type.</p>
-    async fn retrieve_tape_archive(
+    /// Presigns the request `retrieve_tape_archive` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retrieve_tape_archive(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RetrieveTapeArchiveInput,
-    ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retrieve_tape_archive_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retrieve_tape_archive_request(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeArchive");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8151,28 +10349,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `retrieve_tape_recovery_point`, also returning the metadata of the response.
+    pub async fn retrieve_tape_recovery_point_with_metadata(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> Result<
+        (RetrieveTapeRecoveryPointOutput, ResponseMetadata),
+        RusotoError<RetrieveTapeRecoveryPointError>,
+    > {
+        let request = self.build_retrieve_tape_recovery_point_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RetrieveTapeArchiveOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RetrieveTapeRecoveryPointOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RetrieveTapeArchiveError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RetrieveTapeRecoveryPointError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the recovery point for the specified virtual tape. This operation is only supported in the tape gateway type.</p> <p>A recovery point is a point in time view of a virtual tape at which all the data on the tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway.</p> <note> <p>The virtual tape can be retrieved to only one gateway. The retrieved tape is read-only. The virtual tape can be retrieved to only a tape gateway. There is no charge for retrieving recovery points.</p> </note></p>
-    async fn retrieve_tape_recovery_point(
+    /// Presigns the request `retrieve_tape_recovery_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retrieve_tape_recovery_point(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RetrieveTapeRecoveryPointInput,
-    ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retrieve_tape_recovery_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retrieve_tape_recovery_point_request(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeRecoveryPoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8182,28 +10410,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_local_console_password`, also returning the metadata of the response.
+    pub async fn set_local_console_password_with_metadata(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> Result<
+        (SetLocalConsolePasswordOutput, ResponseMetadata),
+        RusotoError<SetLocalConsolePasswordError>,
+    > {
+        let request = self.build_set_local_console_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RetrieveTapeRecoveryPointOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetLocalConsolePasswordOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RetrieveTapeRecoveryPointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetLocalConsolePasswordError::from_response(response))
         }
     }
 
-    /// <p>Sets the password for your VM local console. When you log in to the local console for the first time, you log in to the VM with the default credentials. We recommend that you set a new password. You don't need to know the default password to set a new password.</p>
-    async fn set_local_console_password(
+    /// Presigns the request `set_local_console_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_local_console_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetLocalConsolePasswordInput,
-    ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_local_console_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_local_console_password_request(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetLocalConsolePassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8213,28 +10471,56 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_smb_guest_password`, also returning the metadata of the response.
+    pub async fn set_smb_guest_password_with_metadata(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> Result<(SetSMBGuestPasswordOutput, ResponseMetadata), RusotoError<SetSMBGuestPasswordError>>
+    {
+        let request = self.build_set_smb_guest_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetLocalConsolePasswordOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetSMBGuestPasswordOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetLocalConsolePasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetSMBGuestPasswordError::from_response(response))
         }
     }
 
-    /// <p>Sets the password for the guest user <code>smbguest</code>. The <code>smbguest</code> user is the user when the authentication method for the file share is set to <code>GuestAccess</code>.</p>
-    async fn set_smb_guest_password(
+    /// Presigns the request `set_smb_guest_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_smb_guest_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetSMBGuestPasswordInput,
-    ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_smb_guest_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_smb_guest_password_request(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetSMBGuestPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8244,56 +10530,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `shutdown_gateway`, also returning the metadata of the response.
+    pub async fn shutdown_gateway_with_metadata(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> Result<(ShutdownGatewayOutput, ResponseMetadata), RusotoError<ShutdownGatewayError>> {
+        let request = self.build_shutdown_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetSMBGuestPasswordOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ShutdownGatewayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetSMBGuestPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ShutdownGatewayError::from_response(response))
         }
     }
 
-    /// <p>Shuts down a gateway. To specify which gateway to shut down, use the Amazon Resource Name (ARN) of the gateway in the body of your request.</p> <p>The operation shuts down the gateway service component running in the gateway's virtual machine (VM) and not the host VM.</p> <note> <p>If you want to shut down the VM, it is recommended that you first shut down the gateway component in the VM to avoid unpredictable conditions.</p> </note> <p>After the gateway is shutdown, you cannot call any other API except <a>StartGateway</a>, <a>DescribeGatewayInformation</a>, and <a>ListGateways</a>. For more information, see <a>ActivateGateway</a>. Your applications cannot read from or write to the gateway's storage volumes, and there are no snapshots taken.</p> <note> <p>When you make a shutdown request, you will get a <code>200 OK</code> success response immediately. However, it might take some time for the gateway to shut down. You can call the <a>DescribeGatewayInformation</a> API to check the status. For more information, see <a>ActivateGateway</a>.</p> </note> <p>If do not intend to use the gateway again, you must delete the gateway (using <a>DeleteGateway</a>) to no longer pay software charges associated with the gateway.</p>
-    async fn shutdown_gateway(
+    /// Presigns the request `shutdown_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_shutdown_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ShutdownGatewayInput,
-    ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_shutdown_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_shutdown_gateway_request(&self, input: ShutdownGatewayInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ShutdownGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_availability_monitor_test`, also returning the metadata of the response.
+    pub async fn start_availability_monitor_test_with_metadata(
+        &self,
+        input: StartAvailabilityMonitorTestInput,
+    ) -> Result<
+        (StartAvailabilityMonitorTestOutput, ResponseMetadata),
+        RusotoError<StartAvailabilityMonitorTestError>,
+    > {
+        let request = self.build_start_availability_monitor_test_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ShutdownGatewayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartAvailabilityMonitorTestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ShutdownGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartAvailabilityMonitorTestError::from_response(response))
         }
     }
 
-    /// <p><p>Start a test that verifies that the specified gateway is configured for High Availability monitoring in your host environment. This request only initiates the test and that a successful response only indicates that the test was started. It doesn&#39;t indicate that the test passed. For the status of the test, invoke the <code>DescribeAvailabilityMonitorTest</code> API. </p> <note> <p>Starting this test will cause your gateway to go offline for a brief period.</p> </note></p>
-    async fn start_availability_monitor_test(
+    /// Presigns the request `start_availability_monitor_test` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_availability_monitor_test(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartAvailabilityMonitorTestInput,
-    ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_availability_monitor_test_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_availability_monitor_test_request(
+        &self,
+        input: StartAvailabilityMonitorTestInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("StartAvailabilityMonitorTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8303,55 +10643,110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_gateway`, also returning the metadata of the response.
+    pub async fn start_gateway_with_metadata(
+        &self,
+        input: StartGatewayInput,
+    ) -> Result<(StartGatewayOutput, ResponseMetadata), RusotoError<StartGatewayError>> {
+        let request = self.build_start_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartAvailabilityMonitorTestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartGatewayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartAvailabilityMonitorTestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartGatewayError::from_response(response))
         }
     }
 
-    /// <p>Starts a gateway that you previously shut down (see <a>ShutdownGateway</a>). After the gateway starts, you can then make other API calls, your applications can read from or write to the gateway's storage volumes and you will be able to take snapshot backups.</p> <note> <p>When you make a request, you will get a 200 OK success response immediately. However, it might take some time for the gateway to be ready. You should call <a>DescribeGatewayInformation</a> and check the status before making any additional API calls. For more information, see <a>ActivateGateway</a>.</p> </note> <p>To specify which gateway to start, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
-    async fn start_gateway(
+    /// Presigns the request `start_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartGatewayInput,
-    ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_gateway_request(&self, input: StartGatewayInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("StartGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_bandwidth_rate_limit`, also returning the metadata of the response.
+    pub async fn update_bandwidth_rate_limit_with_metadata(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> Result<
+        (UpdateBandwidthRateLimitOutput, ResponseMetadata),
+        RusotoError<UpdateBandwidthRateLimitError>,
+    > {
+        let request = self.build_update_bandwidth_rate_limit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartGatewayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBandwidthRateLimitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateBandwidthRateLimitError::from_response(response))
         }
     }
 
-    /// <p>Updates the bandwidth rate limits of a gateway. You can update both the upload and download bandwidth rate limit or specify only one of the two. If you don't set a bandwidth rate limit, the existing rate limit remains. This operation is supported for the stored volume, cached volume and tape gateway types.'</p> <p>By default, a gateway's bandwidth rate limits are not set. If you don't set any limit, the gateway does not have any limitations on its bandwidth usage and could potentially use the maximum available bandwidth.</p> <p>To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
-    async fn update_bandwidth_rate_limit(
+    /// Presigns the request `update_bandwidth_rate_limit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_bandwidth_rate_limit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBandwidthRateLimitInput,
-    ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_bandwidth_rate_limit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_bandwidth_rate_limit_request(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateBandwidthRateLimit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8361,28 +10756,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_chap_credentials`, also returning the metadata of the response.
+    pub async fn update_chap_credentials_with_metadata(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> Result<
+        (UpdateChapCredentialsOutput, ResponseMetadata),
+        RusotoError<UpdateChapCredentialsError>,
+    > {
+        let request = self.build_update_chap_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBandwidthRateLimitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateChapCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBandwidthRateLimitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateChapCredentialsError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target. By default, a gateway does not have CHAP enabled; however, for added security, you might use it. This operation is supported in the volume and tape gateway types.</p> <important> <p>When you update CHAP credentials, all existing connections on the target are closed and initiators must reconnect with the new credentials.</p> </important></p>
-    async fn update_chap_credentials(
+    /// Presigns the request `update_chap_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_chap_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateChapCredentialsInput,
-    ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_chap_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_chap_credentials_request(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateChapCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8392,28 +10817,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_gateway_information`, also returning the metadata of the response.
+    pub async fn update_gateway_information_with_metadata(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> Result<
+        (UpdateGatewayInformationOutput, ResponseMetadata),
+        RusotoError<UpdateGatewayInformationError>,
+    > {
+        let request = self.build_update_gateway_information_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateChapCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGatewayInformationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateChapCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGatewayInformationError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a gateway&#39;s metadata, which includes the gateway&#39;s name and time zone. To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p> <note> <p>For Gateways activated after September 2, 2015, the gateway&#39;s ARN contains the gateway ID rather than the gateway name. However, changing the name of the gateway has no effect on the gateway&#39;s ARN.</p> </note></p>
-    async fn update_gateway_information(
+    /// Presigns the request `update_gateway_information` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_gateway_information(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGatewayInformationInput,
-    ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_gateway_information_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_gateway_information_request(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewayInformation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8423,28 +10878,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_gateway_software_now`, also returning the metadata of the response.
+    pub async fn update_gateway_software_now_with_metadata(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> Result<
+        (UpdateGatewaySoftwareNowOutput, ResponseMetadata),
+        RusotoError<UpdateGatewaySoftwareNowError>,
+    > {
+        let request = self.build_update_gateway_software_now_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGatewayInformationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGatewaySoftwareNowOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGatewayInformationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGatewaySoftwareNowError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the gateway virtual machine (VM) software. The request immediately triggers the software update.</p> <note> <p>When you make this request, you get a <code>200 OK</code> success response immediately. However, it might take some time for the update to complete. You can call <a>DescribeGatewayInformation</a> to verify the gateway is in the <code>STATE_RUNNING</code> state.</p> </note> <important> <p>A software update forces a system restart of your gateway. You can minimize the chance of any disruption to your applications by increasing your iSCSI Initiators&#39; timeouts. For more information about increasing iSCSI Initiator timeouts for Windows and Linux, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorWindowsClient.html#CustomizeWindowsiSCSISettings">Customizing Your Windows iSCSI Settings</a> and <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorRedHatClient.html#CustomizeLinuxiSCSISettings">Customizing Your Linux iSCSI Settings</a>, respectively.</p> </important></p>
-    async fn update_gateway_software_now(
+    /// Presigns the request `update_gateway_software_now` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_gateway_software_now(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGatewaySoftwareNowInput,
-    ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_gateway_software_now_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_gateway_software_now_request(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewaySoftwareNow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8454,29 +10939,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_maintenance_start_time`, also returning the metadata of the response.
+    pub async fn update_maintenance_start_time_with_metadata(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> Result<
+        (UpdateMaintenanceStartTimeOutput, ResponseMetadata),
+        RusotoError<UpdateMaintenanceStartTimeError>,
+    > {
+        let request = self.build_update_maintenance_start_time_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGatewaySoftwareNowOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMaintenanceStartTimeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGatewaySoftwareNowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMaintenanceStartTimeError::from_response(response))
         }
     }
 
-    /// <p>Updates a gateway's weekly maintenance start time information, including day and time of the week. The maintenance time is the time in your gateway's time zone.</p>
-    async fn update_maintenance_start_time(
+    /// Presigns the request `update_maintenance_start_time` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_maintenance_start_time(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMaintenanceStartTimeInput,
-    ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_maintenance_start_time_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_maintenance_start_time_request(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateMaintenanceStartTime");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8486,84 +11000,164 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_nfs_file_share`, also returning the metadata of the response.
+    pub async fn update_nfs_file_share_with_metadata(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> Result<(UpdateNFSFileShareOutput, ResponseMetadata), RusotoError<UpdateNFSFileShareError>>
+    {
+        let request = self.build_update_nfs_file_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMaintenanceStartTimeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNFSFileShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMaintenanceStartTimeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNFSFileShareError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a Network File System (NFS) file share. This operation is only supported in the file gateway type.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null.</p> </note> <p>Updates the following file share setting:</p> <ul> <li> <p>Default storage class for your S3 bucket</p> </li> <li> <p>Metadata defaults for your S3 bucket</p> </li> <li> <p>Allowed NFS clients for your file share</p> </li> <li> <p>Squash settings</p> </li> <li> <p>Write status of your file share</p> </li> </ul> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported in file gateways.</p> </note></p>
-    async fn update_nfs_file_share(
+    /// Presigns the request `update_nfs_file_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_nfs_file_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNFSFileShareInput,
-    ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_nfs_file_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_nfs_file_share_request(&self, input: UpdateNFSFileShareInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateNFSFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_smb_file_share`, also returning the metadata of the response.
+    pub async fn update_smb_file_share_with_metadata(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> Result<(UpdateSMBFileShareOutput, ResponseMetadata), RusotoError<UpdateSMBFileShareError>>
+    {
+        let request = self.build_update_smb_file_share_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNFSFileShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSMBFileShareOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNFSFileShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSMBFileShareError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a Server Message Block (SMB) file share.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported for file gateways.</p> </note> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
-    async fn update_smb_file_share(
+    /// Presigns the request `update_smb_file_share` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_smb_file_share(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSMBFileShareInput,
-    ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_smb_file_share_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_smb_file_share_request(&self, input: UpdateSMBFileShareInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBFileShare");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_smb_security_strategy`, also returning the metadata of the response.
+    pub async fn update_smb_security_strategy_with_metadata(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> Result<
+        (UpdateSMBSecurityStrategyOutput, ResponseMetadata),
+        RusotoError<UpdateSMBSecurityStrategyError>,
+    > {
+        let request = self.build_update_smb_security_strategy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSMBFileShareOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSMBSecurityStrategyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSMBFileShareError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSMBSecurityStrategyError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the SMB security strategy on a file gateway. This action is only supported in file gateways.</p> <note> <p>This API is called Security level in the User Guide.</p> <p>A higher security level can affect performance of the gateway.</p> </note></p>
-    async fn update_smb_security_strategy(
+    /// Presigns the request `update_smb_security_strategy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_smb_security_strategy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSMBSecurityStrategyInput,
-    ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_smb_security_strategy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_smb_security_strategy_request(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBSecurityStrategy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8573,28 +11167,58 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_snapshot_schedule`, also returning the metadata of the response.
+    pub async fn update_snapshot_schedule_with_metadata(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> Result<
+        (UpdateSnapshotScheduleOutput, ResponseMetadata),
+        RusotoError<UpdateSnapshotScheduleError>,
+    > {
+        let request = self.build_update_snapshot_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSMBSecurityStrategyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSnapshotScheduleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSMBSecurityStrategyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSnapshotScheduleError::from_response(response))
         }
     }
 
-    /// <p>Updates a snapshot schedule configured for a gateway volume. This operation is only supported in the cached volume and stored volume gateway types.</p> <p>The default snapshot schedule for volume is once every 24 hours, starting at the creation time of the volume. You can use this API to change the snapshot schedule configured for the volume.</p> <p>In the request you must identify the gateway volume whose snapshot schedule you want to update, and the schedule information, including when you want the snapshot to begin on a day and the frequency (in hours) of snapshots.</p>
-    async fn update_snapshot_schedule(
+    /// Presigns the request `update_snapshot_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_snapshot_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSnapshotScheduleInput,
-    ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_snapshot_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_snapshot_schedule_request(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSnapshotSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8604,28 +11228,56 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_vtl_device_type`, also returning the metadata of the response.
+    pub async fn update_vtl_device_type_with_metadata(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> Result<(UpdateVTLDeviceTypeOutput, ResponseMetadata), RusotoError<UpdateVTLDeviceTypeError>>
+    {
+        let request = self.build_update_vtl_device_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSnapshotScheduleOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVTLDeviceTypeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSnapshotScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVTLDeviceTypeError::from_response(response))
         }
     }
 
-    /// <p>Updates the type of medium changer in a tape gateway. When you activate a tape gateway, you select a medium changer type for the tape gateway. This operation enables you to select a different type of medium changer after a tape gateway is activated. This operation is only supported in the tape gateway type.</p>
-    async fn update_vtl_device_type(
+    /// Presigns the request `update_vtl_device_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_vtl_device_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVTLDeviceTypeInput,
-    ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_vtl_device_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_vtl_device_type_request(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateVTLDeviceType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8635,19 +11287,1110 @@ impl StorageGateway for StorageGatewayClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVTLDeviceTypeOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVTLDeviceTypeError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeTapeArchives` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_tape_archives_pages(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move {
+                client.describe_tape_archives(input).await
+            },
+            |input: &DescribeTapeArchivesInput, output: &DescribeTapeArchivesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTapeArchivesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TapeArchives` of every page of `DescribeTapeArchives` results for `input`, as `describe_tape_archives_pages` requests them.
+    pub fn describe_tape_archives_stream(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> impl futures::Stream<Item = Result<TapeArchive, RusotoError<DescribeTapeArchivesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_tape_archives_pages(input), |page| {
+            page.tape_archives.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTapeRecoveryPoints` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_tape_recovery_points_pages(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeTapeRecoveryPointsOutput,
+            RusotoError<DescribeTapeRecoveryPointsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move {
+                client.describe_tape_recovery_points(input).await
+            },
+            |input: &DescribeTapeRecoveryPointsInput, output: &DescribeTapeRecoveryPointsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTapeRecoveryPointsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TapeRecoveryPointInfos` of every page of `DescribeTapeRecoveryPoints` results for `input`, as `describe_tape_recovery_points_pages` requests them.
+    pub fn describe_tape_recovery_points_stream(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> impl futures::Stream<
+        Item = Result<TapeRecoveryPointInfo, RusotoError<DescribeTapeRecoveryPointsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_tape_recovery_points_pages(input), |page| {
+            page.tape_recovery_point_infos.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTapes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_tapes_pages(
+        &self,
+        input: DescribeTapesInput,
+    ) -> impl futures::Stream<Item = Result<DescribeTapesOutput, RusotoError<DescribeTapesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move { client.describe_tapes(input).await },
+            |input: &DescribeTapesInput, output: &DescribeTapesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTapesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tapes` of every page of `DescribeTapes` results for `input`, as `describe_tapes_pages` requests them.
+    pub fn describe_tapes_stream(
+        &self,
+        input: DescribeTapesInput,
+    ) -> impl futures::Stream<Item = Result<Tape, RusotoError<DescribeTapesError>>> + Send {
+        rusoto_core::pagination::items(self.describe_tapes_pages(input), |page| {
+            page.tapes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeVTLDevices` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_vtl_devices_pages(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move {
+                client.describe_vtl_devices(input).await
+            },
+            |input: &DescribeVTLDevicesInput, output: &DescribeVTLDevicesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeVTLDevicesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `VTLDevices` of every page of `DescribeVTLDevices` results for `input`, as `describe_vtl_devices_pages` requests them.
+    pub fn describe_vtl_devices_stream(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> impl futures::Stream<Item = Result<VTLDevice, RusotoError<DescribeVTLDevicesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_vtl_devices_pages(input), |page| {
+            page.vtl_devices.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFileShares` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_file_shares_pages(
+        &self,
+        input: ListFileSharesInput,
+    ) -> impl futures::Stream<Item = Result<ListFileSharesOutput, RusotoError<ListFileSharesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move { client.list_file_shares(input).await },
+            |input: &ListFileSharesInput, output: &ListFileSharesOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListFileSharesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FileShareInfoList` of every page of `ListFileShares` results for `input`, as `list_file_shares_pages` requests them.
+    pub fn list_file_shares_stream(
+        &self,
+        input: ListFileSharesInput,
+    ) -> impl futures::Stream<Item = Result<FileShareInfo, RusotoError<ListFileSharesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_file_shares_pages(input), |page| {
+            page.file_share_info_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGateways` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_gateways_pages(
+        &self,
+        input: ListGatewaysInput,
+    ) -> impl futures::Stream<Item = Result<ListGatewaysOutput, RusotoError<ListGatewaysError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move { client.list_gateways(input).await },
+            |input: &ListGatewaysInput, output: &ListGatewaysOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListGatewaysInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Gateways` of every page of `ListGateways` results for `input`, as `list_gateways_pages` requests them.
+    pub fn list_gateways_stream(
+        &self,
+        input: ListGatewaysInput,
+    ) -> impl futures::Stream<Item = Result<GatewayInfo, RusotoError<ListGatewaysError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_gateways_pages(input), |page| {
+            page.gateways.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListTagsForResourceInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTapes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_tapes_pages(
+        &self,
+        input: ListTapesInput,
+    ) -> impl futures::Stream<Item = Result<ListTapesOutput, RusotoError<ListTapesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move { client.list_tapes(input).await },
+            |input: &ListTapesInput, output: &ListTapesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListTapesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TapeInfos` of every page of `ListTapes` results for `input`, as `list_tapes_pages` requests them.
+    pub fn list_tapes_stream(
+        &self,
+        input: ListTapesInput,
+    ) -> impl futures::Stream<Item = Result<TapeInfo, RusotoError<ListTapesError>>> + Send {
+        rusoto_core::pagination::items(self.list_tapes_pages(input), |page| {
+            page.tape_infos.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListVolumes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_volumes_pages(
+        &self,
+        input: ListVolumesInput,
+    ) -> impl futures::Stream<Item = Result<ListVolumesOutput, RusotoError<ListVolumesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: StorageGatewayClient, input| async move { client.list_volumes(input).await },
+            |input: &ListVolumesInput, output: &ListVolumesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListVolumesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `VolumeInfos` of every page of `ListVolumes` results for `input`, as `list_volumes_pages` requests them.
+    pub fn list_volumes_stream(
+        &self,
+        input: ListVolumesInput,
+    ) -> impl futures::Stream<Item = Result<VolumeInfo, RusotoError<ListVolumesError>>> + Send {
+        rusoto_core::pagination::items(self.list_volumes_pages(input), |page| {
+            page.volume_infos.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl StorageGateway for StorageGatewayClient {
+    /// <p><p>Activates the gateway you previously deployed on your host. In the activation process, you specify information such as the AWS Region that you want to use for storing snapshots or tapes, the time zone for scheduled snapshots the gateway snapshot schedule window, an activation key, and a name for your gateway. The activation process also associates your gateway with your account; for more information, see <a>UpdateGatewayInformation</a>.</p> <note> <p>You must turn on the gateway VM before you can activate your gateway.</p> </note></p>
+    async fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
+        self.activate_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures one or more gateway local disks as cache for a gateway. This operation is only supported in the cached volume, tape and file gateway type (see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/StorageGatewayConcepts.html">Storage Gateway Concepts</a>).</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add cache, and one or more disk IDs that you want to configure as cache.</p>
+    async fn add_cache(
+        &self,
+        input: AddCacheInput,
+    ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
+        self.add_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to the specified resource. You use tags to add metadata to resources, which you can use to categorize these resources. For example, you can categorize resources by purpose, owner, environment, or team. Each tag consists of a key and a value, which you define. You can add tags to the following AWS Storage Gateway resources:</p> <ul> <li> <p>Storage gateways of all types</p> </li> <li> <p>Storage volumes</p> </li> <li> <p>Virtual tapes</p> </li> <li> <p>NFS and SMB file shares</p> </li> </ul> <p>You can create a maximum of 50 tags for each resource. Virtual tapes and storage volumes that are recovered to a new gateway maintain their tags.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures one or more gateway local disks as upload buffer for a specified gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add upload buffer, and one or more disk IDs that you want to configure as upload buffer.</p>
+    async fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
+        self.add_upload_buffer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures one or more gateway local disks as working storage for a gateway. This operation is only supported in the stored volume gateway type. This operation is deprecated in cached volume API version 20120630. Use <a>AddUploadBuffer</a> instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the <a>AddUploadBuffer</a> operation to add upload buffer to a stored volume gateway.</p> </note> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add working storage, and one or more disk IDs that you want to configure as working storage.</p>
+    async fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
+        self.add_working_storage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns a tape to a tape pool for archiving. The tape assigned to a pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the S3 storage class (Glacier or Deep Archive) that corresponds to the pool.</p> <p>Valid values: "GLACIER", "DEEP_ARCHIVE"</p>
+    async fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
+        self.assign_tape_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Connects a volume to an iSCSI connection and then attaches the volume to the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
+    async fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
+        self.attach_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels archiving of a virtual tape to the virtual tape shelf (VTS) after the archiving process is initiated. This operation is only supported in the tape gateway type.</p>
+    async fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
+        self.cancel_archival_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels retrieval of a virtual tape from the virtual tape shelf (VTS) to a gateway after the retrieval process is initiated. The virtual tape is returned to the VTS. This operation is only supported in the tape gateway type.</p>
+    async fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
+        self.cancel_retrieval_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a cached volume on a specified cached volume gateway. This operation is only supported in the cached volume gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a cached volume. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note> <p>In the request, you must specify the gateway, size of the volume in bytes, the iSCSI target name, an IP address on which to expose the target, and a unique client token. In response, the gateway creates the volume and returns information about it. This information includes the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p> <p>Optionally, you can provide the ARN for an existing volume as the <code>SourceVolumeARN</code> for this cached volume, which creates an exact copy of the existing volume’s latest recovery point. The <code>VolumeSizeInBytes</code> value must be equal to or larger than the size of the copied volume, in bytes.</p>
+    async fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
+        self.create_cachedi_scsi_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a Network File System (NFS) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway exposes file shares using a NFS interface. This operation is only supported for file gateways.</p> <important> <p>File gateway requires AWS Security Token Service (AWS STS) to be activated to enable you create a file share. Make sure AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in the AWS Region, activate it. For information about how to activate AWS STS, see Activating and Deactivating AWS STS in an AWS Region in the AWS Identity and Access Management User Guide. </p> <p>File gateway does not support creating hard or symbolic links on a file share.</p> </important></p>
+    async fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
+        self.create_nfs_file_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a Server Message Block (SMB) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway expose file shares using a SMB interface. This operation is only supported for file gateways.</p> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    async fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
+        self.create_smb_file_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Initiates a snapshot of a volume.</p> <p>AWS Storage Gateway provides the ability to back up point-in-time snapshots of your data to Amazon Simple Storage (S3) for durable off-site recovery, as well as import the data to an Amazon Elastic Block Store (EBS) volume in Amazon Elastic Compute Cloud (EC2). You can take snapshots of your gateway volume on a scheduled or ad hoc basis. This API enables you to take ad-hoc snapshot. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/managing-volumes.html#SchedulingSnapshot">Editing a Snapshot Schedule</a>.</p> <p>In the CreateSnapshot request you identify the volume by providing its Amazon Resource Name (ARN). You must also provide description for the snapshot. When AWS Storage Gateway takes the snapshot of specified volume, the snapshot and description appears in the AWS Storage Gateway Console. In response, AWS Storage Gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot. This operation is only supported in stored and cached volume gateway type.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see DescribeSnapshots or DeleteSnapshot in the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_Operations.html">EC2 API reference</a>.</p> </note> <important> <p>Volume and snapshot IDs are changing to a longer length ID format. For more information, see the important note on the <a href="https://docs.aws.amazon.com/storagegateway/latest/APIReference/Welcome.html">Welcome</a> page.</p> </important></p>
+    async fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
+        self.create_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Initiates a snapshot of a gateway from a volume recovery point. This operation is only supported in the cached volume gateway type.</p> <p>A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot. To get a list of volume recovery point for cached volume gateway, use <a>ListVolumeRecoveryPoints</a>.</p> <p>In the <code>CreateSnapshotFromVolumeRecoveryPoint</code> request, you identify the volume by providing its Amazon Resource Name (ARN). You must also provide a description for the snapshot. When the gateway takes a snapshot of the specified volume, the snapshot and its description appear in the AWS Storage Gateway console. In response, the gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    async fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> Result<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+    > {
+        self.create_snapshot_from_volume_recovery_point_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a volume on a specified gateway. This operation is only supported in the stored volume gateway type.</p> <p>The size of the volume to create is inferred from the disk size. You can choose to preserve existing data on the disk, create volume from an existing snapshot, or create an empty volume. If you choose to create an empty gateway volume, then any existing data on the disk is erased.</p> <p>In the request you must specify the gateway and the disk information on which you are creating the volume. In response, the gateway creates the volume and returns volume information such as the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p>
+    async fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
+        self.create_storedi_scsi_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a virtual tape by using your own barcode. You write data to the virtual tape and then archive the tape. A barcode is unique and can not be reused if it has already been used on a tape . This applies to barcodes used on deleted tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a virtual tape. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
+    async fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
+        self.create_tape_with_barcode_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates one or more virtual tapes. You write data to the virtual tapes and then archive the tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create virtual tapes. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note></p>
+    async fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
+        self.create_tapes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the bandwidth rate limits of a gateway. You can delete either the upload and download bandwidth rate limit, or you can delete both. If you delete only one of the limits, the other limit remains unchanged. To specify which gateway to work with, use the Amazon Resource Name (ARN) of the gateway in your request. This operation is supported for the stored volume, cached volume and tape gateway types.</p>
+    async fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
+        self.delete_bandwidth_rate_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target and initiator pair. This operation is supported in volume and tape gateway types.</p>
+    async fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
+        self.delete_chap_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a file share from a file gateway. This operation is only supported for file gateways.</p>
+    async fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
+        self.delete_file_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a gateway. To specify which gateway to delete, use the Amazon Resource Name (ARN) of the gateway in your request. The operation deletes the gateway; however, it does not delete the gateway virtual machine (VM) from your host computer.</p> <p>After you delete a gateway, you cannot reactivate it. Completed snapshots of the gateway volumes are not deleted upon deleting the gateway, however, pending snapshots will not complete. After you delete a gateway, your next step is to remove it from your environment.</p> <important> <p>You no longer pay software charges after the gateway is deleted; however, your existing Amazon EBS snapshots persist and you will continue to be billed for these snapshots. You can choose to remove all remaining Amazon EBS snapshots by canceling your Amazon EC2 subscription.  If you prefer not to cancel your Amazon EC2 subscription, you can delete your snapshots using the Amazon EC2 console. For more information, see the <a href="http://aws.amazon.com/storagegateway"> AWS Storage Gateway Detail Page</a>. </p> </important></p>
+    async fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
+        self.delete_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a snapshot of a volume.</p> <p>You can take snapshots of your gateway volumes on a scheduled or ad hoc basis. This API action enables you to delete a snapshot schedule for a volume. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/WorkingWithSnapshots.html">Working with Snapshots</a>. In the <code>DeleteSnapshotSchedule</code> request, you identify the volume by providing its Amazon Resource Name (ARN). This operation is only supported in stored and cached volume gateway types.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    async fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
+        self.delete_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified virtual tape. This operation is only supported in the tape gateway type.</p>
+    async fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
+        self.delete_tape_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified virtual tape from the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p>
+    async fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
+        self.delete_tape_archive_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified storage volume that you previously created using the <a>CreateCachediSCSIVolume</a> or <a>CreateStorediSCSIVolume</a> API. This operation is only supported in the cached volume and stored volume types. For stored volume gateways, the local disk that was configured as the storage volume is not deleted. You can reuse the local disk to create another storage volume. </p> <p>Before you delete a volume, make sure there are no iSCSI connections to the volume you are deleting. You should also make sure there is no snapshot in progress. You can use the Amazon Elastic Compute Cloud (Amazon EC2) API to query snapshots on the volume you are deleting and check the snapshot status. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/ApiReference-query-DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> <p>In the request, you must provide the Amazon Resource Name (ARN) of the storage volume you want to delete.</p>
+    async fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
+        self.delete_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the most recent High Availability monitoring test that was performed on the host in a cluster. If a test isn't performed, the status and start time in the response would be null.</p>
+    async fn describe_availability_monitor_test(
+        &self,
+        input: DescribeAvailabilityMonitorTestInput,
+    ) -> Result<
+        DescribeAvailabilityMonitorTestOutput,
+        RusotoError<DescribeAvailabilityMonitorTestError>,
+    > {
+        self.describe_availability_monitor_test_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the bandwidth rate limits of a gateway. By default, these limits are not set, which means no bandwidth rate limiting is in effect. This operation is supported for the stored volume, cached volume and tape gateway types.'</p> <p>This operation only returns a value for a bandwidth rate limit only if the limit is set. If no limits are set for the gateway, then this operation returns only the gateway ARN in the response body. To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    async fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
+    {
+        self.describe_bandwidth_rate_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the cache of a gateway. This operation is only supported in the cached volume, tape and file gateway types.</p> <p>The response includes disk IDs that are configured as cache, and it includes the amount of cache allocated and used.</p>
+    async fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
+        self.describe_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of the gateway volumes specified in the request. This operation is only supported in the cached volume gateway types.</p> <p>The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume Amazon Resource Name (ARN).</p>
+    async fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
+    {
+        self.describe_cachedi_scsi_volumes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of Challenge-Handshake Authentication Protocol (CHAP) credentials information for a specified iSCSI target, one for each target-initiator pair. This operation is supported in the volume and tape gateway types.</p>
+    async fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
+        self.describe_chap_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata about a gateway such as its name, network interfaces, configured time zone, and the state (whether the gateway is running or not). To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    async fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
+    {
+        self.describe_gateway_information_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns your gateway's weekly maintenance start time including the day and time of the week. Note that values are in terms of the gateway's time zone.</p>
+    async fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
+    {
+        self.describe_maintenance_start_time_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a description for one or more Network File System (NFS) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    async fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
+        self.describe_nfs_file_shares_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a description for one or more Server Message Block (SMB) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    async fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
+        self.describe_smb_file_shares_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a description of a Server Message Block (SMB) file share settings from a file gateway. This operation is only supported for file gateways.</p>
+    async fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
+        self.describe_smb_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the snapshot schedule for the specified gateway volume. The snapshot schedule information includes intervals at which snapshots are automatically initiated on the volume. This operation is only supported in the cached volume and stored volume types.</p>
+    async fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
+        self.describe_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of the gateway volumes specified in the request. The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume ARNs. This operation is only supported in stored volume gateway type.</p>
+    async fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
+    {
+        self.describe_storedi_scsi_volumes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of specified virtual tapes in the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p> <p>If a specific <code>TapeARN</code> is not specified, AWS Storage Gateway returns a description of all virtual tapes found in the VTS associated with your account.</p>
+    async fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
+        self.describe_tape_archives_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of virtual tape recovery points that are available for the specified tape gateway.</p> <p>A recovery point is a point-in-time view of a virtual tape at which all the data on the virtual tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway. This operation is only supported in the tape gateway type.</p>
+    async fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
+    {
+        self.describe_tape_recovery_points_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of the specified Amazon Resource Name (ARN) of virtual tapes. If a <code>TapeARN</code> is not specified, returns a description of all virtual tapes associated with the specified gateway. This operation is only supported in the tape gateway type.</p>
+    async fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
+        self.describe_tapes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the upload buffer of a gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>The response includes disk IDs that are configured as upload buffer space, and it includes the amount of upload buffer space allocated and used.</p>
+    async fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
+        self.describe_upload_buffer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of virtual tape library (VTL) devices for the specified tape gateway. In the response, AWS Storage Gateway returns VTL device information.</p> <p>This operation is only supported in the tape gateway type.</p>
+    async fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
+        self.describe_vtl_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the working storage of a gateway. This operation is only supported in the stored volumes gateway type. This operation is deprecated in cached volumes API version (20120630). Use DescribeUploadBuffer instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the DescribeUploadBuffer operation to add upload buffer to a stored volume gateway.</p> </note> <p>The response includes disk IDs that are configured as working storage, and it includes the amount of working storage allocated and used.</p>
+    async fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
+        self.describe_working_storage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disconnects a volume from an iSCSI connection and then detaches the volume from the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance. This operation is only supported in the volume gateway type.</p>
+    async fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
+        self.detach_volume_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Disables a tape gateway when the gateway is no longer functioning. For example, if your gateway VM is damaged, you can disable the gateway so you can recover virtual tapes.</p> <p>Use this operation for a tape gateway that is not reachable or not functioning. This operation is only supported in the tape gateway type.</p> <important> <p>Once a gateway is disabled it cannot be enabled.</p> </important></p>
+    async fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
+        self.disable_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a file gateway to an Active Directory domain. This operation is only supported for file gateways that support the SMB file protocol.</p>
+    async fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
+        self.join_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the file shares for a specific file gateway, or the list of file shares that belong to the calling user account. This operation is only supported for file gateways.</p>
+    async fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
+        self.list_file_shares_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists gateways owned by an AWS account in an AWS Region specified in the request. The returned list is ordered by gateway Amazon Resource Name (ARN).</p> <p>By default, the operation returns a maximum of 100 gateways. This operation supports pagination that allows you to optionally reduce the number of gateways returned in a response.</p> <p>If you have more gateways than are returned in a response (that is, the response returns only a truncated list of your gateways), the response contains a marker that you can specify in your next request to fetch the next page of gateways.</p>
+    async fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
+        self.list_gateways_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the gateway's local disks. To specify which gateway to describe, you use the Amazon Resource Name (ARN) of the gateway in the body of the request.</p> <p>The request returns a list of all disks, specifying which are configured as working storage, cache storage, or stored volume or not configured at all. The response includes a <code>DiskStatus</code> field. This field can have a value of present (the disk is available to use), missing (the disk is no longer connected to the gateway), or mismatch (the disk node is occupied by a disk that has incorrect metadata or the disk content is corrupted).</p>
+    async fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
+        self.list_local_disks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags that have been added to the specified resource. This operation is supported in storage gateways of all types.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists virtual tapes in your virtual tape library (VTL) and your virtual tape shelf (VTS). You specify the tapes to list by specifying one or more tape Amazon Resource Names (ARNs). If you don't specify a tape ARN, the operation lists all virtual tapes in both your VTL and VTS.</p> <p>This operation supports pagination. By default, the operation returns a maximum of up to 100 tapes. You can optionally specify the <code>Limit</code> parameter in the body to limit the number of tapes in the response. If the number of tapes returned in the response is truncated, the response includes a <code>Marker</code> element that you can use in your subsequent request to retrieve the next set of tapes. This operation is only supported in the tape gateway type.</p>
+    async fn list_tapes(
+        &self,
+        input: ListTapesInput,
+    ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
+        self.list_tapes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists iSCSI initiators that are connected to a volume. You can use this operation to determine whether a volume is being used or not. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    async fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
+        self.list_volume_initiators_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the recovery points for a specified gateway. This operation is only supported in the cached volume gateway type.</p> <p>Each cache volume has one recovery point. A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot or clone a new cached volume from a source volume. To create a snapshot from a volume recovery point use the <a>CreateSnapshotFromVolumeRecoveryPoint</a> operation.</p>
+    async fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
+        self.list_volume_recovery_points_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the iSCSI stored volumes of a gateway. Results are sorted by volume ARN. The response includes only the volume ARNs. If you want additional volume information, use the <a>DescribeStorediSCSIVolumes</a> or the <a>DescribeCachediSCSIVolumes</a> API.</p> <p>The operation supports pagination. By default, the operation returns a maximum of up to 100 volumes. You can optionally specify the <code>Limit</code> field in the body to limit the number of volumes in the response. If the number of volumes returned in the response is truncated, the response includes a Marker field. You can use this Marker value in your subsequent request to retrieve the next set of volumes. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    async fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
+        self.list_volumes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends you notification through CloudWatch Events when all files written to your file share have been uploaded to Amazon S3.</p> <p>AWS Storage Gateway can send a notification through Amazon CloudWatch Events when all files written to your file share up to that point in time have been uploaded to Amazon S3. These files include files written to the file share up to the time that you make a request for notification. When the upload is done, Storage Gateway sends you notification through an Amazon CloudWatch Event. You can configure CloudWatch Events to send the notification through event targets such as Amazon SNS or AWS Lambda function. This operation is only supported for file gateways.</p> <p>For more information, see Getting File Upload Notification in the Storage Gateway User Guide (https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-upload-notification). </p>
+    async fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
+        self.notify_when_uploaded_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Refreshes the cache for the specified file share. This operation finds objects in the Amazon S3 bucket that were added, removed or replaced since the gateway last listed the bucket's contents and cached the results. This operation is only supported in the file gateway type. You can subscribe to be notified through an Amazon CloudWatch event when your RefreshCache operation completes. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>When this API is called, it only initiates the refresh operation. When the API call completes and returns a success code, it doesn't necessarily mean that the file refresh has completed. You should use the refresh-complete notification to determine that the operation has completed before you check for new files on the gateway file share. You can subscribe to be notified through an CloudWatch event when your <code>RefreshCache</code> operation completes. </p> <p>Throttle limit: This API is asynchronous so the gateway will accept no more than two refreshes at any time. We recommend using the refresh-complete CloudWatch event notification before issuing additional requests. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>If you invoke the RefreshCache API when two requests are already being processed, any new request will cause an <code>InvalidGatewayRequestException</code> error because too many requests were sent to the server.</p> <p>For more information, see "https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification".</p>
+    async fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
+        self.refresh_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the specified resource. This operation is supported in storage gateways of all types.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Resets all cache disks that have encountered a error and makes the disks available for reconfiguration as cache storage. If your cache disk encounters a error, the gateway prevents read and write operations on virtual tapes in the gateway. For example, an error can occur when a disk is corrupted or removed from the gateway. When a cache is reset, the gateway loses its cache storage. At this point you can reconfigure the disks as cache disks. This operation is only supported in the cached volume and tape types.</p> <important> <p>If the cache disk you are resetting contains data that has not been uploaded to Amazon S3 yet, that data can be lost. After you reset cache disks, there will be no configured cache disks left in the gateway, so you must configure at least one new cache disk for your gateway to function properly.</p> </important></p>
+    async fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
+        self.reset_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an archived virtual tape from the virtual tape shelf (VTS) to a tape gateway. Virtual tapes archived in the VTS are not associated with any gateway. However after a tape is retrieved, it is associated with a gateway, even though it is also listed in the VTS, that is, archive. This operation is only supported in the tape gateway type.</p> <p>Once a tape is successfully retrieved to a gateway, it cannot be retrieved again to another gateway. You must archive the tape again before you can retrieve it to another gateway. This operation is only supported in the tape gateway type.</p>
+    async fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
+        self.retrieve_tape_archive_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the recovery point for the specified virtual tape. This operation is only supported in the tape gateway type.</p> <p>A recovery point is a point in time view of a virtual tape at which all the data on the tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway.</p> <note> <p>The virtual tape can be retrieved to only one gateway. The retrieved tape is read-only. The virtual tape can be retrieved to only a tape gateway. There is no charge for retrieving recovery points.</p> </note></p>
+    async fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
+        self.retrieve_tape_recovery_point_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the password for your VM local console. When you log in to the local console for the first time, you log in to the VM with the default credentials. We recommend that you set a new password. You don't need to know the default password to set a new password.</p>
+    async fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
+        self.set_local_console_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the password for the guest user <code>smbguest</code>. The <code>smbguest</code> user is the user when the authentication method for the file share is set to <code>GuestAccess</code>.</p>
+    async fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
+        self.set_smb_guest_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Shuts down a gateway. To specify which gateway to shut down, use the Amazon Resource Name (ARN) of the gateway in the body of your request.</p> <p>The operation shuts down the gateway service component running in the gateway's virtual machine (VM) and not the host VM.</p> <note> <p>If you want to shut down the VM, it is recommended that you first shut down the gateway component in the VM to avoid unpredictable conditions.</p> </note> <p>After the gateway is shutdown, you cannot call any other API except <a>StartGateway</a>, <a>DescribeGatewayInformation</a>, and <a>ListGateways</a>. For more information, see <a>ActivateGateway</a>. Your applications cannot read from or write to the gateway's storage volumes, and there are no snapshots taken.</p> <note> <p>When you make a shutdown request, you will get a <code>200 OK</code> success response immediately. However, it might take some time for the gateway to shut down. You can call the <a>DescribeGatewayInformation</a> API to check the status. For more information, see <a>ActivateGateway</a>.</p> </note> <p>If do not intend to use the gateway again, you must delete the gateway (using <a>DeleteGateway</a>) to no longer pay software charges associated with the gateway.</p>
+    async fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
+        self.shutdown_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Start a test that verifies that the specified gateway is configured for High Availability monitoring in your host environment. This request only initiates the test and that a successful response only indicates that the test was started. It doesn&#39;t indicate that the test passed. For the status of the test, invoke the <code>DescribeAvailabilityMonitorTest</code> API. </p> <note> <p>Starting this test will cause your gateway to go offline for a brief period.</p> </note></p>
+    async fn start_availability_monitor_test(
+        &self,
+        input: StartAvailabilityMonitorTestInput,
+    ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
+    {
+        self.start_availability_monitor_test_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a gateway that you previously shut down (see <a>ShutdownGateway</a>). After the gateway starts, you can then make other API calls, your applications can read from or write to the gateway's storage volumes and you will be able to take snapshot backups.</p> <note> <p>When you make a request, you will get a 200 OK success response immediately. However, it might take some time for the gateway to be ready. You should call <a>DescribeGatewayInformation</a> and check the status before making any additional API calls. For more information, see <a>ActivateGateway</a>.</p> </note> <p>To specify which gateway to start, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    async fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
+        self.start_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the bandwidth rate limits of a gateway. You can update both the upload and download bandwidth rate limit or specify only one of the two. If you don't set a bandwidth rate limit, the existing rate limit remains. This operation is supported for the stored volume, cached volume and tape gateway types.'</p> <p>By default, a gateway's bandwidth rate limits are not set. If you don't set any limit, the gateway does not have any limitations on its bandwidth usage and could potentially use the maximum available bandwidth.</p> <p>To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    async fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
+        self.update_bandwidth_rate_limit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target. By default, a gateway does not have CHAP enabled; however, for added security, you might use it. This operation is supported in the volume and tape gateway types.</p> <important> <p>When you update CHAP credentials, all existing connections on the target are closed and initiators must reconnect with the new credentials.</p> </important></p>
+    async fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
+        self.update_chap_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a gateway&#39;s metadata, which includes the gateway&#39;s name and time zone. To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p> <note> <p>For Gateways activated after September 2, 2015, the gateway&#39;s ARN contains the gateway ID rather than the gateway name. However, changing the name of the gateway has no effect on the gateway&#39;s ARN.</p> </note></p>
+    async fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
+        self.update_gateway_information_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the gateway virtual machine (VM) software. The request immediately triggers the software update.</p> <note> <p>When you make this request, you get a <code>200 OK</code> success response immediately. However, it might take some time for the update to complete. You can call <a>DescribeGatewayInformation</a> to verify the gateway is in the <code>STATE_RUNNING</code> state.</p> </note> <important> <p>A software update forces a system restart of your gateway. You can minimize the chance of any disruption to your applications by increasing your iSCSI Initiators&#39; timeouts. For more information about increasing iSCSI Initiator timeouts for Windows and Linux, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorWindowsClient.html#CustomizeWindowsiSCSISettings">Customizing Your Windows iSCSI Settings</a> and <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorRedHatClient.html#CustomizeLinuxiSCSISettings">Customizing Your Linux iSCSI Settings</a>, respectively.</p> </important></p>
+    async fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
+        self.update_gateway_software_now_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a gateway's weekly maintenance start time information, including day and time of the week. The maintenance time is the time in your gateway's time zone.</p>
+    async fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
+    {
+        self.update_maintenance_start_time_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a Network File System (NFS) file share. This operation is only supported in the file gateway type.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null.</p> </note> <p>Updates the following file share setting:</p> <ul> <li> <p>Default storage class for your S3 bucket</p> </li> <li> <p>Metadata defaults for your S3 bucket</p> </li> <li> <p>Allowed NFS clients for your file share</p> </li> <li> <p>Squash settings</p> </li> <li> <p>Write status of your file share</p> </li> </ul> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported in file gateways.</p> </note></p>
+    async fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
+        self.update_nfs_file_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a Server Message Block (SMB) file share.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported for file gateways.</p> </note> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    async fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
+        self.update_smb_file_share_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the SMB security strategy on a file gateway. This action is only supported in file gateways.</p> <note> <p>This API is called Security level in the User Guide.</p> <p>A higher security level can affect performance of the gateway.</p> </note></p>
+    async fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
+        self.update_smb_security_strategy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a snapshot schedule configured for a gateway volume. This operation is only supported in the cached volume and stored volume gateway types.</p> <p>The default snapshot schedule for volume is once every 24 hours, starting at the creation time of the volume. You can use this API to change the snapshot schedule configured for the volume.</p> <p>In the request you must identify the gateway volume whose snapshot schedule you want to update, and the schedule information, including when you want the snapshot to begin on a day and the frequency (in hours) of snapshots.</p>
+    async fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
+        self.update_snapshot_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the type of medium changer in a tape gateway. When you activate a tape gateway, you select a medium changer type for the tape gateway. This operation enables you to select a different type of medium changer after a tape gateway is activated. This operation is only supported in the tape gateway type.</p>
+    async fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
+        self.update_vtl_device_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
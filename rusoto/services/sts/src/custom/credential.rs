@@ -1,9 +1,15 @@
+use std::fmt;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use chrono::prelude::*;
 use chrono::Duration;
+use futures::lock::Mutex;
 
 use rusoto_core;
-use rusoto_core::RusotoError;
+use rusoto_core::credential::{AssumeProfileRole, ProfileRole, StaticProvider};
+use rusoto_core::request::{DispatchSignedRequest, HttpClient, TlsError};
+use rusoto_core::{Client, Region, RusotoError};
 
 use crate::{
     AssumeRoleError, AssumeRoleRequest, AssumeRoleResponse, AssumeRoleWithSAMLError,
@@ -13,12 +19,16 @@ use crate::{
     DecodeAuthorizationMessageResponse, GetCallerIdentityError, GetCallerIdentityRequest,
     GetCallerIdentityResponse, GetFederationTokenError, GetFederationTokenRequest,
     GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
-    GetSessionTokenResponse, Sts, StsClient,
+    GetSessionTokenResponse, Sts, StsClient, Tag,
+};
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, ProfileProvider, ProvideAwsCredentials,
 };
-use rusoto_core::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
 
 pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i32 = 900;
+/// Cached session credentials are refreshed this many seconds before they expire.
+pub const DEFAULT_REFRESH_BUFFER_SECONDS: i64 = 300;
 
 /// Trait for conversions from STS Credentials to AWS Credentials.
 pub trait NewAwsCredsForStsCreds {
@@ -146,16 +156,53 @@ where
     }
 }
 
+/// Source of the codes of MFA devices, e.g. a prompt asking the user of an interactive tool.
+///
+/// Set on a provider with `set_mfa_token_provider`, it is asked for a code each time the provider
+/// acquires new session credentials with an MFA serial number but no MFA code set.
+#[async_trait]
+pub trait MfaTokenProvider {
+    /// The current code of the MFA device with the serial number or ARN `mfa_serial`.
+    async fn mfa_token(&self, mfa_serial: &str) -> Result<String, CredentialsError>;
+}
+
+#[async_trait]
+impl<F> MfaTokenProvider for F
+where
+    F: Fn(&str) -> Result<String, CredentialsError> + Send + Sync,
+{
+    async fn mfa_token(&self, mfa_serial: &str) -> Result<String, CredentialsError> {
+        self(mfa_serial)
+    }
+}
+
+/// The MFA code to send: the one set, else one from `mfa_token_provider` if MFA is used.
+async fn resolve_mfa_code(
+    mfa_serial: &Option<String>,
+    mfa_code: &Option<String>,
+    mfa_token_provider: &Option<Arc<dyn MfaTokenProvider + Send + Sync>>,
+) -> Result<Option<String>, CredentialsError> {
+    match (mfa_serial, mfa_code, mfa_token_provider) {
+        (_, Some(code), _) => Ok(Some(code.clone())),
+        (Some(serial), None, Some(provider)) => provider.mfa_token(serial).await.map(Some),
+        _ => Ok(None),
+    }
+}
+
 /// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that calls
 /// `GetSessionToken` using the provided [StsClient](struct.StsClient.html).
-/// To use with MFA, pass in the MFA serial number then set the MFA code.
-/// You will need to ensure the provider has a valid code each time you
-/// acquire a new STS token.
+/// To use with MFA, pass in the MFA serial number then set the MFA code or an
+/// [MfaTokenProvider](trait.MfaTokenProvider.html). You will need to ensure the provider has a
+/// valid code each time you acquire a new STS token.
+///
+/// The session credentials are cached until shortly before they expire.
 pub struct StsSessionCredentialsProvider {
     sts_client: Box<dyn StsSessionCredentialsClient + Send + Sync>,
     session_duration: Duration,
     mfa_serial: Option<String>,
     mfa_code: Option<String>,
+    mfa_token_provider: Option<Arc<dyn MfaTokenProvider + Send + Sync>>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
 }
 
 impl StsSessionCredentialsProvider {
@@ -176,6 +223,8 @@ impl StsSessionCredentialsProvider {
                 .unwrap_or(Duration::seconds(DEFAULT_DURATION_SECONDS as i64)),
             mfa_serial,
             mfa_code: None,
+            mfa_token_provider: None,
+            credentials: Default::default(),
         }
     }
 
@@ -192,14 +241,30 @@ impl StsSessionCredentialsProvider {
         self.mfa_code = None;
     }
 
+    /// Ask `mfa_token_provider` for the MFA code when acquiring session tokens without one set.
+    pub fn set_mfa_token_provider<P>(&mut self, mfa_token_provider: P)
+    where
+        P: MfaTokenProvider + Send + Sync + 'static,
+    {
+        self.mfa_token_provider = Some(Arc::new(mfa_token_provider));
+    }
+
     /// Calls `GetSessionToken` to get a session token from the STS Api.
     /// Optionally uses MFA if the MFA serial number and code are set.
     pub async fn get_session_token(
         &self,
+    ) -> Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>> {
+        self.get_session_token_with_code(self.mfa_code.clone())
+            .await
+    }
+
+    async fn get_session_token_with_code(
+        &self,
+        mfa_code: Option<String>,
     ) -> Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>> {
         let request = GetSessionTokenRequest {
             serial_number: self.mfa_serial.clone(),
-            token_code: self.mfa_code.clone(),
+            token_code: mfa_code,
             duration_seconds: Some(self.session_duration.num_seconds() as i64),
             ..Default::default()
         };
@@ -210,22 +275,37 @@ impl StsSessionCredentialsProvider {
 #[async_trait]
 impl ProvideAwsCredentials for StsSessionCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let resp = self.get_session_token().await.map_err(|err| {
-            CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
-        })?;
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref creds) = *cached {
+            if !expires_within(creds, Duration::seconds(DEFAULT_REFRESH_BUFFER_SECONDS)) {
+                return Ok(creds.clone());
+            }
+        }
+        let mfa_code =
+            resolve_mfa_code(&self.mfa_serial, &self.mfa_code, &self.mfa_token_provider).await?;
+        let resp = self
+            .get_session_token_with_code(mfa_code)
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
+            })?;
         let creds = resp
             .credentials
             .ok_or_else(|| CredentialsError::new("no credentials in response"))?;
 
-        AwsCredentials::new_for_credentials(creds)
+        let creds = AwsCredentials::new_for_credentials(creds)?;
+        *cached = Some(creds.clone());
+        Ok(creds)
     }
 }
 
 /// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that calls
 /// `AssumeRole` using the provided [StsClient](struct.StsClient.html).
-/// To use with MFA, pass in the MFA serial number then set the MFA code.
-/// You will need to ensure the provider has a valid code each time you
-/// acquire a new STS token.
+/// To use with MFA, pass in the MFA serial number then set the MFA code or an
+/// [MfaTokenProvider](trait.MfaTokenProvider.html). You will need to ensure the provider has a
+/// valid code each time you acquire a new STS token.
+///
+/// The session credentials are cached, and the role is assumed again shortly before they expire.
 pub struct StsAssumeRoleSessionCredentialsProvider {
     sts_client: Box<dyn StsSessionCredentialsClient + Send + Sync>,
     role_arn: String,
@@ -235,6 +315,11 @@ pub struct StsAssumeRoleSessionCredentialsProvider {
     scope_down_policy: Option<String>,
     mfa_serial: Option<String>,
     mfa_code: Option<String>,
+    mfa_token_provider: Option<Arc<dyn MfaTokenProvider + Send + Sync>>,
+    tags: Option<Vec<Tag>>,
+    transitive_tag_keys: Option<Vec<String>>,
+    refresh_buffer: Duration,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
 }
 
 impl StsAssumeRoleSessionCredentialsProvider {
@@ -267,9 +352,47 @@ impl StsAssumeRoleSessionCredentialsProvider {
             scope_down_policy,
             mfa_serial,
             mfa_code: None,
+            mfa_token_provider: None,
+            tags: None,
+            transitive_tag_keys: None,
+            refresh_buffer: Duration::seconds(DEFAULT_REFRESH_BUFFER_SECONDS),
+            credentials: Default::default(),
         }
     }
 
+    /// Creates a provider assuming the role configured by `profile` in `~/.aws/config` (or the
+    /// file named by `AWS_CONFIG_FILE`), from its `role_arn`, `role_session_name`, `external_id`,
+    /// `duration_seconds` and `mfa_serial` keys.
+    ///
+    /// Set an [MfaTokenProvider](trait.MfaTokenProvider.html) if the profile has an `mfa_serial`.
+    pub fn from_profile(
+        sts_client: StsClient,
+        profile: &str,
+    ) -> Result<StsAssumeRoleSessionCredentialsProvider, CredentialsError> {
+        let value = |key| ProfileProvider::profile_config_value(profile, key);
+        let role_arn = value("role_arn")?
+            .ok_or_else(|| CredentialsError::new(format!("Profile {} has no role_arn", profile)))?;
+        let session_name = value("role_session_name")?.unwrap_or_else(default_session_name);
+        let session_duration = match value("duration_seconds")? {
+            Some(seconds) => Some(Duration::seconds(seconds.trim().parse().map_err(|_| {
+                CredentialsError::new(format!(
+                    "Invalid duration_seconds in profile {}: {}",
+                    profile, seconds
+                ))
+            })?)),
+            None => None,
+        };
+        Ok(StsAssumeRoleSessionCredentialsProvider::new(
+            sts_client,
+            role_arn,
+            session_name,
+            value("external_id")?,
+            session_duration,
+            None,
+            value("mfa_serial")?,
+        ))
+    }
+
     /// Set the MFA code for use when acquiring session tokens.
     pub fn set_mfa_code<S>(&mut self, code: S)
     where
@@ -283,9 +406,40 @@ impl StsAssumeRoleSessionCredentialsProvider {
         self.mfa_code = None;
     }
 
+    /// Ask `mfa_token_provider` for the MFA code when acquiring session tokens without one set.
+    pub fn set_mfa_token_provider<P>(&mut self, mfa_token_provider: P)
+    where
+        P: MfaTokenProvider + Send + Sync + 'static,
+    {
+        self.mfa_token_provider = Some(Arc::new(mfa_token_provider));
+    }
+
+    /// Set the session tags passed when assuming the role.
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = Some(tags);
+    }
+
+    /// Set the keys of the session tags that are passed on to roles assumed in turn by the session.
+    pub fn set_transitive_tag_keys(&mut self, transitive_tag_keys: Vec<String>) {
+        self.transitive_tag_keys = Some(transitive_tag_keys);
+    }
+
+    /// Set how long before they expire cached session credentials are refreshed. Default 5
+    /// minutes.
+    pub fn set_refresh_buffer(&mut self, refresh_buffer: Duration) {
+        self.refresh_buffer = refresh_buffer;
+    }
+
     /// Calls `AssumeRole` to get a session token from the STS Api.
     /// Optionally uses MFA if the MFA serial number and code are set.
     pub async fn assume_role(&self) -> Result<AwsCredentials, RusotoError<AssumeRoleError>> {
+        self.assume_role_with_code(self.mfa_code.clone()).await
+    }
+
+    async fn assume_role_with_code(
+        &self,
+        mfa_code: Option<String>,
+    ) -> Result<AwsCredentials, RusotoError<AssumeRoleError>> {
         let request = AssumeRoleRequest {
             role_arn: self.role_arn.clone(),
             role_session_name: self.session_name.clone(),
@@ -293,7 +447,9 @@ impl StsAssumeRoleSessionCredentialsProvider {
             external_id: self.external_id.clone(),
             policy: self.scope_down_policy.clone(),
             serial_number: self.mfa_serial.clone(),
-            token_code: self.mfa_code.clone(),
+            token_code: mfa_code,
+            tags: self.tags.clone(),
+            transitive_tag_keys: self.transitive_tag_keys.clone(),
             ..Default::default()
         };
         let resp = self.sts_client.assume_role(request).await?;
@@ -309,9 +465,114 @@ impl StsAssumeRoleSessionCredentialsProvider {
 #[async_trait]
 impl ProvideAwsCredentials for StsAssumeRoleSessionCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        self.assume_role().await.map_err(|err| {
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref creds) = *cached {
+            if !expires_within(creds, self.refresh_buffer) {
+                return Ok(creds.clone());
+            }
+        }
+        let mfa_code =
+            resolve_mfa_code(&self.mfa_serial, &self.mfa_code, &self.mfa_token_provider).await?;
+        let creds = self.assume_role_with_code(mfa_code).await.map_err(|err| {
             CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
-        })
+        })?;
+        *cached = Some(creds.clone());
+        Ok(creds)
+    }
+}
+
+/// Assumes the roles of profiles for a
+/// [ProfileProvider](../rusoto_credential/struct.ProfileProvider.html) with `AssumeRole`, see
+/// `ProfileProvider::set_role_assumer`.
+///
+/// ```rust,no_run
+/// use rusoto_core::credential::ProfileProvider;
+/// use rusoto_sts::StsRoleAssumer;
+///
+/// let mut provider = ProfileProvider::new().unwrap();
+/// provider.set_role_assumer(StsRoleAssumer::new().unwrap());
+/// ```
+///
+/// The requests go to STS in the region of the profile, or else the default region.
+#[derive(Clone)]
+pub struct StsRoleAssumer {
+    dispatcher: Arc<dyn DispatchSignedRequest + Send + Sync>,
+    mfa_token_provider: Option<Arc<dyn MfaTokenProvider + Send + Sync>>,
+}
+
+impl StsRoleAssumer {
+    /// Creates a role assumer sending requests with a new `HttpClient`.
+    pub fn new() -> Result<StsRoleAssumer, TlsError> {
+        Ok(StsRoleAssumer::new_with(HttpClient::new()?))
+    }
+
+    /// Creates a role assumer sending requests with `dispatcher`.
+    pub fn new_with<D>(dispatcher: D) -> StsRoleAssumer
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StsRoleAssumer {
+            dispatcher: Arc::new(dispatcher),
+            mfa_token_provider: None,
+        }
+    }
+
+    /// Ask `mfa_token_provider` for the MFA code of roles with an `mfa_serial`.
+    pub fn set_mfa_token_provider<P>(&mut self, mfa_token_provider: P)
+    where
+        P: MfaTokenProvider + Send + Sync + 'static,
+    {
+        self.mfa_token_provider = Some(Arc::new(mfa_token_provider));
+    }
+}
+
+impl fmt::Debug for StsRoleAssumer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StsRoleAssumer")
+            .field("mfa_token_provider", &self.mfa_token_provider.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl AssumeProfileRole for StsRoleAssumer {
+    async fn assume_role(
+        &self,
+        source: AwsCredentials,
+        role: &ProfileRole,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        let region = role
+            .region
+            .as_ref()
+            .and_then(|region| region.parse::<Region>().ok())
+            .unwrap_or_default();
+        let client = Client::new_with(StaticProvider::from(source), self.dispatcher.clone());
+        let mut provider = StsAssumeRoleSessionCredentialsProvider::new(
+            StsClient::new_with_client(client, region),
+            role.role_arn.clone(),
+            role.role_session_name
+                .clone()
+                .unwrap_or_else(default_session_name),
+            role.external_id.clone(),
+            role.duration_seconds.map(Duration::seconds),
+            None,
+            role.mfa_serial.clone(),
+        );
+        provider.mfa_token_provider = self.mfa_token_provider.clone();
+        provider.credentials().await
+    }
+}
+
+/// The name of role sessions no name is configured for.
+fn default_session_name() -> String {
+    format!("rusoto-session-{}", Utc::now().timestamp())
+}
+
+/// Whether `creds` expire within `buffer` from now.
+pub(crate) fn expires_within(creds: &AwsCredentials, buffer: Duration) -> bool {
+    match creds.expires_at() {
+        Some(expires_at) => *expires_at < Utc::now() + buffer,
+        None => false,
     }
 }
 
@@ -416,3 +677,152 @@ fn sts_futures_are_send() {
     is_send::<StsAssumeRoleSessionCredentialsProvider>();
     is_send::<StsWebIdentityFederationSessionCredentialsProvider>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn assume_role_response(expiration: &str) -> String {
+        format!(
+            "<AssumeRoleResponse><AssumeRoleResult><Credentials>\
+             <AccessKeyId>key</AccessKeyId><SecretAccessKey>secret</SecretAccessKey>\
+             <SessionToken>token</SessionToken><Expiration>{}</Expiration>\
+             </Credentials></AssumeRoleResult>\
+             <ResponseMetadata><RequestId>id</RequestId></ResponseMetadata>\
+             </AssumeRoleResponse>",
+            expiration
+        )
+    }
+
+    fn provider(
+        expiration: &str,
+        calls: Arc<AtomicUsize>,
+    ) -> StsAssumeRoleSessionCredentialsProvider {
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(&assume_role_response(expiration))
+            .with_request_checker(move |_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+            });
+        let sts = StsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let mut provider = StsAssumeRoleSessionCredentialsProvider::new(
+            sts,
+            "arn:aws:iam::111122223333:role/deploy".to_owned(),
+            "session".to_owned(),
+            Some("external".to_owned()),
+            None,
+            None,
+            None,
+        );
+        provider.set_tags(vec![Tag {
+            key: "team".to_owned(),
+            value: "platform".to_owned(),
+        }]);
+        provider
+    }
+
+    #[tokio::test]
+    async fn caches_assumed_role_credentials() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = provider("2099-01-01T00:00:00Z", calls.clone());
+        let creds = provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refreshes_expiring_credentials() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let expiration = (Utc::now() + Duration::minutes(1)).to_rfc3339();
+        let provider = provider(&expiration, calls.clone());
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn assumes_role_of_profile_with_mfa_token_provider() {
+        let mut config = tempfile::NamedTempFile::new().unwrap();
+        writeln!(
+            config,
+            "[profile deploy]\n\
+             role_arn = arn:aws:iam::111122223333:role/deploy\n\
+             mfa_serial = arn:aws:iam::111122223333:mfa/user\n\
+             duration_seconds = 1800"
+        )
+        .unwrap();
+        std::env::set_var("AWS_CONFIG_FILE", config.path());
+
+        let payloads = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = payloads.clone();
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(&assume_role_response("2099-01-01T00:00:00Z"))
+            .with_request_checker(move |request| {
+                if let Some(SignedRequestPayload::Buffer(ref payload)) = request.payload {
+                    seen.lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(payload).into_owned());
+                }
+            });
+        let sts = StsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let mut provider =
+            StsAssumeRoleSessionCredentialsProvider::from_profile(sts, "deploy").unwrap();
+        std::env::remove_var("AWS_CONFIG_FILE");
+
+        let prompts = Arc::new(AtomicUsize::new(0));
+        let prompted = prompts.clone();
+        provider.set_mfa_token_provider(move |serial: &str| {
+            assert_eq!(serial, "arn:aws:iam::111122223333:mfa/user");
+            prompted.fetch_add(1, Ordering::SeqCst);
+            Ok("123456".to_owned())
+        });
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+
+        assert_eq!(prompts.load(Ordering::SeqCst), 1);
+        let payloads = payloads.lock().unwrap();
+        assert_eq!(payloads.len(), 1);
+        assert!(payloads[0].contains("DurationSeconds=1800"));
+        assert!(payloads[0].contains("RoleArn=arn%3Aaws%3Aiam%3A%3A111122223333%3Arole%2Fdeploy"));
+        assert!(payloads[0].contains("SerialNumber=arn%3Aaws%3Aiam%3A%3A111122223333%3Amfa%2Fuser"));
+        assert!(payloads[0].contains("TokenCode=123456"));
+    }
+
+    #[tokio::test]
+    async fn assumes_profile_roles_in_their_region() {
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(&assume_role_response("2099-01-01T00:00:00Z"))
+            .with_request_checker(|request| {
+                assert_eq!(request.region, Region::EuWest1);
+                match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref payload)) => {
+                        let payload = String::from_utf8_lossy(payload);
+                        assert!(payload.contains("ExternalId=external"));
+                        assert!(payload.contains("RoleSessionName=session"));
+                    }
+                    _ => panic!("no payload"),
+                }
+            });
+        let role = ProfileRole {
+            profile: "deploy".to_owned(),
+            role_arn: "arn:aws:iam::111122223333:role/deploy".to_owned(),
+            role_session_name: Some("session".to_owned()),
+            external_id: Some("external".to_owned()),
+            region: Some("eu-west-1".to_owned()),
+            ..Default::default()
+        };
+        let source = AwsCredentials::new("source", "secret", None, None);
+        let creds = StsRoleAssumer::new_with(dispatcher)
+            .assume_role(source, &role)
+            .await
+            .unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+    }
+}
@@ -1,9 +1,13 @@
 mod credential;
 
 pub use self::credential::{
-    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
+    MfaTokenProvider, NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider,
+    StsRoleAssumer, StsSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
 };
 
 mod web_identity;
 pub use self::web_identity::*;
+
+mod saml;
+pub use self::saml::{SamlProvider, SamlRole};
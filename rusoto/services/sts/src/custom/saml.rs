@@ -0,0 +1,318 @@
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use futures::lock::Mutex;
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+use crate::custom::credential::{
+    expires_within, NewAwsCredsForStsCreds, StsSessionCredentialsClient,
+    DEFAULT_REFRESH_BUFFER_SECONDS,
+};
+use crate::{AssumeRoleWithSAMLRequest, StsClient};
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
+};
+
+/// Name of the SAML attribute listing the roles a federated user may assume.
+const ROLE_ATTRIBUTE: &str = "https://aws.amazon.com/SAML/Attributes/Role";
+
+/// A role granted by a SAML assertion, with the IAM SAML provider it trusts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SamlRole {
+    /// The ARN of the role, e.g. `arn:aws:iam::111122223333:role/Admin`.
+    pub role_arn: String,
+    /// The ARN of the SAML provider, e.g. `arn:aws:iam::111122223333:saml-provider/ADFS`.
+    pub principal_arn: String,
+}
+
+impl SamlRole {
+    /// The roles listed in the `https://aws.amazon.com/SAML/Attributes/Role` attribute of the
+    /// base64-encoded SAML response `assertion`.
+    pub fn from_assertion(assertion: &str) -> Result<Vec<SamlRole>, CredentialsError> {
+        let xml = base64::decode(assertion.trim())
+            .map_err(|e| CredentialsError::new(format!("Invalid SAML assertion: {}", e)))?;
+        let mut roles = Vec::new();
+        let mut in_role_attribute = false;
+        let mut in_value = false;
+        for event in EventReader::new(xml.as_slice()) {
+            match event
+                .map_err(|e| CredentialsError::new(format!("Invalid SAML assertion: {}", e)))?
+            {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => match name.local_name.as_str() {
+                    "Attribute" => {
+                        in_role_attribute = attributes
+                            .iter()
+                            .any(|a| a.name.local_name == "Name" && a.value == ROLE_ATTRIBUTE)
+                    }
+                    "AttributeValue" => in_value = in_role_attribute,
+                    _ => (),
+                },
+                XmlEvent::EndElement { name } => match name.local_name.as_str() {
+                    "Attribute" => in_role_attribute = false,
+                    "AttributeValue" => in_value = false,
+                    _ => (),
+                },
+                XmlEvent::Characters(value) if in_value => {
+                    if let Some(role) = SamlRole::from_attribute_value(&value) {
+                        roles.push(role);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(roles)
+    }
+
+    /// Parses the comma-separated ARNs of a role and a SAML provider, in either order.
+    fn from_attribute_value(value: &str) -> Option<SamlRole> {
+        let mut arns = value.split(',').map(str::trim);
+        let (first, second) = (arns.next()?, arns.next()?);
+        if arns.next().is_some() {
+            return None;
+        }
+        let (role_arn, principal_arn) = if first.contains(":saml-provider/") {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        Some(SamlRole {
+            role_arn: role_arn.to_owned(),
+            principal_arn: principal_arn.to_owned(),
+        })
+    }
+}
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that exchanges a
+/// SAML assertion for credentials by calling `AssumeRoleWithSAML`.
+///
+/// The assertion is the base64-encoded SAML response of the identity provider, e.g. ADFS. Pass a
+/// `Variable::dynamic` to obtain a fresh one each time the credentials are refreshed, shortly
+/// before they expire.
+///
+/// The role to assume is chosen from the roles the assertion grants: the one with the ARN set by
+/// `set_role_arn`, or the only one if there is just one.
+///
+/// See https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRoleWithSAML.html for more
+/// details.
+pub struct SamlProvider {
+    sts_client: Box<dyn StsSessionCredentialsClient + Send + Sync>,
+    saml_assertion: Variable<Secret, CredentialsError>,
+    role_arn: Option<String>,
+    principal_arn: Option<String>,
+    session_duration: Option<Duration>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl SamlProvider {
+    /// Create a provider exchanging the assertions of `saml_assertion` with `sts_client`.
+    ///
+    /// `AssumeRoleWithSAML` requests do not need to be signed, so `sts_client` can be created with
+    /// `Client::new_not_signing`.
+    pub fn new<A>(sts_client: StsClient, saml_assertion: A) -> SamlProvider
+    where
+        A: Into<Variable<Secret, CredentialsError>>,
+    {
+        SamlProvider {
+            sts_client: Box::new(sts_client),
+            saml_assertion: saml_assertion.into(),
+            role_arn: None,
+            principal_arn: None,
+            session_duration: None,
+            credentials: Default::default(),
+        }
+    }
+
+    /// Assume the role with the ARN `role_arn`, which the assertion must grant.
+    pub fn set_role_arn<S: Into<String>>(&mut self, role_arn: S) {
+        self.role_arn = Some(role_arn.into());
+    }
+
+    /// Only assume roles trusting the SAML provider with the ARN `principal_arn`.
+    pub fn set_principal_arn<S: Into<String>>(&mut self, principal_arn: S) {
+        self.principal_arn = Some(principal_arn.into());
+    }
+
+    /// Set the duration of the session. Default 1 hour.
+    pub fn set_session_duration(&mut self, session_duration: Duration) {
+        self.session_duration = Some(session_duration);
+    }
+
+    /// Choose the role to assume among those `assertion` grants.
+    fn select_role(&self, assertion: &str) -> Result<SamlRole, CredentialsError> {
+        if let (Some(role_arn), Some(principal_arn)) = (&self.role_arn, &self.principal_arn) {
+            return Ok(SamlRole {
+                role_arn: role_arn.clone(),
+                principal_arn: principal_arn.clone(),
+            });
+        }
+        let mut roles: Vec<SamlRole> = SamlRole::from_assertion(assertion)?
+            .into_iter()
+            .filter(|role| {
+                self.role_arn
+                    .as_ref()
+                    .map_or(true, |arn| *arn == role.role_arn)
+            })
+            .filter(|role| {
+                self.principal_arn
+                    .as_ref()
+                    .map_or(true, |arn| *arn == role.principal_arn)
+            })
+            .collect();
+        match roles.len() {
+            0 => Err(CredentialsError::new(
+                "The SAML assertion grants no matching role",
+            )),
+            1 => Ok(roles.remove(0)),
+            _ => Err(CredentialsError::new(format!(
+                "The SAML assertion grants several roles, choose one with set_role_arn: {}",
+                roles
+                    .iter()
+                    .map(|role| role.role_arn.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    async fn assume_role_with_saml(&self) -> Result<AwsCredentials, CredentialsError> {
+        let assertion = self.saml_assertion.resolve()?;
+        let role = self.select_role(assertion.as_ref())?;
+        let request = AssumeRoleWithSAMLRequest {
+            duration_seconds: self.session_duration.map(|d| d.num_seconds()),
+            principal_arn: role.principal_arn,
+            role_arn: role.role_arn,
+            saml_assertion: assertion.as_ref().to_owned(),
+            ..Default::default()
+        };
+        let response = self
+            .sts_client
+            .assume_role_with_saml(request)
+            .await
+            .map_err(|e| CredentialsError::new(format!("AssumeRoleWithSAML error: {:?}", e)))?;
+        let creds = response.credentials.ok_or_else(|| {
+            CredentialsError::new("No credentials found in AssumeRoleWithSAMLResponse")
+        })?;
+        AwsCredentials::new_for_credentials(creds)
+    }
+}
+
+impl fmt::Debug for SamlProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SamlProvider")
+            .field("saml_assertion", &self.saml_assertion)
+            .field("role_arn", &self.role_arn)
+            .field("principal_arn", &self.principal_arn)
+            .field("session_duration", &self.session_duration)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for SamlProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref creds) = *cached {
+            if !expires_within(creds, Duration::seconds(DEFAULT_REFRESH_BUFFER_SECONDS)) {
+                return Ok(creds.clone());
+            }
+        }
+        let creds = self.assume_role_with_saml().await?;
+        *cached = Some(creds.clone());
+        Ok(creds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    const ADMIN: &str = "arn:aws:iam::111122223333:role/Admin";
+    const READ_ONLY: &str = "arn:aws:iam::111122223333:role/ReadOnly";
+    const ADFS: &str = "arn:aws:iam::111122223333:saml-provider/ADFS";
+
+    fn assertion(roles: &[&str]) -> String {
+        let values: String = roles
+            .iter()
+            .map(|role| format!("<saml:AttributeValue>{}</saml:AttributeValue>", role))
+            .collect();
+        let xml = format!(
+            "<samlp:Response xmlns:samlp=\"urn:oasis:names:tc:SAML:2.0:protocol\" \
+             xmlns:saml=\"urn:oasis:names:tc:SAML:2.0:assertion\"><saml:Assertion>\
+             <saml:AttributeStatement>\
+             <saml:Attribute Name=\"https://aws.amazon.com/SAML/Attributes/RoleSessionName\">\
+             <saml:AttributeValue>user@example.com</saml:AttributeValue></saml:Attribute>\
+             <saml:Attribute Name=\"https://aws.amazon.com/SAML/Attributes/Role\">{}\
+             </saml:Attribute></saml:AttributeStatement></saml:Assertion></samlp:Response>",
+            values
+        );
+        base64::encode(&xml)
+    }
+
+    fn provider(assertion: String) -> SamlProvider {
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(
+                "<AssumeRoleWithSAMLResponse><AssumeRoleWithSAMLResult><Credentials>\
+                 <AccessKeyId>key</AccessKeyId><SecretAccessKey>secret</SecretAccessKey>\
+                 <SessionToken>token</SessionToken><Expiration>2099-01-01T00:00:00Z</Expiration>\
+                 </Credentials></AssumeRoleWithSAMLResult>\
+                 <ResponseMetadata><RequestId>id</RequestId></ResponseMetadata>\
+                 </AssumeRoleWithSAMLResponse>",
+            )
+            .with_request_checker(|request| {
+                let payload = match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref payload)) => payload,
+                    _ => panic!("no payload"),
+                };
+                let payload = String::from_utf8_lossy(payload);
+                assert!(
+                    payload.contains("RoleArn=arn%3Aaws%3Aiam%3A%3A111122223333%3Arole%2FAdmin")
+                );
+                assert!(payload.contains("PrincipalArn="));
+            });
+        let sts = StsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        SamlProvider::new(sts, Secret::from(assertion))
+    }
+
+    #[test]
+    fn parses_roles_of_assertion() {
+        let roles =
+            SamlRole::from_assertion(&assertion(&[&format!("{},{}", ADFS, ADMIN)])).unwrap();
+        assert_eq!(
+            roles,
+            vec![SamlRole {
+                role_arn: ADMIN.to_owned(),
+                principal_arn: ADFS.to_owned(),
+            }]
+        );
+        assert!(SamlRole::from_assertion("not base64!").is_err());
+    }
+
+    #[tokio::test]
+    async fn assumes_the_only_role() {
+        let provider = provider(assertion(&[&format!("{},{}", ADMIN, ADFS)]));
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+    }
+
+    #[tokio::test]
+    async fn selects_among_several_roles() {
+        let assertion = assertion(&[
+            &format!("{},{}", READ_ONLY, ADFS),
+            &format!("{},{}", ADMIN, ADFS),
+        ]);
+        let mut provider = provider(assertion);
+        let err = provider.credentials().await.unwrap_err();
+        assert!(err.message.contains("several roles"), "{}", err);
+
+        provider.set_role_arn(ADMIN);
+        assert!(provider.credentials().await.is_ok());
+    }
+}
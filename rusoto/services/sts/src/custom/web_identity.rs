@@ -1,4 +1,10 @@
-use crate::custom::credential::NewAwsCredsForStsCreds;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::custom::credential::{
+    expires_within, NewAwsCredsForStsCreds, DEFAULT_REFRESH_BUFFER_SECONDS,
+};
 use crate::{AssumeRoleWithWebIdentityRequest, Sts, StsClient};
 use rusoto_core::credential::{
     AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
@@ -7,6 +13,8 @@ use rusoto_core::request::HttpClient;
 use rusoto_core::{Client, Region};
 
 use async_trait::async_trait;
+use chrono::Duration;
+use futures::lock::Mutex;
 
 const AWS_WEB_IDENTITY_TOKEN_FILE: &str = "AWS_WEB_IDENTITY_TOKEN_FILE";
 
@@ -18,7 +26,10 @@ const AWS_ROLE_SESSION_NAME: &str = "AWS_ROLE_SESSION_NAME";
 ///
 /// See https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRoleWithWebIdentity.html for
 /// more details.
-#[derive(Debug, Clone)]
+///
+/// The credentials are cached, and the token is resolved again to refresh them shortly before
+/// they expire, so a token file rotated in the meantime is picked up.
+#[derive(Clone)]
 pub struct WebIdentityProvider {
     /// The OAuth 2.0 access token or OpenID Connect ID token that is provided by the identity provider.
     /// Your application must get this token by authenticating the user who is using your application
@@ -31,6 +42,19 @@ pub struct WebIdentityProvider {
     /// that your application will use are associated with that user. This session name is included as part
     /// of the ARN and assumed role ID in the AssumedRoleUser response element.
     pub role_session_name: Variable<String, CredentialsError>,
+    sts: Option<StsClient>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl fmt::Debug for WebIdentityProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebIdentityProvider")
+            .field("web_identity_token", &self.web_identity_token)
+            .field("role_arn", &self.role_arn)
+            .field("role_session_name", &self.role_session_name)
+            .field("sts", &self.sts.is_some())
+            .finish()
+    }
 }
 
 impl WebIdentityProvider {
@@ -47,6 +71,8 @@ impl WebIdentityProvider {
             role_session_name: role_session_name
                 .map(|v| v.into())
                 .unwrap_or_else(|| Variable::with_value(Self::create_session_name())),
+            sts: None,
+            credentials: Default::default(),
         }
     }
 
@@ -56,16 +82,26 @@ impl WebIdentityProvider {
     /// - `AWS_ROLE_ARN` ARN of the role to assume.
     /// - `AWS_ROLE_SESSION_NAME` (optional) name applied to the assume-role session.
     ///
+    /// The token file is read again each time the credentials are refreshed.
+    ///
     /// See https://docs.aws.amazon.com/eks/latest/userguide/iam-roles-for-service-accounts-technical-overview.html
     /// for more information about how IAM Roles for Kubernetes Service Accounts works.
     pub fn from_k8s_env() -> Self {
         Self::_from_k8s_env(
             Variable::from_env_var(AWS_WEB_IDENTITY_TOKEN_FILE),
             Variable::from_env_var(AWS_ROLE_ARN),
-            Some(Variable::from_env_var(AWS_ROLE_SESSION_NAME)),
+            Some(Variable::dynamic(|| {
+                Ok(env::var(AWS_ROLE_SESSION_NAME).unwrap_or_else(|_| Self::create_session_name()))
+            })),
         )
     }
 
+    /// Send `AssumeRoleWithWebIdentity` requests with `sts`, instead of a client that doesn't sign
+    /// requests in the default region.
+    pub fn set_sts_client(&mut self, sts: StsClient) {
+        self.sts = Some(sts);
+    }
+
     /// Used by unit testing
     pub(crate) fn _from_k8s_env(
         token_file: Variable<String, CredentialsError>,
@@ -99,16 +135,35 @@ impl WebIdentityProvider {
 #[async_trait]
 impl ProvideAwsCredentials for WebIdentityProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let http_client = match HttpClient::new() {
-            Ok(c) => c,
-            Err(e) => return Err(CredentialsError::new(e)),
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref creds) = *cached {
+            if !expires_within(creds, Duration::seconds(DEFAULT_REFRESH_BUFFER_SECONDS)) {
+                return Ok(creds.clone());
+            }
+        }
+        let creds = self.assume_role_with_web_identity().await?;
+        *cached = Some(creds.clone());
+        Ok(creds)
+    }
+}
+
+impl WebIdentityProvider {
+    async fn assume_role_with_web_identity(&self) -> Result<AwsCredentials, CredentialsError> {
+        let sts = match self.sts {
+            Some(ref sts) => sts.clone(),
+            None => {
+                let http_client = match HttpClient::new() {
+                    Ok(c) => c,
+                    Err(e) => return Err(CredentialsError::new(e)),
+                };
+                let client = Client::new_not_signing(http_client);
+                StsClient::new_with_client(client, Region::default())
+            }
         };
-        let client = Client::new_not_signing(http_client);
-        let sts = StsClient::new_with_client(client, Region::default());
         let mut req = AssumeRoleWithWebIdentityRequest::default();
 
         req.role_arn = self.role_arn.resolve()?;
-        req.web_identity_token = self.web_identity_token.resolve()?.to_string();
+        req.web_identity_token = self.web_identity_token.resolve()?.as_ref().to_owned();
         req.role_session_name = self.role_session_name.resolve()?;
 
         let assume_role = sts.assume_role_with_web_identity(req).await;
@@ -131,6 +186,11 @@ mod tests {
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    use chrono::Utc;
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::sync::Mutex;
+
     #[test]
     fn api_ergonomy() {
         WebIdentityProvider::new(Secret::from("".to_string()), "", Some("".to_string()));
@@ -154,4 +214,52 @@ mod tests {
         assert_eq!(token.as_ref(), TOKEN_VALUE);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn rereads_token_file_on_refresh() -> Result<(), CredentialsError> {
+        let file = NamedTempFile::new()?;
+        std::fs::write(file.path(), "first")?;
+        let expiration = (Utc::now() + Duration::minutes(1)).to_rfc3339();
+        let body = format!(
+            "<AssumeRoleWithWebIdentityResponse><AssumeRoleWithWebIdentityResult><Credentials>\
+             <AccessKeyId>key</AccessKeyId><SecretAccessKey>secret</SecretAccessKey>\
+             <SessionToken>token</SessionToken><Expiration>{}</Expiration>\
+             </Credentials></AssumeRoleWithWebIdentityResult>\
+             <ResponseMetadata><RequestId>id</RequestId></ResponseMetadata>\
+             </AssumeRoleWithWebIdentityResponse>",
+            expiration
+        );
+        let payloads = Arc::new(Mutex::new(Vec::new()));
+        let seen = payloads.clone();
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(&body)
+            .with_request_checker(move |request| {
+                if let Some(SignedRequestPayload::Buffer(ref payload)) = request.payload {
+                    seen.lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(payload).into_owned());
+                }
+            });
+        let mut p = WebIdentityProvider::_from_k8s_env(
+            Variable::with_value(file.path().to_string_lossy().to_string()),
+            Variable::with_value("role".to_string()),
+            None,
+        );
+        p.set_sts_client(StsClient::new_with(
+            dispatcher,
+            MockCredentialsProvider,
+            Region::UsEast1,
+        ));
+
+        let creds = p.credentials().await?;
+        assert_eq!(creds.aws_access_key_id(), "key");
+        std::fs::write(file.path(), "second")?;
+        p.credentials().await?;
+
+        let payloads = payloads.lock().unwrap();
+        assert_eq!(payloads.len(), 2);
+        assert!(payloads[0].contains("WebIdentityToken=first"));
+        assert!(payloads[1].contains("WebIdentityToken=second"));
+        Ok(())
+    }
 }
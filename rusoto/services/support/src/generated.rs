@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -721,6 +721,7 @@ pub struct TrustedAdvisorResourcesSummary {
 
 /// Errors returned by AddAttachmentsToSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddAttachmentsToSetError {
     /// <p>The limit for the number of attachment sets created in a short period of time has been exceeded.</p>
     AttachmentLimitExceeded(String),
@@ -732,6 +733,8 @@ pub enum AddAttachmentsToSetError {
     AttachmentSetSizeLimitExceeded(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddAttachmentsToSetError {
@@ -739,32 +742,37 @@ impl AddAttachmentsToSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentLimitExceeded" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttachmentsToSetError::AttachmentLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetExpired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttachmentsToSetError::AttachmentSetExpired(err.msg),
+                        &res,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttachmentsToSetError::AttachmentSetIdNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "AttachmentSetSizeLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddAttachmentsToSetError::AttachmentSetSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddAttachmentsToSetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddAttachmentsToSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -781,12 +789,14 @@ impl fmt::Display for AddAttachmentsToSetError {
                 write!(f, "{}", cause)
             }
             AddAttachmentsToSetError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AddAttachmentsToSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddAttachmentsToSetError {}
 /// Errors returned by AddCommunicationToCase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddCommunicationToCaseError {
     /// <p>The expiration time of the attachment set has passed. The set expires 1 hour after it is created.</p>
     AttachmentSetExpired(String),
@@ -796,6 +806,8 @@ pub enum AddCommunicationToCaseError {
     CaseIdNotFound(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddCommunicationToCaseError {
@@ -803,27 +815,31 @@ impl AddCommunicationToCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::AttachmentSetExpired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCommunicationToCaseError::AttachmentSetExpired(err.msg),
+                        &res,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddCommunicationToCaseError::AttachmentSetIdNotFound(err.msg),
+                        &res,
                     )
                 }
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::CaseIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCommunicationToCaseError::CaseIdNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCommunicationToCaseError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddCommunicationToCaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -839,12 +855,14 @@ impl fmt::Display for AddCommunicationToCaseError {
             }
             AddCommunicationToCaseError::CaseIdNotFound(ref cause) => write!(f, "{}", cause),
             AddCommunicationToCaseError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AddCommunicationToCaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddCommunicationToCaseError {}
 /// Errors returned by CreateCase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCaseError {
     /// <p>The expiration time of the attachment set has passed. The set expires 1 hour after it is created.</p>
     AttachmentSetExpired(String),
@@ -854,6 +872,8 @@ pub enum CreateCaseError {
     CaseCreationLimitExceeded(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCaseError {
@@ -861,21 +881,31 @@ impl CreateCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetExpired(err.msg))
+                    return RusotoError::service(
+                        CreateCaseError::AttachmentSetExpired(err.msg),
+                        &res,
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetIdNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateCaseError::AttachmentSetIdNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CaseCreationLimitExceeded" => {
-                    return RusotoError::Service(CreateCaseError::CaseCreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCaseError::CaseCreationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCaseError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateCaseError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -889,12 +919,14 @@ impl fmt::Display for CreateCaseError {
             CreateCaseError::AttachmentSetIdNotFound(ref cause) => write!(f, "{}", cause),
             CreateCaseError::CaseCreationLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateCaseError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateCaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCaseError {}
 /// Errors returned by DescribeAttachment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAttachmentError {
     /// <p>An attachment with the specified ID could not be found.</p>
     AttachmentIdNotFound(String),
@@ -902,6 +934,8 @@ pub enum DescribeAttachmentError {
     DescribeAttachmentLimitExceeded(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAttachmentError {
@@ -909,22 +943,25 @@ impl DescribeAttachmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentIdNotFound" => {
-                    return RusotoError::Service(DescribeAttachmentError::AttachmentIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAttachmentError::AttachmentIdNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "DescribeAttachmentLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAttachmentError::DescribeAttachmentLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAttachmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAttachmentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAttachmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -939,17 +976,21 @@ impl fmt::Display for DescribeAttachmentError {
                 write!(f, "{}", cause)
             }
             DescribeAttachmentError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeAttachmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAttachmentError {}
 /// Errors returned by DescribeCases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCasesError {
     /// <p>The requested <code>caseId</code> could not be located.</p>
     CaseIdNotFound(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCasesError {
@@ -957,13 +998,16 @@ impl DescribeCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCasesError::CaseIdNotFound(err.msg))
+                    return RusotoError::service(DescribeCasesError::CaseIdNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCasesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeCasesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -975,17 +1019,21 @@ impl fmt::Display for DescribeCasesError {
         match *self {
             DescribeCasesError::CaseIdNotFound(ref cause) => write!(f, "{}", cause),
             DescribeCasesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeCasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCasesError {}
 /// Errors returned by DescribeCommunications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCommunicationsError {
     /// <p>The requested <code>caseId</code> could not be located.</p>
     CaseIdNotFound(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCommunicationsError {
@@ -993,17 +1041,19 @@ impl DescribeCommunicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCommunicationsError::CaseIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCommunicationsError::CaseIdNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCommunicationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCommunicationsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCommunicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1015,15 +1065,19 @@ impl fmt::Display for DescribeCommunicationsError {
         match *self {
             DescribeCommunicationsError::CaseIdNotFound(ref cause) => write!(f, "{}", cause),
             DescribeCommunicationsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeCommunicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCommunicationsError {}
 /// Errors returned by DescribeServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServicesError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServicesError {
@@ -1031,12 +1085,13 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeServicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeServicesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1047,15 +1102,19 @@ impl fmt::Display for DescribeServicesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeServicesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServicesError {}
 /// Errors returned by DescribeSeverityLevels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSeverityLevelsError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSeverityLevelsError {
@@ -1063,12 +1122,13 @@ impl DescribeSeverityLevelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSeverityLevelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSeverityLevelsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSeverityLevelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1079,15 +1139,19 @@ impl fmt::Display for DescribeSeverityLevelsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSeverityLevelsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeSeverityLevelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSeverityLevelsError {}
 /// Errors returned by DescribeTrustedAdvisorCheckRefreshStatuses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrustedAdvisorCheckRefreshStatusesError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrustedAdvisorCheckRefreshStatusesError {
@@ -1097,14 +1161,20 @@ impl DescribeTrustedAdvisorCheckRefreshStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTrustedAdvisorCheckRefreshStatusesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1117,15 +1187,21 @@ impl fmt::Display for DescribeTrustedAdvisorCheckRefreshStatusesError {
             DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTrustedAdvisorCheckRefreshStatusesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTrustedAdvisorCheckRefreshStatusesError {}
 /// Errors returned by DescribeTrustedAdvisorCheckResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrustedAdvisorCheckResultError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrustedAdvisorCheckResultError {
@@ -1135,12 +1211,18 @@ impl DescribeTrustedAdvisorCheckResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTrustedAdvisorCheckResultError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTrustedAdvisorCheckResultError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1153,15 +1235,21 @@ impl fmt::Display for DescribeTrustedAdvisorCheckResultError {
             DescribeTrustedAdvisorCheckResultError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTrustedAdvisorCheckResultError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTrustedAdvisorCheckResultError {}
 /// Errors returned by DescribeTrustedAdvisorCheckSummaries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrustedAdvisorCheckSummariesError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrustedAdvisorCheckSummariesError {
@@ -1171,12 +1259,18 @@ impl DescribeTrustedAdvisorCheckSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTrustedAdvisorCheckSummariesError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTrustedAdvisorCheckSummariesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1189,15 +1283,21 @@ impl fmt::Display for DescribeTrustedAdvisorCheckSummariesError {
             DescribeTrustedAdvisorCheckSummariesError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTrustedAdvisorCheckSummariesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTrustedAdvisorCheckSummariesError {}
 /// Errors returned by DescribeTrustedAdvisorChecks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrustedAdvisorChecksError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrustedAdvisorChecksError {
@@ -1207,12 +1307,18 @@ impl DescribeTrustedAdvisorChecksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTrustedAdvisorChecksError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTrustedAdvisorChecksError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1225,15 +1331,19 @@ impl fmt::Display for DescribeTrustedAdvisorChecksError {
             DescribeTrustedAdvisorChecksError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTrustedAdvisorChecksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrustedAdvisorChecksError {}
 /// Errors returned by RefreshTrustedAdvisorCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RefreshTrustedAdvisorCheckError {
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RefreshTrustedAdvisorCheckError {
@@ -1243,12 +1353,15 @@ impl RefreshTrustedAdvisorCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RefreshTrustedAdvisorCheckError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RefreshTrustedAdvisorCheckError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1261,17 +1374,21 @@ impl fmt::Display for RefreshTrustedAdvisorCheckError {
             RefreshTrustedAdvisorCheckError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RefreshTrustedAdvisorCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RefreshTrustedAdvisorCheckError {}
 /// Errors returned by ResolveCase
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResolveCaseError {
     /// <p>The requested <code>caseId</code> could not be located.</p>
     CaseIdNotFound(String),
     /// <p>An internal server error occurred.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResolveCaseError {
@@ -1279,13 +1396,16 @@ impl ResolveCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(ResolveCaseError::CaseIdNotFound(err.msg))
+                    return RusotoError::service(ResolveCaseError::CaseIdNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResolveCaseError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ResolveCaseError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResolveCaseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1297,6 +1417,7 @@ impl fmt::Display for ResolveCaseError {
         match *self {
             ResolveCaseError::CaseIdNotFound(ref cause) => write!(f, "{}", cause),
             ResolveCaseError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ResolveCaseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1408,6 +1529,7 @@ impl AWSSupportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AWSSupportClient {
         AWSSupportClient {
             client: Client::shared(),
@@ -1433,241 +1555,518 @@ impl AWSSupportClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportClient {
         AWSSupportClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AWSSupportClient> {
+        rusoto_core::ClientBuilder::new(AWSSupportClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AWSSupportClient {
+        AWSSupportClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AWSSupport for AWSSupportClient {
-    /// <p>Adds one or more attachments to an attachment set. If an <code>attachmentSetId</code> is not specified, a new attachment set is created, and the ID of the set is returned in the response. If an <code>attachmentSetId</code> is specified, the attachments are added to the specified set, if it exists.</p> <p>An attachment set is a temporary container for attachments that are to be added to a case or case communication. The set is available for one hour after it is created; the <code>expiryTime</code> returned in the response indicates when the set expires. The maximum number of attachments in a set is 3, and the maximum size of any attachment in the set is 5 MB.</p>
-    async fn add_attachments_to_set(
+impl AWSSupportClient {
+    /// Calls `add_attachments_to_set`, also returning the metadata of the response.
+    pub async fn add_attachments_to_set_with_metadata(
         &self,
         input: AddAttachmentsToSetRequest,
-    ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
+    ) -> Result<
+        (AddAttachmentsToSetResponse, ResponseMetadata),
+        RusotoError<AddAttachmentsToSetError>,
+    > {
+        let request = self.build_add_attachments_to_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddAttachmentsToSetResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddAttachmentsToSetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_attachments_to_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_attachments_to_set(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddAttachmentsToSetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_attachments_to_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_attachments_to_set_request(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddAttachmentsToSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddAttachmentsToSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_communication_to_case`, also returning the metadata of the response.
+    pub async fn add_communication_to_case_with_metadata(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> Result<
+        (AddCommunicationToCaseResponse, ResponseMetadata),
+        RusotoError<AddCommunicationToCaseError>,
+    > {
+        let request = self.build_add_communication_to_case_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddAttachmentsToSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddCommunicationToCaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddAttachmentsToSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddCommunicationToCaseError::from_response(response))
         }
     }
 
-    /// <p>Adds additional customer communication to an AWS Support case. You use the <code>caseId</code> value to identify the case to add communication to. You can list a set of email addresses to copy on the communication using the <code>ccEmailAddresses</code> value. The <code>communicationBody</code> value contains the text of the communication.</p> <p>The response indicates the success or failure of the request.</p> <p>This operation implements a subset of the features of the AWS Support Center.</p>
-    async fn add_communication_to_case(
+    /// Presigns the request `add_communication_to_case` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_communication_to_case(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddCommunicationToCaseRequest,
-    ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_communication_to_case_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_communication_to_case_request(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddCommunicationToCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddCommunicationToCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_case`, also returning the metadata of the response.
+    pub async fn create_case_with_metadata(
+        &self,
+        input: CreateCaseRequest,
+    ) -> Result<(CreateCaseResponse, ResponseMetadata), RusotoError<CreateCaseError>> {
+        let request = self.build_create_case_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddCommunicationToCaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddCommunicationToCaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCaseError::from_response(response))
         }
     }
 
-    /// <p>Creates a new case in the AWS Support Center. This operation is modeled on the behavior of the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. Its parameters require you to specify the following information:</p> <ul> <li> <p> <b>issueType.</b> The type of issue for the case. You can specify either "customer-service" or "technical." If you do not indicate a value, the default is "technical."</p> <note> <p>Service limit increases are not supported by the Support API; you must submit service limit increase requests in <a href="https://console.aws.amazon.com/support">Support Center</a>.</p> <p>The <code>caseId</code> is not the <code>displayId</code> that appears in <a href="https://console.aws.amazon.com/support">Support Center</a>. You can use the <a>DescribeCases</a> API to get the <code>displayId</code>.</p> </note> </li> <li> <p> <b>serviceCode.</b> The code for an AWS service. You can get the possible <code>serviceCode</code> values by calling <a>DescribeServices</a>.</p> </li> <li> <p> <b>categoryCode.</b> The category for the service defined for the <code>serviceCode</code> value. You also get the category code for a service by calling <a>DescribeServices</a>. Each AWS service defines its own set of category codes.</p> </li> <li> <p> <b>severityCode.</b> A value that indicates the urgency of the case, which in turn determines the response time according to your service level agreement with AWS Support. You can get the possible <code>severityCode</code> values by calling <a>DescribeSeverityLevels</a>. For more information about the meaning of the codes, see <a>SeverityLevel</a> and <a href="https://docs.aws.amazon.com/awssupport/latest/user/getting-started.html#choosing-severity">Choosing a Severity</a>.</p> </li> <li> <p> <b>subject.</b> The <b>Subject</b> field on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> </li> <li> <p> <b>communicationBody.</b> The <b>Description</b> field on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> </li> <li> <p> <b>attachmentSetId.</b> The ID of a set of attachments that has been created by using <a>AddAttachmentsToSet</a>.</p> </li> <li> <p> <b>language.</b> The human language in which AWS Support handles the case. English and Japanese are currently supported.</p> </li> <li> <p> <b>ccEmailAddresses.</b> The AWS Support Center <b>CC</b> field on the <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. You can list email addresses to be copied on any correspondence about the case. The account that opens the case is already identified by passing the AWS Credentials in the HTTP POST method or in a method or function call from one of the programming languages supported by an <a href="http://aws.amazon.com/tools/">AWS SDK</a>. </p> </li> </ul> <note> <p>To add additional communication or attachments to an existing case, use <a>AddCommunicationToCase</a>.</p> </note> <p>A successful <a>CreateCase</a> request returns an AWS Support case number. Case numbers are used by the <a>DescribeCases</a> operation to retrieve existing AWS Support cases.</p>
-    async fn create_case(
+    /// Presigns the request `create_case` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_case(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCaseRequest,
-    ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_case_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_case_request(&self, input: CreateCaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("CreateCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.CreateCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_attachment`, also returning the metadata of the response.
+    pub async fn describe_attachment_with_metadata(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> Result<(DescribeAttachmentResponse, ResponseMetadata), RusotoError<DescribeAttachmentError>>
+    {
+        let request = self.build_describe_attachment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateCaseResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAttachmentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCaseError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAttachmentError::from_response(response))
         }
     }
 
-    /// <p>Returns the attachment that has the specified ID. Attachment IDs are generated by the case management system when you add an attachment to a case or case communication. Attachment IDs are returned in the <a>AttachmentDetails</a> objects that are returned by the <a>DescribeCommunications</a> operation.</p>
-    async fn describe_attachment(
+    /// Presigns the request `describe_attachment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_attachment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAttachmentRequest,
-    ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_attachment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_attachment_request(&self, input: DescribeAttachmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeAttachment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeAttachment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cases`, also returning the metadata of the response.
+    pub async fn describe_cases_with_metadata(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> Result<(DescribeCasesResponse, ResponseMetadata), RusotoError<DescribeCasesError>> {
+        let request = self.build_describe_cases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAttachmentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCasesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAttachmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCasesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a list of cases that you specify by passing one or more case IDs. In addition, you can filter the cases by date by setting values for the <code>afterTime</code> and <code>beforeTime</code> request parameters. You can set values for the <code>includeResolvedCases</code> and <code>includeCommunications</code> request parameters to control how much information is returned.</p> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request for data might cause an error.</p> <p>The response returns the following in JSON format:</p> <ul> <li> <p>One or more <a>CaseDetails</a> data types.</p> </li> <li> <p>One or more <code>nextToken</code> values, which specify where to paginate the returned records represented by the <code>CaseDetails</code> objects.</p> </li> </ul></p>
-    async fn describe_cases(
+    /// Presigns the request `describe_cases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCasesRequest,
-    ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cases_request(&self, input: DescribeCasesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_communications`, also returning the metadata of the response.
+    pub async fn describe_communications_with_metadata(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> Result<
+        (DescribeCommunicationsResponse, ResponseMetadata),
+        RusotoError<DescribeCommunicationsError>,
+    > {
+        let request = self.build_describe_communications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeCasesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCommunicationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCommunicationsError::from_response(response))
         }
     }
 
-    /// <p>Returns communications (and attachments) for one or more support cases. You can use the <code>afterTime</code> and <code>beforeTime</code> parameters to filter by date. You can use the <code>caseId</code> parameter to restrict the results to a particular case.</p> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request for data might cause an error.</p> <p>You can use the <code>maxResults</code> and <code>nextToken</code> parameters to control the pagination of the result set. Set <code>maxResults</code> to the number of cases you want displayed on each page, and use <code>nextToken</code> to specify the resumption of pagination.</p>
-    async fn describe_communications(
+    /// Presigns the request `describe_communications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_communications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCommunicationsRequest,
-    ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_communications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_communications_request(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCommunications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCommunications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_services`, also returning the metadata of the response.
+    pub async fn describe_services_with_metadata(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<(DescribeServicesResponse, ResponseMetadata), RusotoError<DescribeServicesError>>
+    {
+        let request = self.build_describe_services_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCommunicationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCommunicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServicesError::from_response(response))
         }
     }
 
-    /// <p>Returns the current list of AWS services and a list of service categories that applies to each one. You then use service names and categories in your <a>CreateCase</a> requests. Each AWS service has its own set of categories.</p> <p>The service codes and category codes correspond to the values that are displayed in the <b>Service</b> and <b>Category</b> drop-down lists on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. The values in those fields, however, do not necessarily match the service codes and categories returned by the <code>DescribeServices</code> request. Always use the service codes and categories obtained programmatically. This practice ensures that you always have the most recent set of service and category codes.</p>
-    async fn describe_services(
+    /// Presigns the request `describe_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServicesRequest,
-    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_services_request(&self, input: DescribeServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_severity_levels`, also returning the metadata of the response.
+    pub async fn describe_severity_levels_with_metadata(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> Result<
+        (DescribeSeverityLevelsResponse, ResponseMetadata),
+        RusotoError<DescribeSeverityLevelsError>,
+    > {
+        let request = self.build_describe_severity_levels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSeverityLevelsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSeverityLevelsError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of severity levels that you can assign to an AWS Support case. The severity level for a case is also a field in the <a>CaseDetails</a> data type included in any <a>CreateCase</a> request.</p>
-    async fn describe_severity_levels(
+    /// Presigns the request `describe_severity_levels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_severity_levels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSeverityLevelsRequest,
-    ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_severity_levels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_severity_levels_request(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeSeverityLevels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeSeverityLevels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trusted_advisor_check_refresh_statuses`, also returning the metadata of the response.
+    pub async fn describe_trusted_advisor_check_refresh_statuses_with_metadata(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> Result<
+        (
+            DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+    > {
+        let request = self.build_describe_trusted_advisor_check_refresh_statuses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSeverityLevelsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrustedAdvisorCheckRefreshStatusesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSeverityLevelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrustedAdvisorCheckRefreshStatusesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the refresh status of the Trusted Advisor checks that have the specified check IDs. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <note> <p>Some checks are refreshed automatically, and their refresh statuses cannot be retrieved by using this operation. Use of the <code>DescribeTrustedAdvisorCheckRefreshStatuses</code> operation for these checks causes an <code>InvalidParameterValue</code> error.</p> </note></p>
-    async fn describe_trusted_advisor_check_refresh_statuses(
+    /// Presigns the request `describe_trusted_advisor_check_refresh_statuses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trusted_advisor_check_refresh_statuses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
-    ) -> Result<
-        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
-        RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trusted_advisor_check_refresh_statuses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trusted_advisor_check_refresh_statuses_request(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckRefreshStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1677,31 +2076,60 @@ impl AWSSupport for AWSSupportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trusted_advisor_check_result`, also returning the metadata of the response.
+    pub async fn describe_trusted_advisor_check_result_with_metadata(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> Result<
+        (DescribeTrustedAdvisorCheckResultResponse, ResponseMetadata),
+        RusotoError<DescribeTrustedAdvisorCheckResultError>,
+    > {
+        let request = self.build_describe_trusted_advisor_check_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrustedAdvisorCheckRefreshStatusesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrustedAdvisorCheckResultResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrustedAdvisorCheckRefreshStatusesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrustedAdvisorCheckResultError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns the results of the Trusted Advisor check that has the specified check ID. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <p>The response contains a <a>TrustedAdvisorCheckResult</a> object, which contains these three objects:</p> <ul> <li> <p> <a>TrustedAdvisorCategorySpecificSummary</a> </p> </li> <li> <p> <a>TrustedAdvisorResourceDetail</a> </p> </li> <li> <p> <a>TrustedAdvisorResourcesSummary</a> </p> </li> </ul> <p>In addition, the response contains these fields:</p> <ul> <li> <p> <b>status.</b> The alert status of the check: &quot;ok&quot; (green), &quot;warning&quot; (yellow), &quot;error&quot; (red), or &quot;not_available&quot;.</p> </li> <li> <p> <b>timestamp.</b> The time of the last refresh of the check.</p> </li> <li> <p> <b>checkId.</b> The unique identifier for the check.</p> </li> </ul></p>
-    async fn describe_trusted_advisor_check_result(
+    /// Presigns the request `describe_trusted_advisor_check_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trusted_advisor_check_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrustedAdvisorCheckResultRequest,
-    ) -> Result<
-        DescribeTrustedAdvisorCheckResultResponse,
-        RusotoError<DescribeTrustedAdvisorCheckResultError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trusted_advisor_check_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trusted_advisor_check_result_request(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1711,33 +2139,63 @@ impl AWSSupport for AWSSupportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trusted_advisor_check_summaries`, also returning the metadata of the response.
+    pub async fn describe_trusted_advisor_check_summaries_with_metadata(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> Result<
+        (
+            DescribeTrustedAdvisorCheckSummariesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+    > {
+        let request = self.build_describe_trusted_advisor_check_summaries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrustedAdvisorCheckResultResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrustedAdvisorCheckSummariesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrustedAdvisorCheckResultError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrustedAdvisorCheckSummariesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the summaries of the results of the Trusted Advisor checks that have the specified check IDs. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <p>The response contains an array of <a>TrustedAdvisorCheckSummary</a> objects.</p>
-    async fn describe_trusted_advisor_check_summaries(
+    /// Presigns the request `describe_trusted_advisor_check_summaries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trusted_advisor_check_summaries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrustedAdvisorCheckSummariesRequest,
-    ) -> Result<
-        DescribeTrustedAdvisorCheckSummariesResponse,
-        RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trusted_advisor_check_summaries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trusted_advisor_check_summaries_request(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1747,31 +2205,58 @@ impl AWSSupport for AWSSupportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trusted_advisor_checks`, also returning the metadata of the response.
+    pub async fn describe_trusted_advisor_checks_with_metadata(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> Result<
+        (DescribeTrustedAdvisorChecksResponse, ResponseMetadata),
+        RusotoError<DescribeTrustedAdvisorChecksError>,
+    > {
+        let request = self.build_describe_trusted_advisor_checks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrustedAdvisorCheckSummariesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrustedAdvisorChecksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrustedAdvisorCheckSummariesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrustedAdvisorChecksError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all available Trusted Advisor checks, including name, ID, category, description, and metadata. You must specify a language code; English ("en") and Japanese ("ja") are currently supported. The response contains a <a>TrustedAdvisorCheckDescription</a> for each check. The region must be set to us-east-1.</p>
-    async fn describe_trusted_advisor_checks(
+    /// Presigns the request `describe_trusted_advisor_checks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trusted_advisor_checks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrustedAdvisorChecksRequest,
-    ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trusted_advisor_checks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trusted_advisor_checks_request(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorChecks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1781,29 +2266,58 @@ impl AWSSupport for AWSSupportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `refresh_trusted_advisor_check`, also returning the metadata of the response.
+    pub async fn refresh_trusted_advisor_check_with_metadata(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> Result<
+        (RefreshTrustedAdvisorCheckResponse, ResponseMetadata),
+        RusotoError<RefreshTrustedAdvisorCheckError>,
+    > {
+        let request = self.build_refresh_trusted_advisor_check_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTrustedAdvisorChecksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RefreshTrustedAdvisorCheckResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrustedAdvisorChecksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RefreshTrustedAdvisorCheckError::from_response(response))
         }
     }
 
-    /// <p><p>Requests a refresh of the Trusted Advisor check that has the specified check ID. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <note> <p>Some checks are refreshed automatically, and they cannot be refreshed by using this operation. Use of the <code>RefreshTrustedAdvisorCheck</code> operation for these checks causes an <code>InvalidParameterValue</code> error.</p> </note> <p>The response contains a <a>TrustedAdvisorCheckRefreshStatus</a> object, which contains these fields:</p> <ul> <li> <p> <b>status.</b> The refresh status of the check: </p> <ul> <li> <p> <code>none:</code> The check is not refreshed or the non-success status exceeds the timeout</p> </li> <li> <p> <code>enqueued:</code> The check refresh requests has entered the refresh queue</p> </li> <li> <p> <code>processing:</code> The check refresh request is picked up by the rule processing engine</p> </li> <li> <p> <code>success:</code> The check is successfully refreshed</p> </li> <li> <p> <code>abandoned:</code> The check refresh has failed</p> </li> </ul> </li> <li> <p> <b>millisUntilNextRefreshable.</b> The amount of time, in milliseconds, until the check is eligible for refresh.</p> </li> <li> <p> <b>checkId.</b> The unique identifier for the check.</p> </li> </ul></p>
-    async fn refresh_trusted_advisor_check(
+    /// Presigns the request `refresh_trusted_advisor_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_refresh_trusted_advisor_check(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RefreshTrustedAdvisorCheckRequest,
-    ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_refresh_trusted_advisor_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_refresh_trusted_advisor_check_request(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("RefreshTrustedAdvisorCheck");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1813,46 +2327,285 @@ impl AWSSupport for AWSSupportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resolve_case`, also returning the metadata of the response.
+    pub async fn resolve_case_with_metadata(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> Result<(ResolveCaseResponse, ResponseMetadata), RusotoError<ResolveCaseError>> {
+        let request = self.build_resolve_case_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RefreshTrustedAdvisorCheckResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResolveCaseResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RefreshTrustedAdvisorCheckError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResolveCaseError::from_response(response))
         }
     }
 
-    /// <p>Takes a <code>caseId</code> and returns the initial state of the case along with the state of the case after the call to <a>ResolveCase</a> completed.</p>
-    async fn resolve_case(
+    /// Presigns the request `resolve_case` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resolve_case(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResolveCaseRequest,
-    ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resolve_case_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resolve_case_request(&self, input: ResolveCaseRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("ResolveCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.ResolveCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeCases` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_cases_pages(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeCasesResponse, RusotoError<DescribeCasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSSupportClient, input| async move { client.describe_cases(input).await },
+            |input: &DescribeCasesRequest, output: &DescribeCasesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeCasesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `cases` of every page of `DescribeCases` results for `input`, as `describe_cases_pages` requests them.
+    pub fn describe_cases_stream(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> impl futures::Stream<Item = Result<CaseDetails, RusotoError<DescribeCasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_cases_pages(input), |page| {
+            page.cases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCommunications` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_communications_pages(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AWSSupportClient, input| async move { client.describe_communications(input).await },
+            |input: &DescribeCommunicationsRequest, output: &DescribeCommunicationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeCommunicationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `communications` of every page of `DescribeCommunications` results for `input`, as `describe_communications_pages` requests them.
+    pub fn describe_communications_stream(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> impl futures::Stream<Item = Result<Communication, RusotoError<DescribeCommunicationsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_communications_pages(input), |page| {
+            page.communications.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl AWSSupport for AWSSupportClient {
+    /// <p>Adds one or more attachments to an attachment set. If an <code>attachmentSetId</code> is not specified, a new attachment set is created, and the ID of the set is returned in the response. If an <code>attachmentSetId</code> is specified, the attachments are added to the specified set, if it exists.</p> <p>An attachment set is a temporary container for attachments that are to be added to a case or case communication. The set is available for one hour after it is created; the <code>expiryTime</code> returned in the response indicates when the set expires. The maximum number of attachments in a set is 3, and the maximum size of any attachment in the set is 5 MB.</p>
+    async fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
+        self.add_attachments_to_set_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResolveCaseResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResolveCaseError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds additional customer communication to an AWS Support case. You use the <code>caseId</code> value to identify the case to add communication to. You can list a set of email addresses to copy on the communication using the <code>ccEmailAddresses</code> value. The <code>communicationBody</code> value contains the text of the communication.</p> <p>The response indicates the success or failure of the request.</p> <p>This operation implements a subset of the features of the AWS Support Center.</p>
+    async fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
+        self.add_communication_to_case_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new case in the AWS Support Center. This operation is modeled on the behavior of the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. Its parameters require you to specify the following information:</p> <ul> <li> <p> <b>issueType.</b> The type of issue for the case. You can specify either "customer-service" or "technical." If you do not indicate a value, the default is "technical."</p> <note> <p>Service limit increases are not supported by the Support API; you must submit service limit increase requests in <a href="https://console.aws.amazon.com/support">Support Center</a>.</p> <p>The <code>caseId</code> is not the <code>displayId</code> that appears in <a href="https://console.aws.amazon.com/support">Support Center</a>. You can use the <a>DescribeCases</a> API to get the <code>displayId</code>.</p> </note> </li> <li> <p> <b>serviceCode.</b> The code for an AWS service. You can get the possible <code>serviceCode</code> values by calling <a>DescribeServices</a>.</p> </li> <li> <p> <b>categoryCode.</b> The category for the service defined for the <code>serviceCode</code> value. You also get the category code for a service by calling <a>DescribeServices</a>. Each AWS service defines its own set of category codes.</p> </li> <li> <p> <b>severityCode.</b> A value that indicates the urgency of the case, which in turn determines the response time according to your service level agreement with AWS Support. You can get the possible <code>severityCode</code> values by calling <a>DescribeSeverityLevels</a>. For more information about the meaning of the codes, see <a>SeverityLevel</a> and <a href="https://docs.aws.amazon.com/awssupport/latest/user/getting-started.html#choosing-severity">Choosing a Severity</a>.</p> </li> <li> <p> <b>subject.</b> The <b>Subject</b> field on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> </li> <li> <p> <b>communicationBody.</b> The <b>Description</b> field on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> </li> <li> <p> <b>attachmentSetId.</b> The ID of a set of attachments that has been created by using <a>AddAttachmentsToSet</a>.</p> </li> <li> <p> <b>language.</b> The human language in which AWS Support handles the case. English and Japanese are currently supported.</p> </li> <li> <p> <b>ccEmailAddresses.</b> The AWS Support Center <b>CC</b> field on the <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. You can list email addresses to be copied on any correspondence about the case. The account that opens the case is already identified by passing the AWS Credentials in the HTTP POST method or in a method or function call from one of the programming languages supported by an <a href="http://aws.amazon.com/tools/">AWS SDK</a>. </p> </li> </ul> <note> <p>To add additional communication or attachments to an existing case, use <a>AddCommunicationToCase</a>.</p> </note> <p>A successful <a>CreateCase</a> request returns an AWS Support case number. Case numbers are used by the <a>DescribeCases</a> operation to retrieve existing AWS Support cases.</p>
+    async fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
+        self.create_case_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the attachment that has the specified ID. Attachment IDs are generated by the case management system when you add an attachment to a case or case communication. Attachment IDs are returned in the <a>AttachmentDetails</a> objects that are returned by the <a>DescribeCommunications</a> operation.</p>
+    async fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
+        self.describe_attachment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of cases that you specify by passing one or more case IDs. In addition, you can filter the cases by date by setting values for the <code>afterTime</code> and <code>beforeTime</code> request parameters. You can set values for the <code>includeResolvedCases</code> and <code>includeCommunications</code> request parameters to control how much information is returned.</p> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request for data might cause an error.</p> <p>The response returns the following in JSON format:</p> <ul> <li> <p>One or more <a>CaseDetails</a> data types.</p> </li> <li> <p>One or more <code>nextToken</code> values, which specify where to paginate the returned records represented by the <code>CaseDetails</code> objects.</p> </li> </ul></p>
+    async fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
+        self.describe_cases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns communications (and attachments) for one or more support cases. You can use the <code>afterTime</code> and <code>beforeTime</code> parameters to filter by date. You can use the <code>caseId</code> parameter to restrict the results to a particular case.</p> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request for data might cause an error.</p> <p>You can use the <code>maxResults</code> and <code>nextToken</code> parameters to control the pagination of the result set. Set <code>maxResults</code> to the number of cases you want displayed on each page, and use <code>nextToken</code> to specify the resumption of pagination.</p>
+    async fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
+        self.describe_communications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current list of AWS services and a list of service categories that applies to each one. You then use service names and categories in your <a>CreateCase</a> requests. Each AWS service has its own set of categories.</p> <p>The service codes and category codes correspond to the values that are displayed in the <b>Service</b> and <b>Category</b> drop-down lists on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. The values in those fields, however, do not necessarily match the service codes and categories returned by the <code>DescribeServices</code> request. Always use the service codes and categories obtained programmatically. This practice ensures that you always have the most recent set of service and category codes.</p>
+    async fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.describe_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of severity levels that you can assign to an AWS Support case. The severity level for a case is also a field in the <a>CaseDetails</a> data type included in any <a>CreateCase</a> request.</p>
+    async fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
+        self.describe_severity_levels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the refresh status of the Trusted Advisor checks that have the specified check IDs. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <note> <p>Some checks are refreshed automatically, and their refresh statuses cannot be retrieved by using this operation. Use of the <code>DescribeTrustedAdvisorCheckRefreshStatuses</code> operation for these checks causes an <code>InvalidParameterValue</code> error.</p> </note></p>
+    async fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+    > {
+        self.describe_trusted_advisor_check_refresh_statuses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the results of the Trusted Advisor check that has the specified check ID. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <p>The response contains a <a>TrustedAdvisorCheckResult</a> object, which contains these three objects:</p> <ul> <li> <p> <a>TrustedAdvisorCategorySpecificSummary</a> </p> </li> <li> <p> <a>TrustedAdvisorResourceDetail</a> </p> </li> <li> <p> <a>TrustedAdvisorResourcesSummary</a> </p> </li> </ul> <p>In addition, the response contains these fields:</p> <ul> <li> <p> <b>status.</b> The alert status of the check: &quot;ok&quot; (green), &quot;warning&quot; (yellow), &quot;error&quot; (red), or &quot;not_available&quot;.</p> </li> <li> <p> <b>timestamp.</b> The time of the last refresh of the check.</p> </li> <li> <p> <b>checkId.</b> The unique identifier for the check.</p> </li> </ul></p>
+    async fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckResultResponse,
+        RusotoError<DescribeTrustedAdvisorCheckResultError>,
+    > {
+        self.describe_trusted_advisor_check_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the summaries of the results of the Trusted Advisor checks that have the specified check IDs. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <p>The response contains an array of <a>TrustedAdvisorCheckSummary</a> objects.</p>
+    async fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+    > {
+        self.describe_trusted_advisor_check_summaries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all available Trusted Advisor checks, including name, ID, category, description, and metadata. You must specify a language code; English ("en") and Japanese ("ja") are currently supported. The response contains a <a>TrustedAdvisorCheckDescription</a> for each check. The region must be set to us-east-1.</p>
+    async fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
+    {
+        self.describe_trusted_advisor_checks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Requests a refresh of the Trusted Advisor check that has the specified check ID. Check IDs can be obtained by calling <a>DescribeTrustedAdvisorChecks</a>.</p> <note> <p>Some checks are refreshed automatically, and they cannot be refreshed by using this operation. Use of the <code>RefreshTrustedAdvisorCheck</code> operation for these checks causes an <code>InvalidParameterValue</code> error.</p> </note> <p>The response contains a <a>TrustedAdvisorCheckRefreshStatus</a> object, which contains these fields:</p> <ul> <li> <p> <b>status.</b> The refresh status of the check: </p> <ul> <li> <p> <code>none:</code> The check is not refreshed or the non-success status exceeds the timeout</p> </li> <li> <p> <code>enqueued:</code> The check refresh requests has entered the refresh queue</p> </li> <li> <p> <code>processing:</code> The check refresh request is picked up by the rule processing engine</p> </li> <li> <p> <code>success:</code> The check is successfully refreshed</p> </li> <li> <p> <code>abandoned:</code> The check refresh has failed</p> </li> </ul> </li> <li> <p> <b>millisUntilNextRefreshable.</b> The amount of time, in milliseconds, until the check is eligible for refresh.</p> </li> <li> <p> <b>checkId.</b> The unique identifier for the check.</p> </li> </ul></p>
+    async fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
+    {
+        self.refresh_trusted_advisor_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Takes a <code>caseId</code> and returns the initial state of the case along with the state of the case after the call to <a>ResolveCase</a> completed.</p>
+    async fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
+        self.resolve_case_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
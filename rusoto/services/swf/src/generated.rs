@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2956,11 +2956,14 @@ pub struct WorkflowTypeInfos {
 
 /// Errors returned by CountClosedWorkflowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CountClosedWorkflowExecutionsError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CountClosedWorkflowExecutionsError {
@@ -2970,17 +2973,24 @@ impl CountClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CountClosedWorkflowExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2996,17 +3006,23 @@ impl fmt::Display for CountClosedWorkflowExecutionsError {
             CountClosedWorkflowExecutionsError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CountClosedWorkflowExecutionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CountClosedWorkflowExecutionsError {}
 /// Errors returned by CountOpenWorkflowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CountOpenWorkflowExecutionsError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CountOpenWorkflowExecutionsError {
@@ -3016,17 +3032,24 @@ impl CountOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CountOpenWorkflowExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3042,17 +3065,21 @@ impl fmt::Display for CountOpenWorkflowExecutionsError {
             CountOpenWorkflowExecutionsError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CountOpenWorkflowExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CountOpenWorkflowExecutionsError {}
 /// Errors returned by CountPendingActivityTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CountPendingActivityTasksError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CountPendingActivityTasksError {
@@ -3060,17 +3087,21 @@ impl CountPendingActivityTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountPendingActivityTasksError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountPendingActivityTasksError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CountPendingActivityTasksError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3086,17 +3117,21 @@ impl fmt::Display for CountPendingActivityTasksError {
             CountPendingActivityTasksError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CountPendingActivityTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CountPendingActivityTasksError {}
 /// Errors returned by CountPendingDecisionTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CountPendingDecisionTasksError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CountPendingDecisionTasksError {
@@ -3104,17 +3139,21 @@ impl CountPendingDecisionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountPendingDecisionTasksError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CountPendingDecisionTasksError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CountPendingDecisionTasksError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3130,12 +3169,14 @@ impl fmt::Display for CountPendingDecisionTasksError {
             CountPendingDecisionTasksError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CountPendingDecisionTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CountPendingDecisionTasksError {}
 /// Errors returned by DeprecateActivityType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeprecateActivityTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
@@ -3143,6 +3184,8 @@ pub enum DeprecateActivityTypeError {
     TypeDeprecatedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeprecateActivityTypeError {
@@ -3150,22 +3193,25 @@ impl DeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateActivityTypeError::TypeDeprecatedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateActivityTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeprecateActivityTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3180,12 +3226,14 @@ impl fmt::Display for DeprecateActivityTypeError {
             }
             DeprecateActivityTypeError::TypeDeprecatedFault(ref cause) => write!(f, "{}", cause),
             DeprecateActivityTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DeprecateActivityTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeprecateActivityTypeError {}
 /// Errors returned by DeprecateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeprecateDomainError {
     /// <p>Returned when the specified domain has been deprecated.</p>
     DomainDeprecatedFault(String),
@@ -3193,6 +3241,8 @@ pub enum DeprecateDomainError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeprecateDomainError {
@@ -3200,22 +3250,25 @@ impl DeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::DomainDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateDomainError::DomainDeprecatedFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateDomainError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateDomainError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateDomainError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeprecateDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3228,12 +3281,14 @@ impl fmt::Display for DeprecateDomainError {
             DeprecateDomainError::DomainDeprecatedFault(ref cause) => write!(f, "{}", cause),
             DeprecateDomainError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             DeprecateDomainError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DeprecateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeprecateDomainError {}
 /// Errors returned by DeprecateWorkflowType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeprecateWorkflowTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
@@ -3241,6 +3296,8 @@ pub enum DeprecateWorkflowTypeError {
     TypeDeprecatedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeprecateWorkflowTypeError {
@@ -3248,22 +3305,25 @@ impl DeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateWorkflowTypeError::TypeDeprecatedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeprecateWorkflowTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3278,17 +3338,21 @@ impl fmt::Display for DeprecateWorkflowTypeError {
             }
             DeprecateWorkflowTypeError::TypeDeprecatedFault(ref cause) => write!(f, "{}", cause),
             DeprecateWorkflowTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DeprecateWorkflowTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeprecateWorkflowTypeError {}
 /// Errors returned by DescribeActivityType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeActivityTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeActivityTypeError {
@@ -3296,17 +3360,19 @@ impl DescribeActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeActivityTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeActivityTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeActivityTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3320,17 +3386,21 @@ impl fmt::Display for DescribeActivityTypeError {
                 write!(f, "{}", cause)
             }
             DescribeActivityTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DescribeActivityTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeActivityTypeError {}
 /// Errors returned by DescribeDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDomainError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDomainError {
@@ -3338,15 +3408,19 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DescribeDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDomainError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeDomainError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        DescribeDomainError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3358,17 +3432,21 @@ impl fmt::Display for DescribeDomainError {
         match *self {
             DescribeDomainError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             DescribeDomainError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DescribeDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDomainError {}
 /// Errors returned by DescribeWorkflowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeWorkflowExecutionError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeWorkflowExecutionError {
@@ -3376,17 +3454,21 @@ impl DescribeWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeWorkflowExecutionError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeWorkflowExecutionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3402,17 +3484,21 @@ impl fmt::Display for DescribeWorkflowExecutionError {
             DescribeWorkflowExecutionError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeWorkflowExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeWorkflowExecutionError {}
 /// Errors returned by DescribeWorkflowType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeWorkflowTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeWorkflowTypeError {
@@ -3420,17 +3506,19 @@ impl DescribeWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeWorkflowTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeWorkflowTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3444,17 +3532,21 @@ impl fmt::Display for DescribeWorkflowTypeError {
                 write!(f, "{}", cause)
             }
             DescribeWorkflowTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            DescribeWorkflowTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeWorkflowTypeError {}
 /// Errors returned by GetWorkflowExecutionHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkflowExecutionHistoryError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkflowExecutionHistoryError {
@@ -3464,17 +3556,24 @@ impl GetWorkflowExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetWorkflowExecutionHistoryError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetWorkflowExecutionHistoryError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetWorkflowExecutionHistoryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3490,17 +3589,21 @@ impl fmt::Display for GetWorkflowExecutionHistoryError {
             GetWorkflowExecutionHistoryError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetWorkflowExecutionHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkflowExecutionHistoryError {}
 /// Errors returned by ListActivityTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListActivityTypesError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListActivityTypesError {
@@ -3508,17 +3611,19 @@ impl ListActivityTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListActivityTypesError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListActivityTypesError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListActivityTypesError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListActivityTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3530,17 +3635,21 @@ impl fmt::Display for ListActivityTypesError {
         match *self {
             ListActivityTypesError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             ListActivityTypesError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            ListActivityTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListActivityTypesError {}
 /// Errors returned by ListClosedWorkflowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClosedWorkflowExecutionsError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClosedWorkflowExecutionsError {
@@ -3550,17 +3659,24 @@ impl ListClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListClosedWorkflowExecutionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3576,15 +3692,19 @@ impl fmt::Display for ListClosedWorkflowExecutionsError {
             ListClosedWorkflowExecutionsError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListClosedWorkflowExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClosedWorkflowExecutionsError {}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainsError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainsError {
@@ -3592,12 +3712,13 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(ListDomainsError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDomainsError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDomainsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3608,17 +3729,21 @@ impl fmt::Display for ListDomainsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDomainsError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
+            ListDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainsError {}
 /// Errors returned by ListOpenWorkflowExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOpenWorkflowExecutionsError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOpenWorkflowExecutionsError {
@@ -3628,17 +3753,21 @@ impl ListOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListOpenWorkflowExecutionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3654,12 +3783,14 @@ impl fmt::Display for ListOpenWorkflowExecutionsError {
             ListOpenWorkflowExecutionsError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListOpenWorkflowExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOpenWorkflowExecutionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -3667,6 +3798,8 @@ pub enum ListTagsForResourceError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3674,22 +3807,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTagsForResourceError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3704,17 +3840,21 @@ impl fmt::Display for ListTagsForResourceError {
                 write!(f, "{}", cause)
             }
             ListTagsForResourceError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListWorkflowTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWorkflowTypesError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWorkflowTypesError {
@@ -3722,17 +3862,19 @@ impl ListWorkflowTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListWorkflowTypesError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListWorkflowTypesError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListWorkflowTypesError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWorkflowTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3744,12 +3886,14 @@ impl fmt::Display for ListWorkflowTypesError {
         match *self {
             ListWorkflowTypesError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             ListWorkflowTypesError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            ListWorkflowTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWorkflowTypesError {}
 /// Errors returned by PollForActivityTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PollForActivityTaskError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -3757,6 +3901,8 @@ pub enum PollForActivityTaskError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PollForActivityTaskError {
@@ -3764,22 +3910,25 @@ impl PollForActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PollForActivityTaskError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PollForActivityTaskError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PollForActivityTaskError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PollForActivityTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3794,12 +3943,14 @@ impl fmt::Display for PollForActivityTaskError {
                 write!(f, "{}", cause)
             }
             PollForActivityTaskError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            PollForActivityTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PollForActivityTaskError {}
 /// Errors returned by PollForDecisionTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PollForDecisionTaskError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -3807,6 +3958,8 @@ pub enum PollForDecisionTaskError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PollForDecisionTaskError {
@@ -3814,22 +3967,25 @@ impl PollForDecisionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PollForDecisionTaskError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PollForDecisionTaskError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PollForDecisionTaskError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PollForDecisionTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3844,17 +4000,21 @@ impl fmt::Display for PollForDecisionTaskError {
                 write!(f, "{}", cause)
             }
             PollForDecisionTaskError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            PollForDecisionTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PollForDecisionTaskError {}
 /// Errors returned by RecordActivityTaskHeartbeat
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RecordActivityTaskHeartbeatError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RecordActivityTaskHeartbeatError {
@@ -3864,17 +4024,24 @@ impl RecordActivityTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RecordActivityTaskHeartbeatError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RecordActivityTaskHeartbeatError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RecordActivityTaskHeartbeatError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3890,12 +4057,14 @@ impl fmt::Display for RecordActivityTaskHeartbeatError {
             RecordActivityTaskHeartbeatError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RecordActivityTaskHeartbeatError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RecordActivityTaskHeartbeatError {}
 /// Errors returned by RegisterActivityType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterActivityTypeError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -3905,6 +4074,8 @@ pub enum RegisterActivityTypeError {
     TypeAlreadyExistsFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterActivityTypeError {
@@ -3912,27 +4083,31 @@ impl RegisterActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterActivityTypeError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterActivityTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::TypeAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterActivityTypeError::TypeAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterActivityTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterActivityTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3948,12 +4123,14 @@ impl fmt::Display for RegisterActivityTypeError {
             }
             RegisterActivityTypeError::TypeAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             RegisterActivityTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            RegisterActivityTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterActivityTypeError {}
 /// Errors returned by RegisterDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterDomainError {
     /// <p>Returned if the domain already exists. You may get this fault if you are registering a domain that is either already registered or deprecated, or if you undeprecate a domain that is currently registered.</p>
     DomainAlreadyExistsFault(String),
@@ -3963,6 +4140,8 @@ pub enum RegisterDomainError {
     OperationNotPermittedFault(String),
     /// <p>You've exceeded the number of tags allowed for a domain.</p>
     TooManyTagsFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterDomainError {
@@ -3970,23 +4149,31 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterDomainError::DomainAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterDomainError::DomainAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterDomainError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        RegisterDomainError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(RegisterDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterDomainError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(RegisterDomainError::TooManyTagsFault(err.msg))
+                    return RusotoError::service(
+                        RegisterDomainError::TooManyTagsFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4000,12 +4187,14 @@ impl fmt::Display for RegisterDomainError {
             RegisterDomainError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
             RegisterDomainError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             RegisterDomainError::TooManyTagsFault(ref cause) => write!(f, "{}", cause),
+            RegisterDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterDomainError {}
 /// Errors returned by RegisterWorkflowType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterWorkflowTypeError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -4015,6 +4204,8 @@ pub enum RegisterWorkflowTypeError {
     TypeAlreadyExistsFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterWorkflowTypeError {
@@ -4022,27 +4213,31 @@ impl RegisterWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterWorkflowTypeError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::TypeAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterWorkflowTypeError::TypeAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterWorkflowTypeError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterWorkflowTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4058,17 +4253,21 @@ impl fmt::Display for RegisterWorkflowTypeError {
             }
             RegisterWorkflowTypeError::TypeAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             RegisterWorkflowTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            RegisterWorkflowTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterWorkflowTypeError {}
 /// Errors returned by RequestCancelWorkflowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RequestCancelWorkflowExecutionError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RequestCancelWorkflowExecutionError {
@@ -4078,17 +4277,24 @@ impl RequestCancelWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestCancelWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestCancelWorkflowExecutionError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RequestCancelWorkflowExecutionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4104,17 +4310,23 @@ impl fmt::Display for RequestCancelWorkflowExecutionError {
             RequestCancelWorkflowExecutionError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RequestCancelWorkflowExecutionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RequestCancelWorkflowExecutionError {}
 /// Errors returned by RespondActivityTaskCanceled
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RespondActivityTaskCanceledError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RespondActivityTaskCanceledError {
@@ -4124,17 +4336,24 @@ impl RespondActivityTaskCanceledError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskCanceledError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskCanceledError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RespondActivityTaskCanceledError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4150,17 +4369,21 @@ impl fmt::Display for RespondActivityTaskCanceledError {
             RespondActivityTaskCanceledError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RespondActivityTaskCanceledError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RespondActivityTaskCanceledError {}
 /// Errors returned by RespondActivityTaskCompleted
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RespondActivityTaskCompletedError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RespondActivityTaskCompletedError {
@@ -4170,17 +4393,24 @@ impl RespondActivityTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskCompletedError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskCompletedError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RespondActivityTaskCompletedError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4196,17 +4426,21 @@ impl fmt::Display for RespondActivityTaskCompletedError {
             RespondActivityTaskCompletedError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RespondActivityTaskCompletedError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RespondActivityTaskCompletedError {}
 /// Errors returned by RespondActivityTaskFailed
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RespondActivityTaskFailedError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RespondActivityTaskFailedError {
@@ -4214,17 +4448,21 @@ impl RespondActivityTaskFailedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskFailedError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondActivityTaskFailedError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RespondActivityTaskFailedError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4240,17 +4478,21 @@ impl fmt::Display for RespondActivityTaskFailedError {
             RespondActivityTaskFailedError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RespondActivityTaskFailedError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RespondActivityTaskFailedError {}
 /// Errors returned by RespondDecisionTaskCompleted
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RespondDecisionTaskCompletedError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RespondDecisionTaskCompletedError {
@@ -4260,17 +4502,24 @@ impl RespondDecisionTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondDecisionTaskCompletedError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondDecisionTaskCompletedError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RespondDecisionTaskCompletedError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4286,17 +4535,21 @@ impl fmt::Display for RespondDecisionTaskCompletedError {
             RespondDecisionTaskCompletedError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RespondDecisionTaskCompletedError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RespondDecisionTaskCompletedError {}
 /// Errors returned by SignalWorkflowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SignalWorkflowExecutionError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SignalWorkflowExecutionError {
@@ -4304,17 +4557,19 @@ impl SignalWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SignalWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SignalWorkflowExecutionError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SignalWorkflowExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4328,12 +4583,14 @@ impl fmt::Display for SignalWorkflowExecutionError {
                 write!(f, "{}", cause)
             }
             SignalWorkflowExecutionError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            SignalWorkflowExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SignalWorkflowExecutionError {}
 /// Errors returned by StartWorkflowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartWorkflowExecutionError {
     /// <p><p>The <code>StartWorkflowExecution</code> API action was called without the required parameters set.</p> <p>Some workflow execution parameters, such as the decision <code>taskList</code>, must be set to start the execution. However, these parameters might have been set as defaults when the workflow type was registered. In this case, you can omit these parameters from the <code>StartWorkflowExecution</code> call and Amazon SWF uses the values defined in the workflow type.</p> <note> <p>If these parameters aren&#39;t set and no default parameters were defined in the workflow type, this error is displayed.</p> </note></p>
     DefaultUndefinedFault(String),
@@ -4347,6 +4604,8 @@ pub enum StartWorkflowExecutionError {
     UnknownResourceFault(String),
     /// <p>Returned by <a>StartWorkflowExecution</a> when an open execution with the same workflowId is already running in the specified domain.</p>
     WorkflowExecutionAlreadyStartedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartWorkflowExecutionError {
@@ -4354,37 +4613,43 @@ impl StartWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DefaultUndefinedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartWorkflowExecutionError::DefaultUndefinedFault(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartWorkflowExecutionError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartWorkflowExecutionError::TypeDeprecatedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartWorkflowExecutionError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "WorkflowExecutionAlreadyStartedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartWorkflowExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4404,12 +4669,14 @@ impl fmt::Display for StartWorkflowExecutionError {
             StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartWorkflowExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartWorkflowExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -4419,6 +4686,8 @@ pub enum TagResourceError {
     TooManyTagsFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4426,21 +4695,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(TagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(TagResourceError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(TagResourceError::TooManyTagsFault(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTagsFault(err.msg), &res)
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(TagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4454,17 +4730,21 @@ impl fmt::Display for TagResourceError {
             TagResourceError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTagsFault(ref cause) => write!(f, "{}", cause),
             TagResourceError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TerminateWorkflowExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateWorkflowExecutionError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateWorkflowExecutionError {
@@ -4474,17 +4754,21 @@ impl TerminateWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TerminateWorkflowExecutionError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TerminateWorkflowExecutionError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(TerminateWorkflowExecutionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4500,12 +4784,14 @@ impl fmt::Display for TerminateWorkflowExecutionError {
             TerminateWorkflowExecutionError::UnknownResourceFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            TerminateWorkflowExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateWorkflowExecutionError {}
 /// Errors returned by UndeprecateActivityType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UndeprecateActivityTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
@@ -4513,6 +4799,8 @@ pub enum UndeprecateActivityTypeError {
     TypeAlreadyExistsFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UndeprecateActivityTypeError {
@@ -4520,22 +4808,25 @@ impl UndeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateActivityTypeError::TypeAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateActivityTypeError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UndeprecateActivityTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4552,12 +4843,14 @@ impl fmt::Display for UndeprecateActivityTypeError {
                 write!(f, "{}", cause)
             }
             UndeprecateActivityTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            UndeprecateActivityTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UndeprecateActivityTypeError {}
 /// Errors returned by UndeprecateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UndeprecateDomainError {
     /// <p>Returned if the domain already exists. You may get this fault if you are registering a domain that is either already registered or deprecated, or if you undeprecate a domain that is currently registered.</p>
     DomainAlreadyExistsFault(String),
@@ -4565,6 +4858,8 @@ pub enum UndeprecateDomainError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UndeprecateDomainError {
@@ -4572,22 +4867,25 @@ impl UndeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::DomainAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeprecateDomainError::DomainAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateDomainError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UndeprecateDomainError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UndeprecateDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4600,12 +4898,14 @@ impl fmt::Display for UndeprecateDomainError {
             UndeprecateDomainError::DomainAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             UndeprecateDomainError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             UndeprecateDomainError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            UndeprecateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UndeprecateDomainError {}
 /// Errors returned by UndeprecateWorkflowType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UndeprecateWorkflowTypeError {
     /// <p>Returned when the caller doesn't have sufficient permissions to invoke the action.</p>
     OperationNotPermittedFault(String),
@@ -4613,6 +4913,8 @@ pub enum UndeprecateWorkflowTypeError {
     TypeAlreadyExistsFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UndeprecateWorkflowTypeError {
@@ -4620,22 +4922,25 @@ impl UndeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
+                        &res,
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateWorkflowTypeError::TypeAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UndeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UndeprecateWorkflowTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4652,12 +4957,14 @@ impl fmt::Display for UndeprecateWorkflowTypeError {
                 write!(f, "{}", cause)
             }
             UndeprecateWorkflowTypeError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            UndeprecateWorkflowTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UndeprecateWorkflowTypeError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Returned by any operation if a system imposed limitation has been reached. To address this fault you should either clean up unused resources or increase the limit by contacting AWS.</p>
     LimitExceededFault(String),
@@ -4665,6 +4972,8 @@ pub enum UntagResourceError {
     OperationNotPermittedFault(String),
     /// <p>Returned when the named resource cannot be found with in the scope of this operation (region or domain). This could happen if the named resource was never created or is no longer available for this operation.</p>
     UnknownResourceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4672,18 +4981,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::LimitExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(UntagResourceError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::OperationNotPermittedFault(err.msg),
+                        &res,
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UntagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::UnknownResourceFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4696,6 +5012,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
             UntagResourceError::OperationNotPermittedFault(ref cause) => write!(f, "{}", cause),
             UntagResourceError::UnknownResourceFault(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4936,6 +5253,7 @@ impl SwfClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> SwfClient {
         SwfClient {
             client: Client::shared(),
@@ -4961,16 +5279,73 @@ impl SwfClient {
     pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
         SwfClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<SwfClient> {
+        rusoto_core::ClientBuilder::new(SwfClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> SwfClient {
+        SwfClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Swf for SwfClient {
-    /// <p>Returns the number of closed workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn count_closed_workflow_executions(
+impl SwfClient {
+    /// Calls `count_closed_workflow_executions`, also returning the metadata of the response.
+    pub async fn count_closed_workflow_executions_with_metadata(
         &self,
         input: CountClosedWorkflowExecutionsInput,
-    ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
+    ) -> Result<
+        (WorkflowExecutionCount, ResponseMetadata),
+        RusotoError<CountClosedWorkflowExecutionsError>,
+    > {
+        let request = self.build_count_closed_workflow_executions_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowExecutionCount, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CountClosedWorkflowExecutionsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `count_closed_workflow_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_count_closed_workflow_executions(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_count_closed_workflow_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_count_closed_workflow_executions_request(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountClosedWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -4980,27 +5355,58 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `count_open_workflow_executions`, also returning the metadata of the response.
+    pub async fn count_open_workflow_executions_with_metadata(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> Result<
+        (WorkflowExecutionCount, ResponseMetadata),
+        RusotoError<CountOpenWorkflowExecutionsError>,
+    > {
+        let request = self.build_count_open_workflow_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionCount, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowExecutionCount, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CountClosedWorkflowExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CountOpenWorkflowExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns the number of open workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn count_open_workflow_executions(
+    /// Presigns the request `count_open_workflow_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_count_open_workflow_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CountOpenWorkflowExecutionsInput,
-    ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_count_open_workflow_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_count_open_workflow_executions_request(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountOpenWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5010,27 +5416,56 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `count_pending_activity_tasks`, also returning the metadata of the response.
+    pub async fn count_pending_activity_tasks_with_metadata(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> Result<(PendingTaskCount, ResponseMetadata), RusotoError<CountPendingActivityTasksError>>
+    {
+        let request = self.build_count_pending_activity_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionCount, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PendingTaskCount, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CountOpenWorkflowExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CountPendingActivityTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns the estimated number of activity tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no activity task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn count_pending_activity_tasks(
+    /// Presigns the request `count_pending_activity_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_count_pending_activity_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CountPendingActivityTasksInput,
-    ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_count_pending_activity_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_count_pending_activity_tasks_request(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingActivityTasks");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5040,27 +5475,56 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `count_pending_decision_tasks`, also returning the metadata of the response.
+    pub async fn count_pending_decision_tasks_with_metadata(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> Result<(PendingTaskCount, ResponseMetadata), RusotoError<CountPendingDecisionTasksError>>
+    {
+        let request = self.build_count_pending_decision_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PendingTaskCount, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PendingTaskCount, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CountPendingActivityTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CountPendingDecisionTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns the estimated number of decision tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no decision task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn count_pending_decision_tasks(
+    /// Presigns the request `count_pending_decision_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_count_pending_decision_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CountPendingDecisionTasksInput,
-    ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_count_pending_decision_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_count_pending_decision_tasks_request(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingDecisionTasks");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5070,27 +5534,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deprecate_activity_type`, also returning the metadata of the response.
+    pub async fn deprecate_activity_type_with_metadata(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeprecateActivityTypeError>> {
+        let request = self.build_deprecate_activity_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PendingTaskCount, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CountPendingDecisionTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeprecateActivityTypeError::from_response(response))
         }
     }
 
-    /// <p>Deprecates the specified <i>activity type</i>. After an activity type has been deprecated, you cannot create new tasks of that activity type. Tasks of this type that were scheduled before the type was deprecated continue to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn deprecate_activity_type(
+    /// Presigns the request `deprecate_activity_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deprecate_activity_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeprecateActivityTypeInput,
-    ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deprecate_activity_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deprecate_activity_type_request(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5100,54 +5587,97 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deprecate_domain`, also returning the metadata of the response.
+    pub async fn deprecate_domain_with_metadata(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeprecateDomainError>> {
+        let request = self.build_deprecate_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeprecateActivityTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeprecateDomainError::from_response(response))
         }
     }
 
-    /// <p>Deprecates the specified domain. After a domain has been deprecated it cannot be used to create new workflow executions or register new types. However, you can still use visibility actions on this domain. Deprecating a domain also deprecates all activity and workflow types registered in the domain. Executions that were started before the domain was deprecated continues to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn deprecate_domain(
+    /// Presigns the request `deprecate_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deprecate_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeprecateDomainInput,
-    ) -> Result<(), RusotoError<DeprecateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deprecate_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deprecate_domain_request(&self, input: DeprecateDomainInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deprecate_workflow_type`, also returning the metadata of the response.
+    pub async fn deprecate_workflow_type_with_metadata(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeprecateWorkflowTypeError>> {
+        let request = self.build_deprecate_workflow_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeprecateDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeprecateWorkflowTypeError::from_response(response))
         }
     }
 
-    /// <p>Deprecates the specified <i>workflow type</i>. After a workflow type has been deprecated, you cannot create new executions of that type. Executions that were started before the type was deprecated continues to run. A deprecated workflow type may still be used when calling visibility actions.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn deprecate_workflow_type(
+    /// Presigns the request `deprecate_workflow_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deprecate_workflow_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeprecateWorkflowTypeInput,
-    ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deprecate_workflow_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deprecate_workflow_type_request(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5157,81 +5687,166 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_activity_type`, also returning the metadata of the response.
+    pub async fn describe_activity_type_with_metadata(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> Result<(ActivityTypeDetail, ResponseMetadata), RusotoError<DescribeActivityTypeError>>
+    {
+        let request = self.build_describe_activity_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ActivityTypeDetail, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeprecateWorkflowTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeActivityTypeError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified activity type. This includes configuration settings provided when the type was registered and other general information about the type.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn describe_activity_type(
+    /// Presigns the request `describe_activity_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_activity_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeActivityTypeInput,
-    ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_activity_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_activity_type_request(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_domain`, also returning the metadata of the response.
+    pub async fn describe_domain_with_metadata(
+        &self,
+        input: DescribeDomainInput,
+    ) -> Result<(DomainDetail, ResponseMetadata), RusotoError<DescribeDomainError>> {
+        let request = self.build_describe_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivityTypeDetail, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainDetail, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeActivityTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDomainError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified domain, including description and status.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn describe_domain(
+    /// Presigns the request `describe_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDomainInput,
-    ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_domain_request(&self, input: DescribeDomainInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_workflow_execution`, also returning the metadata of the response.
+    pub async fn describe_workflow_execution_with_metadata(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> Result<
+        (WorkflowExecutionDetail, ResponseMetadata),
+        RusotoError<DescribeWorkflowExecutionError>,
+    > {
+        let request = self.build_describe_workflow_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DomainDetail, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowExecutionDetail, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeWorkflowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified workflow execution including its type and some statistics.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn describe_workflow_execution(
+    /// Presigns the request `describe_workflow_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_workflow_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeWorkflowExecutionInput,
-    ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_workflow_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_workflow_execution_request(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5241,54 +5856,111 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_workflow_type`, also returning the metadata of the response.
+    pub async fn describe_workflow_type_with_metadata(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> Result<(WorkflowTypeDetail, ResponseMetadata), RusotoError<DescribeWorkflowTypeError>>
+    {
+        let request = self.build_describe_workflow_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionDetail, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowTypeDetail, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeWorkflowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeWorkflowTypeError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified <i>workflow type</i>. This includes configuration settings specified when the type was registered and other information such as creation date, current status, etc.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn describe_workflow_type(
+    /// Presigns the request `describe_workflow_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_workflow_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeWorkflowTypeInput,
-    ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_workflow_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_workflow_type_request(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_workflow_execution_history`, also returning the metadata of the response.
+    pub async fn get_workflow_execution_history_with_metadata(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> Result<(History, ResponseMetadata), RusotoError<GetWorkflowExecutionHistoryError>> {
+        let request = self.build_get_workflow_execution_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowTypeDetail, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<History, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeWorkflowTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkflowExecutionHistoryError::from_response(response))
         }
     }
 
-    /// <p>Returns the history of the specified workflow execution. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn get_workflow_execution_history(
+    /// Presigns the request `get_workflow_execution_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_workflow_execution_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkflowExecutionHistoryInput,
-    ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_workflow_execution_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_workflow_execution_history_request(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("GetWorkflowExecutionHistory");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5298,54 +5970,110 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `list_activity_types`, also returning the metadata of the response.
+    pub async fn list_activity_types_with_metadata(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> Result<(ActivityTypeInfos, ResponseMetadata), RusotoError<ListActivityTypesError>> {
+        let request = self.build_list_activity_types_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<History, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ActivityTypeInfos, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkflowExecutionHistoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListActivityTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all activities registered in the specified domain that match the specified name and registration status. The result includes information like creation date, current status of the activity, etc. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn list_activity_types(
+    /// Presigns the request `list_activity_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_activity_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListActivityTypesInput,
-    ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_activity_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_activity_types_request(&self, input: ListActivityTypesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListActivityTypes");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_closed_workflow_executions`, also returning the metadata of the response.
+    pub async fn list_closed_workflow_executions_with_metadata(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> Result<
+        (WorkflowExecutionInfos, ResponseMetadata),
+        RusotoError<ListClosedWorkflowExecutionsError>,
+    > {
+        let request = self.build_list_closed_workflow_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivityTypeInfos, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowExecutionInfos, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListActivityTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListClosedWorkflowExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of closed workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn list_closed_workflow_executions(
+    /// Presigns the request `list_closed_workflow_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_closed_workflow_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClosedWorkflowExecutionsInput,
-    ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_closed_workflow_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_closed_workflow_executions_request(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListClosedWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5355,54 +6083,110 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_domains`, also returning the metadata of the response.
+    pub async fn list_domains_with_metadata(
+        &self,
+        input: ListDomainsInput,
+    ) -> Result<(DomainInfos, ResponseMetadata), RusotoError<ListDomainsError>> {
+        let request = self.build_list_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionInfos, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainInfos, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClosedWorkflowExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDomainsError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of domains registered in the account. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains. The element must be set to <code>arn:aws:swf::AccountID:domain/*</code>, where <i>AccountID</i> is the account ID, with no dashes.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn list_domains(
+    /// Presigns the request `list_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domains(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDomainsInput,
-    ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domains_request(&self, input: ListDomainsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListDomains");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_open_workflow_executions`, also returning the metadata of the response.
+    pub async fn list_open_workflow_executions_with_metadata(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> Result<
+        (WorkflowExecutionInfos, ResponseMetadata),
+        RusotoError<ListOpenWorkflowExecutionsError>,
+    > {
+        let request = self.build_list_open_workflow_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DomainInfos, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowExecutionInfos, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOpenWorkflowExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of open workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn list_open_workflow_executions(
+    /// Presigns the request `list_open_workflow_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_open_workflow_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOpenWorkflowExecutionsInput,
-    ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_open_workflow_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_open_workflow_executions_request(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListOpenWorkflowExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5412,136 +6196,274 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowExecutionInfos, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOpenWorkflowExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List tags for a given domain.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_workflow_types`, also returning the metadata of the response.
+    pub async fn list_workflow_types_with_metadata(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> Result<(WorkflowTypeInfos, ResponseMetadata), RusotoError<ListWorkflowTypesError>> {
+        let request = self.build_list_workflow_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<WorkflowTypeInfos, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkflowTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about workflow types in the specified domain. The results may be split into multiple pages that can be retrieved by making the call repeatedly.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn list_workflow_types(
+    /// Presigns the request `list_workflow_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_workflow_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkflowTypesInput,
-    ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_workflow_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_workflow_types_request(&self, input: ListWorkflowTypesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListWorkflowTypes");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `poll_for_activity_task`, also returning the metadata of the response.
+    pub async fn poll_for_activity_task_with_metadata(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> Result<(ActivityTask, ResponseMetadata), RusotoError<PollForActivityTaskError>> {
+        let request = self.build_poll_for_activity_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<WorkflowTypeInfos, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ActivityTask, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkflowTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PollForActivityTaskError::from_response(response))
         }
     }
 
-    /// <p>Used by workers to get an <a>ActivityTask</a> from the specified activity <code>taskList</code>. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available. The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns an empty result. An empty result, in this context, means that an ActivityTask is returned, but that the value of taskToken is an empty string. If a task is returned, the worker should use its type to identify and process it correctly.</p> <important> <p>Workers should set their client side socket timeout to at least 70 seconds (10 seconds higher than the maximum time service may hold the poll request).</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn poll_for_activity_task(
+    /// Presigns the request `poll_for_activity_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_poll_for_activity_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PollForActivityTaskInput,
-    ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_poll_for_activity_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_poll_for_activity_task_request(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForActivityTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `poll_for_decision_task`, also returning the metadata of the response.
+    pub async fn poll_for_decision_task_with_metadata(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> Result<(DecisionTask, ResponseMetadata), RusotoError<PollForDecisionTaskError>> {
+        let request = self.build_poll_for_decision_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivityTask, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DecisionTask, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PollForActivityTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PollForDecisionTaskError::from_response(response))
         }
     }
 
-    /// <p>Used by deciders to get a <a>DecisionTask</a> from the specified decision <code>taskList</code>. A decision task may be returned for any open workflow execution that is using the specified task list. The task includes a paginated view of the history of the workflow execution. The decider should use the workflow type and the history to determine how to properly handle the task.</p> <p>This action initiates a long poll, where the service holds the HTTP connection open and responds as soon a task becomes available. If no decision task is available in the specified task list before the timeout of 60 seconds expires, an empty result is returned. An empty result, in this context, means that a DecisionTask is returned, but that the value of taskToken is an empty string.</p> <important> <p>Deciders should set their client side socket timeout to at least 70 seconds (10 seconds higher than the timeout).</p> </important> <important> <p>Because the number of workflow history events for a single workflow execution might be very large, the result returned might be split up across a number of pages. To retrieve subsequent pages, make additional calls to <code>PollForDecisionTask</code> using the <code>nextPageToken</code> returned by the initial call. Note that you do <i>not</i> call <code>GetWorkflowExecutionHistory</code> with this <code>nextPageToken</code>. Instead, call <code>PollForDecisionTask</code> again.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn poll_for_decision_task(
+    /// Presigns the request `poll_for_decision_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_poll_for_decision_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PollForDecisionTaskInput,
-    ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_poll_for_decision_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_poll_for_decision_task_request(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForDecisionTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `record_activity_task_heartbeat`, also returning the metadata of the response.
+    pub async fn record_activity_task_heartbeat_with_metadata(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> Result<(ActivityTaskStatus, ResponseMetadata), RusotoError<RecordActivityTaskHeartbeatError>>
+    {
+        let request = self.build_record_activity_task_heartbeat_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DecisionTask, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ActivityTaskStatus, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PollForDecisionTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RecordActivityTaskHeartbeatError::from_response(response))
         }
     }
 
-    /// <p>Used by activity workers to report to the service that the <a>ActivityTask</a> represented by the specified <code>taskToken</code> is still making progress. The worker can also specify details of the progress, for example percent complete, using the <code>details</code> parameter. This action can also be used by the worker as a mechanism to check if cancellation is being requested for the activity task. If a cancellation is being attempted for the specified task, then the boolean <code>cancelRequested</code> flag returned by the service is set to <code>true</code>.</p> <p>This action resets the <code>taskHeartbeatTimeout</code> clock. The <code>taskHeartbeatTimeout</code> is specified in <a>RegisterActivityType</a>.</p> <p>This action doesn't in itself create an event in the workflow execution history. However, if the task times out, the workflow execution history contains a <code>ActivityTaskTimedOut</code> event that contains the information from the last heartbeat generated by the activity worker.</p> <note> <p>The <code>taskStartToCloseTimeout</code> of an activity type is the maximum duration of an activity task, regardless of the number of <a>RecordActivityTaskHeartbeat</a> requests received. The <code>taskStartToCloseTimeout</code> is also specified in <a>RegisterActivityType</a>.</p> </note> <note> <p>This operation is only useful for long-lived activities to report liveliness of the task and to determine if a cancellation is being attempted.</p> </note> <important> <p>If the <code>cancelRequested</code> flag returns <code>true</code>, a cancellation is being attempted. If the worker can cancel the activity, it should respond with <a>RespondActivityTaskCanceled</a>. Otherwise, it should ignore the cancellation request.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn record_activity_task_heartbeat(
+    /// Presigns the request `record_activity_task_heartbeat` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_record_activity_task_heartbeat(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RecordActivityTaskHeartbeatInput,
-    ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_record_activity_task_heartbeat_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_record_activity_task_heartbeat_request(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RecordActivityTaskHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5551,108 +6473,197 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_activity_type`, also returning the metadata of the response.
+    pub async fn register_activity_type_with_metadata(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterActivityTypeError>> {
+        let request = self.build_register_activity_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivityTaskStatus, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RecordActivityTaskHeartbeatError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterActivityTypeError::from_response(response))
         }
     }
 
-    /// <p>Registers a new <i>activity type</i> along with its configuration settings in the specified domain.</p> <important> <p>A <code>TypeAlreadyExists</code> fault is returned if the type already exists in the domain. You cannot change any configuration settings of the type after its registration, and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn register_activity_type(
+    /// Presigns the request `register_activity_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_activity_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterActivityTypeInput,
-    ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_activity_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_activity_type_request(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_domain`, also returning the metadata of the response.
+    pub async fn register_domain_with_metadata(
+        &self,
+        input: RegisterDomainInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterDomainError>> {
+        let request = self.build_register_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterActivityTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterDomainError::from_response(response))
         }
     }
 
-    /// <p>Registers a new domain.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>You cannot use an IAM policy to control domain access for this action. The name of the domain being registered is available as the resource of this action.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn register_domain(
+    /// Presigns the request `register_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterDomainInput,
-    ) -> Result<(), RusotoError<RegisterDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_domain_request(&self, input: RegisterDomainInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_workflow_type`, also returning the metadata of the response.
+    pub async fn register_workflow_type_with_metadata(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterWorkflowTypeError>> {
+        let request = self.build_register_workflow_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterWorkflowTypeError::from_response(response))
         }
     }
 
-    /// <p>Registers a new <i>workflow type</i> and its configuration settings in the specified domain.</p> <p>The retention period for the workflow history is set by the <a>RegisterDomain</a> action.</p> <important> <p>If the type already exists, then a <code>TypeAlreadyExists</code> fault is returned. You cannot change the configuration settings of a workflow type once it is registered and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn register_workflow_type(
+    /// Presigns the request `register_workflow_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_workflow_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterWorkflowTypeInput,
-    ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_workflow_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_workflow_type_request(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `request_cancel_workflow_execution`, also returning the metadata of the response.
+    pub async fn request_cancel_workflow_execution_with_metadata(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RequestCancelWorkflowExecutionError>> {
+        let request = self.build_request_cancel_workflow_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterWorkflowTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RequestCancelWorkflowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Records a <code>WorkflowExecutionCancelRequested</code> event in the currently running workflow execution identified by the given domain, workflowId, and runId. This logically requests the cancellation of the workflow execution as a whole. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> <note> <p>If the runId isn't specified, the <code>WorkflowExecutionCancelRequested</code> event is recorded in the history of the current open workflow execution with the specified workflowId in the domain.</p> </note> <note> <p>Because this action allows the workflow to properly clean up and gracefully close, it should be used instead of <a>TerminateWorkflowExecution</a> when possible.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn request_cancel_workflow_execution(
+    /// Presigns the request `request_cancel_workflow_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_request_cancel_workflow_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RequestCancelWorkflowExecutionInput,
-    ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_request_cancel_workflow_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_request_cancel_workflow_execution_request(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RequestCancelWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5662,27 +6673,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `respond_activity_task_canceled`, also returning the metadata of the response.
+    pub async fn respond_activity_task_canceled_with_metadata(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RespondActivityTaskCanceledError>> {
+        let request = self.build_respond_activity_task_canceled_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RequestCancelWorkflowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RespondActivityTaskCanceledError::from_response(response))
         }
     }
 
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> was successfully canceled. Additional <code>details</code> can be provided using the <code>details</code> argument.</p> <p>These <code>details</code> (if provided) appear in the <code>ActivityTaskCanceled</code> event added to the workflow history.</p> <important> <p>Only use this operation if the <code>canceled</code> flag of a <a>RecordActivityTaskHeartbeat</a> request returns <code>true</code> and if the activity can be safely undone or abandoned.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, RespondActivityTaskCanceled, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn respond_activity_task_canceled(
+    /// Presigns the request `respond_activity_task_canceled` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_respond_activity_task_canceled(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RespondActivityTaskCanceledInput,
-    ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_respond_activity_task_canceled_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_respond_activity_task_canceled_request(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCanceled");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5692,27 +6726,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `respond_activity_task_completed`, also returning the metadata of the response.
+    pub async fn respond_activity_task_completed_with_metadata(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RespondActivityTaskCompletedError>> {
+        let request = self.build_respond_activity_task_completed_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RespondActivityTaskCanceledError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RespondActivityTaskCompletedError::from_response(response))
         }
     }
 
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> completed successfully with a <code>result</code> (if provided). The <code>result</code> appears in the <code>ActivityTaskCompleted</code> event in the workflow history.</p> <important> <p>If the requested task doesn't complete successfully, use <a>RespondActivityTaskFailed</a> instead. If the worker finds that the task is canceled through the <code>canceled</code> flag returned by <a>RecordActivityTaskHeartbeat</a>, it should cancel the task, clean up and then call <a>RespondActivityTaskCanceled</a>.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to RespondActivityTaskCompleted, <a>RespondActivityTaskCanceled</a>, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn respond_activity_task_completed(
+    /// Presigns the request `respond_activity_task_completed` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_respond_activity_task_completed(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RespondActivityTaskCompletedInput,
-    ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_respond_activity_task_completed_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_respond_activity_task_completed_request(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCompleted");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5722,27 +6779,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `respond_activity_task_failed`, also returning the metadata of the response.
+    pub async fn respond_activity_task_failed_with_metadata(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RespondActivityTaskFailedError>> {
+        let request = self.build_respond_activity_task_failed_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RespondActivityTaskCompletedError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RespondActivityTaskFailedError::from_response(response))
         }
     }
 
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> has failed with <code>reason</code> (if specified). The <code>reason</code> and <code>details</code> appear in the <code>ActivityTaskFailed</code> event added to the workflow history.</p> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, <a>RespondActivityTaskCanceled</a>, RespondActivityTaskFailed, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn respond_activity_task_failed(
+    /// Presigns the request `respond_activity_task_failed` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_respond_activity_task_failed(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RespondActivityTaskFailedInput,
-    ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_respond_activity_task_failed_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_respond_activity_task_failed_request(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskFailed");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5752,27 +6832,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `respond_decision_task_completed`, also returning the metadata of the response.
+    pub async fn respond_decision_task_completed_with_metadata(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RespondDecisionTaskCompletedError>> {
+        let request = self.build_respond_decision_task_completed_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RespondActivityTaskFailedError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RespondDecisionTaskCompletedError::from_response(response))
         }
     }
 
-    /// <p>Used by deciders to tell the service that the <a>DecisionTask</a> identified by the <code>taskToken</code> has successfully completed. The <code>decisions</code> argument specifies the list of decisions made while processing the task.</p> <p>A <code>DecisionTaskCompleted</code> event is added to the workflow history. The <code>executionContext</code> specified is attached to the event in the workflow execution history.</p> <p> <b>Access Control</b> </p> <p>If an IAM policy grants permission to use <code>RespondDecisionTaskCompleted</code>, it can express permissions for the list of decisions in the <code>decisions</code> parameter. Each of the decisions has one or more parameters, much like a regular API call. To allow for policies to be as readable as possible, you can express permissions on decisions as if they were actual API calls, including applying conditions to some parameters. For more information, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn respond_decision_task_completed(
+    /// Presigns the request `respond_decision_task_completed` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_respond_decision_task_completed(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RespondDecisionTaskCompletedInput,
-    ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_respond_decision_task_completed_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_respond_decision_task_completed_request(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondDecisionTaskCompleted");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5782,27 +6885,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `signal_workflow_execution`, also returning the metadata of the response.
+    pub async fn signal_workflow_execution_with_metadata(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SignalWorkflowExecutionError>> {
+        let request = self.build_signal_workflow_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RespondDecisionTaskCompletedError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SignalWorkflowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Records a <code>WorkflowExecutionSignaled</code> event in the workflow execution history and creates a decision task for the workflow execution identified by the given domain, workflowId and runId. The event is recorded with the specified user defined signalName and input (if provided).</p> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionSignaled</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>If the specified workflow execution isn't open, this method fails with <code>UnknownResource</code>.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn signal_workflow_execution(
+    /// Presigns the request `signal_workflow_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_signal_workflow_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SignalWorkflowExecutionInput,
-    ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_signal_workflow_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_signal_workflow_execution_request(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("SignalWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5812,27 +6938,54 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_workflow_execution`, also returning the metadata of the response.
+    pub async fn start_workflow_execution_with_metadata(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> Result<(Run, ResponseMetadata), RusotoError<StartWorkflowExecutionError>> {
+        let request = self.build_start_workflow_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Run, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SignalWorkflowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartWorkflowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Starts an execution of the workflow type in the specified domain using the provided <code>workflowId</code> and input data.</p> <p>This action returns the newly started workflow execution.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.0</code>: The key is <code>swf:tagList.member.0</code>.</p> </li> <li> <p> <code>tagList.member.1</code>: The key is <code>swf:tagList.member.1</code>.</p> </li> <li> <p> <code>tagList.member.2</code>: The key is <code>swf:tagList.member.2</code>.</p> </li> <li> <p> <code>tagList.member.3</code>: The key is <code>swf:tagList.member.3</code>.</p> </li> <li> <p> <code>tagList.member.4</code>: The key is <code>swf:tagList.member.4</code>.</p> </li> <li> <p> <code>taskList</code>: String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn start_workflow_execution(
+    /// Presigns the request `start_workflow_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_workflow_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartWorkflowExecutionInput,
-    ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_workflow_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_workflow_execution_request(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("StartWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5842,54 +6995,97 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Run, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartWorkflowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Add a tag to a Amazon SWF domain.</p> <note> <p>Amazon SWF supports a maximum of 50 tags per resource.</p> </note></p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `terminate_workflow_execution`, also returning the metadata of the response.
+    pub async fn terminate_workflow_execution_with_metadata(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TerminateWorkflowExecutionError>> {
+        let request = self.build_terminate_workflow_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TerminateWorkflowExecutionError::from_response(response))
         }
     }
 
-    /// <p>Records a <code>WorkflowExecutionTerminated</code> event and forces closure of the workflow execution identified by the given domain, runId, and workflowId. The child policy, registered with the workflow type or specified when starting this execution, is applied to any open child workflow executions of this workflow execution.</p> <important> <p>If the identified workflow execution was in progress, it is terminated immediately.</p> </important> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionTerminated</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>You should consider using <a>RequestCancelWorkflowExecution</a> action instead because it allows the workflow to gracefully close while <a>TerminateWorkflowExecution</a> doesn't.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn terminate_workflow_execution(
+    /// Presigns the request `terminate_workflow_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_workflow_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TerminateWorkflowExecutionInput,
-    ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_workflow_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_workflow_execution_request(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TerminateWorkflowExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5899,27 +7095,50 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `undeprecate_activity_type`, also returning the metadata of the response.
+    pub async fn undeprecate_activity_type_with_metadata(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UndeprecateActivityTypeError>> {
+        let request = self.build_undeprecate_activity_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TerminateWorkflowExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UndeprecateActivityTypeError::from_response(response))
         }
     }
 
-    /// <p>Undeprecates a previously deprecated <i>activity type</i>. After an activity type has been undeprecated, you can create new tasks of that activity type.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn undeprecate_activity_type(
+    /// Presigns the request `undeprecate_activity_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_undeprecate_activity_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UndeprecateActivityTypeInput,
-    ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_undeprecate_activity_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_undeprecate_activity_type_request(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateActivityType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5929,54 +7148,97 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `undeprecate_domain`, also returning the metadata of the response.
+    pub async fn undeprecate_domain_with_metadata(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UndeprecateDomainError>> {
+        let request = self.build_undeprecate_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UndeprecateActivityTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UndeprecateDomainError::from_response(response))
         }
     }
 
-    /// <p>Undeprecates a previously deprecated domain. After a domain has been undeprecated it can be used to create new workflow executions or register new types.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn undeprecate_domain(
+    /// Presigns the request `undeprecate_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_undeprecate_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UndeprecateDomainInput,
-    ) -> Result<(), RusotoError<UndeprecateDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_undeprecate_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_undeprecate_domain_request(&self, input: UndeprecateDomainInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateDomain");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `undeprecate_workflow_type`, also returning the metadata of the response.
+    pub async fn undeprecate_workflow_type_with_metadata(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UndeprecateWorkflowTypeError>> {
+        let request = self.build_undeprecate_workflow_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UndeprecateDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UndeprecateWorkflowTypeError::from_response(response))
         }
     }
 
-    /// <p>Undeprecates a previously deprecated <i>workflow type</i>. After a workflow type has been undeprecated, you can create new executions of that type. </p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
-    async fn undeprecate_workflow_type(
+    /// Presigns the request `undeprecate_workflow_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_undeprecate_workflow_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UndeprecateWorkflowTypeInput,
-    ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_undeprecate_workflow_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_undeprecate_workflow_type_request(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateWorkflowType");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5986,45 +7248,680 @@ impl Swf for SwfClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UndeprecateWorkflowTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove a tag from a Amazon SWF domain.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetWorkflowExecutionHistory` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn get_workflow_execution_history_pages(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> impl futures::Stream<Item = Result<History, RusotoError<GetWorkflowExecutionHistoryError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.get_workflow_execution_history(input).await },
+            |input: &GetWorkflowExecutionHistoryInput, output: &History| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(GetWorkflowExecutionHistoryInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `events` of every page of `GetWorkflowExecutionHistory` results for `input`, as `get_workflow_execution_history_pages` requests them.
+    pub fn get_workflow_execution_history_stream(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> impl futures::Stream<
+        Item = Result<HistoryEvent, RusotoError<GetWorkflowExecutionHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_workflow_execution_history_pages(input), |page| {
+            page.events
+        })
+    }
+
+    /// The pages of `ListActivityTypes` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn list_activity_types_pages(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> impl futures::Stream<Item = Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.list_activity_types(input).await },
+            |input: &ListActivityTypesInput, output: &ActivityTypeInfos| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(ListActivityTypesInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `typeInfos` of every page of `ListActivityTypes` results for `input`, as `list_activity_types_pages` requests them.
+    pub fn list_activity_types_stream(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> impl futures::Stream<Item = Result<ActivityTypeInfo, RusotoError<ListActivityTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_activity_types_pages(input), |page| {
+            page.type_infos
+        })
+    }
+
+    /// The pages of `ListClosedWorkflowExecutions` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn list_closed_workflow_executions_pages(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move {
+                client.list_closed_workflow_executions(input).await
+            },
+            |input: &ListClosedWorkflowExecutionsInput, output: &WorkflowExecutionInfos| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(ListClosedWorkflowExecutionsInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `executionInfos` of every page of `ListClosedWorkflowExecutions` results for `input`, as `list_closed_workflow_executions_pages` requests them.
+    pub fn list_closed_workflow_executions_stream(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<WorkflowExecutionInfo, RusotoError<ListClosedWorkflowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_closed_workflow_executions_pages(input), |page| {
+            page.execution_infos
+        })
+    }
+
+    /// The pages of `ListDomains` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn list_domains_pages(
+        &self,
+        input: ListDomainsInput,
+    ) -> impl futures::Stream<Item = Result<DomainInfos, RusotoError<ListDomainsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.list_domains(input).await },
+            |input: &ListDomainsInput, output: &DomainInfos| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(ListDomainsInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `domainInfos` of every page of `ListDomains` results for `input`, as `list_domains_pages` requests them.
+    pub fn list_domains_stream(
+        &self,
+        input: ListDomainsInput,
+    ) -> impl futures::Stream<Item = Result<DomainInfo, RusotoError<ListDomainsError>>> + Send {
+        rusoto_core::pagination::items(self.list_domains_pages(input), |page| page.domain_infos)
+    }
+
+    /// The pages of `ListOpenWorkflowExecutions` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn list_open_workflow_executions_pages(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.list_open_workflow_executions(input).await },
+            |input: &ListOpenWorkflowExecutionsInput, output: &WorkflowExecutionInfos| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(ListOpenWorkflowExecutionsInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `executionInfos` of every page of `ListOpenWorkflowExecutions` results for `input`, as `list_open_workflow_executions_pages` requests them.
+    pub fn list_open_workflow_executions_stream(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<WorkflowExecutionInfo, RusotoError<ListOpenWorkflowExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_open_workflow_executions_pages(input), |page| {
+            page.execution_infos
+        })
+    }
+
+    /// The pages of `ListWorkflowTypes` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn list_workflow_types_pages(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> impl futures::Stream<Item = Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.list_workflow_types(input).await },
+            |input: &ListWorkflowTypesInput, output: &WorkflowTypeInfos| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(ListWorkflowTypesInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `typeInfos` of every page of `ListWorkflowTypes` results for `input`, as `list_workflow_types_pages` requests them.
+    pub fn list_workflow_types_stream(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> impl futures::Stream<Item = Result<WorkflowTypeInfo, RusotoError<ListWorkflowTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_workflow_types_pages(input), |page| {
+            page.type_infos
+        })
+    }
+
+    /// The pages of `PollForDecisionTask` results for `input`, requesting each next page with the `nextPageToken` of the last one as its `nextPageToken`.
+    /// The page size is the `maximumPageSize` of `input`.
+    pub fn poll_for_decision_task_pages(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> impl futures::Stream<Item = Result<DecisionTask, RusotoError<PollForDecisionTaskError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: SwfClient, input| async move { client.poll_for_decision_task(input).await },
+            |input: &PollForDecisionTaskInput, output: &DecisionTask| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(PollForDecisionTaskInput {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `events` of every page of `PollForDecisionTask` results for `input`, as `poll_for_decision_task_pages` requests them.
+    pub fn poll_for_decision_task_stream(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> impl futures::Stream<Item = Result<HistoryEvent, RusotoError<PollForDecisionTaskError>>> + Send
+    {
+        rusoto_core::pagination::items(self.poll_for_decision_task_pages(input), |page| page.events)
+    }
+}
+
+#[async_trait]
+impl Swf for SwfClient {
+    /// <p>Returns the number of closed workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
+        self.count_closed_workflow_executions_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of open workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
+        self.count_open_workflow_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the estimated number of activity tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no activity task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
+        self.count_pending_activity_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the estimated number of decision tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no decision task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
+        self.count_pending_decision_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecates the specified <i>activity type</i>. After an activity type has been deprecated, you cannot create new tasks of that activity type. Tasks of this type that were scheduled before the type was deprecated continue to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
+        self.deprecate_activity_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecates the specified domain. After a domain has been deprecated it cannot be used to create new workflow executions or register new types. However, you can still use visibility actions on this domain. Deprecating a domain also deprecates all activity and workflow types registered in the domain. Executions that were started before the domain was deprecated continues to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> Result<(), RusotoError<DeprecateDomainError>> {
+        self.deprecate_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecates the specified <i>workflow type</i>. After a workflow type has been deprecated, you cannot create new executions of that type. Executions that were started before the type was deprecated continues to run. A deprecated workflow type may still be used when calling visibility actions.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
+        self.deprecate_workflow_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified activity type. This includes configuration settings provided when the type was registered and other general information about the type.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
+        self.describe_activity_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified domain, including description and status.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
+        self.describe_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified workflow execution including its type and some statistics.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
+        self.describe_workflow_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified <i>workflow type</i>. This includes configuration settings specified when the type was registered and other information such as creation date, current status, etc.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
+        self.describe_workflow_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the history of the specified workflow execution. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
+        self.get_workflow_execution_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all activities registered in the specified domain that match the specified name and registration status. The result includes information like creation date, current status of the activity, etc. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
+        self.list_activity_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of closed workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
+        self.list_closed_workflow_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of domains registered in the account. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains. The element must be set to <code>arn:aws:swf::AccountID:domain/*</code>, where <i>AccountID</i> is the account ID, with no dashes.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn list_domains(
+        &self,
+        input: ListDomainsInput,
+    ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
+        self.list_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of open workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
+        self.list_open_workflow_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List tags for a given domain.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about workflow types in the specified domain. The results may be split into multiple pages that can be retrieved by making the call repeatedly.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
+        self.list_workflow_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by workers to get an <a>ActivityTask</a> from the specified activity <code>taskList</code>. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available. The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns an empty result. An empty result, in this context, means that an ActivityTask is returned, but that the value of taskToken is an empty string. If a task is returned, the worker should use its type to identify and process it correctly.</p> <important> <p>Workers should set their client side socket timeout to at least 70 seconds (10 seconds higher than the maximum time service may hold the poll request).</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
+        self.poll_for_activity_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by deciders to get a <a>DecisionTask</a> from the specified decision <code>taskList</code>. A decision task may be returned for any open workflow execution that is using the specified task list. The task includes a paginated view of the history of the workflow execution. The decider should use the workflow type and the history to determine how to properly handle the task.</p> <p>This action initiates a long poll, where the service holds the HTTP connection open and responds as soon a task becomes available. If no decision task is available in the specified task list before the timeout of 60 seconds expires, an empty result is returned. An empty result, in this context, means that a DecisionTask is returned, but that the value of taskToken is an empty string.</p> <important> <p>Deciders should set their client side socket timeout to at least 70 seconds (10 seconds higher than the timeout).</p> </important> <important> <p>Because the number of workflow history events for a single workflow execution might be very large, the result returned might be split up across a number of pages. To retrieve subsequent pages, make additional calls to <code>PollForDecisionTask</code> using the <code>nextPageToken</code> returned by the initial call. Note that you do <i>not</i> call <code>GetWorkflowExecutionHistory</code> with this <code>nextPageToken</code>. Instead, call <code>PollForDecisionTask</code> again.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
+        self.poll_for_decision_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by activity workers to report to the service that the <a>ActivityTask</a> represented by the specified <code>taskToken</code> is still making progress. The worker can also specify details of the progress, for example percent complete, using the <code>details</code> parameter. This action can also be used by the worker as a mechanism to check if cancellation is being requested for the activity task. If a cancellation is being attempted for the specified task, then the boolean <code>cancelRequested</code> flag returned by the service is set to <code>true</code>.</p> <p>This action resets the <code>taskHeartbeatTimeout</code> clock. The <code>taskHeartbeatTimeout</code> is specified in <a>RegisterActivityType</a>.</p> <p>This action doesn't in itself create an event in the workflow execution history. However, if the task times out, the workflow execution history contains a <code>ActivityTaskTimedOut</code> event that contains the information from the last heartbeat generated by the activity worker.</p> <note> <p>The <code>taskStartToCloseTimeout</code> of an activity type is the maximum duration of an activity task, regardless of the number of <a>RecordActivityTaskHeartbeat</a> requests received. The <code>taskStartToCloseTimeout</code> is also specified in <a>RegisterActivityType</a>.</p> </note> <note> <p>This operation is only useful for long-lived activities to report liveliness of the task and to determine if a cancellation is being attempted.</p> </note> <important> <p>If the <code>cancelRequested</code> flag returns <code>true</code>, a cancellation is being attempted. If the worker can cancel the activity, it should respond with <a>RespondActivityTaskCanceled</a>. Otherwise, it should ignore the cancellation request.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
+        self.record_activity_task_heartbeat_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a new <i>activity type</i> along with its configuration settings in the specified domain.</p> <important> <p>A <code>TypeAlreadyExists</code> fault is returned if the type already exists in the domain. You cannot change any configuration settings of the type after its registration, and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
+        self.register_activity_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a new domain.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>You cannot use an IAM policy to control domain access for this action. The name of the domain being registered is available as the resource of this action.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn register_domain(
+        &self,
+        input: RegisterDomainInput,
+    ) -> Result<(), RusotoError<RegisterDomainError>> {
+        self.register_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a new <i>workflow type</i> and its configuration settings in the specified domain.</p> <p>The retention period for the workflow history is set by the <a>RegisterDomain</a> action.</p> <important> <p>If the type already exists, then a <code>TypeAlreadyExists</code> fault is returned. You cannot change the configuration settings of a workflow type once it is registered and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
+        self.register_workflow_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Records a <code>WorkflowExecutionCancelRequested</code> event in the currently running workflow execution identified by the given domain, workflowId, and runId. This logically requests the cancellation of the workflow execution as a whole. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> <note> <p>If the runId isn't specified, the <code>WorkflowExecutionCancelRequested</code> event is recorded in the history of the current open workflow execution with the specified workflowId in the domain.</p> </note> <note> <p>Because this action allows the workflow to properly clean up and gracefully close, it should be used instead of <a>TerminateWorkflowExecution</a> when possible.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
+        self.request_cancel_workflow_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> was successfully canceled. Additional <code>details</code> can be provided using the <code>details</code> argument.</p> <p>These <code>details</code> (if provided) appear in the <code>ActivityTaskCanceled</code> event added to the workflow history.</p> <important> <p>Only use this operation if the <code>canceled</code> flag of a <a>RecordActivityTaskHeartbeat</a> request returns <code>true</code> and if the activity can be safely undone or abandoned.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, RespondActivityTaskCanceled, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
+        self.respond_activity_task_canceled_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> completed successfully with a <code>result</code> (if provided). The <code>result</code> appears in the <code>ActivityTaskCompleted</code> event in the workflow history.</p> <important> <p>If the requested task doesn't complete successfully, use <a>RespondActivityTaskFailed</a> instead. If the worker finds that the task is canceled through the <code>canceled</code> flag returned by <a>RecordActivityTaskHeartbeat</a>, it should cancel the task, clean up and then call <a>RespondActivityTaskCanceled</a>.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to RespondActivityTaskCompleted, <a>RespondActivityTaskCanceled</a>, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
+        self.respond_activity_task_completed_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> has failed with <code>reason</code> (if specified). The <code>reason</code> and <code>details</code> appear in the <code>ActivityTaskFailed</code> event added to the workflow history.</p> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, <a>RespondActivityTaskCanceled</a>, RespondActivityTaskFailed, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
+        self.respond_activity_task_failed_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by deciders to tell the service that the <a>DecisionTask</a> identified by the <code>taskToken</code> has successfully completed. The <code>decisions</code> argument specifies the list of decisions made while processing the task.</p> <p>A <code>DecisionTaskCompleted</code> event is added to the workflow history. The <code>executionContext</code> specified is attached to the event in the workflow execution history.</p> <p> <b>Access Control</b> </p> <p>If an IAM policy grants permission to use <code>RespondDecisionTaskCompleted</code>, it can express permissions for the list of decisions in the <code>decisions</code> parameter. Each of the decisions has one or more parameters, much like a regular API call. To allow for policies to be as readable as possible, you can express permissions on decisions as if they were actual API calls, including applying conditions to some parameters. For more information, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
+        self.respond_decision_task_completed_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Records a <code>WorkflowExecutionSignaled</code> event in the workflow execution history and creates a decision task for the workflow execution identified by the given domain, workflowId and runId. The event is recorded with the specified user defined signalName and input (if provided).</p> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionSignaled</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>If the specified workflow execution isn't open, this method fails with <code>UnknownResource</code>.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
+        self.signal_workflow_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an execution of the workflow type in the specified domain using the provided <code>workflowId</code> and input data.</p> <p>This action returns the newly started workflow execution.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.0</code>: The key is <code>swf:tagList.member.0</code>.</p> </li> <li> <p> <code>tagList.member.1</code>: The key is <code>swf:tagList.member.1</code>.</p> </li> <li> <p> <code>tagList.member.2</code>: The key is <code>swf:tagList.member.2</code>.</p> </li> <li> <p> <code>tagList.member.3</code>: The key is <code>swf:tagList.member.3</code>.</p> </li> <li> <p> <code>tagList.member.4</code>: The key is <code>swf:tagList.member.4</code>.</p> </li> <li> <p> <code>taskList</code>: String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
+        self.start_workflow_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Add a tag to a Amazon SWF domain.</p> <note> <p>Amazon SWF supports a maximum of 50 tags per resource.</p> </note></p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Records a <code>WorkflowExecutionTerminated</code> event and forces closure of the workflow execution identified by the given domain, runId, and workflowId. The child policy, registered with the workflow type or specified when starting this execution, is applied to any open child workflow executions of this workflow execution.</p> <important> <p>If the identified workflow execution was in progress, it is terminated immediately.</p> </important> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionTerminated</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>You should consider using <a>RequestCancelWorkflowExecution</a> action instead because it allows the workflow to gracefully close while <a>TerminateWorkflowExecution</a> doesn't.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
+        self.terminate_workflow_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>activity type</i>. After an activity type has been undeprecated, you can create new tasks of that activity type.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
+        self.undeprecate_activity_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Undeprecates a previously deprecated domain. After a domain has been undeprecated it can be used to create new workflow executions or register new types.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> Result<(), RusotoError<UndeprecateDomainError>> {
+        self.undeprecate_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>workflow type</i>. After a workflow type has been undeprecated, you can create new executions of that type. </p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    async fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
+        self.undeprecate_workflow_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove a tag from a Amazon SWF domain.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -495,6 +495,7 @@ pub struct Warning {
 
 /// Errors returned by AnalyzeDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AnalyzeDocumentError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -516,6 +517,8 @@ pub enum AnalyzeDocumentError {
     Throttling(String),
     /// <p>The format of the input document isn't supported. Documents for synchronous operations can be in PNG or JPEG format. Documents for asynchronous operations can also be in PDF format.</p>
     UnsupportedDocument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AnalyzeDocumentError {
@@ -523,41 +526,58 @@ impl AnalyzeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(AnalyzeDocumentError::AccessDenied(err.msg), &res)
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::BadDocument(err.msg))
+                    return RusotoError::service(AnalyzeDocumentError::BadDocument(err.msg), &res)
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::DocumentTooLarge(err.msg))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::DocumentTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::HumanLoopQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::HumanLoopQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AnalyzeDocumentError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::Throttling(err.msg))
+                    return RusotoError::service(AnalyzeDocumentError::Throttling(err.msg), &res)
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::UnsupportedDocument(err.msg))
+                    return RusotoError::service(
+                        AnalyzeDocumentError::UnsupportedDocument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AnalyzeDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -579,12 +599,14 @@ impl fmt::Display for AnalyzeDocumentError {
             }
             AnalyzeDocumentError::Throttling(ref cause) => write!(f, "{}", cause),
             AnalyzeDocumentError::UnsupportedDocument(ref cause) => write!(f, "{}", cause),
+            AnalyzeDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AnalyzeDocumentError {}
 /// Errors returned by DetectDocumentText
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectDocumentTextError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -604,6 +626,8 @@ pub enum DetectDocumentTextError {
     Throttling(String),
     /// <p>The format of the input document isn't supported. Documents for synchronous operations can be in PNG or JPEG format. Documents for asynchronous operations can also be in PDF format.</p>
     UnsupportedDocument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectDocumentTextError {
@@ -611,40 +635,58 @@ impl DetectDocumentTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectDocumentTextError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DetectDocumentTextError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::BadDocument(err.msg))
+                    return RusotoError::service(
+                        DetectDocumentTextError::BadDocument(err.msg),
+                        &res,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(DetectDocumentTextError::DocumentTooLarge(err.msg))
+                    return RusotoError::service(
+                        DetectDocumentTextError::DocumentTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectDocumentTextError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectDocumentTextError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DetectDocumentTextError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        DetectDocumentTextError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetectDocumentTextError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectDocumentTextError::Throttling(err.msg))
+                    return RusotoError::service(DetectDocumentTextError::Throttling(err.msg), &res)
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::UnsupportedDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectDocumentTextError::UnsupportedDocument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectDocumentTextError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -665,12 +707,14 @@ impl fmt::Display for DetectDocumentTextError {
             }
             DetectDocumentTextError::Throttling(ref cause) => write!(f, "{}", cause),
             DetectDocumentTextError::UnsupportedDocument(ref cause) => write!(f, "{}", cause),
+            DetectDocumentTextError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectDocumentTextError {}
 /// Errors returned by GetDocumentAnalysis
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentAnalysisError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -684,6 +728,8 @@ pub enum GetDocumentAnalysisError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Textract is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentAnalysisError {
@@ -691,31 +737,43 @@ impl GetDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetDocumentAnalysisError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentAnalysisError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidJobId(err.msg))
+                    return RusotoError::service(
+                        GetDocumentAnalysisError::InvalidJobId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentAnalysisError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetDocumentAnalysisError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentAnalysisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -733,12 +791,14 @@ impl fmt::Display for GetDocumentAnalysisError {
                 write!(f, "{}", cause)
             }
             GetDocumentAnalysisError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDocumentAnalysisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentAnalysisError {}
 /// Errors returned by GetDocumentTextDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentTextDetectionError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -752,6 +812,8 @@ pub enum GetDocumentTextDetectionError {
     ProvisionedThroughputExceeded(String),
     /// <p>Amazon Textract is temporarily unable to process the request. Try your call again.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentTextDetectionError {
@@ -759,35 +821,43 @@ impl GetDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentTextDetectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDocumentTextDetectionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidJobId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentTextDetectionError::InvalidJobId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentTextDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetDocumentTextDetectionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentTextDetectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -805,12 +875,14 @@ impl fmt::Display for GetDocumentTextDetectionError {
                 write!(f, "{}", cause)
             }
             GetDocumentTextDetectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDocumentTextDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentTextDetectionError {}
 /// Errors returned by StartDocumentAnalysis
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDocumentAnalysisError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -834,6 +906,8 @@ pub enum StartDocumentAnalysisError {
     Throttling(String),
     /// <p>The format of the input document isn't supported. Documents for synchronous operations can be in PNG or JPEG format. Documents for asynchronous operations can also be in PDF format.</p>
     UnsupportedDocument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDocumentAnalysisError {
@@ -841,54 +915,73 @@ impl StartDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::BadDocument(err.msg))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::BadDocument(err.msg),
+                        &res,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::DocumentTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::DocumentTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentAnalysisError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::UnsupportedDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentAnalysisError::UnsupportedDocument(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDocumentAnalysisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -913,12 +1006,14 @@ impl fmt::Display for StartDocumentAnalysisError {
             }
             StartDocumentAnalysisError::Throttling(ref cause) => write!(f, "{}", cause),
             StartDocumentAnalysisError::UnsupportedDocument(ref cause) => write!(f, "{}", cause),
+            StartDocumentAnalysisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDocumentAnalysisError {}
 /// Errors returned by StartDocumentTextDetection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDocumentTextDetectionError {
     /// <p>You aren't authorized to perform the action.</p>
     AccessDenied(String),
@@ -942,6 +1037,8 @@ pub enum StartDocumentTextDetectionError {
     Throttling(String),
     /// <p>The format of the input document isn't supported. Documents for synchronous operations can be in PNG or JPEG format. Documents for asynchronous operations can also be in PDF format.</p>
     UnsupportedDocument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDocumentTextDetectionError {
@@ -951,62 +1048,75 @@ impl StartDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::BadDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::BadDocument(err.msg),
+                        &res,
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::DocumentTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::DocumentTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentTextDetectionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentTextDetectionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::InvalidS3Object(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDocumentTextDetectionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentTextDetectionError::UnsupportedDocument(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartDocumentTextDetectionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1035,6 +1145,7 @@ impl fmt::Display for StartDocumentTextDetectionError {
             StartDocumentTextDetectionError::UnsupportedDocument(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartDocumentTextDetectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1089,6 +1200,7 @@ impl TextractClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> TextractClient {
         TextractClient {
             client: Client::shared(),
@@ -1114,175 +1226,426 @@ impl TextractClient {
     pub fn new_with_client(client: Client, region: region::Region) -> TextractClient {
         TextractClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<TextractClient> {
+        rusoto_core::ClientBuilder::new(TextractClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> TextractClient {
+        TextractClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Textract for TextractClient {
-    /// <p>Analyzes an input document for relationships between detected items. </p> <p>The types of information returned are as follows: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of <code>FeatureTypes</code>). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>You can choose which type of analysis to perform by specifying the <code>FeatureTypes</code> list. </p> <p>The output is returned in a list of <code>Block</code> objects.</p> <p> <code>AnalyzeDocument</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentAnalysis</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
-    async fn analyze_document(
+impl TextractClient {
+    /// Calls `analyze_document`, also returning the metadata of the response.
+    pub async fn analyze_document_with_metadata(
         &self,
         input: AnalyzeDocumentRequest,
-    ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
+    ) -> Result<(AnalyzeDocumentResponse, ResponseMetadata), RusotoError<AnalyzeDocumentError>>
+    {
+        let request = self.build_analyze_document_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AnalyzeDocumentResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AnalyzeDocumentError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `analyze_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_analyze_document(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AnalyzeDocumentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_analyze_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_analyze_document_request(&self, input: AnalyzeDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("AnalyzeDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.AnalyzeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_document_text`, also returning the metadata of the response.
+    pub async fn detect_document_text_with_metadata(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> Result<(DetectDocumentTextResponse, ResponseMetadata), RusotoError<DetectDocumentTextError>>
+    {
+        let request = self.build_detect_document_text_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AnalyzeDocumentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectDocumentTextResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AnalyzeDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectDocumentTextError::from_response(response))
         }
     }
 
-    /// <p>Detects text in the input document. Amazon Textract can detect lines of text and the words that make up a line of text. The input document must be an image in JPEG or PNG format. <code>DetectDocumentText</code> returns the detected text in an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p> <code>DetectDocumentText</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentTextDetection</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
-    async fn detect_document_text(
+    /// Presigns the request `detect_document_text` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_document_text(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectDocumentTextRequest,
-    ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_document_text_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_document_text_request(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("DetectDocumentText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.DetectDocumentText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_document_analysis`, also returning the metadata of the response.
+    pub async fn get_document_analysis_with_metadata(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> Result<
+        (GetDocumentAnalysisResponse, ResponseMetadata),
+        RusotoError<GetDocumentAnalysisError>,
+    > {
+        let request = self.build_get_document_analysis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectDocumentTextResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDocumentAnalysisResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectDocumentTextError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDocumentAnalysisError::from_response(response))
         }
     }
 
-    /// <p>Gets the results for an Amazon Textract asynchronous operation that analyzes text in a document.</p> <p>You start asynchronous text analysis by calling <a>StartDocumentAnalysis</a>, which returns a job identifier (<code>JobId</code>). When the text analysis operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentAnalysis</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentAnalysis</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p> <code>GetDocumentAnalysis</code> returns an array of <a>Block</a> objects. The following types of information are returned: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of the <code>StartDocumentAnalysis</code> <code>FeatureTypes</code> input parameter). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentAnalysis</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
-    async fn get_document_analysis(
+    /// Presigns the request `get_document_analysis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_document_analysis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentAnalysisRequest,
-    ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_document_analysis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_document_analysis_request(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_document_text_detection`, also returning the metadata of the response.
+    pub async fn get_document_text_detection_with_metadata(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> Result<
+        (GetDocumentTextDetectionResponse, ResponseMetadata),
+        RusotoError<GetDocumentTextDetectionError>,
+    > {
+        let request = self.build_get_document_text_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDocumentAnalysisResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDocumentTextDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentAnalysisError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDocumentTextDetectionError::from_response(response))
         }
     }
 
-    /// <p>Gets the results for an Amazon Textract asynchronous operation that detects text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p>You start asynchronous text detection by calling <a>StartDocumentTextDetection</a>, which returns a job identifier (<code>JobId</code>). When the text detection operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentTextDetection</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentTextDetection</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p> <code>GetDocumentTextDetection</code> returns an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p>Use the MaxResults parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentTextDetection</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
-    async fn get_document_text_detection(
+    /// Presigns the request `get_document_text_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_document_text_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentTextDetectionRequest,
-    ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_document_text_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_document_text_detection_request(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_document_analysis`, also returning the metadata of the response.
+    pub async fn start_document_analysis_with_metadata(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> Result<
+        (StartDocumentAnalysisResponse, ResponseMetadata),
+        RusotoError<StartDocumentAnalysisError>,
+    > {
+        let request = self.build_start_document_analysis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDocumentTextDetectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDocumentAnalysisResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentTextDetectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDocumentAnalysisError::from_response(response))
         }
     }
 
-    /// <p>Starts the asynchronous analysis of an input document for relationships between detected items such as key-value pairs, tables, and selection elements.</p> <p> <code>StartDocumentAnalysis</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartDocumentAnalysis</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text analysis is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text analysis operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentAnalysis</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
-    async fn start_document_analysis(
+    /// Presigns the request `start_document_analysis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_document_analysis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDocumentAnalysisRequest,
-    ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_document_analysis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_document_analysis_request(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_document_text_detection`, also returning the metadata of the response.
+    pub async fn start_document_text_detection_with_metadata(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> Result<
+        (StartDocumentTextDetectionResponse, ResponseMetadata),
+        RusotoError<StartDocumentTextDetectionError>,
+    > {
+        let request = self.build_start_document_text_detection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDocumentAnalysisResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDocumentTextDetectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDocumentAnalysisError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDocumentTextDetectionError::from_response(response))
         }
     }
 
-    /// <p>Starts the asynchronous detection of text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p> <code>StartDocumentTextDetection</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartTextDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text detection is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentTextDetection</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
-    async fn start_document_text_detection(
+    /// Presigns the request `start_document_text_detection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_document_text_detection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDocumentTextDetectionRequest,
-    ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_document_text_detection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_document_text_detection_request(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Textract for TextractClient {
+    /// <p>Analyzes an input document for relationships between detected items. </p> <p>The types of information returned are as follows: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of <code>FeatureTypes</code>). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>You can choose which type of analysis to perform by specifying the <code>FeatureTypes</code> list. </p> <p>The output is returned in a list of <code>Block</code> objects.</p> <p> <code>AnalyzeDocument</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentAnalysis</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    async fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
+        self.analyze_document_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDocumentTextDetectionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDocumentTextDetectionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects text in the input document. Amazon Textract can detect lines of text and the words that make up a line of text. The input document must be an image in JPEG or PNG format. <code>DetectDocumentText</code> returns the detected text in an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p> <code>DetectDocumentText</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentTextDetection</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    async fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
+        self.detect_document_text_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the results for an Amazon Textract asynchronous operation that analyzes text in a document.</p> <p>You start asynchronous text analysis by calling <a>StartDocumentAnalysis</a>, which returns a job identifier (<code>JobId</code>). When the text analysis operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentAnalysis</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentAnalysis</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p> <code>GetDocumentAnalysis</code> returns an array of <a>Block</a> objects. The following types of information are returned: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of the <code>StartDocumentAnalysis</code> <code>FeatureTypes</code> input parameter). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentAnalysis</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    async fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
+        self.get_document_analysis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the results for an Amazon Textract asynchronous operation that detects text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p>You start asynchronous text detection by calling <a>StartDocumentTextDetection</a>, which returns a job identifier (<code>JobId</code>). When the text detection operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentTextDetection</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentTextDetection</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p> <code>GetDocumentTextDetection</code> returns an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p>Use the MaxResults parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentTextDetection</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    async fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
+        self.get_document_text_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the asynchronous analysis of an input document for relationships between detected items such as key-value pairs, tables, and selection elements.</p> <p> <code>StartDocumentAnalysis</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartDocumentAnalysis</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text analysis is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text analysis operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentAnalysis</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    async fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
+        self.start_document_analysis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the asynchronous detection of text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p> <code>StartDocumentTextDetection</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartTextDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text detection is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentTextDetection</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    async fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
+    {
+        self.start_document_text_detection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -1 +1,2 @@
-
+/// Real-time transcription with Amazon Transcribe streaming
+pub mod streaming;
@@ -0,0 +1,581 @@
+//! Real-time transcription with Amazon Transcribe streaming, sending audio as an event stream
+//! and receiving its transcripts as one while the audio is still being sent.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::channel::mpsc;
+use futures::{Sink, Stream, StreamExt};
+use rusoto_core::credential::ProvideAwsCredentials;
+use rusoto_core::proto;
+use rusoto_core::proto::event_stream::{encode_messages, Message, MessageStream};
+use rusoto_core::region;
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
+use rusoto_core::signature::{PayloadSigning, SignedRequest};
+use rusoto_core::{ByteStream, Client, ErrorMetadata, RusotoError};
+use serde::Deserialize;
+
+/// The number of audio events buffered while the request body is not read.
+const AUDIO_BUFFER: usize = 8;
+
+/// A client for the Amazon Transcribe Streaming Service API.
+#[derive(Clone)]
+pub struct TranscribeStreamingClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl TranscribeStreamingClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new(region: region::Region) -> TranscribeStreamingClient {
+        TranscribeStreamingClient {
+            client: Client::shared(),
+            region,
+        }
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TranscribeStreamingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TranscribeStreamingClient {
+            client: Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        }
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> TranscribeStreamingClient {
+        TranscribeStreamingClient { client, region }
+    }
+}
+
+/// The settings of a `StartStreamTranscription` session, sent as the headers of its request.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StartStreamTranscriptionRequest {
+    /// Whether the channels of the audio are transcribed separately.
+    pub enable_channel_identification: Option<bool>,
+    /// The language code of the audio, e.g. `en-US`.
+    pub language_code: String,
+    /// The encoding of the audio, `pcm` for signed 16-bit little-endian PCM, `ogg-opus` or
+    /// `flac`.
+    pub media_encoding: String,
+    /// The sample rate of the audio, in Hertz.
+    pub media_sample_rate_hertz: i64,
+    /// The number of channels of the audio, with `enable_channel_identification`.
+    pub number_of_channels: Option<i64>,
+    /// An identifier of the session, generated by Transcribe if it is missing.
+    pub session_id: Option<String>,
+    /// Whether the speakers of the audio are identified.
+    pub show_speaker_label: Option<bool>,
+    /// How the words of the vocabulary filter are shown: `remove`, `mask` or `tag`.
+    pub vocabulary_filter_method: Option<String>,
+    /// The name of the vocabulary filter of the transcription.
+    pub vocabulary_filter_name: Option<String>,
+    /// The name of the custom vocabulary of the transcription.
+    pub vocabulary_name: Option<String>,
+}
+
+/// A chunk of the audio of a session, of up to 32 KB.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AudioEvent {
+    /// The audio, in the encoding and at the sample rate of the session.
+    pub audio_chunk: Bytes,
+}
+
+/// A word or punctuation mark of a transcript.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Item {
+    /// The word or punctuation mark.
+    #[serde(rename = "Content")]
+    pub content: Option<String>,
+    /// The offset in seconds of the end of the item in the audio.
+    #[serde(rename = "EndTime")]
+    pub end_time: Option<f64>,
+    /// The speaker of the item, with `show_speaker_label`.
+    #[serde(rename = "Speaker")]
+    pub speaker: Option<String>,
+    /// The offset in seconds of the start of the item in the audio.
+    #[serde(rename = "StartTime")]
+    pub start_time: Option<f64>,
+    /// The type of the item, `pronunciation` or `punctuation`.
+    #[serde(rename = "Type")]
+    pub type_: Option<String>,
+    /// Whether the item is a word of the vocabulary filter.
+    #[serde(rename = "VocabularyFilterMatch")]
+    pub vocabulary_filter_match: Option<bool>,
+}
+
+/// A possible transcription of a part of the audio.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Alternative {
+    /// The words and punctuation marks of the transcript.
+    #[serde(rename = "Items")]
+    pub items: Option<Vec<Item>>,
+    /// The text of the transcript.
+    #[serde(rename = "Transcript")]
+    pub transcript: Option<String>,
+}
+
+/// The transcription of a part of the audio, revised by later results with the same
+/// identifier while it is partial.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptResult {
+    /// The possible transcriptions of the part of the audio.
+    #[serde(rename = "Alternatives")]
+    pub alternatives: Option<Vec<Alternative>>,
+    /// The channel of the audio, with `enable_channel_identification`.
+    #[serde(rename = "ChannelId")]
+    pub channel_id: Option<String>,
+    /// The offset in seconds of the end of the part of the audio.
+    #[serde(rename = "EndTime")]
+    pub end_time: Option<f64>,
+    /// Whether later results revise this one.
+    #[serde(rename = "IsPartial")]
+    pub is_partial: Option<bool>,
+    /// The identifier shared by the revisions of the result.
+    #[serde(rename = "ResultId")]
+    pub result_id: Option<String>,
+    /// The offset in seconds of the start of the part of the audio.
+    #[serde(rename = "StartTime")]
+    pub start_time: Option<f64>,
+}
+
+/// The results of a transcript event.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct Transcript {
+    /// The results transcribed or revised since the previous event.
+    #[serde(rename = "Results")]
+    pub results: Option<Vec<TranscriptResult>>,
+}
+
+/// An event of the transcript stream of a session.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptEvent {
+    /// The results of the event.
+    #[serde(rename = "Transcript")]
+    pub transcript: Option<Transcript>,
+}
+
+/// Errors returned by StartStreamTranscription
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum StartStreamTranscriptionError {
+    /// The request or the audio is invalid, e.g. in another encoding than the one of the
+    /// session.
+    BadRequest(String),
+    /// A session with the same identifier is already in progress.
+    Conflict(String),
+    /// Transcribe failed to process the audio.
+    InternalFailure(String),
+    /// The audio is sent faster than real time, or too many sessions are in progress.
+    LimitExceeded(String),
+    /// Transcribe is unavailable.
+    ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
+
+impl StartStreamTranscriptionError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartStreamTranscriptionError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match StartStreamTranscriptionError::from_code(&err.typ, err.msg) {
+                Some(error) => return RusotoError::service(error, &res),
+                None => {
+                    return RusotoError::unhandled(StartStreamTranscriptionError::Unhandled, &res)
+                }
+            }
+        }
+        RusotoError::Unknown(res)
+    }
+
+    /// The error of the exception or error message of a transcript stream.
+    fn from_metadata(metadata: ErrorMetadata) -> RusotoError<StartStreamTranscriptionError> {
+        let message = metadata.message().unwrap_or_default().to_owned();
+        let error = metadata
+            .code()
+            .and_then(|code| StartStreamTranscriptionError::from_code(code, message))
+            .unwrap_or_else(|| StartStreamTranscriptionError::Unhandled(metadata.clone()));
+        RusotoError::Service(error, metadata)
+    }
+
+    fn from_code(code: &str, message: String) -> Option<StartStreamTranscriptionError> {
+        match code {
+            "BadRequestException" => Some(StartStreamTranscriptionError::BadRequest(message)),
+            "ConflictException" => Some(StartStreamTranscriptionError::Conflict(message)),
+            "InternalFailureException" => {
+                Some(StartStreamTranscriptionError::InternalFailure(message))
+            }
+            "LimitExceededException" => Some(StartStreamTranscriptionError::LimitExceeded(message)),
+            "ServiceUnavailableException" => {
+                Some(StartStreamTranscriptionError::ServiceUnavailable(message))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StartStreamTranscriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StartStreamTranscriptionError::BadRequest(ref cause) => write!(f, "{}", cause),
+            StartStreamTranscriptionError::Conflict(ref cause) => write!(f, "{}", cause),
+            StartStreamTranscriptionError::InternalFailure(ref cause) => write!(f, "{}", cause),
+            StartStreamTranscriptionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartStreamTranscriptionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StartStreamTranscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
+    }
+}
+
+impl Error for StartStreamTranscriptionError {}
+
+type TranscriptionError = RusotoError<StartStreamTranscriptionError>;
+
+/// The audio of a session, sent as the request body as it is written.
+///
+/// Closing the sink ends the audio, after which Transcribe sends the last transcripts and
+/// ends the transcript stream.
+#[derive(Debug)]
+pub struct AudioStream {
+    events: mpsc::Sender<AudioEvent>,
+}
+
+impl Sink<AudioEvent> for AudioStream {
+    type Error = mpsc::SendError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.events).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, event: AudioEvent) -> Result<(), Self::Error> {
+        Pin::new(&mut self.events).start_send(event)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.events).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.events).poll_close(cx)
+    }
+}
+
+type Response = Pin<Box<dyn Future<Output = Result<ByteStream, TranscriptionError>> + Send>>;
+
+enum State {
+    Connecting(Response),
+    Streaming(MessageStream),
+    Ended,
+}
+
+/// The transcript events of a session, decoded as they arrive.
+///
+/// Events of unknown types are skipped, and the stream ends after the first error.
+pub struct TranscriptResultStream {
+    state: State,
+}
+
+impl fmt::Debug for TranscriptResultStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match self.state {
+            State::Connecting(_) => "Connecting",
+            State::Streaming(_) => "Streaming",
+            State::Ended => "Ended",
+        };
+        f.debug_struct("TranscriptResultStream")
+            .field("state", &state)
+            .finish()
+    }
+}
+
+impl Stream for TranscriptResultStream {
+    type Item = Result<TranscriptEvent, TranscriptionError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match self.state {
+                State::Connecting(ref mut response) => {
+                    match futures::ready!(response.as_mut().poll(cx)) {
+                        Ok(body) => self.state = State::Streaming(MessageStream::new(body)),
+                        Err(err) => {
+                            self.state = State::Ended;
+                            return Poll::Ready(Some(Err(err)));
+                        }
+                    }
+                    continue;
+                }
+                State::Streaming(ref mut messages) => futures::ready!(messages.poll_next_unpin(cx)),
+                State::Ended => return Poll::Ready(None),
+            };
+            let message = match message {
+                Some(Ok(message)) => message,
+                Some(Err(err)) => {
+                    self.state = State::Ended;
+                    return Poll::Ready(Some(Err(RusotoError::ParseError(err.to_string()))));
+                }
+                None => {
+                    self.state = State::Ended;
+                    return Poll::Ready(None);
+                }
+            };
+            if let Some(metadata) = message.error_metadata() {
+                self.state = State::Ended;
+                return Poll::Ready(Some(Err(StartStreamTranscriptionError::from_metadata(
+                    metadata,
+                ))));
+            }
+            if message.event_type() != Some("TranscriptEvent") {
+                continue;
+            }
+            match serde_json::from_slice(&message.payload) {
+                Ok(event) => return Poll::Ready(Some(Ok(event))),
+                Err(err) => {
+                    self.state = State::Ended;
+                    return Poll::Ready(Some(Err(RusotoError::ParseError(err.to_string()))));
+                }
+            }
+        }
+    }
+}
+
+/// The request body of the audio events of `events`, each sent as an `AudioEvent` message.
+fn audio_body(events: mpsc::Receiver<AudioEvent>) -> ByteStream {
+    encode_messages(events.map(|event| {
+        Message::event("AudioEvent", event.audio_chunk)
+            .with_header(":content-type", "application/octet-stream")
+    }))
+}
+
+impl TranscribeStreamingClient {
+    /// Starts a transcription session with the settings of `input`, returning the sink its
+    /// audio is written to and the stream of its transcripts.
+    ///
+    /// The request is sent once the transcript stream is first polled, and its body, signed
+    /// event by event, is the audio written to the sink, so the transcripts have to be read
+    /// while the audio is written, e.g. in another task. Transcribe streaming is only served
+    /// over HTTP/2, which the client negotiates by default.
+    ///
+    /// ```rust,ignore
+    /// let (mut audio, mut transcripts) = client.start_stream_transcription(input);
+    /// tokio::spawn(async move {
+    ///     while let Some(audio_chunk) = microphone.next().await {
+    ///         audio.send(AudioEvent { audio_chunk }).await?;
+    ///     }
+    ///     audio.close().await
+    /// });
+    /// while let Some(event) = transcripts.try_next().await? {
+    ///     print_transcript(event);
+    /// }
+    /// ```
+    pub fn start_stream_transcription(
+        &self,
+        input: StartStreamTranscriptionRequest,
+    ) -> (AudioStream, TranscriptResultStream) {
+        let (sender, receiver) = mpsc::channel(AUDIO_BUFFER);
+
+        let mut request =
+            SignedRequest::new("POST", "transcribe", &self.region, "/stream-transcription");
+        request.set_operation("StartStreamTranscription");
+        request.set_endpoint_prefix("transcribestreaming".to_string());
+        request.set_content_type("application/vnd.amazon.eventstream".to_owned());
+        request.add_header("x-amz-transcribe-language-code", &input.language_code);
+        request.add_header("x-amz-transcribe-media-encoding", &input.media_encoding);
+        request.add_header(
+            "x-amz-transcribe-sample-rate",
+            &input.media_sample_rate_hertz.to_string(),
+        );
+        if let Some(enable_channel_identification) = input.enable_channel_identification {
+            request.add_header(
+                "x-amz-transcribe-enable-channel-identification",
+                &enable_channel_identification.to_string(),
+            );
+        }
+        if let Some(number_of_channels) = input.number_of_channels {
+            request.add_header(
+                "x-amz-transcribe-number-of-channels",
+                &number_of_channels.to_string(),
+            );
+        }
+        if let Some(ref session_id) = input.session_id {
+            request.add_header("x-amz-transcribe-session-id", session_id);
+        }
+        if let Some(show_speaker_label) = input.show_speaker_label {
+            request.add_header(
+                "x-amz-transcribe-show-speaker-label",
+                &show_speaker_label.to_string(),
+            );
+        }
+        if let Some(ref vocabulary_filter_method) = input.vocabulary_filter_method {
+            request.add_header(
+                "x-amz-transcribe-vocabulary-filter-method",
+                vocabulary_filter_method,
+            );
+        }
+        if let Some(ref vocabulary_filter_name) = input.vocabulary_filter_name {
+            request.add_header(
+                "x-amz-transcribe-vocabulary-filter-name",
+                vocabulary_filter_name,
+            );
+        }
+        if let Some(ref vocabulary_name) = input.vocabulary_name {
+            request.add_header("x-amz-transcribe-vocabulary-name", vocabulary_name);
+        }
+        request.set_payload_stream(audio_body(receiver));
+        request.set_payload_signing(PayloadSigning::EventStream);
+
+        let client = self.client.clone();
+        let response = async move {
+            let mut response = client
+                .sign_and_dispatch(request)
+                .await
+                .map_err(RusotoError::from)?;
+            if response.status.is_success() {
+                Ok(response.body)
+            } else {
+                let try_response = response
+                    .buffer_with_limit(client.max_buffered_body_size())
+                    .await;
+                let response = try_response.map_err(RusotoError::from)?;
+                Err(StartStreamTranscriptionError::from_response(response))
+            }
+        };
+        (
+            AudioStream { events: sender },
+            TranscriptResultStream {
+                state: State::Connecting(Box::pin(response)),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{SinkExt, TryStreamExt};
+    use rusoto_core::proto::event_stream::MessageDecoder;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    fn input() -> StartStreamTranscriptionRequest {
+        StartStreamTranscriptionRequest {
+            language_code: "en-US".to_owned(),
+            media_encoding: "pcm".to_owned(),
+            media_sample_rate_hertz: 16000,
+            ..Default::default()
+        }
+    }
+
+    fn body(messages: &[Message]) -> Vec<u8> {
+        messages
+            .iter()
+            .flat_map(|message| message.encode().unwrap().to_vec())
+            .collect()
+    }
+
+    fn client(dispatcher: MockRequestDispatcher) -> TranscribeStreamingClient {
+        TranscribeStreamingClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1)
+    }
+
+    #[tokio::test]
+    async fn sends_audio_events() {
+        let (mut sender, receiver) = mpsc::channel(AUDIO_BUFFER);
+        let body = audio_body(receiver);
+        sender
+            .send(AudioEvent {
+                audio_chunk: Bytes::from_static(b"\x01\x02"),
+            })
+            .await
+            .unwrap();
+        sender.close().await.unwrap();
+
+        let chunks: Vec<Bytes> = body.try_collect().await.unwrap();
+        let mut decoder = MessageDecoder::new();
+        decoder.push(&chunks.concat());
+        let message = decoder.next_message().unwrap().unwrap();
+        assert_eq!(message.event_type(), Some("AudioEvent"));
+        assert_eq!(
+            message.str_header(":content-type"),
+            Some("application/octet-stream")
+        );
+        assert_eq!(&message.payload[..], b"\x01\x02");
+        assert!(decoder.is_empty());
+    }
+
+    #[tokio::test]
+    async fn streams_transcript_events() {
+        let transcript = r#"{"Transcript": {"Results": [{"ResultId": "1", "IsPartial": false,
+            "Alternatives": [{"Transcript": "Hello."}]}]}}"#;
+        let exception = Message::new(
+            vec![(":message-type".to_owned(), "exception".into())],
+            r#"{"Message": "audio sent too fast"}"#,
+        )
+        .with_header(":exception-type", "LimitExceededException");
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_request_checker(|request| {
+                assert_eq!(request.path(), "/stream-transcription");
+                assert_eq!(
+                    request.hostname(),
+                    "transcribestreaming.us-east-1.amazonaws.com"
+                );
+                assert_eq!(
+                    request.headers["x-amz-transcribe-sample-rate"][0],
+                    b"16000".to_vec()
+                );
+                assert_eq!(request.payload_signing, PayloadSigning::EventStream);
+            })
+            .with_binary_body(&body(&[
+                Message::event("TranscriptEvent", transcript),
+                exception,
+            ]));
+        let (_audio, transcripts) = client(dispatcher).start_stream_transcription(input());
+        let events: Vec<_> = transcripts.collect().await;
+        assert_eq!(events.len(), 2);
+        let results = events[0]
+            .as_ref()
+            .unwrap()
+            .transcript
+            .as_ref()
+            .unwrap()
+            .results
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            results[0].alternatives.as_ref().unwrap()[0].transcript,
+            Some("Hello.".to_owned())
+        );
+        match events[1] {
+            Err(RusotoError::Service(
+                StartStreamTranscriptionError::LimitExceeded(ref message),
+                _,
+            )) => {
+                assert_eq!(message, "audio sent too fast")
+            }
+            ref result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_on_rejected_sessions() {
+        let dispatcher = MockRequestDispatcher::with_status(400)
+            .with_header("x-amzn-ErrorType", "BadRequestException")
+            .with_body(r#"{"Message": "unsupported sample rate"}"#);
+        let (_audio, mut transcripts) = client(dispatcher).start_stream_transcription(input());
+        match transcripts.next().await {
+            Some(Err(RusotoError::Service(StartStreamTranscriptionError::BadRequest(_), _))) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert!(transcripts.next().await.is_none());
+    }
+}
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -644,6 +644,7 @@ pub struct VocabularyInfo {
 
 /// Errors returned by CreateVocabulary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVocabularyError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -653,6 +654,8 @@ pub enum CreateVocabularyError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVocabularyError {
@@ -660,19 +663,25 @@ impl CreateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(CreateVocabularyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(CreateVocabularyError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateVocabularyError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateVocabularyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVocabularyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -686,12 +695,14 @@ impl fmt::Display for CreateVocabularyError {
             CreateVocabularyError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateVocabularyError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateVocabularyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateVocabularyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVocabularyError {}
 /// Errors returned by CreateVocabularyFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVocabularyFilterError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -701,6 +712,8 @@ pub enum CreateVocabularyFilterError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVocabularyFilterError {
@@ -708,23 +721,31 @@ impl CreateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateVocabularyFilterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateVocabularyFilterError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVocabularyFilterError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVocabularyFilterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVocabularyFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -738,12 +759,14 @@ impl fmt::Display for CreateVocabularyFilterError {
             CreateVocabularyFilterError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateVocabularyFilterError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateVocabularyFilterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateVocabularyFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVocabularyFilterError {}
 /// Errors returned by DeleteTranscriptionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTranscriptionJobError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -751,6 +774,8 @@ pub enum DeleteTranscriptionJobError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTranscriptionJobError {
@@ -758,20 +783,25 @@ impl DeleteTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteTranscriptionJobError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTranscriptionJobError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTranscriptionJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTranscriptionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -784,12 +814,14 @@ impl fmt::Display for DeleteTranscriptionJobError {
             DeleteTranscriptionJobError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteTranscriptionJobError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteTranscriptionJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteTranscriptionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTranscriptionJobError {}
 /// Errors returned by DeleteVocabulary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVocabularyError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -799,6 +831,8 @@ pub enum DeleteVocabularyError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVocabularyError {
@@ -806,19 +840,25 @@ impl DeleteVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteVocabularyError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteVocabularyError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteVocabularyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVocabularyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVocabularyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -832,12 +872,14 @@ impl fmt::Display for DeleteVocabularyError {
             DeleteVocabularyError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteVocabularyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteVocabularyError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteVocabularyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVocabularyError {}
 /// Errors returned by DeleteVocabularyFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVocabularyFilterError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -847,6 +889,8 @@ pub enum DeleteVocabularyFilterError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVocabularyFilterError {
@@ -854,23 +898,31 @@ impl DeleteVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteVocabularyFilterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVocabularyFilterError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVocabularyFilterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteVocabularyFilterError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVocabularyFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -884,12 +936,14 @@ impl fmt::Display for DeleteVocabularyFilterError {
             DeleteVocabularyFilterError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteVocabularyFilterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteVocabularyFilterError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteVocabularyFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVocabularyFilterError {}
 /// Errors returned by GetTranscriptionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTranscriptionJobError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -899,6 +953,8 @@ pub enum GetTranscriptionJobError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTranscriptionJobError {
@@ -906,19 +962,28 @@ impl GetTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetTranscriptionJobError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetTranscriptionJobError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetTranscriptionJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::NotFound(err.msg))
+                    return RusotoError::service(GetTranscriptionJobError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTranscriptionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -932,12 +997,14 @@ impl fmt::Display for GetTranscriptionJobError {
             GetTranscriptionJobError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetTranscriptionJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetTranscriptionJobError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetTranscriptionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTranscriptionJobError {}
 /// Errors returned by GetVocabulary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVocabularyError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -947,6 +1014,8 @@ pub enum GetVocabularyError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVocabularyError {
@@ -954,19 +1023,19 @@ impl GetVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(GetVocabularyError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(GetVocabularyError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetVocabularyError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(GetVocabularyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVocabularyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -980,12 +1049,14 @@ impl fmt::Display for GetVocabularyError {
             GetVocabularyError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetVocabularyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetVocabularyError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetVocabularyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVocabularyError {}
 /// Errors returned by GetVocabularyFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVocabularyFilterError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -995,6 +1066,8 @@ pub enum GetVocabularyFilterError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVocabularyFilterError {
@@ -1002,19 +1075,28 @@ impl GetVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetVocabularyFilterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetVocabularyFilterError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetVocabularyFilterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(GetVocabularyFilterError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVocabularyFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1028,12 +1110,14 @@ impl fmt::Display for GetVocabularyFilterError {
             GetVocabularyFilterError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetVocabularyFilterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetVocabularyFilterError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetVocabularyFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVocabularyFilterError {}
 /// Errors returned by ListTranscriptionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTranscriptionJobsError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1041,6 +1125,8 @@ pub enum ListTranscriptionJobsError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTranscriptionJobsError {
@@ -1048,18 +1134,25 @@ impl ListTranscriptionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTranscriptionJobsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTranscriptionJobsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListTranscriptionJobsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTranscriptionJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1072,12 +1165,14 @@ impl fmt::Display for ListTranscriptionJobsError {
             ListTranscriptionJobsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTranscriptionJobsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListTranscriptionJobsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListTranscriptionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTranscriptionJobsError {}
 /// Errors returned by ListVocabularies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVocabulariesError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1085,6 +1180,8 @@ pub enum ListVocabulariesError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVocabulariesError {
@@ -1092,16 +1189,22 @@ impl ListVocabulariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabulariesError::BadRequest(err.msg))
+                    return RusotoError::service(ListVocabulariesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabulariesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListVocabulariesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabulariesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListVocabulariesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVocabulariesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1114,12 +1217,14 @@ impl fmt::Display for ListVocabulariesError {
             ListVocabulariesError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListVocabulariesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListVocabulariesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListVocabulariesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVocabulariesError {}
 /// Errors returned by ListVocabularyFilters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVocabularyFiltersError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1127,6 +1232,8 @@ pub enum ListVocabularyFiltersError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVocabularyFiltersError {
@@ -1134,18 +1241,25 @@ impl ListVocabularyFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListVocabularyFiltersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVocabularyFiltersError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListVocabularyFiltersError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVocabularyFiltersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1158,12 +1272,14 @@ impl fmt::Display for ListVocabularyFiltersError {
             ListVocabularyFiltersError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListVocabularyFiltersError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListVocabularyFiltersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListVocabularyFiltersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVocabularyFiltersError {}
 /// Errors returned by StartTranscriptionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTranscriptionJobError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1173,6 +1289,8 @@ pub enum StartTranscriptionJobError {
     InternalFailure(String),
     /// <p>Either you have sent too many requests or your input file is too long. Wait before you resend your request, or use a smaller file and resend the request.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTranscriptionJobError {
@@ -1180,21 +1298,31 @@ impl StartTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        StartTranscriptionJobError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::Conflict(err.msg))
+                    return RusotoError::service(
+                        StartTranscriptionJobError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTranscriptionJobError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartTranscriptionJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTranscriptionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1208,12 +1336,14 @@ impl fmt::Display for StartTranscriptionJobError {
             StartTranscriptionJobError::Conflict(ref cause) => write!(f, "{}", cause),
             StartTranscriptionJobError::InternalFailure(ref cause) => write!(f, "{}", cause),
             StartTranscriptionJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartTranscriptionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTranscriptionJobError {}
 /// Errors returned by UpdateVocabulary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVocabularyError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1225,6 +1355,8 @@ pub enum UpdateVocabularyError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVocabularyError {
@@ -1232,22 +1364,28 @@ impl UpdateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateVocabularyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(UpdateVocabularyError::Conflict(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateVocabularyError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateVocabularyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVocabularyError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVocabularyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1262,12 +1400,14 @@ impl fmt::Display for UpdateVocabularyError {
             UpdateVocabularyError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateVocabularyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateVocabularyError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateVocabularyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateVocabularyError {}
 /// Errors returned by UpdateVocabularyFilter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVocabularyFilterError {
     /// <p>Your request didn't pass one or more validation tests. For example, if the transcription you're trying to delete doesn't exist or if it is in a non-terminal state (for example, it's "in progress"). See the exception <code>Message</code> field for more information.</p>
     BadRequest(String),
@@ -1277,6 +1417,8 @@ pub enum UpdateVocabularyFilterError {
     LimitExceeded(String),
     /// <p>We can't find the requested resource. Check the name and try your request again.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVocabularyFilterError {
@@ -1284,23 +1426,31 @@ impl UpdateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateVocabularyFilterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVocabularyFilterError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVocabularyFilterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateVocabularyFilterError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVocabularyFilterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1314,6 +1464,7 @@ impl fmt::Display for UpdateVocabularyFilterError {
             UpdateVocabularyFilterError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateVocabularyFilterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateVocabularyFilterError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateVocabularyFilterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1416,6 +1567,7 @@ impl TranscribeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> TranscribeClient {
         TranscribeClient {
             client: Client::shared(),
@@ -1441,395 +1593,1013 @@ impl TranscribeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> TranscribeClient {
         TranscribeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<TranscribeClient> {
+        rusoto_core::ClientBuilder::new(TranscribeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> TranscribeClient {
+        TranscribeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Transcribe for TranscribeClient {
-    /// <p>Creates a new custom vocabulary that you can use to change the way Amazon Transcribe handles transcription of an audio file. </p>
-    async fn create_vocabulary(
+impl TranscribeClient {
+    /// Calls `create_vocabulary`, also returning the metadata of the response.
+    pub async fn create_vocabulary_with_metadata(
         &self,
         input: CreateVocabularyRequest,
-    ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
+    ) -> Result<(CreateVocabularyResponse, ResponseMetadata), RusotoError<CreateVocabularyError>>
+    {
+        let request = self.build_create_vocabulary_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVocabularyResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVocabularyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_vocabulary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vocabulary(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateVocabularyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vocabulary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vocabulary_request(&self, input: CreateVocabularyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("CreateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.CreateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_vocabulary_filter`, also returning the metadata of the response.
+    pub async fn create_vocabulary_filter_with_metadata(
+        &self,
+        input: CreateVocabularyFilterRequest,
+    ) -> Result<
+        (CreateVocabularyFilterResponse, ResponseMetadata),
+        RusotoError<CreateVocabularyFilterError>,
+    > {
+        let request = self.build_create_vocabulary_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVocabularyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVocabularyFilterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVocabularyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVocabularyFilterError::from_response(response))
         }
     }
 
-    /// <p>Creates a new vocabulary filter that you can use to filter words, such as profane words, from the output of a transcription job.</p>
-    async fn create_vocabulary_filter(
+    /// Presigns the request `create_vocabulary_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vocabulary_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVocabularyFilterRequest,
-    ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vocabulary_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vocabulary_filter_request(
+        &self,
+        input: CreateVocabularyFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("CreateVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.CreateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_transcription_job`, also returning the metadata of the response.
+    pub async fn delete_transcription_job_with_metadata(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTranscriptionJobError>> {
+        let request = self.build_delete_transcription_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVocabularyFilterResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVocabularyFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTranscriptionJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes a previously submitted transcription job along with any other generated results such as the transcription, models, and so on.</p>
-    async fn delete_transcription_job(
+    /// Presigns the request `delete_transcription_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_transcription_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTranscriptionJobRequest,
-    ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_transcription_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_transcription_job_request(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_vocabulary`, also returning the metadata of the response.
+    pub async fn delete_vocabulary_with_metadata(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVocabularyError>> {
+        let request = self.build_delete_vocabulary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTranscriptionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVocabularyError::from_response(response))
         }
     }
 
-    /// <p>Deletes a vocabulary from Amazon Transcribe. </p>
-    async fn delete_vocabulary(
+    /// Presigns the request `delete_vocabulary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vocabulary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVocabularyRequest,
-    ) -> Result<(), RusotoError<DeleteVocabularyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vocabulary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vocabulary_request(&self, input: DeleteVocabularyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_vocabulary_filter`, also returning the metadata of the response.
+    pub async fn delete_vocabulary_filter_with_metadata(
+        &self,
+        input: DeleteVocabularyFilterRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVocabularyFilterError>> {
+        let request = self.build_delete_vocabulary_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVocabularyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVocabularyFilterError::from_response(response))
         }
     }
 
-    /// <p>Removes a vocabulary filter.</p>
-    async fn delete_vocabulary_filter(
+    /// Presigns the request `delete_vocabulary_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vocabulary_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVocabularyFilterRequest,
-    ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vocabulary_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vocabulary_filter_request(
+        &self,
+        input: DeleteVocabularyFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_transcription_job`, also returning the metadata of the response.
+    pub async fn get_transcription_job_with_metadata(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> Result<
+        (GetTranscriptionJobResponse, ResponseMetadata),
+        RusotoError<GetTranscriptionJobError>,
+    > {
+        let request = self.build_get_transcription_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTranscriptionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVocabularyFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTranscriptionJobError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a transcription job. To see the status of the job, check the <code>TranscriptionJobStatus</code> field. If the status is <code>COMPLETED</code>, the job is finished and you can find the results at the location specified in the <code>TranscriptionFileUri</code> field.</p>
-    async fn get_transcription_job(
+    /// Presigns the request `get_transcription_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_transcription_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTranscriptionJobRequest,
-    ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_transcription_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_transcription_job_request(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_vocabulary`, also returning the metadata of the response.
+    pub async fn get_vocabulary_with_metadata(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> Result<(GetVocabularyResponse, ResponseMetadata), RusotoError<GetVocabularyError>> {
+        let request = self.build_get_vocabulary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTranscriptionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetVocabularyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTranscriptionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetVocabularyError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a vocabulary. </p>
-    async fn get_vocabulary(
+    /// Presigns the request `get_vocabulary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vocabulary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVocabularyRequest,
-    ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vocabulary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vocabulary_request(&self, input: GetVocabularyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_vocabulary_filter`, also returning the metadata of the response.
+    pub async fn get_vocabulary_filter_with_metadata(
+        &self,
+        input: GetVocabularyFilterRequest,
+    ) -> Result<
+        (GetVocabularyFilterResponse, ResponseMetadata),
+        RusotoError<GetVocabularyFilterError>,
+    > {
+        let request = self.build_get_vocabulary_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetVocabularyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetVocabularyFilterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVocabularyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetVocabularyFilterError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a vocabulary filter.</p>
-    async fn get_vocabulary_filter(
+    /// Presigns the request `get_vocabulary_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vocabulary_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVocabularyFilterRequest,
-    ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vocabulary_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vocabulary_filter_request(
+        &self,
+        input: GetVocabularyFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_transcription_jobs`, also returning the metadata of the response.
+    pub async fn list_transcription_jobs_with_metadata(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> Result<
+        (ListTranscriptionJobsResponse, ResponseMetadata),
+        RusotoError<ListTranscriptionJobsError>,
+    > {
+        let request = self.build_list_transcription_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVocabularyFilterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTranscriptionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVocabularyFilterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTranscriptionJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists transcription jobs with the specified status.</p>
-    async fn list_transcription_jobs(
+    /// Presigns the request `list_transcription_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_transcription_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTranscriptionJobsRequest,
-    ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_transcription_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_transcription_jobs_request(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListTranscriptionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListTranscriptionJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_vocabularies`, also returning the metadata of the response.
+    pub async fn list_vocabularies_with_metadata(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> Result<(ListVocabulariesResponse, ResponseMetadata), RusotoError<ListVocabulariesError>>
+    {
+        let request = self.build_list_vocabularies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTranscriptionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVocabulariesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTranscriptionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVocabulariesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of vocabularies that match the specified criteria. If no criteria are specified, returns the entire list of vocabularies.</p>
-    async fn list_vocabularies(
+    /// Presigns the request `list_vocabularies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_vocabularies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVocabulariesRequest,
-    ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_vocabularies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_vocabularies_request(&self, input: ListVocabulariesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListVocabularies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListVocabularies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_vocabulary_filters`, also returning the metadata of the response.
+    pub async fn list_vocabulary_filters_with_metadata(
+        &self,
+        input: ListVocabularyFiltersRequest,
+    ) -> Result<
+        (ListVocabularyFiltersResponse, ResponseMetadata),
+        RusotoError<ListVocabularyFiltersError>,
+    > {
+        let request = self.build_list_vocabulary_filters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVocabulariesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVocabularyFiltersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVocabulariesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVocabularyFiltersError::from_response(response))
         }
     }
 
-    /// <p>Gets information about vocabulary filters.</p>
-    async fn list_vocabulary_filters(
+    /// Presigns the request `list_vocabulary_filters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_vocabulary_filters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVocabularyFiltersRequest,
-    ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_vocabulary_filters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_vocabulary_filters_request(
+        &self,
+        input: ListVocabularyFiltersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListVocabularyFilters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListVocabularyFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_transcription_job`, also returning the metadata of the response.
+    pub async fn start_transcription_job_with_metadata(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> Result<
+        (StartTranscriptionJobResponse, ResponseMetadata),
+        RusotoError<StartTranscriptionJobError>,
+    > {
+        let request = self.build_start_transcription_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVocabularyFiltersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTranscriptionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVocabularyFiltersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTranscriptionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous job to transcribe speech to text. </p>
-    async fn start_transcription_job(
+    /// Presigns the request `start_transcription_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_transcription_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTranscriptionJobRequest,
-    ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_transcription_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_transcription_job_request(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("StartTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.StartTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_vocabulary`, also returning the metadata of the response.
+    pub async fn update_vocabulary_with_metadata(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> Result<(UpdateVocabularyResponse, ResponseMetadata), RusotoError<UpdateVocabularyError>>
+    {
+        let request = self.build_update_vocabulary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartTranscriptionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVocabularyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTranscriptionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVocabularyError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing vocabulary with new values. The <code>UpdateVocabulary</code> operation overwrites all of the existing information with the values that you provide in the request. </p>
-    async fn update_vocabulary(
+    /// Presigns the request `update_vocabulary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_vocabulary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVocabularyRequest,
-    ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_vocabulary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_vocabulary_request(&self, input: UpdateVocabularyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("UpdateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.UpdateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_vocabulary_filter`, also returning the metadata of the response.
+    pub async fn update_vocabulary_filter_with_metadata(
+        &self,
+        input: UpdateVocabularyFilterRequest,
+    ) -> Result<
+        (UpdateVocabularyFilterResponse, ResponseMetadata),
+        RusotoError<UpdateVocabularyFilterError>,
+    > {
+        let request = self.build_update_vocabulary_filter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVocabularyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVocabularyFilterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVocabularyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVocabularyFilterError::from_response(response))
         }
     }
 
-    /// <p>Updates a vocabulary filter with a new list of filtered words.</p>
-    async fn update_vocabulary_filter(
+    /// Presigns the request `update_vocabulary_filter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_vocabulary_filter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVocabularyFilterRequest,
-    ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_vocabulary_filter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_vocabulary_filter_request(
+        &self,
+        input: UpdateVocabularyFilterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("UpdateVocabularyFilter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.UpdateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Waits for `TranscriptionJobCompleted`, polling `GetTranscriptionJob` with `input` every 10 seconds, up to 180 times.
+    pub async fn wait_until_transcription_job_completed(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetTranscriptionJobError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 180);
+        self.wait_until_transcription_job_completed_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVocabularyFilterResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVocabularyFilterError::from_response(response))
-        }
+    }
+
+    /// Waits for `TranscriptionJobCompleted` like `wait_until_transcription_job_completed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_transcription_job_completed_with(
+        &self,
+        input: GetTranscriptionJobRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetTranscriptionJobError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_transcription_job(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.transcription_job.as_ref())
+                        .filter_map(|value| value.transcription_job_status.as_ref())
+                        .any(|value| value == "COMPLETED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.transcription_job.as_ref())
+                        .filter_map(|value| value.transcription_job_status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `VocabularyReady`, polling `GetVocabulary` with `input` every 10 seconds, up to 180 times.
+    pub async fn wait_until_vocabulary_ready(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetVocabularyError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 180);
+        self.wait_until_vocabulary_ready_with(input, &waiter).await
+    }
+
+    /// Waits for `VocabularyReady` like `wait_until_vocabulary_ready`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_vocabulary_ready_with(
+        &self,
+        input: GetVocabularyRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetVocabularyError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_vocabulary(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.vocabulary_state.as_ref())
+                        .any(|value| value == "READY"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.vocabulary_state.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Transcribe for TranscribeClient {
+    /// <p>Creates a new custom vocabulary that you can use to change the way Amazon Transcribe handles transcription of an audio file. </p>
+    async fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
+        self.create_vocabulary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new vocabulary filter that you can use to filter words, such as profane words, from the output of a transcription job.</p>
+    async fn create_vocabulary_filter(
+        &self,
+        input: CreateVocabularyFilterRequest,
+    ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
+        self.create_vocabulary_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously submitted transcription job along with any other generated results such as the transcription, models, and so on.</p>
+    async fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
+        self.delete_transcription_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a vocabulary from Amazon Transcribe. </p>
+    async fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyError>> {
+        self.delete_vocabulary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a vocabulary filter.</p>
+    async fn delete_vocabulary_filter(
+        &self,
+        input: DeleteVocabularyFilterRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
+        self.delete_vocabulary_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a transcription job. To see the status of the job, check the <code>TranscriptionJobStatus</code> field. If the status is <code>COMPLETED</code>, the job is finished and you can find the results at the location specified in the <code>TranscriptionFileUri</code> field.</p>
+    async fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
+        self.get_transcription_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a vocabulary. </p>
+    async fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
+        self.get_vocabulary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a vocabulary filter.</p>
+    async fn get_vocabulary_filter(
+        &self,
+        input: GetVocabularyFilterRequest,
+    ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
+        self.get_vocabulary_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists transcription jobs with the specified status.</p>
+    async fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
+        self.list_transcription_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of vocabularies that match the specified criteria. If no criteria are specified, returns the entire list of vocabularies.</p>
+    async fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
+        self.list_vocabularies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about vocabulary filters.</p>
+    async fn list_vocabulary_filters(
+        &self,
+        input: ListVocabularyFiltersRequest,
+    ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
+        self.list_vocabulary_filters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous job to transcribe speech to text. </p>
+    async fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
+        self.start_transcription_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing vocabulary with new values. The <code>UpdateVocabulary</code> operation overwrites all of the existing information with the values that you provide in the request. </p>
+    async fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
+        self.update_vocabulary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a vocabulary filter with a new list of filtered words.</p>
+    async fn update_vocabulary_filter(
+        &self,
+        input: UpdateVocabularyFilterRequest,
+    ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
+        self.update_vocabulary_filter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -679,6 +679,7 @@ pub struct UpdateUserResponse {
 
 /// Errors returned by CreateServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateServerError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -688,6 +689,8 @@ pub enum CreateServerError {
     ResourceExists(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateServerError {
@@ -695,19 +698,25 @@ impl CreateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateServerError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateServerError::ResourceExists(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateServerError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -721,12 +730,14 @@ impl fmt::Display for CreateServerError {
             CreateServerError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateServerError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateServerError {}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -738,6 +749,8 @@ pub enum CreateUserError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -745,22 +758,25 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateUserError::ResourceExists(err.msg))
+                    return RusotoError::service(CreateUserError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -775,12 +791,14 @@ impl fmt::Display for CreateUserError {
             CreateUserError::ResourceExists(ref cause) => write!(f, "{}", cause),
             CreateUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by DeleteServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServerError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -790,6 +808,8 @@ pub enum DeleteServerError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServerError {
@@ -797,19 +817,25 @@ impl DeleteServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteServerError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -823,12 +849,14 @@ impl fmt::Display for DeleteServerError {
             DeleteServerError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServerError {}
 /// Errors returned by DeleteSshPublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSshPublicKeyError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -840,6 +868,8 @@ pub enum DeleteSshPublicKeyError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSshPublicKeyError {
@@ -847,26 +877,34 @@ impl DeleteSshPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSshPublicKeyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteSshPublicKeyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteSshPublicKeyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSshPublicKeyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(DeleteSshPublicKeyError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSshPublicKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -881,12 +919,14 @@ impl fmt::Display for DeleteSshPublicKeyError {
             DeleteSshPublicKeyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteSshPublicKeyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteSshPublicKeyError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteSshPublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSshPublicKeyError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -896,6 +936,8 @@ pub enum DeleteUserError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -903,19 +945,22 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(DeleteUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -929,12 +974,14 @@ impl fmt::Display for DeleteUserError {
             DeleteUserError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DescribeServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServerError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -944,6 +991,8 @@ pub enum DescribeServerError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServerError {
@@ -951,19 +1000,28 @@ impl DescribeServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DescribeServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeServerError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -977,12 +1035,14 @@ impl fmt::Display for DescribeServerError {
             DescribeServerError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServerError {}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -992,6 +1052,8 @@ pub enum DescribeUserError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserError {
@@ -999,19 +1061,25 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DescribeUserError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeUserError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1025,12 +1093,14 @@ impl fmt::Display for DescribeUserError {
             DescribeUserError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserError {}
 /// Errors returned by ImportSshPublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportSshPublicKeyError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1044,6 +1114,8 @@ pub enum ImportSshPublicKeyError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportSshPublicKeyError {
@@ -1051,29 +1123,40 @@ impl ImportSshPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportSshPublicKeyError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ImportSshPublicKeyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        ImportSshPublicKeyError::ResourceExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ImportSshPublicKeyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportSshPublicKeyError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(ImportSshPublicKeyError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportSshPublicKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1089,12 +1172,14 @@ impl fmt::Display for ImportSshPublicKeyError {
             ImportSshPublicKeyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ImportSshPublicKeyError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ImportSshPublicKeyError::Throttling(ref cause) => write!(f, "{}", cause),
+            ImportSshPublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportSshPublicKeyError {}
 /// Errors returned by ListServers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServersError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1104,6 +1189,8 @@ pub enum ListServersError {
     InvalidRequest(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServersError {
@@ -1111,19 +1198,25 @@ impl ListServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListServersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListServersError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListServersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListServersError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListServersError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListServersError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListServersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListServersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1137,12 +1230,14 @@ impl fmt::Display for ListServersError {
             ListServersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListServersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListServersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListServersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServersError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1152,6 +1247,8 @@ pub enum ListTagsForResourceError {
     InvalidRequest(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1159,25 +1256,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1191,12 +1294,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1208,6 +1313,8 @@ pub enum ListUsersError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -1215,22 +1322,25 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListUsersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListUsersError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUsersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUsersError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListUsersError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListUsersError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1245,12 +1355,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListUsersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUsersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by StartServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartServerError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1262,6 +1374,8 @@ pub enum StartServerError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartServerError {
@@ -1269,22 +1383,28 @@ impl StartServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(StartServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        StartServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(StartServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StartServerError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartServerError::Throttling(err.msg))
+                    return RusotoError::service(StartServerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1299,12 +1419,14 @@ impl fmt::Display for StartServerError {
             StartServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StartServerError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartServerError {}
 /// Errors returned by StopServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopServerError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1316,6 +1438,8 @@ pub enum StopServerError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopServerError {
@@ -1323,22 +1447,25 @@ impl StopServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(StopServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        StopServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(StopServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(StopServerError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopServerError::Throttling(err.msg))
+                    return RusotoError::service(StopServerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1353,12 +1480,14 @@ impl fmt::Display for StopServerError {
             StopServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             StopServerError::Throttling(ref cause) => write!(f, "{}", cause),
+            StopServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopServerError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1368,6 +1497,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1375,19 +1506,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1401,12 +1538,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TestIdentityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestIdentityProviderError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1416,6 +1555,8 @@ pub enum TestIdentityProviderError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestIdentityProviderError {
@@ -1423,25 +1564,31 @@ impl TestIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TestIdentityProviderError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestIdentityProviderError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestIdentityProviderError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        TestIdentityProviderError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestIdentityProviderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestIdentityProviderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestIdentityProviderError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestIdentityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1455,12 +1602,14 @@ impl fmt::Display for TestIdentityProviderError {
             TestIdentityProviderError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TestIdentityProviderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TestIdentityProviderError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            TestIdentityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestIdentityProviderError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1470,6 +1619,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request has failed because the AWS Transfer for SFTP service is not available.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1477,19 +1628,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1503,12 +1663,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServerError {
     /// <p>This exception is thrown when the <code>UpdatServer</code> is called for a server that has VPC as the endpoint type and the server's <code>VpcEndpointID</code> is not in the available state.</p>
     Conflict(String),
@@ -1524,6 +1686,8 @@ pub enum UpdateServerError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServerError {
@@ -1531,28 +1695,34 @@ impl UpdateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateServerError::Conflict(err.msg))
+                    return RusotoError::service(UpdateServerError::Conflict(err.msg), &res)
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UpdateServerError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateServerError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceExists(err.msg))
+                    return RusotoError::service(UpdateServerError::ResourceExists(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateServerError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateServerError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateServerError::Throttling(err.msg))
+                    return RusotoError::service(UpdateServerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1569,12 +1739,14 @@ impl fmt::Display for UpdateServerError {
             UpdateServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateServerError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateServerError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateServerError {}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserError {
     /// <p>This exception is thrown when an error occurs in the AWS Transfer for SFTP service.</p>
     InternalServiceError(String),
@@ -1586,6 +1758,8 @@ pub enum UpdateUserError {
     ServiceUnavailable(String),
     /// <p>The request was denied due to request throttling.</p> <p> HTTP Status Code: 400</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserError {
@@ -1593,22 +1767,25 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        UpdateUserError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(UpdateUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserError::Throttling(err.msg))
+                    return RusotoError::service(UpdateUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1623,6 +1800,7 @@ impl fmt::Display for UpdateUserError {
             UpdateUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1749,6 +1927,7 @@ impl TransferClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> TransferClient {
         TransferClient {
             client: Client::shared(),
@@ -1774,496 +1953,1314 @@ impl TransferClient {
     pub fn new_with_client(client: Client, region: region::Region) -> TransferClient {
         TransferClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<TransferClient> {
+        rusoto_core::ClientBuilder::new(TransferClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> TransferClient {
+        TransferClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Transfer for TransferClient {
-    /// <p>Instantiates an autoscaling virtual server based on Secure File Transfer Protocol (SFTP) in AWS. When you make updates to your server or when you work with users, use the service-generated <code>ServerId</code> property that is assigned to the newly created server.</p>
-    async fn create_server(
+impl TransferClient {
+    /// Calls `create_server`, also returning the metadata of the response.
+    pub async fn create_server_with_metadata(
         &self,
         input: CreateServerRequest,
-    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+    ) -> Result<(CreateServerResponse, ResponseMetadata), RusotoError<CreateServerError>> {
+        let request = self.build_create_server_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateServerResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateServerError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_server(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateServerRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_server_request(&self, input: CreateServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.CreateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<(CreateUserResponse, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates a user and associates them with an existing Secure File Transfer Protocol (SFTP) server. You can only create and associate users with SFTP servers that have the <code>IdentityProviderType</code> set to <code>SERVICE_MANAGED</code>. Using parameters for <code>CreateUser</code>, you can specify the user name, set the home directory, store the user's public key, and assign the user's AWS Identity and Access Management (IAM) role. You can also optionally add a scope-down policy, and assign metadata with tags that can be used to group and search for users.</p>
-    async fn create_user(
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_server`, also returning the metadata of the response.
+    pub async fn delete_server_with_metadata(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteServerError>> {
+        let request = self.build_delete_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServerError::from_response(response))
         }
     }
 
-    /// <p>Deletes the Secure File Transfer Protocol (SFTP) server that you specify.</p> <p>No response returns from this operation.</p>
-    async fn delete_server(
+    /// Presigns the request `delete_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServerRequest,
-    ) -> Result<(), RusotoError<DeleteServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_server_request(&self, input: DeleteServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_ssh_public_key`, also returning the metadata of the response.
+    pub async fn delete_ssh_public_key_with_metadata(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteSshPublicKeyError>> {
+        let request = self.build_delete_ssh_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSshPublicKeyError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user's Secure Shell (SSH) public key.</p> <p>No response is returned from this operation.</p>
-    async fn delete_ssh_public_key(
+    /// Presigns the request `delete_ssh_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ssh_public_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSshPublicKeyRequest,
-    ) -> Result<(), RusotoError<DeleteSshPublicKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ssh_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ssh_public_key_request(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteSshPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteSshPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSshPublicKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the user belonging to the server you specify.</p> <p>No response returns from this operation.</p> <note> <p>When you delete a user from a server, the user&#39;s information is lost.</p> </note></p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<(), RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_server`, also returning the metadata of the response.
+    pub async fn describe_server_with_metadata(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<(DescribeServerResponse, ResponseMetadata), RusotoError<DescribeServerError>> {
+        let request = self.build_describe_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServerError::from_response(response))
         }
     }
 
-    /// <p>Describes the server that you specify by passing the <code>ServerId</code> parameter.</p> <p>The response contains a description of the server's properties. When you set <code>EndpointType</code> to VPC, the response will contain the <code>EndpointDetails</code>.</p>
-    async fn describe_server(
+    /// Presigns the request `describe_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServerRequest,
-    ) -> Result<DescribeServerResponse, RusotoError<DescribeServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_server_request(&self, input: DescribeServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user`, also returning the metadata of the response.
+    pub async fn describe_user_with_metadata(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<(DescribeUserResponse, ResponseMetadata), RusotoError<DescribeUserError>> {
+        let request = self.build_describe_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserError::from_response(response))
         }
     }
 
-    /// <p>Describes the user assigned to a specific server, as identified by its <code>ServerId</code> property.</p> <p>The response from this call returns the properties of the user associated with the <code>ServerId</code> value that was specified.</p>
-    async fn describe_user(
+    /// Presigns the request `describe_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserRequest,
-    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_request(&self, input: DescribeUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_ssh_public_key`, also returning the metadata of the response.
+    pub async fn import_ssh_public_key_with_metadata(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> Result<(ImportSshPublicKeyResponse, ResponseMetadata), RusotoError<ImportSshPublicKeyError>>
+    {
+        let request = self.build_import_ssh_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportSshPublicKeyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportSshPublicKeyError::from_response(response))
         }
     }
 
-    /// <p>Adds a Secure Shell (SSH) public key to a user account identified by a <code>UserName</code> value assigned to a specific server, identified by <code>ServerId</code>.</p> <p>The response returns the <code>UserName</code> value, the <code>ServerId</code> value, and the name of the <code>SshPublicKeyId</code>.</p>
-    async fn import_ssh_public_key(
+    /// Presigns the request `import_ssh_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_ssh_public_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportSshPublicKeyRequest,
-    ) -> Result<ImportSshPublicKeyResponse, RusotoError<ImportSshPublicKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_ssh_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_ssh_public_key_request(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ImportSshPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ImportSshPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_servers`, also returning the metadata of the response.
+    pub async fn list_servers_with_metadata(
+        &self,
+        input: ListServersRequest,
+    ) -> Result<(ListServersResponse, ResponseMetadata), RusotoError<ListServersError>> {
+        let request = self.build_list_servers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportSshPublicKeyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportSshPublicKeyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServersError::from_response(response))
         }
     }
 
-    /// <p>Lists the Secure File Transfer Protocol (SFTP) servers that are associated with your AWS account.</p>
-    async fn list_servers(
+    /// Presigns the request `list_servers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_servers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServersRequest,
-    ) -> Result<ListServersResponse, RusotoError<ListServersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_servers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_servers_request(&self, input: ListServersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListServersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the tags associated with the Amazon Resource Number (ARN) you specify. The resource can be a user, server, or role.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUsersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Lists the users for the server that you specify by passing the <code>ServerId</code> parameter.</p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_server`, also returning the metadata of the response.
+    pub async fn start_server_with_metadata(
+        &self,
+        input: StartServerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartServerError>> {
+        let request = self.build_start_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListUsersResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartServerError::from_response(response))
         }
     }
 
-    /// <p>Changes the state of a Secure File Transfer Protocol (SFTP) server from <code>OFFLINE</code> to <code>ONLINE</code>. It has no impact on an SFTP server that is already <code>ONLINE</code>. An <code>ONLINE</code> server can accept and process file transfer jobs.</p> <p>The state of <code>STARTING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully online. The values of <code>START_FAILED</code> can indicate an error condition. </p> <p>No response is returned from this call.</p>
-    async fn start_server(
+    /// Presigns the request `start_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartServerRequest,
-    ) -> Result<(), RusotoError<StartServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_server_request(&self, input: StartServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StartServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StartServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_server`, also returning the metadata of the response.
+    pub async fn stop_server_with_metadata(
+        &self,
+        input: StopServerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopServerError>> {
+        let request = self.build_stop_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopServerError::from_response(response))
         }
     }
 
-    /// <p>Changes the state of an SFTP server from <code>ONLINE</code> to <code>OFFLINE</code>. An <code>OFFLINE</code> server cannot accept and process file transfer jobs. Information tied to your server such as server and user properties are not affected by stopping your server. Stopping a server will not reduce or impact your Secure File Transfer Protocol (SFTP) endpoint billing.</p> <p>The state of <code>STOPPING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>STOP_FAILED</code> can indicate an error condition.</p> <p>No response is returned from this call.</p>
-    async fn stop_server(
+    /// Presigns the request `stop_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopServerRequest,
-    ) -> Result<(), RusotoError<StopServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_server_request(&self, input: StopServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StopServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StopServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Attaches a key-value pair to a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>There is no response returned from this call.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `test_identity_provider`, also returning the metadata of the response.
+    pub async fn test_identity_provider_with_metadata(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> Result<
+        (TestIdentityProviderResponse, ResponseMetadata),
+        RusotoError<TestIdentityProviderError>,
+    > {
+        let request = self.build_test_identity_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestIdentityProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TestIdentityProviderError::from_response(response))
         }
     }
 
-    /// <p>If the <code>IdentityProviderType</code> of the server is <code>API_Gateway</code>, tests whether your API Gateway is set up successfully. We highly recommend that you call this operation to test your authentication method as soon as you create your server. By doing so, you can troubleshoot issues with the API Gateway integration to ensure that your users can successfully use the service.</p>
-    async fn test_identity_provider(
+    /// Presigns the request `test_identity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_identity_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestIdentityProviderRequest,
-    ) -> Result<TestIdentityProviderResponse, RusotoError<TestIdentityProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_identity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_identity_provider_request(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TestIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TestIdentityProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestIdentityProviderResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TestIdentityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Detaches a key-value pair from a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>No response is returned from this call.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_server`, also returning the metadata of the response.
+    pub async fn update_server_with_metadata(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<(UpdateServerResponse, ResponseMetadata), RusotoError<UpdateServerError>> {
+        let request = self.build_update_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServerError::from_response(response))
         }
     }
 
-    /// <p>Updates the server properties after that server has been created.</p> <p>The <code>UpdateServer</code> call returns the <code>ServerId</code> of the Secure File Transfer Protocol (SFTP) server you updated.</p>
-    async fn update_server(
+    /// Presigns the request `update_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServerRequest,
-    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_server_request(&self, input: UpdateServerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user`, also returning the metadata of the response.
+    pub async fn update_user_with_metadata(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(UpdateUserResponse, ResponseMetadata), RusotoError<UpdateUserError>> {
+        let request = self.build_update_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserError::from_response(response))
         }
     }
 
-    /// <p>Assigns new properties to a user. Parameters you pass modify any or all of the following: the home directory, role, and policy for the <code>UserName</code> and <code>ServerId</code> you specify.</p> <p>The response returns the <code>ServerId</code> and the <code>UserName</code> for the updated user.</p>
-    async fn update_user(
+    /// Presigns the request `update_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserRequest,
-    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_request(&self, input: UpdateUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListServers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_servers_pages(
+        &self,
+        input: ListServersRequest,
+    ) -> impl futures::Stream<Item = Result<ListServersResponse, RusotoError<ListServersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input| async move { client.list_servers(input).await },
+            |input: &ListServersRequest, output: &ListServersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Servers` of every page of `ListServers` results for `input`, as `list_servers_pages` requests them.
+    pub fn list_servers_stream(
+        &self,
+        input: ListServersRequest,
+    ) -> impl futures::Stream<Item = Result<ListedServer, RusotoError<ListServersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_servers_pages(input), |page| page.servers)
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input| async move { client.list_tags_for_resource(input).await },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsersResponse, RusotoError<ListUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TransferClient, input| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Users` of every page of `ListUsers` results for `input`, as `list_users_pages` requests them.
+    pub fn list_users_stream(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListedUser, RusotoError<ListUsersError>>> + Send {
+        rusoto_core::pagination::items(self.list_users_pages(input), |page| page.users)
+    }
+
+    /// Waits for `ServerOffline`, polling `DescribeServer` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_server_offline(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_server_offline_with(input, &waiter).await
+    }
+
+    /// Waits for `ServerOffline` like `wait_until_server_offline`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_server_offline_with(
+        &self,
+        input: DescribeServerRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_server(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.server)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "OFFLINE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.server)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "STOP_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ServerOnline`, polling `DescribeServer` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_server_online(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_server_online_with(input, &waiter).await
+    }
+
+    /// Waits for `ServerOnline` like `wait_until_server_online`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_server_online_with(
+        &self,
+        input: DescribeServerRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServerError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_server(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.server)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "ONLINE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .map(|value| &value.server)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "START_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Transfer for TransferClient {
+    /// <p>Instantiates an autoscaling virtual server based on Secure File Transfer Protocol (SFTP) in AWS. When you make updates to your server or when you work with users, use the service-generated <code>ServerId</code> property that is assigned to the newly created server.</p>
+    async fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+        self.create_server_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a user and associates them with an existing Secure File Transfer Protocol (SFTP) server. You can only create and associate users with SFTP servers that have the <code>IdentityProviderType</code> set to <code>SERVICE_MANAGED</code>. Using parameters for <code>CreateUser</code>, you can specify the user name, set the home directory, store the user's public key, and assign the user's AWS Identity and Access Management (IAM) role. You can also optionally add a scope-down policy, and assign metadata with tags that can be used to group and search for users.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the Secure File Transfer Protocol (SFTP) server that you specify.</p> <p>No response returns from this operation.</p>
+    async fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<(), RusotoError<DeleteServerError>> {
+        self.delete_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user's Secure Shell (SSH) public key.</p> <p>No response is returned from this operation.</p>
+    async fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> Result<(), RusotoError<DeleteSshPublicKeyError>> {
+        self.delete_ssh_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the user belonging to the server you specify.</p> <p>No response returns from this operation.</p> <note> <p>When you delete a user from a server, the user&#39;s information is lost.</p> </note></p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the server that you specify by passing the <code>ServerId</code> parameter.</p> <p>The response contains a description of the server's properties. When you set <code>EndpointType</code> to VPC, the response will contain the <code>EndpointDetails</code>.</p>
+    async fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<DescribeServerResponse, RusotoError<DescribeServerError>> {
+        self.describe_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the user assigned to a specific server, as identified by its <code>ServerId</code> property.</p> <p>The response from this call returns the properties of the user associated with the <code>ServerId</code> value that was specified.</p>
+    async fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.describe_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a Secure Shell (SSH) public key to a user account identified by a <code>UserName</code> value assigned to a specific server, identified by <code>ServerId</code>.</p> <p>The response returns the <code>UserName</code> value, the <code>ServerId</code> value, and the name of the <code>SshPublicKeyId</code>.</p>
+    async fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> Result<ImportSshPublicKeyResponse, RusotoError<ImportSshPublicKeyError>> {
+        self.import_ssh_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Secure File Transfer Protocol (SFTP) servers that are associated with your AWS account.</p>
+    async fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> Result<ListServersResponse, RusotoError<ListServersError>> {
+        self.list_servers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the tags associated with the Amazon Resource Number (ARN) you specify. The resource can be a user, server, or role.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the users for the server that you specify by passing the <code>ServerId</code> parameter.</p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the state of a Secure File Transfer Protocol (SFTP) server from <code>OFFLINE</code> to <code>ONLINE</code>. It has no impact on an SFTP server that is already <code>ONLINE</code>. An <code>ONLINE</code> server can accept and process file transfer jobs.</p> <p>The state of <code>STARTING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully online. The values of <code>START_FAILED</code> can indicate an error condition. </p> <p>No response is returned from this call.</p>
+    async fn start_server(
+        &self,
+        input: StartServerRequest,
+    ) -> Result<(), RusotoError<StartServerError>> {
+        self.start_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the state of an SFTP server from <code>ONLINE</code> to <code>OFFLINE</code>. An <code>OFFLINE</code> server cannot accept and process file transfer jobs. Information tied to your server such as server and user properties are not affected by stopping your server. Stopping a server will not reduce or impact your Secure File Transfer Protocol (SFTP) endpoint billing.</p> <p>The state of <code>STOPPING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>STOP_FAILED</code> can indicate an error condition.</p> <p>No response is returned from this call.</p>
+    async fn stop_server(
+        &self,
+        input: StopServerRequest,
+    ) -> Result<(), RusotoError<StopServerError>> {
+        self.stop_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a key-value pair to a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>There is no response returned from this call.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>If the <code>IdentityProviderType</code> of the server is <code>API_Gateway</code>, tests whether your API Gateway is set up successfully. We highly recommend that you call this operation to test your authentication method as soon as you create your server. By doing so, you can troubleshoot issues with the API Gateway integration to ensure that your users can successfully use the service.</p>
+    async fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> Result<TestIdentityProviderResponse, RusotoError<TestIdentityProviderError>> {
+        self.test_identity_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a key-value pair from a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>No response is returned from this call.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the server properties after that server has been created.</p> <p>The <code>UpdateServer</code> call returns the <code>ServerId</code> of the Secure File Transfer Protocol (SFTP) server you updated.</p>
+    async fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+        self.update_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns new properties to a user. Parameters you pass modify any or all of the following: the home directory, role, and policy for the <code>UserName</code> and <code>ServerId</code> you specify.</p> <p>The response returns the <code>ServerId</code> and the <code>UserName</code> for the updated user.</p>
+    async fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.update_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -494,6 +494,7 @@ pub struct TranslateTextResponse {
 
 /// Errors returned by DeleteTerminology
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTerminologyError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -501,6 +502,8 @@ pub enum DeleteTerminologyError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTerminologyError {
@@ -508,16 +511,25 @@ impl DeleteTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteTerminologyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTerminologyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteTerminologyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTerminologyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -530,12 +542,14 @@ impl fmt::Display for DeleteTerminologyError {
             DeleteTerminologyError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteTerminologyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTerminologyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteTerminologyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTerminologyError {}
 /// Errors returned by DescribeTextTranslationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTextTranslationJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -543,6 +557,8 @@ pub enum DescribeTextTranslationJobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTextTranslationJobError {
@@ -552,22 +568,27 @@ impl DescribeTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTextTranslationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTextTranslationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTextTranslationJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeTextTranslationJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -580,12 +601,14 @@ impl fmt::Display for DescribeTextTranslationJobError {
             DescribeTextTranslationJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeTextTranslationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTextTranslationJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeTextTranslationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTextTranslationJobError {}
 /// Errors returned by GetTerminology
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTerminologyError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -595,6 +618,8 @@ pub enum GetTerminologyError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTerminologyError {
@@ -602,21 +627,28 @@ impl GetTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(GetTerminologyError::InternalServer(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetTerminologyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTerminologyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetTerminologyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetTerminologyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTerminologyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -630,12 +662,14 @@ impl fmt::Display for GetTerminologyError {
             GetTerminologyError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             GetTerminologyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetTerminologyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetTerminologyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTerminologyError {}
 /// Errors returned by ImportTerminology
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportTerminologyError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -645,6 +679,8 @@ pub enum ImportTerminologyError {
     LimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportTerminologyError {
@@ -652,21 +688,31 @@ impl ImportTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ImportTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ImportTerminologyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ImportTerminologyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportTerminologyError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportTerminologyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ImportTerminologyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ImportTerminologyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportTerminologyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -680,12 +726,14 @@ impl fmt::Display for ImportTerminologyError {
             ImportTerminologyError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ImportTerminologyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ImportTerminologyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ImportTerminologyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportTerminologyError {}
 /// Errors returned by ListTerminologies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTerminologiesError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -693,6 +741,8 @@ pub enum ListTerminologiesError {
     InvalidParameterValue(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTerminologiesError {
@@ -700,18 +750,25 @@ impl ListTerminologiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTerminologiesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTerminologiesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTerminologiesError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTerminologiesError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTerminologiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTerminologiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTerminologiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -724,12 +781,14 @@ impl fmt::Display for ListTerminologiesError {
             ListTerminologiesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTerminologiesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListTerminologiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTerminologiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTerminologiesError {}
 /// Errors returned by ListTextTranslationJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTextTranslationJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -739,6 +798,8 @@ pub enum ListTextTranslationJobsError {
     InvalidRequest(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTextTranslationJobsError {
@@ -746,27 +807,31 @@ impl ListTextTranslationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTextTranslationJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTextTranslationJobsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTextTranslationJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTextTranslationJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTextTranslationJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -780,12 +845,14 @@ impl fmt::Display for ListTextTranslationJobsError {
             ListTextTranslationJobsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListTextTranslationJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTextTranslationJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTextTranslationJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTextTranslationJobsError {}
 /// Errors returned by StartTextTranslationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTextTranslationJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -797,6 +864,8 @@ pub enum StartTextTranslationJobError {
     TooManyRequests(String),
     /// <p>Amazon Translate does not support translation from the language of the source text into the requested target language. For more information, see <a>how-to-error-msg</a>. </p>
     UnsupportedLanguagePair(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTextTranslationJobError {
@@ -804,32 +873,37 @@ impl StartTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTextTranslationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTextTranslationJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTextTranslationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTextTranslationJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartTextTranslationJobError::UnsupportedLanguagePair(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTextTranslationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -846,12 +920,14 @@ impl fmt::Display for StartTextTranslationJobError {
             StartTextTranslationJobError::UnsupportedLanguagePair(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartTextTranslationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTextTranslationJobError {}
 /// Errors returned by StopTextTranslationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTextTranslationJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -859,6 +935,8 @@ pub enum StopTextTranslationJobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTextTranslationJobError {
@@ -866,22 +944,25 @@ impl StopTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopTextTranslationJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopTextTranslationJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopTextTranslationJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopTextTranslationJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -894,12 +975,14 @@ impl fmt::Display for StopTextTranslationJobError {
             StopTextTranslationJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopTextTranslationJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopTextTranslationJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopTextTranslationJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTextTranslationJobError {}
 /// Errors returned by TranslateText
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TranslateTextError {
     /// <p>The confidence that Amazon Comprehend accurately detected the source language is low. If a low confidence level is acceptable for your application, you can use the language in the exception to call Amazon Translate again. For more information, see the <a href="https://docs.aws.amazon.com/comprehend/latest/dg/API_DetectDominantLanguage.html">DetectDominantLanguage</a> operation in the <i>Amazon Comprehend Developer Guide</i>. </p>
     DetectedLanguageLowConfidence(String),
@@ -917,6 +1000,8 @@ pub enum TranslateTextError {
     TooManyRequests(String),
     /// <p>Amazon Translate does not support translation from the language of the source text into the requested target language. For more information, see <a>how-to-error-msg</a>. </p>
     UnsupportedLanguagePair(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TranslateTextError {
@@ -924,35 +1009,46 @@ impl TranslateTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DetectedLanguageLowConfidenceException" => {
-                    return RusotoError::Service(TranslateTextError::DetectedLanguageLowConfidence(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TranslateTextError::DetectedLanguageLowConfidence(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TranslateTextError::InternalServer(err.msg))
+                    return RusotoError::service(TranslateTextError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TranslateTextError::InvalidRequest(err.msg))
+                    return RusotoError::service(TranslateTextError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TranslateTextError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        TranslateTextError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TranslateTextError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TranslateTextError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(TranslateTextError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        TranslateTextError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TranslateTextError::TooManyRequests(err.msg))
+                    return RusotoError::service(TranslateTextError::TooManyRequests(err.msg), &res)
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(TranslateTextError::UnsupportedLanguagePair(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TranslateTextError::UnsupportedLanguagePair(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TranslateTextError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -970,6 +1066,7 @@ impl fmt::Display for TranslateTextError {
             TranslateTextError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             TranslateTextError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             TranslateTextError::UnsupportedLanguagePair(ref cause) => write!(f, "{}", cause),
+            TranslateTextError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1042,6 +1139,7 @@ impl TranslateClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> TranslateClient {
         TranslateClient {
             client: Client::shared(),
@@ -1067,16 +1165,62 @@ impl TranslateClient {
     pub fn new_with_client(client: Client, region: region::Region) -> TranslateClient {
         TranslateClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<TranslateClient> {
+        rusoto_core::ClientBuilder::new(TranslateClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> TranslateClient {
+        TranslateClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Translate for TranslateClient {
-    /// <p>A synchronous action that deletes a custom terminology.</p>
-    async fn delete_terminology(
+impl TranslateClient {
+    /// Calls `delete_terminology`, also returning the metadata of the response.
+    pub async fn delete_terminology_with_metadata(
         &self,
         input: DeleteTerminologyRequest,
-    ) -> Result<(), RusotoError<DeleteTerminologyError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTerminologyError>> {
+        let request = self.build_delete_terminology_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTerminologyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_terminology` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_terminology(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTerminologyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_terminology_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_terminology_request(&self, input: DeleteTerminologyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("DeleteTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1086,28 +1230,58 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_text_translation_job`, also returning the metadata of the response.
+    pub async fn describe_text_translation_job_with_metadata(
+        &self,
+        input: DescribeTextTranslationJobRequest,
+    ) -> Result<
+        (DescribeTextTranslationJobResponse, ResponseMetadata),
+        RusotoError<DescribeTextTranslationJobError>,
+    > {
+        let request = self.build_describe_text_translation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTextTranslationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTerminologyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTextTranslationJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with an asycnhronous batch translation job including name, ID, status, source and target languages, input/output S3 buckets, and so on.</p>
-    async fn describe_text_translation_job(
+    /// Presigns the request `describe_text_translation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_text_translation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTextTranslationJobRequest,
-    ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_text_translation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_text_translation_job_request(
+        &self,
+        input: DescribeTextTranslationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("DescribeTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1117,28 +1291,52 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_terminology`, also returning the metadata of the response.
+    pub async fn get_terminology_with_metadata(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> Result<(GetTerminologyResponse, ResponseMetadata), RusotoError<GetTerminologyError>> {
+        let request = self.build_get_terminology_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTextTranslationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTerminologyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTextTranslationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTerminologyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a custom terminology.</p>
-    async fn get_terminology(
+    /// Presigns the request `get_terminology` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_terminology(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTerminologyRequest,
-    ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_terminology_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_terminology_request(&self, input: GetTerminologyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("GetTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1148,27 +1346,53 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_terminology`, also returning the metadata of the response.
+    pub async fn import_terminology_with_metadata(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> Result<(ImportTerminologyResponse, ResponseMetadata), RusotoError<ImportTerminologyError>>
+    {
+        let request = self.build_import_terminology_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTerminologyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportTerminologyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTerminologyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportTerminologyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a custom terminology, depending on whether or not one already exists for the given terminology name. Importing a terminology with the same name as an existing one will merge the terminologies based on the chosen merge strategy. Currently, the only supported merge strategy is OVERWRITE, and so the imported terminology will overwrite an existing terminology of the same name.</p> <p>If you import a terminology that overwrites an existing one, the new terminology take up to 10 minutes to fully propagate and be available for use in a translation due to cache policies with the DataPlane service that performs the translations.</p>
-    async fn import_terminology(
+    /// Presigns the request `import_terminology` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_terminology(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportTerminologyRequest,
-    ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_terminology_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_terminology_request(&self, input: ImportTerminologyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ImportTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1178,28 +1402,53 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_terminologies`, also returning the metadata of the response.
+    pub async fn list_terminologies_with_metadata(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> Result<(ListTerminologiesResponse, ResponseMetadata), RusotoError<ListTerminologiesError>>
+    {
+        let request = self.build_list_terminologies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportTerminologyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTerminologiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportTerminologyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTerminologiesError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of custom terminologies associated with your account.</p>
-    async fn list_terminologies(
+    /// Presigns the request `list_terminologies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_terminologies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTerminologiesRequest,
-    ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_terminologies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_terminologies_request(&self, input: ListTerminologiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ListTerminologies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1209,28 +1458,58 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_text_translation_jobs`, also returning the metadata of the response.
+    pub async fn list_text_translation_jobs_with_metadata(
+        &self,
+        input: ListTextTranslationJobsRequest,
+    ) -> Result<
+        (ListTextTranslationJobsResponse, ResponseMetadata),
+        RusotoError<ListTextTranslationJobsError>,
+    > {
+        let request = self.build_list_text_translation_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTerminologiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTextTranslationJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTerminologiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTextTranslationJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the batch translation jobs that you have submitted.</p>
-    async fn list_text_translation_jobs(
+    /// Presigns the request `list_text_translation_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_text_translation_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTextTranslationJobsRequest,
-    ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_text_translation_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_text_translation_jobs_request(
+        &self,
+        input: ListTextTranslationJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ListTextTranslationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1240,28 +1519,58 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_text_translation_job`, also returning the metadata of the response.
+    pub async fn start_text_translation_job_with_metadata(
+        &self,
+        input: StartTextTranslationJobRequest,
+    ) -> Result<
+        (StartTextTranslationJobResponse, ResponseMetadata),
+        RusotoError<StartTextTranslationJobError>,
+    > {
+        let request = self.build_start_text_translation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTextTranslationJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTextTranslationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTextTranslationJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTextTranslationJobError::from_response(response))
         }
     }
 
-    /// <p><p>Starts an asynchronous batch translation job. Batch translation jobs can be used to translate large volumes of text across multiple documents at once. For more information, see <a>async</a>.</p> <p>Batch translation jobs can be described with the <a>DescribeTextTranslationJob</a> operation, listed with the <a>ListTextTranslationJobs</a> operation, and stopped with the <a>StopTextTranslationJob</a> operation.</p> <note> <p>Amazon Translate does not support batch translation of multiple source languages at once.</p> </note></p>
-    async fn start_text_translation_job(
+    /// Presigns the request `start_text_translation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_text_translation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTextTranslationJobRequest,
-    ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_text_translation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_text_translation_job_request(
+        &self,
+        input: StartTextTranslationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("StartTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1271,28 +1580,58 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_text_translation_job`, also returning the metadata of the response.
+    pub async fn stop_text_translation_job_with_metadata(
+        &self,
+        input: StopTextTranslationJobRequest,
+    ) -> Result<
+        (StopTextTranslationJobResponse, ResponseMetadata),
+        RusotoError<StopTextTranslationJobError>,
+    > {
+        let request = self.build_stop_text_translation_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartTextTranslationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopTextTranslationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTextTranslationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTextTranslationJobError::from_response(response))
         }
     }
 
-    /// <p>Stops an asynchronous batch translation job that is in progress.</p> <p>If the job's state is <code>IN_PROGRESS</code>, the job will be marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state. Otherwise, the job is put into the <code>STOPPED</code> state.</p> <p>Asynchronous batch translation jobs are started with the <a>StartTextTranslationJob</a> operation. You can use the <a>DescribeTextTranslationJob</a> or <a>ListTextTranslationJobs</a> operations to get a batch translation job's <code>JobId</code>.</p>
-    async fn stop_text_translation_job(
+    /// Presigns the request `stop_text_translation_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_text_translation_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTextTranslationJobRequest,
-    ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_text_translation_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_text_translation_job_request(
+        &self,
+        input: StopTextTranslationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("StopTextTranslationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1302,28 +1641,52 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `translate_text`, also returning the metadata of the response.
+    pub async fn translate_text_with_metadata(
+        &self,
+        input: TranslateTextRequest,
+    ) -> Result<(TranslateTextResponse, ResponseMetadata), RusotoError<TranslateTextError>> {
+        let request = self.build_translate_text_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopTextTranslationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TranslateTextResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTextTranslationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TranslateTextError::from_response(response))
         }
     }
 
-    /// <p>Translates input text from the source language to the target language. For a list of available languages and language codes, see <a>what-is-languages</a>.</p>
-    async fn translate_text(
+    /// Presigns the request `translate_text` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_translate_text(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TranslateTextRequest,
-    ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_translate_text_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_translate_text_request(&self, input: TranslateTextRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("TranslateText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1333,18 +1696,138 @@ impl Translate for TranslateClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListTerminologies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_terminologies_pages(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: TranslateClient, input| async move { client.list_terminologies(input).await },
+            |input: &ListTerminologiesRequest, output: &ListTerminologiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTerminologiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TerminologyPropertiesList` of every page of `ListTerminologies` results for `input`, as `list_terminologies_pages` requests them.
+    pub fn list_terminologies_stream(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TerminologyProperties, RusotoError<ListTerminologiesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_terminologies_pages(input), |page| {
+            page.terminology_properties_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Translate for TranslateClient {
+    /// <p>A synchronous action that deletes a custom terminology.</p>
+    async fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> Result<(), RusotoError<DeleteTerminologyError>> {
+        self.delete_terminology_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TranslateTextResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TranslateTextError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with an asycnhronous batch translation job including name, ID, status, source and target languages, input/output S3 buckets, and so on.</p>
+    async fn describe_text_translation_job(
+        &self,
+        input: DescribeTextTranslationJobRequest,
+    ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
+    {
+        self.describe_text_translation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a custom terminology.</p>
+    async fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
+        self.get_terminology_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a custom terminology, depending on whether or not one already exists for the given terminology name. Importing a terminology with the same name as an existing one will merge the terminologies based on the chosen merge strategy. Currently, the only supported merge strategy is OVERWRITE, and so the imported terminology will overwrite an existing terminology of the same name.</p> <p>If you import a terminology that overwrites an existing one, the new terminology take up to 10 minutes to fully propagate and be available for use in a translation due to cache policies with the DataPlane service that performs the translations.</p>
+    async fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
+        self.import_terminology_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of custom terminologies associated with your account.</p>
+    async fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
+        self.list_terminologies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the batch translation jobs that you have submitted.</p>
+    async fn list_text_translation_jobs(
+        &self,
+        input: ListTextTranslationJobsRequest,
+    ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
+        self.list_text_translation_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts an asynchronous batch translation job. Batch translation jobs can be used to translate large volumes of text across multiple documents at once. For more information, see <a>async</a>.</p> <p>Batch translation jobs can be described with the <a>DescribeTextTranslationJob</a> operation, listed with the <a>ListTextTranslationJobs</a> operation, and stopped with the <a>StopTextTranslationJob</a> operation.</p> <note> <p>Amazon Translate does not support batch translation of multiple source languages at once.</p> </note></p>
+    async fn start_text_translation_job(
+        &self,
+        input: StartTextTranslationJobRequest,
+    ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
+        self.start_text_translation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops an asynchronous batch translation job that is in progress.</p> <p>If the job's state is <code>IN_PROGRESS</code>, the job will be marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state. Otherwise, the job is put into the <code>STOPPED</code> state.</p> <p>Asynchronous batch translation jobs are started with the <a>StartTextTranslationJob</a> operation. You can use the <a>DescribeTextTranslationJob</a> or <a>ListTextTranslationJobs</a> operations to get a batch translation job's <code>JobId</code>.</p>
+    async fn stop_text_translation_job(
+        &self,
+        input: StopTextTranslationJobRequest,
+    ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
+        self.stop_text_translation_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Translates input text from the source language to the target language. For a list of available languages and language codes, see <a>what-is-languages</a>.</p>
+    async fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
+        self.translate_text_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
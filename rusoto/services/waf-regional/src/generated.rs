@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2571,6 +2571,7 @@ pub struct XssMatchTuple {
 
 /// Errors returned by AssociateWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -2582,6 +2583,8 @@ pub enum AssociateWebACLError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because the entity referenced is temporarily unavailable. Retry your request.</p>
     WAFUnavailableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateWebACLError {
@@ -2589,24 +2592,37 @@ impl AssociateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        AssociateWebACLError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        AssociateWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AssociateWebACLError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        AssociateWebACLError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFUnavailableEntityException" => {
-                    return RusotoError::Service(AssociateWebACLError::WAFUnavailableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateWebACLError::WAFUnavailableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2621,12 +2637,14 @@ impl fmt::Display for AssociateWebACLError {
             AssociateWebACLError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             AssociateWebACLError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             AssociateWebACLError::WAFUnavailableEntity(ref cause) => write!(f, "{}", cause),
+            AssociateWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateWebACLError {}
 /// Errors returned by CreateByteMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateByteMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -2640,6 +2658,8 @@ pub enum CreateByteMatchSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateByteMatchSetError {
@@ -2647,33 +2667,43 @@ impl CreateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateByteMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateByteMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2689,12 +2719,14 @@ impl fmt::Display for CreateByteMatchSetError {
             CreateByteMatchSetError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateByteMatchSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateByteMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateByteMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateByteMatchSetError {}
 /// Errors returned by CreateGeoMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGeoMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -2708,6 +2740,8 @@ pub enum CreateGeoMatchSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGeoMatchSetError {
@@ -2715,27 +2749,43 @@ impl CreateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateGeoMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGeoMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2751,12 +2801,14 @@ impl fmt::Display for CreateGeoMatchSetError {
             CreateGeoMatchSetError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateGeoMatchSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateGeoMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateGeoMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGeoMatchSetError {}
 /// Errors returned by CreateIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIPSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -2770,6 +2822,8 @@ pub enum CreateIPSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIPSetError {
@@ -2777,25 +2831,28 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(CreateIPSetError::WAFDisallowedName(err.msg), &res)
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(CreateIPSetError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(CreateIPSetError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateIPSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(CreateIPSetError::WAFLimitsExceeded(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(CreateIPSetError::WAFStaleData(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2811,12 +2868,14 @@ impl fmt::Display for CreateIPSetError {
             CreateIPSetError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateIPSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateIPSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIPSetError {}
 /// Errors returned by CreateRateBasedRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRateBasedRuleError {
     WAFBadRequest(String),
     /// <p>The name specified is invalid.</p>
@@ -2833,6 +2892,8 @@ pub enum CreateRateBasedRuleError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRateBasedRuleError {
@@ -2840,41 +2901,55 @@ impl CreateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFBadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        CreateRateBasedRuleError::WAFTagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRateBasedRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2894,12 +2969,14 @@ impl fmt::Display for CreateRateBasedRuleError {
             CreateRateBasedRuleError::WAFTagOperationInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateRateBasedRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRateBasedRuleError {}
 /// Errors returned by CreateRegexMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRegexMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -2909,6 +2986,8 @@ pub enum CreateRegexMatchSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRegexMatchSetError {
@@ -2916,25 +2995,31 @@ impl CreateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateRegexMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRegexMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2948,12 +3033,14 @@ impl fmt::Display for CreateRegexMatchSetError {
             CreateRegexMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             CreateRegexMatchSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateRegexMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateRegexMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRegexMatchSetError {}
 /// Errors returned by CreateRegexPatternSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRegexPatternSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -2963,6 +3050,8 @@ pub enum CreateRegexPatternSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRegexPatternSetError {
@@ -2970,25 +3059,31 @@ impl CreateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexPatternSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexPatternSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRegexPatternSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateRegexPatternSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRegexPatternSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3002,12 +3097,14 @@ impl fmt::Display for CreateRegexPatternSetError {
             CreateRegexPatternSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             CreateRegexPatternSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateRegexPatternSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateRegexPatternSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRegexPatternSetError {}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRuleError {
     WAFBadRequest(String),
     /// <p>The name specified is invalid.</p>
@@ -3024,6 +3121,8 @@ pub enum CreateRuleError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRuleError {
@@ -3031,33 +3130,37 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFBadRequest(err.msg), &res)
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFDisallowedName(err.msg), &res)
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateRuleError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFLimitsExceeded(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(CreateRuleError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRuleError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3075,12 +3178,14 @@ impl fmt::Display for CreateRuleError {
             CreateRuleError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             CreateRuleError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             CreateRuleError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            CreateRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRuleError {}
 /// Errors returned by CreateRuleGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRuleGroupError {
     WAFBadRequest(String),
     /// <p>The name specified is invalid.</p>
@@ -3095,6 +3200,8 @@ pub enum CreateRuleGroupError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRuleGroupError {
@@ -3102,30 +3209,43 @@ impl CreateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFBadRequest(err.msg))
+                    return RusotoError::service(CreateRuleGroupError::WAFBadRequest(err.msg), &res)
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        CreateRuleGroupError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        CreateRuleGroupError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateRuleGroupError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(CreateRuleGroupError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        CreateRuleGroupError::WAFTagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRuleGroupError::WAFTagOperationInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRuleGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3142,12 +3262,14 @@ impl fmt::Display for CreateRuleGroupError {
             CreateRuleGroupError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             CreateRuleGroupError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             CreateRuleGroupError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            CreateRuleGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRuleGroupError {}
 /// Errors returned by CreateSizeConstraintSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSizeConstraintSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -3161,6 +3283,8 @@ pub enum CreateSizeConstraintSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSizeConstraintSetError {
@@ -3168,37 +3292,43 @@ impl CreateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSizeConstraintSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSizeConstraintSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3214,12 +3344,14 @@ impl fmt::Display for CreateSizeConstraintSetError {
             CreateSizeConstraintSetError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateSizeConstraintSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateSizeConstraintSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateSizeConstraintSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSizeConstraintSetError {}
 /// Errors returned by CreateSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSqlInjectionMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -3233,6 +3365,8 @@ pub enum CreateSqlInjectionMatchSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSqlInjectionMatchSetError {
@@ -3242,37 +3376,45 @@ impl CreateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSqlInjectionMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateSqlInjectionMatchSetError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3290,12 +3432,14 @@ impl fmt::Display for CreateSqlInjectionMatchSetError {
             }
             CreateSqlInjectionMatchSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateSqlInjectionMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateSqlInjectionMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSqlInjectionMatchSetError {}
 /// Errors returned by CreateWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWebACLError {
     WAFBadRequest(String),
     /// <p>The name specified is invalid.</p>
@@ -3314,6 +3458,8 @@ pub enum CreateWebACLError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWebACLError {
@@ -3321,36 +3467,49 @@ impl CreateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFBadRequest(err.msg))
+                    return RusotoError::service(CreateWebACLError::WAFBadRequest(err.msg), &res)
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        CreateWebACLError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(CreateWebACLError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        CreateWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateWebACLError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateWebACLError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(CreateWebACLError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service(CreateWebACLError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateWebACLError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3369,12 +3528,14 @@ impl fmt::Display for CreateWebACLError {
             CreateWebACLError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             CreateWebACLError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             CreateWebACLError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            CreateWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWebACLError {}
 /// Errors returned by CreateXssMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateXssMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -3388,6 +3549,8 @@ pub enum CreateXssMatchSetError {
     WAFLimitsExceeded(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateXssMatchSetError {
@@ -3395,27 +3558,43 @@ impl CreateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        CreateXssMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateXssMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3431,12 +3610,14 @@ impl fmt::Display for CreateXssMatchSetError {
             CreateXssMatchSetError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateXssMatchSetError::WAFLimitsExceeded(ref cause) => write!(f, "{}", cause),
             CreateXssMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            CreateXssMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateXssMatchSetError {}
 /// Errors returned by DeleteByteMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteByteMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3450,6 +3631,8 @@ pub enum DeleteByteMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteByteMatchSetError {
@@ -3457,33 +3640,43 @@ impl DeleteByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteByteMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteByteMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3499,12 +3692,14 @@ impl fmt::Display for DeleteByteMatchSetError {
             DeleteByteMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteByteMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteByteMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteByteMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteByteMatchSetError {}
 /// Errors returned by DeleteGeoMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGeoMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3518,6 +3713,8 @@ pub enum DeleteGeoMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGeoMatchSetError {
@@ -3525,27 +3722,43 @@ impl DeleteGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteGeoMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGeoMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3561,12 +3774,14 @@ impl fmt::Display for DeleteGeoMatchSetError {
             DeleteGeoMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteGeoMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteGeoMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteGeoMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGeoMatchSetError {}
 /// Errors returned by DeleteIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIPSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3580,6 +3795,8 @@ pub enum DeleteIPSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIPSetError {
@@ -3587,25 +3804,28 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(DeleteIPSetError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(DeleteIPSetError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(DeleteIPSetError::WAFNonEmptyEntity(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        DeleteIPSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(DeleteIPSetError::WAFReferencedItem(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(DeleteIPSetError::WAFStaleData(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3621,12 +3841,14 @@ impl fmt::Display for DeleteIPSetError {
             DeleteIPSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteIPSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteIPSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIPSetError {}
 /// Errors returned by DeleteLoggingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoggingConfigurationError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3634,6 +3856,8 @@ pub enum DeleteLoggingConfigurationError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoggingConfigurationError {
@@ -3643,22 +3867,27 @@ impl DeleteLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLoggingConfigurationError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLoggingConfigurationError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteLoggingConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3673,12 +3902,14 @@ impl fmt::Display for DeleteLoggingConfigurationError {
                 write!(f, "{}", cause)
             }
             DeleteLoggingConfigurationError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteLoggingConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoggingConfigurationError {}
 /// Errors returned by DeletePermissionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePermissionPolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3686,6 +3917,8 @@ pub enum DeletePermissionPolicyError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePermissionPolicyError {
@@ -3693,20 +3926,25 @@ impl DeletePermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePermissionPolicyError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePermissionPolicyError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeletePermissionPolicyError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePermissionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3719,12 +3957,14 @@ impl fmt::Display for DeletePermissionPolicyError {
             DeletePermissionPolicyError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             DeletePermissionPolicyError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeletePermissionPolicyError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeletePermissionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePermissionPolicyError {}
 /// Errors returned by DeleteRateBasedRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRateBasedRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3742,6 +3982,8 @@ pub enum DeleteRateBasedRuleError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRateBasedRuleError {
@@ -3749,43 +3991,55 @@ impl DeleteRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteRateBasedRuleError::WAFTagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRateBasedRuleError::WAFTagOperationInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRateBasedRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3805,12 +4059,14 @@ impl fmt::Display for DeleteRateBasedRuleError {
             DeleteRateBasedRuleError::WAFTagOperationInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRateBasedRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRateBasedRuleError {}
 /// Errors returned by DeleteRegexMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRegexMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3824,6 +4080,8 @@ pub enum DeleteRegexMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRegexMatchSetError {
@@ -3831,35 +4089,43 @@ impl DeleteRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteRegexMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRegexMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3875,12 +4141,14 @@ impl fmt::Display for DeleteRegexMatchSetError {
             DeleteRegexMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteRegexMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteRegexMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteRegexMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRegexMatchSetError {}
 /// Errors returned by DeleteRegexPatternSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRegexPatternSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3894,6 +4162,8 @@ pub enum DeleteRegexPatternSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRegexPatternSetError {
@@ -3901,35 +4171,43 @@ impl DeleteRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteRegexPatternSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRegexPatternSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3945,12 +4223,14 @@ impl fmt::Display for DeleteRegexPatternSetError {
             DeleteRegexPatternSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteRegexPatternSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteRegexPatternSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteRegexPatternSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRegexPatternSetError {}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -3968,6 +4248,8 @@ pub enum DeleteRuleError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRuleError {
@@ -3975,33 +4257,34 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFNonEmptyEntity(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFNonexistentItem(err.msg), &res)
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFReferencedItem(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(DeleteRuleError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRuleError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4019,12 +4302,14 @@ impl fmt::Display for DeleteRuleError {
             DeleteRuleError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             DeleteRuleError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             DeleteRuleError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            DeleteRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRuleError {}
 /// Errors returned by DeleteRuleGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRuleGroupError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4042,6 +4327,8 @@ pub enum DeleteRuleGroupError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRuleGroupError {
@@ -4049,33 +4336,52 @@ impl DeleteRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(DeleteRuleGroupError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleGroupError::WAFTagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRuleGroupError::WAFTagOperationInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRuleGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4093,12 +4399,14 @@ impl fmt::Display for DeleteRuleGroupError {
             DeleteRuleGroupError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             DeleteRuleGroupError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             DeleteRuleGroupError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            DeleteRuleGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRuleGroupError {}
 /// Errors returned by DeleteSizeConstraintSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSizeConstraintSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4112,6 +4420,8 @@ pub enum DeleteSizeConstraintSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSizeConstraintSetError {
@@ -4119,37 +4429,43 @@ impl DeleteSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSizeConstraintSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSizeConstraintSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4165,12 +4481,14 @@ impl fmt::Display for DeleteSizeConstraintSetError {
             DeleteSizeConstraintSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteSizeConstraintSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteSizeConstraintSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteSizeConstraintSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSizeConstraintSetError {}
 /// Errors returned by DeleteSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSqlInjectionMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4184,6 +4502,8 @@ pub enum DeleteSqlInjectionMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSqlInjectionMatchSetError {
@@ -4193,37 +4513,45 @@ impl DeleteSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSqlInjectionMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteSqlInjectionMatchSetError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4241,12 +4569,14 @@ impl fmt::Display for DeleteSqlInjectionMatchSetError {
             }
             DeleteSqlInjectionMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteSqlInjectionMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteSqlInjectionMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSqlInjectionMatchSetError {}
 /// Errors returned by DeleteWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4264,6 +4594,8 @@ pub enum DeleteWebACLError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWebACLError {
@@ -4271,33 +4603,46 @@ impl DeleteWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(DeleteWebACLError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        DeleteWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteWebACLError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        DeleteWebACLError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        DeleteWebACLError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(DeleteWebACLError::WAFStaleData(err.msg), &res)
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service(DeleteWebACLError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteWebACLError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4315,12 +4660,14 @@ impl fmt::Display for DeleteWebACLError {
             DeleteWebACLError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             DeleteWebACLError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             DeleteWebACLError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            DeleteWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWebACLError {}
 /// Errors returned by DeleteXssMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteXssMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4334,6 +4681,8 @@ pub enum DeleteXssMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteXssMatchSetError {
@@ -4341,27 +4690,43 @@ impl DeleteXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        DeleteXssMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteXssMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4377,12 +4742,14 @@ impl fmt::Display for DeleteXssMatchSetError {
             DeleteXssMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             DeleteXssMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             DeleteXssMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            DeleteXssMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteXssMatchSetError {}
 /// Errors returned by DisassociateWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4392,6 +4759,8 @@ pub enum DisassociateWebACLError {
     WAFInvalidParameter(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateWebACLError {
@@ -4399,25 +4768,31 @@ impl DisassociateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        DisassociateWebACLError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateWebACLError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DisassociateWebACLError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateWebACLError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4431,12 +4806,14 @@ impl fmt::Display for DisassociateWebACLError {
             DisassociateWebACLError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             DisassociateWebACLError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             DisassociateWebACLError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            DisassociateWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateWebACLError {}
 /// Errors returned by GetByteMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetByteMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4444,6 +4821,8 @@ pub enum GetByteMatchSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetByteMatchSetError {
@@ -4451,16 +4830,25 @@ impl GetByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetByteMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        GetByteMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetByteMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetByteMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4473,15 +4861,19 @@ impl fmt::Display for GetByteMatchSetError {
             GetByteMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetByteMatchSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetByteMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetByteMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetByteMatchSetError {}
 /// Errors returned by GetChangeToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetChangeTokenError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetChangeTokenError {
@@ -4489,10 +4881,13 @@ impl GetChangeTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetChangeTokenError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetChangeTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4503,17 +4898,21 @@ impl fmt::Display for GetChangeTokenError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetChangeTokenError::WAFInternalError(ref cause) => write!(f, "{}", cause),
+            GetChangeTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetChangeTokenError {}
 /// Errors returned by GetChangeTokenStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetChangeTokenStatusError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetChangeTokenStatusError {
@@ -4521,17 +4920,19 @@ impl GetChangeTokenStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetChangeTokenStatusError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetChangeTokenStatusError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetChangeTokenStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4543,12 +4944,14 @@ impl fmt::Display for GetChangeTokenStatusError {
         match *self {
             GetChangeTokenStatusError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetChangeTokenStatusError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetChangeTokenStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetChangeTokenStatusError {}
 /// Errors returned by GetGeoMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGeoMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4556,6 +4959,8 @@ pub enum GetGeoMatchSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGeoMatchSetError {
@@ -4563,16 +4968,25 @@ impl GetGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetGeoMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        GetGeoMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetGeoMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGeoMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4585,12 +4999,14 @@ impl fmt::Display for GetGeoMatchSetError {
             GetGeoMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetGeoMatchSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetGeoMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetGeoMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGeoMatchSetError {}
 /// Errors returned by GetIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIPSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4598,6 +5014,8 @@ pub enum GetIPSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIPSetError {
@@ -4605,16 +5023,16 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(GetIPSetError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(GetIPSetError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(GetIPSetError::WAFNonexistentItem(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4627,17 +5045,21 @@ impl fmt::Display for GetIPSetError {
             GetIPSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetIPSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetIPSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIPSetError {}
 /// Errors returned by GetLoggingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLoggingConfigurationError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLoggingConfigurationError {
@@ -4645,17 +5067,19 @@ impl GetLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoggingConfigurationError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLoggingConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4667,17 +5091,21 @@ impl fmt::Display for GetLoggingConfigurationError {
         match *self {
             GetLoggingConfigurationError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetLoggingConfigurationError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetLoggingConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLoggingConfigurationError {}
 /// Errors returned by GetPermissionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPermissionPolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPermissionPolicyError {
@@ -4685,17 +5113,19 @@ impl GetPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPermissionPolicyError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPermissionPolicyError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPermissionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4707,12 +5137,14 @@ impl fmt::Display for GetPermissionPolicyError {
         match *self {
             GetPermissionPolicyError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetPermissionPolicyError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetPermissionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPermissionPolicyError {}
 /// Errors returned by GetRateBasedRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRateBasedRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4720,6 +5152,8 @@ pub enum GetRateBasedRuleError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRateBasedRuleError {
@@ -4727,16 +5161,25 @@ impl GetRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetRateBasedRuleError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        GetRateBasedRuleError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetRateBasedRuleError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRateBasedRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4749,12 +5192,14 @@ impl fmt::Display for GetRateBasedRuleError {
             GetRateBasedRuleError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetRateBasedRuleError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetRateBasedRuleError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetRateBasedRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRateBasedRuleError {}
 /// Errors returned by GetRateBasedRuleManagedKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRateBasedRuleManagedKeysError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4764,6 +5209,8 @@ pub enum GetRateBasedRuleManagedKeysError {
     WAFInvalidParameter(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRateBasedRuleManagedKeysError {
@@ -4773,27 +5220,36 @@ impl GetRateBasedRuleManagedKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRateBasedRuleManagedKeysError::WAFInternalError(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRateBasedRuleManagedKeysError::WAFInvalidAccount(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRateBasedRuleManagedKeysError::WAFInvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRateBasedRuleManagedKeysError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRateBasedRuleManagedKeysError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4813,12 +5269,14 @@ impl fmt::Display for GetRateBasedRuleManagedKeysError {
             GetRateBasedRuleManagedKeysError::WAFNonexistentItem(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRateBasedRuleManagedKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRateBasedRuleManagedKeysError {}
 /// Errors returned by GetRegexMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRegexMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4826,6 +5284,8 @@ pub enum GetRegexMatchSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRegexMatchSetError {
@@ -4833,16 +5293,25 @@ impl GetRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetRegexMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        GetRegexMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetRegexMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRegexMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4855,12 +5324,14 @@ impl fmt::Display for GetRegexMatchSetError {
             GetRegexMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetRegexMatchSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetRegexMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetRegexMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRegexMatchSetError {}
 /// Errors returned by GetRegexPatternSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRegexPatternSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4868,6 +5339,8 @@ pub enum GetRegexPatternSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRegexPatternSetError {
@@ -4875,20 +5348,25 @@ impl GetRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetRegexPatternSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRegexPatternSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRegexPatternSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRegexPatternSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4901,12 +5379,14 @@ impl fmt::Display for GetRegexPatternSetError {
             GetRegexPatternSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetRegexPatternSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetRegexPatternSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetRegexPatternSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRegexPatternSetError {}
 /// Errors returned by GetRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -4914,6 +5394,8 @@ pub enum GetRuleError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRuleError {
@@ -4921,16 +5403,16 @@ impl GetRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(GetRuleError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(GetRuleError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(GetRuleError::WAFNonexistentItem(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4943,17 +5425,21 @@ impl fmt::Display for GetRuleError {
             GetRuleError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetRuleError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetRuleError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRuleError {}
 /// Errors returned by GetRuleGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRuleGroupError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRuleGroupError {
@@ -4961,13 +5447,16 @@ impl GetRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(GetRuleGroupError::WAFInternalError(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetRuleGroupError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRuleGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4979,17 +5468,21 @@ impl fmt::Display for GetRuleGroupError {
         match *self {
             GetRuleGroupError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetRuleGroupError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetRuleGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRuleGroupError {}
 /// Errors returned by GetSampledRequests
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSampledRequestsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSampledRequestsError {
@@ -4997,15 +5490,19 @@ impl GetSampledRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetSampledRequestsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSampledRequestsError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSampledRequestsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5017,12 +5514,14 @@ impl fmt::Display for GetSampledRequestsError {
         match *self {
             GetSampledRequestsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetSampledRequestsError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetSampledRequestsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSampledRequestsError {}
 /// Errors returned by GetSizeConstraintSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSizeConstraintSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5030,6 +5529,8 @@ pub enum GetSizeConstraintSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSizeConstraintSetError {
@@ -5037,22 +5538,25 @@ impl GetSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSizeConstraintSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSizeConstraintSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5065,12 +5569,14 @@ impl fmt::Display for GetSizeConstraintSetError {
             GetSizeConstraintSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetSizeConstraintSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetSizeConstraintSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetSizeConstraintSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSizeConstraintSetError {}
 /// Errors returned by GetSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSqlInjectionMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5078,6 +5584,8 @@ pub enum GetSqlInjectionMatchSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSqlInjectionMatchSetError {
@@ -5085,22 +5593,25 @@ impl GetSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSqlInjectionMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5113,12 +5624,14 @@ impl fmt::Display for GetSqlInjectionMatchSetError {
             GetSqlInjectionMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetSqlInjectionMatchSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetSqlInjectionMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetSqlInjectionMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSqlInjectionMatchSetError {}
 /// Errors returned by GetWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5126,6 +5639,8 @@ pub enum GetWebACLError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWebACLError {
@@ -5133,16 +5648,16 @@ impl GetWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(GetWebACLError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(GetWebACLError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(GetWebACLError::WAFNonexistentItem(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5155,12 +5670,14 @@ impl fmt::Display for GetWebACLError {
             GetWebACLError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetWebACLError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetWebACLError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWebACLError {}
 /// Errors returned by GetWebACLForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWebACLForResourceError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5172,6 +5689,8 @@ pub enum GetWebACLForResourceError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because the entity referenced is temporarily unavailable. Retry your request.</p>
     WAFUnavailableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWebACLForResourceError {
@@ -5179,32 +5698,37 @@ impl GetWebACLForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWebACLForResourceError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWebACLForResourceError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWebACLForResourceError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWebACLForResourceError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFUnavailableEntityException" => {
-                    return RusotoError::Service(GetWebACLForResourceError::WAFUnavailableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetWebACLForResourceError::WAFUnavailableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWebACLForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5219,12 +5743,14 @@ impl fmt::Display for GetWebACLForResourceError {
             GetWebACLForResourceError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             GetWebACLForResourceError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             GetWebACLForResourceError::WAFUnavailableEntity(ref cause) => write!(f, "{}", cause),
+            GetWebACLForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWebACLForResourceError {}
 /// Errors returned by GetXssMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetXssMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5232,6 +5758,8 @@ pub enum GetXssMatchSetError {
     WAFInvalidAccount(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetXssMatchSetError {
@@ -5239,16 +5767,25 @@ impl GetXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        GetXssMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        GetXssMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        GetXssMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetXssMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5261,12 +5798,14 @@ impl fmt::Display for GetXssMatchSetError {
             GetXssMatchSetError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             GetXssMatchSetError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             GetXssMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            GetXssMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetXssMatchSetError {}
 /// Errors returned by ListActivatedRulesInRuleGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListActivatedRulesInRuleGroupError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5274,6 +5813,8 @@ pub enum ListActivatedRulesInRuleGroupError {
     WAFInvalidParameter(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListActivatedRulesInRuleGroupError {
@@ -5283,22 +5824,30 @@ impl ListActivatedRulesInRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListActivatedRulesInRuleGroupError::WAFInternalError(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListActivatedRulesInRuleGroupError::WAFInvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListActivatedRulesInRuleGroupError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListActivatedRulesInRuleGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5317,17 +5866,23 @@ impl fmt::Display for ListActivatedRulesInRuleGroupError {
             ListActivatedRulesInRuleGroupError::WAFNonexistentItem(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListActivatedRulesInRuleGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListActivatedRulesInRuleGroupError {}
 /// Errors returned by ListByteMatchSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListByteMatchSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListByteMatchSetsError {
@@ -5335,13 +5890,19 @@ impl ListByteMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListByteMatchSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        ListByteMatchSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListByteMatchSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5353,17 +5914,21 @@ impl fmt::Display for ListByteMatchSetsError {
         match *self {
             ListByteMatchSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListByteMatchSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListByteMatchSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListByteMatchSetsError {}
 /// Errors returned by ListGeoMatchSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGeoMatchSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGeoMatchSetsError {
@@ -5371,13 +5936,19 @@ impl ListGeoMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListGeoMatchSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        ListGeoMatchSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGeoMatchSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5389,17 +5960,21 @@ impl fmt::Display for ListGeoMatchSetsError {
         match *self {
             ListGeoMatchSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListGeoMatchSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListGeoMatchSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGeoMatchSetsError {}
 /// Errors returned by ListIPSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIPSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIPSetsError {
@@ -5407,13 +5982,13 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(ListIPSetsError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(ListIPSetsError::WAFInvalidAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIPSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5425,12 +6000,14 @@ impl fmt::Display for ListIPSetsError {
         match *self {
             ListIPSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListIPSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListIPSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIPSetsError {}
 /// Errors returned by ListLoggingConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLoggingConfigurationsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5438,6 +6015,8 @@ pub enum ListLoggingConfigurationsError {
     WAFInvalidParameter(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLoggingConfigurationsError {
@@ -5445,22 +6024,27 @@ impl ListLoggingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListLoggingConfigurationsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLoggingConfigurationsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLoggingConfigurationsError::WAFInvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListLoggingConfigurationsError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListLoggingConfigurationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5475,17 +6059,21 @@ impl fmt::Display for ListLoggingConfigurationsError {
                 write!(f, "{}", cause)
             }
             ListLoggingConfigurationsError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            ListLoggingConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLoggingConfigurationsError {}
 /// Errors returned by ListRateBasedRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRateBasedRulesError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRateBasedRulesError {
@@ -5493,15 +6081,19 @@ impl ListRateBasedRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListRateBasedRulesError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRateBasedRulesError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRateBasedRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5513,17 +6105,21 @@ impl fmt::Display for ListRateBasedRulesError {
         match *self {
             ListRateBasedRulesError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListRateBasedRulesError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListRateBasedRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRateBasedRulesError {}
 /// Errors returned by ListRegexMatchSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRegexMatchSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRegexMatchSetsError {
@@ -5531,15 +6127,19 @@ impl ListRegexMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListRegexMatchSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRegexMatchSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRegexMatchSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5551,17 +6151,21 @@ impl fmt::Display for ListRegexMatchSetsError {
         match *self {
             ListRegexMatchSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListRegexMatchSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListRegexMatchSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRegexMatchSetsError {}
 /// Errors returned by ListRegexPatternSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRegexPatternSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRegexPatternSetsError {
@@ -5569,17 +6173,19 @@ impl ListRegexPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRegexPatternSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRegexPatternSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRegexPatternSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5591,12 +6197,14 @@ impl fmt::Display for ListRegexPatternSetsError {
         match *self {
             ListRegexPatternSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListRegexPatternSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListRegexPatternSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRegexPatternSetsError {}
 /// Errors returned by ListResourcesForWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourcesForWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5606,6 +6214,8 @@ pub enum ListResourcesForWebACLError {
     WAFInvalidParameter(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourcesForWebACLError {
@@ -5613,27 +6223,31 @@ impl ListResourcesForWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForWebACLError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForWebACLError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListResourcesForWebACLError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourcesForWebACLError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourcesForWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5647,15 +6261,19 @@ impl fmt::Display for ListResourcesForWebACLError {
             ListResourcesForWebACLError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
             ListResourcesForWebACLError::WAFInvalidParameter(ref cause) => write!(f, "{}", cause),
             ListResourcesForWebACLError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            ListResourcesForWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourcesForWebACLError {}
 /// Errors returned by ListRuleGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRuleGroupsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRuleGroupsError {
@@ -5663,10 +6281,13 @@ impl ListRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRuleGroupsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListRuleGroupsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRuleGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5677,17 +6298,21 @@ impl fmt::Display for ListRuleGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListRuleGroupsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
+            ListRuleGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRuleGroupsError {}
 /// Errors returned by ListRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRulesError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRulesError {
@@ -5695,13 +6320,13 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service(ListRulesError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRulesError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(ListRulesError::WAFInvalidAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5713,17 +6338,21 @@ impl fmt::Display for ListRulesError {
         match *self {
             ListRulesError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListRulesError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRulesError {}
 /// Errors returned by ListSizeConstraintSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSizeConstraintSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSizeConstraintSetsError {
@@ -5731,17 +6360,19 @@ impl ListSizeConstraintSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSizeConstraintSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSizeConstraintSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSizeConstraintSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5753,17 +6384,21 @@ impl fmt::Display for ListSizeConstraintSetsError {
         match *self {
             ListSizeConstraintSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListSizeConstraintSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListSizeConstraintSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSizeConstraintSetsError {}
 /// Errors returned by ListSqlInjectionMatchSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSqlInjectionMatchSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSqlInjectionMatchSetsError {
@@ -5771,17 +6406,21 @@ impl ListSqlInjectionMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSqlInjectionMatchSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSqlInjectionMatchSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSqlInjectionMatchSetsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5793,17 +6432,21 @@ impl fmt::Display for ListSqlInjectionMatchSetsError {
         match *self {
             ListSqlInjectionMatchSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListSqlInjectionMatchSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListSqlInjectionMatchSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSqlInjectionMatchSetsError {}
 /// Errors returned by ListSubscribedRuleGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSubscribedRuleGroupsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because the referenced object doesn't exist.</p>
     WAFNonexistentItem(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSubscribedRuleGroupsError {
@@ -5811,17 +6454,19 @@ impl ListSubscribedRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSubscribedRuleGroupsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSubscribedRuleGroupsError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSubscribedRuleGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5833,12 +6478,14 @@ impl fmt::Display for ListSubscribedRuleGroupsError {
         match *self {
             ListSubscribedRuleGroupsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListSubscribedRuleGroupsError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
+            ListSubscribedRuleGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSubscribedRuleGroupsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     WAFBadRequest(String),
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
@@ -5851,6 +6498,8 @@ pub enum ListTagsForResourceError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5858,33 +6507,43 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::WAFBadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::WAFTagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTagsForResourceError::WAFTagOperationInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5902,17 +6561,21 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::WAFTagOperationInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListWebACLs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWebACLsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWebACLsError {
@@ -5920,13 +6583,13 @@ impl ListWebACLsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInternalError(err.msg))
+                    return RusotoError::service(ListWebACLsError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(ListWebACLsError::WAFInvalidAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWebACLsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5938,17 +6601,21 @@ impl fmt::Display for ListWebACLsError {
         match *self {
             ListWebACLsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListWebACLsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListWebACLsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWebACLsError {}
 /// Errors returned by ListXssMatchSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListXssMatchSetsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using an invalid account identifier.</p>
     WAFInvalidAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListXssMatchSetsError {
@@ -5956,13 +6623,19 @@ impl ListXssMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        ListXssMatchSetsError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        ListXssMatchSetsError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListXssMatchSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5974,12 +6647,14 @@ impl fmt::Display for ListXssMatchSetsError {
         match *self {
             ListXssMatchSetsError::WAFInternalError(ref cause) => write!(f, "{}", cause),
             ListXssMatchSetsError::WAFInvalidAccount(ref cause) => write!(f, "{}", cause),
+            ListXssMatchSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListXssMatchSetsError {}
 /// Errors returned by PutLoggingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLoggingConfigurationError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -5989,6 +6664,8 @@ pub enum PutLoggingConfigurationError {
     WAFServiceLinkedRoleError(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLoggingConfigurationError {
@@ -5996,27 +6673,31 @@ impl PutLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingConfigurationError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFServiceLinkedRoleErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutLoggingConfigurationError::WAFServiceLinkedRoleError(err.msg),
+                        &res,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLoggingConfigurationError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLoggingConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6032,12 +6713,14 @@ impl fmt::Display for PutLoggingConfigurationError {
                 write!(f, "{}", cause)
             }
             PutLoggingConfigurationError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            PutLoggingConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLoggingConfigurationError {}
 /// Errors returned by PutPermissionPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutPermissionPolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6047,6 +6730,8 @@ pub enum PutPermissionPolicyError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutPermissionPolicyError {
@@ -6054,25 +6739,31 @@ impl PutPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPermissionPolicyError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidPermissionPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutPermissionPolicyError::WAFInvalidPermissionPolicy(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPermissionPolicyError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        PutPermissionPolicyError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPermissionPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6088,12 +6779,14 @@ impl fmt::Display for PutPermissionPolicyError {
             }
             PutPermissionPolicyError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             PutPermissionPolicyError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            PutPermissionPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutPermissionPolicyError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     WAFBadRequest(String),
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
@@ -6108,6 +6801,8 @@ pub enum TagResourceError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -6115,30 +6810,37 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(TagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::WAFBadRequest(err.msg), &res)
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service(TagResourceError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(TagResourceError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::WAFLimitsExceeded(err.msg), &res)
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(TagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(TagResourceError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6155,12 +6857,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             TagResourceError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             TagResourceError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     WAFBadRequest(String),
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
@@ -6173,6 +6877,8 @@ pub enum UntagResourceError {
     WAFTagOperation(String),
 
     WAFTagOperationInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -6180,27 +6886,37 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::WAFBadRequest(err.msg), &res)
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UntagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(UntagResourceError::WAFTagOperation(err.msg), &res)
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::WAFTagOperationInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6216,12 +6932,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UntagResourceError::WAFTagOperation(ref cause) => write!(f, "{}", cause),
             UntagResourceError::WAFTagOperationInternalError(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateByteMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateByteMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6239,6 +6957,8 @@ pub enum UpdateByteMatchSetError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateByteMatchSetError {
@@ -6246,43 +6966,55 @@ impl UpdateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateByteMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateByteMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6300,12 +7032,14 @@ impl fmt::Display for UpdateByteMatchSetError {
             UpdateByteMatchSetError::WAFNonexistentContainer(ref cause) => write!(f, "{}", cause),
             UpdateByteMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateByteMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateByteMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateByteMatchSetError {}
 /// Errors returned by UpdateGeoMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGeoMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6325,6 +7059,8 @@ pub enum UpdateGeoMatchSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGeoMatchSetError {
@@ -6332,42 +7068,61 @@ impl UpdateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateGeoMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGeoMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6386,12 +7141,14 @@ impl fmt::Display for UpdateGeoMatchSetError {
             UpdateGeoMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateGeoMatchSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateGeoMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateGeoMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGeoMatchSetError {}
 /// Errors returned by UpdateIPSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIPSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6411,6 +7168,8 @@ pub enum UpdateIPSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIPSetError {
@@ -6418,34 +7177,46 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(UpdateIPSetError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(UpdateIPSetError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateIPSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateIPSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(UpdateIPSetError::WAFLimitsExceeded(err.msg), &res)
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service(
+                        UpdateIPSetError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        UpdateIPSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(UpdateIPSetError::WAFReferencedItem(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(UpdateIPSetError::WAFStaleData(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIPSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6464,12 +7235,14 @@ impl fmt::Display for UpdateIPSetError {
             UpdateIPSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateIPSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateIPSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateIPSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIPSetError {}
 /// Errors returned by UpdateRateBasedRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRateBasedRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6489,6 +7262,8 @@ pub enum UpdateRateBasedRuleError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRateBasedRuleError {
@@ -6496,50 +7271,61 @@ impl UpdateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateRateBasedRuleError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRateBasedRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6558,12 +7344,14 @@ impl fmt::Display for UpdateRateBasedRuleError {
             UpdateRateBasedRuleError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateRateBasedRuleError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateRateBasedRuleError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateRateBasedRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRateBasedRuleError {}
 /// Errors returned by UpdateRegexMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRegexMatchSetError {
     /// <p>The name specified is invalid.</p>
     WAFDisallowedName(String),
@@ -6581,6 +7369,8 @@ pub enum UpdateRegexMatchSetError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRegexMatchSetError {
@@ -6588,45 +7378,55 @@ impl UpdateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFDisallowedName(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateRegexMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRegexMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6644,12 +7444,14 @@ impl fmt::Display for UpdateRegexMatchSetError {
             UpdateRegexMatchSetError::WAFNonexistentContainer(ref cause) => write!(f, "{}", cause),
             UpdateRegexMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateRegexMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateRegexMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRegexMatchSetError {}
 /// Errors returned by UpdateRegexPatternSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRegexPatternSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6667,6 +7469,8 @@ pub enum UpdateRegexPatternSetError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRegexPatternSetError {
@@ -6674,45 +7478,55 @@ impl UpdateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidRegexPatternException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRegexPatternSetError::WAFInvalidRegexPattern(err.msg),
+                        &res,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRegexPatternSetError::WAFNonexistentContainer(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateRegexPatternSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRegexPatternSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6732,12 +7546,14 @@ impl fmt::Display for UpdateRegexPatternSetError {
             }
             UpdateRegexPatternSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateRegexPatternSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateRegexPatternSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRegexPatternSetError {}
 /// Errors returned by UpdateRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRuleError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6757,6 +7573,8 @@ pub enum UpdateRuleError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRuleError {
@@ -6764,34 +7582,43 @@ impl UpdateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFInvalidAccount(err.msg), &res)
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFLimitsExceeded(err.msg), &res)
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFNonexistentItem(err.msg), &res)
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFReferencedItem(err.msg), &res)
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(UpdateRuleError::WAFStaleData(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6810,12 +7637,14 @@ impl fmt::Display for UpdateRuleError {
             UpdateRuleError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateRuleError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateRuleError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRuleError {}
 /// Errors returned by UpdateRuleGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRuleGroupError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6831,6 +7660,8 @@ pub enum UpdateRuleGroupError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRuleGroupError {
@@ -6838,30 +7669,46 @@ impl UpdateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleGroupError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(UpdateRuleGroupError::WAFStaleData(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRuleGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6878,12 +7725,14 @@ impl fmt::Display for UpdateRuleGroupError {
             UpdateRuleGroupError::WAFNonexistentContainer(ref cause) => write!(f, "{}", cause),
             UpdateRuleGroupError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateRuleGroupError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateRuleGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRuleGroupError {}
 /// Errors returned by UpdateSizeConstraintSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSizeConstraintSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6903,6 +7752,8 @@ pub enum UpdateSizeConstraintSetError {
     WAFReferencedItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSizeConstraintSetError {
@@ -6910,52 +7761,61 @@ impl UpdateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSizeConstraintSetError::WAFNonexistentContainer(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSizeConstraintSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSizeConstraintSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6976,12 +7836,14 @@ impl fmt::Display for UpdateSizeConstraintSetError {
             UpdateSizeConstraintSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateSizeConstraintSetError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateSizeConstraintSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateSizeConstraintSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSizeConstraintSetError {}
 /// Errors returned by UpdateSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSqlInjectionMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -6999,6 +7861,8 @@ pub enum UpdateSqlInjectionMatchSetError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSqlInjectionMatchSetError {
@@ -7008,47 +7872,57 @@ impl UpdateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFInvalidOperation(err.msg),
+                        &res,
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFNonexistentContainer(err.msg),
+                        &res,
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateSqlInjectionMatchSetError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7074,12 +7948,14 @@ impl fmt::Display for UpdateSqlInjectionMatchSetError {
                 write!(f, "{}", cause)
             }
             UpdateSqlInjectionMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateSqlInjectionMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSqlInjectionMatchSetError {}
 /// Errors returned by UpdateWebACL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWebACLError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -7101,6 +7977,8 @@ pub enum UpdateWebACLError {
     WAFStaleData(String),
     /// <p>The specified subscription does not exist.</p>
     WAFSubscriptionNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWebACLError {
@@ -7108,41 +7986,61 @@ impl UpdateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(UpdateWebACLError::WAFInternalError(err.msg), &res)
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFReferencedItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(UpdateWebACLError::WAFStaleData(err.msg), &res)
                 }
                 "WAFSubscriptionNotFoundException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFSubscriptionNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateWebACLError::WAFSubscriptionNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWebACLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7162,12 +8060,14 @@ impl fmt::Display for UpdateWebACLError {
             UpdateWebACLError::WAFReferencedItem(ref cause) => write!(f, "{}", cause),
             UpdateWebACLError::WAFStaleData(ref cause) => write!(f, "{}", cause),
             UpdateWebACLError::WAFSubscriptionNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateWebACLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateWebACLError {}
 /// Errors returned by UpdateXssMatchSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateXssMatchSetError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     WAFInternalError(String),
@@ -7185,6 +8085,8 @@ pub enum UpdateXssMatchSetError {
     WAFNonexistentItem(String),
     /// <p>The operation failed because you tried to create, update, or delete an object by using a change token that has already been used.</p>
     WAFStaleData(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateXssMatchSetError {
@@ -7192,39 +8094,55 @@ impl UpdateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFInvalidAccount(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFInvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFInvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFLimitsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFNonexistentContainer(err.msg),
+                        &res,
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFNonexistentItem(err.msg),
+                        &res,
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        UpdateXssMatchSetError::WAFStaleData(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateXssMatchSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7242,6 +8160,7 @@ impl fmt::Display for UpdateXssMatchSetError {
             UpdateXssMatchSetError::WAFNonexistentContainer(ref cause) => write!(f, "{}", cause),
             UpdateXssMatchSetError::WAFNonexistentItem(ref cause) => write!(f, "{}", cause),
             UpdateXssMatchSetError::WAFStaleData(ref cause) => write!(f, "{}", cause),
+            UpdateXssMatchSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -7739,6 +8658,7 @@ impl WAFRegionalClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> WAFRegionalClient {
         WAFRegionalClient {
             client: Client::shared(),
@@ -7764,43 +8684,124 @@ impl WAFRegionalClient {
     pub fn new_with_client(client: Client, region: region::Region) -> WAFRegionalClient {
         WAFRegionalClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<WAFRegionalClient> {
+        rusoto_core::ClientBuilder::new(WAFRegionalClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> WAFRegionalClient {
+        WAFRegionalClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl WAFRegional for WAFRegionalClient {
-    /// <p>Associates a web ACL with a resource, either an application load balancer or Amazon API Gateway stage.</p>
-    async fn associate_web_acl(
+impl WAFRegionalClient {
+    /// Calls `associate_web_acl`, also returning the metadata of the response.
+    pub async fn associate_web_acl_with_metadata(
         &self,
         input: AssociateWebACLRequest,
-    ) -> Result<AssociateWebACLResponse, RusotoError<AssociateWebACLError>> {
+    ) -> Result<(AssociateWebACLResponse, ResponseMetadata), RusotoError<AssociateWebACLError>>
+    {
+        let request = self.build_associate_web_acl_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateWebACLResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateWebACLError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_web_acl(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateWebACLRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_web_acl_request(&self, input: AssociateWebACLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("AssociateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.AssociateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_byte_match_set`, also returning the metadata of the response.
+    pub async fn create_byte_match_set_with_metadata(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> Result<(CreateByteMatchSetResponse, ResponseMetadata), RusotoError<CreateByteMatchSetError>>
+    {
+        let request = self.build_create_byte_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AssociateWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateByteMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateByteMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>ByteMatchSet</code>. You then use <a>UpdateByteMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>ByteMatchSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>BadBot</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateByteMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_byte_match_set(
+    /// Presigns the request `create_byte_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_byte_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateByteMatchSetRequest,
-    ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_byte_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_byte_match_set_request(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7810,83 +8811,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_geo_match_set`, also returning the metadata of the response.
+    pub async fn create_geo_match_set_with_metadata(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> Result<(CreateGeoMatchSetResponse, ResponseMetadata), RusotoError<CreateGeoMatchSetError>>
+    {
+        let request = self.build_create_geo_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateByteMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGeoMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateByteMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGeoMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Creates an <a>GeoMatchSet</a>, which you use to specify which web requests you want to allow or block based on the country that the requests originate from. For example, if you're receiving a lot of requests from one or more countries and you want to block the requests, you can create an <code>GeoMatchSet</code> that contains those countries and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSetSet</code> request to specify the countries that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_geo_match_set(
+    /// Presigns the request `create_geo_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_geo_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGeoMatchSetRequest,
-    ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_geo_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_geo_match_set_request(&self, input: CreateGeoMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_ip_set`, also returning the metadata of the response.
+    pub async fn create_ip_set_with_metadata(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<(CreateIPSetResponse, ResponseMetadata), RusotoError<CreateIPSetError>> {
+        let request = self.build_create_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGeoMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateIPSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGeoMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateIPSetError::from_response(response))
         }
     }
 
-    /// <p>Creates an <a>IPSet</a>, which you use to specify which web requests that you want to allow or block based on the IP addresses that the requests originate from. For example, if you're receiving a lot of requests from one or more individual IP addresses or one or more ranges of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that contains those IP addresses and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateIPSet</code> request.</p> </li> <li> <p>Submit a <code>CreateIPSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_ip_set(
+    /// Presigns the request `create_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIPSetRequest,
-    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_ip_set_request(&self, input: CreateIPSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_rate_based_rule`, also returning the metadata of the response.
+    pub async fn create_rate_based_rule_with_metadata(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> Result<
+        (CreateRateBasedRuleResponse, ResponseMetadata),
+        RusotoError<CreateRateBasedRuleError>,
+    > {
+        let request = self.build_create_rate_based_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateIPSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRateBasedRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIPSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRateBasedRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>RateBasedRule</a>. The <code>RateBasedRule</code> contains a <code>RateLimit</code>, which specifies the maximum number of requests that AWS WAF allows from a specified IP address in a five-minute period. The <code>RateBasedRule</code> also contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to count or block if these requests exceed the <code>RateLimit</code>.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request not only must exceed the <code>RateLimit</code>, but it also must match all the specifications to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that meet the conditions in the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions must be received at a rate of more than 15,000 requests every five minutes. If both conditions are met and the rate is exceeded, AWS WAF blocks the requests. If the rate drops below 15,000 for a five-minute period, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p> <p>To create and configure a <code>RateBasedRule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the rule. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRateBasedRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRateBasedRule</code> request to specify the predicates that you want to include in the rule.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RateBasedRule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_rate_based_rule(
+    /// Presigns the request `create_rate_based_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rate_based_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRateBasedRuleRequest,
-    ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rate_based_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rate_based_rule_request(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7896,28 +8977,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_regex_match_set`, also returning the metadata of the response.
+    pub async fn create_regex_match_set_with_metadata(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> Result<
+        (CreateRegexMatchSetResponse, ResponseMetadata),
+        RusotoError<CreateRegexMatchSetError>,
+    > {
+        let request = self.build_create_regex_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRateBasedRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRegexMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRateBasedRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRegexMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>RegexMatchSet</a>. You then use <a>UpdateRegexMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>RegexMatchSet</code> that contains a <code>RegexMatchTuple</code> that looks for any requests with <code>User-Agent</code> headers that match a <code>RegexPatternSet</code> with pattern <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value, using a <code>RegexPatternSet</code>, that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_regex_match_set(
+    /// Presigns the request `create_regex_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_regex_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRegexMatchSetRequest,
-    ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_regex_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_regex_match_set_request(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7927,28 +9038,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_regex_pattern_set`, also returning the metadata of the response.
+    pub async fn create_regex_pattern_set_with_metadata(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> Result<
+        (CreateRegexPatternSetResponse, ResponseMetadata),
+        RusotoError<CreateRegexPatternSetError>,
+    > {
+        let request = self.build_create_regex_pattern_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRegexMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRegexPatternSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRegexMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRegexPatternSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>RegexPatternSet</code>. You then use <a>UpdateRegexPatternSet</a> to specify the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexPatternSet</a> request to specify the string that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_regex_pattern_set(
+    /// Presigns the request `create_regex_pattern_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_regex_pattern_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRegexPatternSetRequest,
-    ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_regex_pattern_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_regex_pattern_set_request(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7958,82 +9099,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_rule`, also returning the metadata of the response.
+    pub async fn create_rule_with_metadata(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<(CreateRuleResponse, ResponseMetadata), RusotoError<CreateRuleError>> {
+        let request = self.build_create_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRegexPatternSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRegexPatternSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>Rule</code>, which contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to block. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed or blocked. For example, suppose that you add the following to a <code>Rule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to blocks requests that satisfy the <code>Rule</code>. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to specify the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_rule(
+    /// Presigns the request `create_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRuleRequest,
-    ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rule_request(&self, input: CreateRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_rule_group`, also returning the metadata of the response.
+    pub async fn create_rule_group_with_metadata(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> Result<(CreateRuleGroupResponse, ResponseMetadata), RusotoError<CreateRuleGroupError>>
+    {
+        let request = self.build_create_rule_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRuleGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRuleGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>RuleGroup</code>. A rule group is a collection of predefined rules that you add to a web ACL. You use <a>UpdateRuleGroup</a> to add rules to the rule group.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_rule_group(
+    /// Presigns the request `create_rule_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rule_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRuleGroupRequest,
-    ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rule_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rule_group_request(&self, input: CreateRuleGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_size_constraint_set`, also returning the metadata of the response.
+    pub async fn create_size_constraint_set_with_metadata(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> Result<
+        (CreateSizeConstraintSetResponse, ResponseMetadata),
+        RusotoError<CreateSizeConstraintSetError>,
+    > {
+        let request = self.build_create_size_constraint_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSizeConstraintSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRuleGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSizeConstraintSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>SizeConstraintSet</code>. You then use <a>UpdateSizeConstraintSet</a> to identify the part of a web request that you want AWS WAF to check for length, such as the length of the <code>User-Agent</code> header or the length of the query string. For example, you can create a <code>SizeConstraintSet</code> that matches any requests that have a query string that is longer than 100 bytes. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateSizeConstraintSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_size_constraint_set(
+    /// Presigns the request `create_size_constraint_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_size_constraint_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSizeConstraintSetRequest,
-    ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_size_constraint_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_size_constraint_set_request(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8043,29 +9265,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_sql_injection_match_set`, also returning the metadata of the response.
+    pub async fn create_sql_injection_match_set_with_metadata(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> Result<
+        (CreateSqlInjectionMatchSetResponse, ResponseMetadata),
+        RusotoError<CreateSqlInjectionMatchSetError>,
+    > {
+        let request = self.build_create_sql_injection_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSizeConstraintSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSqlInjectionMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSizeConstraintSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSqlInjectionMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>SqlInjectionMatchSet</a>, which you use to allow, block, or count requests that contain snippets of SQL code in a specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateSqlInjectionMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count malicious SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_sql_injection_match_set(
+    /// Presigns the request `create_sql_injection_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_sql_injection_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSqlInjectionMatchSetRequest,
-    ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_sql_injection_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_sql_injection_match_set_request(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8075,83 +9326,161 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_web_acl`, also returning the metadata of the response.
+    pub async fn create_web_acl_with_metadata(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> Result<(CreateWebACLResponse, ResponseMetadata), RusotoError<CreateWebACLError>> {
+        let request = self.build_create_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSqlInjectionMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSqlInjectionMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWebACLError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>WebACL</code>, which contains the <code>Rules</code> that identify the CloudFront web requests that you want to allow, block, or count. AWS WAF evaluates <code>Rules</code> in order based on the value of <code>Priority</code> for each <code>Rule</code>.</p> <p>You also specify a default action, either <code>ALLOW</code> or <code>BLOCK</code>. If a web request doesn't match any of the <code>Rules</code> in a <code>WebACL</code>, AWS WAF responds to the request with the default action. </p> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>ByteMatchSet</code> objects and other predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateWebACL</code> request.</p> </li> <li> <p>Submit a <code>CreateWebACL</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <a>UpdateWebACL</a> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution.</p> </li> </ol> <p>For more information about how to use the AWS WAF API, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_web_acl(
+    /// Presigns the request `create_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWebACLRequest,
-    ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_web_acl_request(&self, input: CreateWebACLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_xss_match_set`, also returning the metadata of the response.
+    pub async fn create_xss_match_set_with_metadata(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> Result<(CreateXssMatchSetResponse, ResponseMetadata), RusotoError<CreateXssMatchSetError>>
+    {
+        let request = self.build_create_xss_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateXssMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateXssMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Creates an <a>XssMatchSet</a>, which you use to allow, block, or count requests that contain cross-site scripting attacks in the specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateXssMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateXssMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn create_xss_match_set(
+    /// Presigns the request `create_xss_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_xss_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateXssMatchSetRequest,
-    ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_xss_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_xss_match_set_request(&self, input: CreateXssMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("CreateXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.CreateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_byte_match_set`, also returning the metadata of the response.
+    pub async fn delete_byte_match_set_with_metadata(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> Result<(DeleteByteMatchSetResponse, ResponseMetadata), RusotoError<DeleteByteMatchSetError>>
+    {
+        let request = self.build_delete_byte_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateXssMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteByteMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateXssMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteByteMatchSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>ByteMatchSet</a>. You can&#39;t delete a <code>ByteMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>ByteMatchTuple</a> objects (any filters).</p> <p>If you just want to remove a <code>ByteMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>ByteMatchSet</code> to remove filters, if any. For more information, see <a>UpdateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteByteMatchSet</code> request.</p> </li> </ol></p>
-    async fn delete_byte_match_set(
+    /// Presigns the request `delete_byte_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_byte_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteByteMatchSetRequest,
-    ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_byte_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_byte_match_set_request(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8161,84 +9490,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `delete_geo_match_set`, also returning the metadata of the response.
+    pub async fn delete_geo_match_set_with_metadata(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> Result<(DeleteGeoMatchSetResponse, ResponseMetadata), RusotoError<DeleteGeoMatchSetError>>
+    {
+        let request = self.build_delete_geo_match_set_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteByteMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteGeoMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteByteMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGeoMatchSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>GeoMatchSet</a>. You can&#39;t delete a <code>GeoMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any countries.</p> <p>If you just want to remove a <code>GeoMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>GeoMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>GeoMatchSet</code> to remove any countries. For more information, see <a>UpdateGeoMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteGeoMatchSet</code> request.</p> </li> </ol></p>
-    async fn delete_geo_match_set(
+    /// Presigns the request `delete_geo_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_geo_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGeoMatchSetRequest,
-    ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_geo_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_geo_match_set_request(&self, input: DeleteGeoMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_ip_set`, also returning the metadata of the response.
+    pub async fn delete_ip_set_with_metadata(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<(DeleteIPSetResponse, ResponseMetadata), RusotoError<DeleteIPSetError>> {
+        let request = self.build_delete_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGeoMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteIPSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGeoMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteIPSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes an <a>IPSet</a>. You can&#39;t delete an <code>IPSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any IP addresses.</p> <p>If you just want to remove an <code>IPSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>IPSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>IPSet</code> to remove IP address ranges, if any. For more information, see <a>UpdateIPSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteIPSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteIPSet</code> request.</p> </li> </ol></p>
-    async fn delete_ip_set(
+    /// Presigns the request `delete_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIPSetRequest,
-    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_ip_set_request(&self, input: DeleteIPSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_logging_configuration`, also returning the metadata of the response.
+    pub async fn delete_logging_configuration_with_metadata(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> Result<
+        (DeleteLoggingConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteLoggingConfigurationError>,
+    > {
+        let request = self.build_delete_logging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteIPSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLoggingConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIPSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLoggingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Permanently deletes the <a>LoggingConfiguration</a> from the specified web ACL.</p>
-    async fn delete_logging_configuration(
+    /// Presigns the request `delete_logging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_logging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLoggingConfigurationRequest,
-    ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_logging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_logging_configuration_request(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8248,28 +9656,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_permission_policy`, also returning the metadata of the response.
+    pub async fn delete_permission_policy_with_metadata(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> Result<
+        (DeletePermissionPolicyResponse, ResponseMetadata),
+        RusotoError<DeletePermissionPolicyError>,
+    > {
+        let request = self.build_delete_permission_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLoggingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePermissionPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLoggingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePermissionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Permanently deletes an IAM policy from the specified RuleGroup.</p> <p>The user making the request must be the owner of the RuleGroup.</p>
-    async fn delete_permission_policy(
+    /// Presigns the request `delete_permission_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_permission_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePermissionPolicyRequest,
-    ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_permission_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_permission_policy_request(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeletePermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8279,28 +9717,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_rate_based_rule`, also returning the metadata of the response.
+    pub async fn delete_rate_based_rule_with_metadata(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> Result<
+        (DeleteRateBasedRuleResponse, ResponseMetadata),
+        RusotoError<DeleteRateBasedRuleError>,
+    > {
+        let request = self.build_delete_rate_based_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePermissionPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRateBasedRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePermissionPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRateBasedRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>RateBasedRule</a>. You can&#39;t delete a rule if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a rule from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RateBasedRule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RateBasedRule</code> to remove predicates, if any. For more information, see <a>UpdateRateBasedRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRateBasedRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRateBasedRule</code> request.</p> </li> </ol></p>
-    async fn delete_rate_based_rule(
+    /// Presigns the request `delete_rate_based_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rate_based_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRateBasedRuleRequest,
-    ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rate_based_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rate_based_rule_request(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8310,28 +9778,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_regex_match_set`, also returning the metadata of the response.
+    pub async fn delete_regex_match_set_with_metadata(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> Result<
+        (DeleteRegexMatchSetResponse, ResponseMetadata),
+        RusotoError<DeleteRegexMatchSetError>,
+    > {
+        let request = self.build_delete_regex_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRateBasedRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRegexMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRateBasedRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRegexMatchSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>RegexMatchSet</a>. You can&#39;t delete a <code>RegexMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <code>RegexMatchTuples</code> objects (any filters).</p> <p>If you just want to remove a <code>RegexMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>RegexMatchSet</code> to remove filters, if any. For more information, see <a>UpdateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteRegexMatchSet</code> request.</p> </li> </ol></p>
-    async fn delete_regex_match_set(
+    /// Presigns the request `delete_regex_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_regex_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRegexMatchSetRequest,
-    ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_regex_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_regex_match_set_request(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8341,28 +9839,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_regex_pattern_set`, also returning the metadata of the response.
+    pub async fn delete_regex_pattern_set_with_metadata(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> Result<
+        (DeleteRegexPatternSetResponse, ResponseMetadata),
+        RusotoError<DeleteRegexPatternSetError>,
+    > {
+        let request = self.build_delete_regex_pattern_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRegexMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRegexPatternSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRegexMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRegexPatternSetError::from_response(response))
         }
     }
 
-    /// <p>Permanently deletes a <a>RegexPatternSet</a>. You can't delete a <code>RegexPatternSet</code> if it's still used in any <code>RegexMatchSet</code> or if the <code>RegexPatternSet</code> is not empty. </p>
-    async fn delete_regex_pattern_set(
+    /// Presigns the request `delete_regex_pattern_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_regex_pattern_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRegexPatternSetRequest,
-    ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_regex_pattern_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_regex_pattern_set_request(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8372,82 +9900,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_rule`, also returning the metadata of the response.
+    pub async fn delete_rule_with_metadata(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<(DeleteRuleResponse, ResponseMetadata), RusotoError<DeleteRuleError>> {
+        let request = self.build_delete_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRegexPatternSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRegexPatternSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>Rule</a>. You can&#39;t delete a <code>Rule</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a <code>Rule</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>Rule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>Rule</code> to remove predicates, if any. For more information, see <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRule</code> request.</p> </li> </ol></p>
-    async fn delete_rule(
+    /// Presigns the request `delete_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRuleRequest,
-    ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rule_request(&self, input: DeleteRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_rule_group`, also returning the metadata of the response.
+    pub async fn delete_rule_group_with_metadata(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> Result<(DeleteRuleGroupResponse, ResponseMetadata), RusotoError<DeleteRuleGroupError>>
+    {
+        let request = self.build_delete_rule_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRuleGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRuleGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>RuleGroup</a>. You can&#39;t delete a <code>RuleGroup</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any rules.</p> <p>If you just want to remove a <code>RuleGroup</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RuleGroup</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RuleGroup</code> to remove rules, if any. For more information, see <a>UpdateRuleGroup</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRuleGroup</code> request.</p> </li> <li> <p>Submit a <code>DeleteRuleGroup</code> request.</p> </li> </ol></p>
-    async fn delete_rule_group(
+    /// Presigns the request `delete_rule_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rule_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRuleGroupRequest,
-    ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rule_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rule_group_request(&self, input: DeleteRuleGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_size_constraint_set`, also returning the metadata of the response.
+    pub async fn delete_size_constraint_set_with_metadata(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> Result<
+        (DeleteSizeConstraintSetResponse, ResponseMetadata),
+        RusotoError<DeleteSizeConstraintSetError>,
+    > {
+        let request = self.build_delete_size_constraint_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteRuleGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSizeConstraintSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRuleGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSizeConstraintSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>SizeConstraintSet</a>. You can&#39;t delete a <code>SizeConstraintSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>SizeConstraint</a> objects (any filters).</p> <p>If you just want to remove a <code>SizeConstraintSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>SizeConstraintSet</code> to remove filters, if any. For more information, see <a>UpdateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSizeConstraintSet</code> request.</p> </li> </ol></p>
-    async fn delete_size_constraint_set(
+    /// Presigns the request `delete_size_constraint_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_size_constraint_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSizeConstraintSetRequest,
-    ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_size_constraint_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_size_constraint_set_request(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8457,29 +10066,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_sql_injection_match_set`, also returning the metadata of the response.
+    pub async fn delete_sql_injection_match_set_with_metadata(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> Result<
+        (DeleteSqlInjectionMatchSetResponse, ResponseMetadata),
+        RusotoError<DeleteSqlInjectionMatchSetError>,
+    > {
+        let request = self.build_delete_sql_injection_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSizeConstraintSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSqlInjectionMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSizeConstraintSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSqlInjectionMatchSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>SqlInjectionMatchSet</a>. You can&#39;t delete a <code>SqlInjectionMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>SqlInjectionMatchTuple</a> objects.</p> <p>If you just want to remove a <code>SqlInjectionMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SqlInjectionMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>SqlInjectionMatchSet</code> to remove filters, if any. For more information, see <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> </ol></p>
-    async fn delete_sql_injection_match_set(
+    /// Presigns the request `delete_sql_injection_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_sql_injection_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSqlInjectionMatchSetRequest,
-    ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_sql_injection_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_sql_injection_match_set_request(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8489,83 +10127,161 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_web_acl`, also returning the metadata of the response.
+    pub async fn delete_web_acl_with_metadata(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> Result<(DeleteWebACLResponse, ResponseMetadata), RusotoError<DeleteWebACLError>> {
+        let request = self.build_delete_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSqlInjectionMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSqlInjectionMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWebACLError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes a <a>WebACL</a>. You can&#39;t delete a <code>WebACL</code> if it still contains any <code>Rules</code>.</p> <p>To delete a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>WebACL</code> to remove <code>Rules</code>, if any. For more information, see <a>UpdateWebACL</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteWebACL</code> request.</p> </li> <li> <p>Submit a <code>DeleteWebACL</code> request.</p> </li> </ol></p>
-    async fn delete_web_acl(
+    /// Presigns the request `delete_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWebACLRequest,
-    ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_web_acl_request(&self, input: DeleteWebACLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_xss_match_set`, also returning the metadata of the response.
+    pub async fn delete_xss_match_set_with_metadata(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> Result<(DeleteXssMatchSetResponse, ResponseMetadata), RusotoError<DeleteXssMatchSetError>>
+    {
+        let request = self.build_delete_xss_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteXssMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteXssMatchSetError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently deletes an <a>XssMatchSet</a>. You can&#39;t delete an <code>XssMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>XssMatchTuple</a> objects.</p> <p>If you just want to remove an <code>XssMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>XssMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>XssMatchSet</code> to remove filters, if any. For more information, see <a>UpdateXssMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteXssMatchSet</code> request.</p> </li> </ol></p>
-    async fn delete_xss_match_set(
+    /// Presigns the request `delete_xss_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_xss_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteXssMatchSetRequest,
-    ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_xss_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_xss_match_set_request(&self, input: DeleteXssMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DeleteXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.DeleteXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_web_acl`, also returning the metadata of the response.
+    pub async fn disassociate_web_acl_with_metadata(
+        &self,
+        input: DisassociateWebACLRequest,
+    ) -> Result<(DisassociateWebACLResponse, ResponseMetadata), RusotoError<DisassociateWebACLError>>
+    {
+        let request = self.build_disassociate_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteXssMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteXssMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateWebACLError::from_response(response))
         }
     }
 
-    /// <p>Removes a web ACL from the specified resource, either an application load balancer or Amazon API Gateway stage.</p>
-    async fn disassociate_web_acl(
+    /// Presigns the request `disassociate_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateWebACLRequest,
-    ) -> Result<DisassociateWebACLResponse, RusotoError<DisassociateWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_web_acl_request(
+        &self,
+        input: DisassociateWebACLRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("DisassociateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8575,80 +10291,160 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_byte_match_set`, also returning the metadata of the response.
+    pub async fn get_byte_match_set_with_metadata(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> Result<(GetByteMatchSetResponse, ResponseMetadata), RusotoError<GetByteMatchSetError>>
+    {
+        let request = self.build_get_byte_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetByteMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetByteMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>ByteMatchSet</a> specified by <code>ByteMatchSetId</code>.</p>
-    async fn get_byte_match_set(
+    /// Presigns the request `get_byte_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_byte_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetByteMatchSetRequest,
-    ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_byte_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_byte_match_set_request(&self, input: GetByteMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_change_token`, also returning the metadata of the response.
+    pub async fn get_change_token_with_metadata(
+        &self,
+    ) -> Result<(GetChangeTokenResponse, ResponseMetadata), RusotoError<GetChangeTokenError>> {
+        let request = self.build_get_change_token_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetByteMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetChangeTokenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetByteMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetChangeTokenError::from_response(response))
         }
     }
 
-    /// <p>When you want to create, update, or delete AWS WAF objects, get a change token and include the change token in the create, update, or delete request. Change tokens ensure that your application doesn't submit conflicting requests to AWS WAF.</p> <p>Each create, update, or delete request must use a unique change token. If your application submits a <code>GetChangeToken</code> request and then submits a second <code>GetChangeToken</code> request before submitting a create, update, or delete request, the second <code>GetChangeToken</code> request returns the same value as the first <code>GetChangeToken</code> request.</p> <p>When you use a change token in a create, update, or delete request, the status of the change token changes to <code>PENDING</code>, which indicates that AWS WAF is propagating the change to all AWS WAF servers. Use <code>GetChangeTokenStatus</code> to determine the status of your change token.</p>
-    async fn get_change_token(
+    /// Presigns the request `get_change_token` would send with `presigner` instead of sending it.
+    pub async fn presign_get_change_token(
         &self,
-    ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_change_token_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_change_token_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetChangeToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetChangeToken");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_change_token_status`, also returning the metadata of the response.
+    pub async fn get_change_token_status_with_metadata(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> Result<
+        (GetChangeTokenStatusResponse, ResponseMetadata),
+        RusotoError<GetChangeTokenStatusError>,
+    > {
+        let request = self.build_get_change_token_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetChangeTokenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetChangeTokenStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetChangeTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetChangeTokenStatusError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the status of a <code>ChangeToken</code> that you got by calling <a>GetChangeToken</a>. <code>ChangeTokenStatus</code> is one of the following values:</p> <ul> <li> <p> <code>PROVISIONED</code>: You requested the change token by calling <code>GetChangeToken</code>, but you haven&#39;t used it yet in a call to create, update, or delete an AWS WAF object.</p> </li> <li> <p> <code>PENDING</code>: AWS WAF is propagating the create, update, or delete request to all AWS WAF servers.</p> </li> <li> <p> <code>INSYNC</code>: Propagation is complete.</p> </li> </ul></p>
-    async fn get_change_token_status(
+    /// Presigns the request `get_change_token_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_change_token_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetChangeTokenStatusRequest,
-    ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_change_token_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_change_token_status_request(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetChangeTokenStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8658,82 +10454,162 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_geo_match_set`, also returning the metadata of the response.
+    pub async fn get_geo_match_set_with_metadata(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> Result<(GetGeoMatchSetResponse, ResponseMetadata), RusotoError<GetGeoMatchSetError>> {
+        let request = self.build_get_geo_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetChangeTokenStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGeoMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetChangeTokenStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetGeoMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>GeoMatchSet</a> that is specified by <code>GeoMatchSetId</code>.</p>
-    async fn get_geo_match_set(
+    /// Presigns the request `get_geo_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_geo_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGeoMatchSetRequest,
-    ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
-        let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_geo_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_geo_match_set_request(&self, input: GetGeoMatchSetRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ip_set`, also returning the metadata of the response.
+    pub async fn get_ip_set_with_metadata(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<(GetIPSetResponse, ResponseMetadata), RusotoError<GetIPSetError>> {
+        let request = self.build_get_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetGeoMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetIPSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGeoMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetIPSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>IPSet</a> that is specified by <code>IPSetId</code>.</p>
-    async fn get_ip_set(
+    /// Presigns the request `get_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIPSetRequest,
-    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ip_set_request(&self, input: GetIPSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_logging_configuration`, also returning the metadata of the response.
+    pub async fn get_logging_configuration_with_metadata(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> Result<
+        (GetLoggingConfigurationResponse, ResponseMetadata),
+        RusotoError<GetLoggingConfigurationError>,
+    > {
+        let request = self.build_get_logging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetIPSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLoggingConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIPSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLoggingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>LoggingConfiguration</a> for the specified web ACL.</p>
-    async fn get_logging_configuration(
+    /// Presigns the request `get_logging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_logging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLoggingConfigurationRequest,
-    ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_logging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_logging_configuration_request(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8743,28 +10619,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_permission_policy`, also returning the metadata of the response.
+    pub async fn get_permission_policy_with_metadata(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> Result<
+        (GetPermissionPolicyResponse, ResponseMetadata),
+        RusotoError<GetPermissionPolicyError>,
+    > {
+        let request = self.build_get_permission_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLoggingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPermissionPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLoggingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPermissionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the IAM policy attached to the RuleGroup.</p>
-    async fn get_permission_policy(
+    /// Presigns the request `get_permission_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_permission_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPermissionPolicyRequest,
-    ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_permission_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_permission_policy_request(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetPermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8774,57 +10680,111 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rate_based_rule`, also returning the metadata of the response.
+    pub async fn get_rate_based_rule_with_metadata(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> Result<(GetRateBasedRuleResponse, ResponseMetadata), RusotoError<GetRateBasedRuleError>>
+    {
+        let request = self.build_get_rate_based_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPermissionPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRateBasedRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPermissionPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRateBasedRuleError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>RateBasedRule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRateBasedRule</code> request.</p>
-    async fn get_rate_based_rule(
+    /// Presigns the request `get_rate_based_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rate_based_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRateBasedRuleRequest,
-    ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rate_based_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rate_based_rule_request(&self, input: GetRateBasedRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rate_based_rule_managed_keys`, also returning the metadata of the response.
+    pub async fn get_rate_based_rule_managed_keys_with_metadata(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> Result<
+        (GetRateBasedRuleManagedKeysResponse, ResponseMetadata),
+        RusotoError<GetRateBasedRuleManagedKeysError>,
+    > {
+        let request = self.build_get_rate_based_rule_managed_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRateBasedRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRateBasedRuleManagedKeysResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRateBasedRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRateBasedRuleManagedKeysError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of IP addresses currently being blocked by the <a>RateBasedRule</a> that is specified by the <code>RuleId</code>. The maximum number of managed keys that will be blocked is 10,000. If more than 10,000 addresses exceed the rate limit, the 10,000 addresses with the highest rates will be blocked.</p>
-    async fn get_rate_based_rule_managed_keys(
+    /// Presigns the request `get_rate_based_rule_managed_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rate_based_rule_managed_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRateBasedRuleManagedKeysRequest,
-    ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rate_based_rule_managed_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rate_based_rule_managed_keys_request(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRateBasedRuleManagedKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8834,56 +10794,109 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_regex_match_set`, also returning the metadata of the response.
+    pub async fn get_regex_match_set_with_metadata(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> Result<(GetRegexMatchSetResponse, ResponseMetadata), RusotoError<GetRegexMatchSetError>>
+    {
+        let request = self.build_get_regex_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRateBasedRuleManagedKeysResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRegexMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRateBasedRuleManagedKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRegexMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>RegexMatchSet</a> specified by <code>RegexMatchSetId</code>.</p>
-    async fn get_regex_match_set(
+    /// Presigns the request `get_regex_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_regex_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRegexMatchSetRequest,
-    ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_regex_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_regex_match_set_request(&self, input: GetRegexMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_regex_pattern_set`, also returning the metadata of the response.
+    pub async fn get_regex_pattern_set_with_metadata(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> Result<(GetRegexPatternSetResponse, ResponseMetadata), RusotoError<GetRegexPatternSetError>>
+    {
+        let request = self.build_get_regex_pattern_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRegexMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRegexPatternSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRegexMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRegexPatternSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>RegexPatternSet</a> specified by <code>RegexPatternSetId</code>.</p>
-    async fn get_regex_pattern_set(
+    /// Presigns the request `get_regex_pattern_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_regex_pattern_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRegexPatternSetRequest,
-    ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_regex_pattern_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_regex_pattern_set_request(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8893,82 +10906,160 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rule`, also returning the metadata of the response.
+    pub async fn get_rule_with_metadata(
+        &self,
+        input: GetRuleRequest,
+    ) -> Result<(GetRuleResponse, ResponseMetadata), RusotoError<GetRuleError>> {
+        let request = self.build_get_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRegexPatternSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRegexPatternSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRuleError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>Rule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRule</code> request.</p>
-    async fn get_rule(
+    /// Presigns the request `get_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRuleRequest,
-    ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rule_request(&self, input: GetRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rule_group`, also returning the metadata of the response.
+    pub async fn get_rule_group_with_metadata(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> Result<(GetRuleGroupResponse, ResponseMetadata), RusotoError<GetRuleGroupError>> {
+        let request = self.build_get_rule_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRuleGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRuleGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>RuleGroup</a> that is specified by the <code>RuleGroupId</code> that you included in the <code>GetRuleGroup</code> request.</p> <p>To view the rules in a rule group, use <a>ListActivatedRulesInRuleGroup</a>.</p>
-    async fn get_rule_group(
+    /// Presigns the request `get_rule_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rule_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRuleGroupRequest,
-    ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rule_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rule_group_request(&self, input: GetRuleGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_sampled_requests`, also returning the metadata of the response.
+    pub async fn get_sampled_requests_with_metadata(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> Result<(GetSampledRequestsResponse, ResponseMetadata), RusotoError<GetSampledRequestsError>>
+    {
+        let request = self.build_get_sampled_requests_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRuleGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSampledRequestsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRuleGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSampledRequestsError::from_response(response))
         }
     }
 
-    /// <p>Gets detailed information about a specified number of requests--a sample--that AWS WAF randomly selects from among the first 5,000 requests that your AWS resource received during a time range that you choose. You can specify a sample size of up to 500 requests, and you can specify any time range in the previous three hours.</p> <p> <code>GetSampledRequests</code> returns a time range, which is usually the time range that you specified. However, if your resource (such as a CloudFront distribution) received 5,000 requests before the specified time range elapsed, <code>GetSampledRequests</code> returns an updated time range. This new time range indicates the actual period during which AWS WAF selected the requests in the sample.</p>
-    async fn get_sampled_requests(
+    /// Presigns the request `get_sampled_requests` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sampled_requests(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSampledRequestsRequest,
-    ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sampled_requests_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sampled_requests_request(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetSampledRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8978,28 +11069,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_size_constraint_set`, also returning the metadata of the response.
+    pub async fn get_size_constraint_set_with_metadata(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> Result<
+        (GetSizeConstraintSetResponse, ResponseMetadata),
+        RusotoError<GetSizeConstraintSetError>,
+    > {
+        let request = self.build_get_size_constraint_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSampledRequestsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSizeConstraintSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSampledRequestsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSizeConstraintSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>SizeConstraintSet</a> specified by <code>SizeConstraintSetId</code>.</p>
-    async fn get_size_constraint_set(
+    /// Presigns the request `get_size_constraint_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_size_constraint_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSizeConstraintSetRequest,
-    ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_size_constraint_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_size_constraint_set_request(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9009,28 +11130,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_sql_injection_match_set`, also returning the metadata of the response.
+    pub async fn get_sql_injection_match_set_with_metadata(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> Result<
+        (GetSqlInjectionMatchSetResponse, ResponseMetadata),
+        RusotoError<GetSqlInjectionMatchSetError>,
+    > {
+        let request = self.build_get_sql_injection_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSizeConstraintSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSqlInjectionMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSizeConstraintSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSqlInjectionMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>SqlInjectionMatchSet</a> that is specified by <code>SqlInjectionMatchSetId</code>.</p>
-    async fn get_sql_injection_match_set(
+    /// Presigns the request `get_sql_injection_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sql_injection_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSqlInjectionMatchSetRequest,
-    ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sql_injection_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sql_injection_match_set_request(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9040,55 +11191,110 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_web_acl`, also returning the metadata of the response.
+    pub async fn get_web_acl_with_metadata(
+        &self,
+        input: GetWebACLRequest,
+    ) -> Result<(GetWebACLResponse, ResponseMetadata), RusotoError<GetWebACLError>> {
+        let request = self.build_get_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSqlInjectionMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSqlInjectionMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWebACLError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>WebACL</a> that is specified by <code>WebACLId</code>.</p>
-    async fn get_web_acl(
+    /// Presigns the request `get_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWebACLRequest,
-    ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_web_acl_request(&self, input: GetWebACLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_web_acl_for_resource`, also returning the metadata of the response.
+    pub async fn get_web_acl_for_resource_with_metadata(
+        &self,
+        input: GetWebACLForResourceRequest,
+    ) -> Result<
+        (GetWebACLForResourceResponse, ResponseMetadata),
+        RusotoError<GetWebACLForResourceError>,
+    > {
+        let request = self.build_get_web_acl_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWebACLForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWebACLForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns the web ACL for the specified resource, either an application load balancer or Amazon API Gateway stage.</p>
-    async fn get_web_acl_for_resource(
+    /// Presigns the request `get_web_acl_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_web_acl_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWebACLForResourceRequest,
-    ) -> Result<GetWebACLForResourceResponse, RusotoError<GetWebACLForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_web_acl_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_web_acl_for_resource_request(
+        &self,
+        input: GetWebACLForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetWebACLForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9098,58 +11304,110 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_xss_match_set`, also returning the metadata of the response.
+    pub async fn get_xss_match_set_with_metadata(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> Result<(GetXssMatchSetResponse, ResponseMetadata), RusotoError<GetXssMatchSetError>> {
+        let request = self.build_get_xss_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetWebACLForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetXssMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWebACLForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetXssMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Returns the <a>XssMatchSet</a> that is specified by <code>XssMatchSetId</code>.</p>
-    async fn get_xss_match_set(
+    /// Presigns the request `get_xss_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_xss_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetXssMatchSetRequest,
-    ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_xss_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_xss_match_set_request(&self, input: GetXssMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("GetXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.GetXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_activated_rules_in_rule_group`, also returning the metadata of the response.
+    pub async fn list_activated_rules_in_rule_group_with_metadata(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> Result<
+        (ListActivatedRulesInRuleGroupResponse, ResponseMetadata),
+        RusotoError<ListActivatedRulesInRuleGroupError>,
+    > {
+        let request = self.build_list_activated_rules_in_rule_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetXssMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListActivatedRulesInRuleGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetXssMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListActivatedRulesInRuleGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>ActivatedRule</a> objects.</p>
-    async fn list_activated_rules_in_rule_group(
+    /// Presigns the request `list_activated_rules_in_rule_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_activated_rules_in_rule_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListActivatedRulesInRuleGroupRequest,
-    ) -> Result<
-        ListActivatedRulesInRuleGroupResponse,
-        RusotoError<ListActivatedRulesInRuleGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_activated_rules_in_rule_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_activated_rules_in_rule_group_request(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListActivatedRulesInRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9159,112 +11417,216 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_byte_match_sets`, also returning the metadata of the response.
+    pub async fn list_byte_match_sets_with_metadata(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> Result<(ListByteMatchSetsResponse, ResponseMetadata), RusotoError<ListByteMatchSetsError>>
+    {
+        let request = self.build_list_byte_match_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListActivatedRulesInRuleGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListByteMatchSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListActivatedRulesInRuleGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListByteMatchSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>ByteMatchSetSummary</a> objects.</p>
-    async fn list_byte_match_sets(
+    /// Presigns the request `list_byte_match_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_byte_match_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListByteMatchSetsRequest,
-    ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_byte_match_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_byte_match_sets_request(&self, input: ListByteMatchSetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListByteMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListByteMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_geo_match_sets`, also returning the metadata of the response.
+    pub async fn list_geo_match_sets_with_metadata(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> Result<(ListGeoMatchSetsResponse, ResponseMetadata), RusotoError<ListGeoMatchSetsError>>
+    {
+        let request = self.build_list_geo_match_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListByteMatchSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGeoMatchSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListByteMatchSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGeoMatchSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>GeoMatchSetSummary</a> objects in the response.</p>
-    async fn list_geo_match_sets(
+    /// Presigns the request `list_geo_match_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_geo_match_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGeoMatchSetsRequest,
-    ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_geo_match_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_geo_match_sets_request(&self, input: ListGeoMatchSetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListGeoMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListGeoMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_ip_sets`, also returning the metadata of the response.
+    pub async fn list_ip_sets_with_metadata(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<(ListIPSetsResponse, ResponseMetadata), RusotoError<ListIPSetsError>> {
+        let request = self.build_list_ip_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGeoMatchSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIPSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGeoMatchSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIPSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>IPSetSummary</a> objects in the response.</p>
-    async fn list_ip_sets(
+    /// Presigns the request `list_ip_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ip_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIPSetsRequest,
-    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ip_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ip_sets_request(&self, input: ListIPSetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListIPSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListIPSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_logging_configurations`, also returning the metadata of the response.
+    pub async fn list_logging_configurations_with_metadata(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> Result<
+        (ListLoggingConfigurationsResponse, ResponseMetadata),
+        RusotoError<ListLoggingConfigurationsError>,
+    > {
+        let request = self.build_list_logging_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListIPSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLoggingConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIPSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLoggingConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>LoggingConfiguration</a> objects.</p>
-    async fn list_logging_configurations(
+    /// Presigns the request `list_logging_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_logging_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLoggingConfigurationsRequest,
-    ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_logging_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_logging_configurations_request(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListLoggingConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9274,28 +11636,56 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rate_based_rules`, also returning the metadata of the response.
+    pub async fn list_rate_based_rules_with_metadata(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> Result<(ListRateBasedRulesResponse, ResponseMetadata), RusotoError<ListRateBasedRulesError>>
+    {
+        let request = self.build_list_rate_based_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLoggingConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRateBasedRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLoggingConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRateBasedRulesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
-    async fn list_rate_based_rules(
+    /// Presigns the request `list_rate_based_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rate_based_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRateBasedRulesRequest,
-    ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rate_based_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rate_based_rules_request(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListRateBasedRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9305,28 +11695,56 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_regex_match_sets`, also returning the metadata of the response.
+    pub async fn list_regex_match_sets_with_metadata(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> Result<(ListRegexMatchSetsResponse, ResponseMetadata), RusotoError<ListRegexMatchSetsError>>
+    {
+        let request = self.build_list_regex_match_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRateBasedRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRegexMatchSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRateBasedRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRegexMatchSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RegexMatchSetSummary</a> objects.</p>
-    async fn list_regex_match_sets(
+    /// Presigns the request `list_regex_match_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_regex_match_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRegexMatchSetsRequest,
-    ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_regex_match_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_regex_match_sets_request(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListRegexMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9336,28 +11754,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_regex_pattern_sets`, also returning the metadata of the response.
+    pub async fn list_regex_pattern_sets_with_metadata(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> Result<
+        (ListRegexPatternSetsResponse, ResponseMetadata),
+        RusotoError<ListRegexPatternSetsError>,
+    > {
+        let request = self.build_list_regex_pattern_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRegexMatchSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRegexPatternSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRegexMatchSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRegexPatternSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RegexPatternSetSummary</a> objects.</p>
-    async fn list_regex_pattern_sets(
+    /// Presigns the request `list_regex_pattern_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_regex_pattern_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRegexPatternSetsRequest,
-    ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_regex_pattern_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_regex_pattern_sets_request(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListRegexPatternSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9367,28 +11815,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resources_for_web_acl`, also returning the metadata of the response.
+    pub async fn list_resources_for_web_acl_with_metadata(
+        &self,
+        input: ListResourcesForWebACLRequest,
+    ) -> Result<
+        (ListResourcesForWebACLResponse, ResponseMetadata),
+        RusotoError<ListResourcesForWebACLError>,
+    > {
+        let request = self.build_list_resources_for_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRegexPatternSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourcesForWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRegexPatternSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourcesForWebACLError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of resources associated with the specified web ACL.</p>
-    async fn list_resources_for_web_acl(
+    /// Presigns the request `list_resources_for_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resources_for_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourcesForWebACLRequest,
-    ) -> Result<ListResourcesForWebACLResponse, RusotoError<ListResourcesForWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resources_for_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resources_for_web_acl_request(
+        &self,
+        input: ListResourcesForWebACLRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListResourcesForWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9398,82 +11876,162 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rule_groups`, also returning the metadata of the response.
+    pub async fn list_rule_groups_with_metadata(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> Result<(ListRuleGroupsResponse, ResponseMetadata), RusotoError<ListRuleGroupsError>> {
+        let request = self.build_list_rule_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourcesForWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRuleGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourcesForWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRuleGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RuleGroup</a> objects.</p>
-    async fn list_rule_groups(
+    /// Presigns the request `list_rule_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rule_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRuleGroupsRequest,
-    ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rule_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rule_groups_request(&self, input: ListRuleGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListRuleGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rules`, also returning the metadata of the response.
+    pub async fn list_rules_with_metadata(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<(ListRulesResponse, ResponseMetadata), RusotoError<ListRulesError>> {
+        let request = self.build_list_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRuleGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRuleGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRulesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
-    async fn list_rules(
+    /// Presigns the request `list_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRulesRequest,
-    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rules_request(&self, input: ListRulesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_size_constraint_sets`, also returning the metadata of the response.
+    pub async fn list_size_constraint_sets_with_metadata(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> Result<
+        (ListSizeConstraintSetsResponse, ResponseMetadata),
+        RusotoError<ListSizeConstraintSetsError>,
+    > {
+        let request = self.build_list_size_constraint_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSizeConstraintSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSizeConstraintSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>SizeConstraintSetSummary</a> objects.</p>
-    async fn list_size_constraint_sets(
+    /// Presigns the request `list_size_constraint_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_size_constraint_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSizeConstraintSetsRequest,
-    ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_size_constraint_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_size_constraint_sets_request(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListSizeConstraintSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9483,29 +12041,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_sql_injection_match_sets`, also returning the metadata of the response.
+    pub async fn list_sql_injection_match_sets_with_metadata(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> Result<
+        (ListSqlInjectionMatchSetsResponse, ResponseMetadata),
+        RusotoError<ListSqlInjectionMatchSetsError>,
+    > {
+        let request = self.build_list_sql_injection_match_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSizeConstraintSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSqlInjectionMatchSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSizeConstraintSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSqlInjectionMatchSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>SqlInjectionMatchSet</a> objects.</p>
-    async fn list_sql_injection_match_sets(
+    /// Presigns the request `list_sql_injection_match_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_sql_injection_match_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSqlInjectionMatchSetsRequest,
-    ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_sql_injection_match_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_sql_injection_match_sets_request(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListSqlInjectionMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9515,28 +12102,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_subscribed_rule_groups`, also returning the metadata of the response.
+    pub async fn list_subscribed_rule_groups_with_metadata(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> Result<
+        (ListSubscribedRuleGroupsResponse, ResponseMetadata),
+        RusotoError<ListSubscribedRuleGroupsError>,
+    > {
+        let request = self.build_list_subscribed_rule_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSqlInjectionMatchSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSubscribedRuleGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSqlInjectionMatchSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSubscribedRuleGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>RuleGroup</a> objects that you are subscribed to.</p>
-    async fn list_subscribed_rule_groups(
+    /// Presigns the request `list_subscribed_rule_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_subscribed_rule_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSubscribedRuleGroupsRequest,
-    ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_subscribed_rule_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_subscribed_rule_groups_request(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListSubscribedRuleGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9546,27 +12163,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSubscribedRuleGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSubscribedRuleGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9576,83 +12224,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_web_ac_ls`, also returning the metadata of the response.
+    pub async fn list_web_ac_ls_with_metadata(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> Result<(ListWebACLsResponse, ResponseMetadata), RusotoError<ListWebACLsError>> {
+        let request = self.build_list_web_ac_ls_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWebACLsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWebACLsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>WebACLSummary</a> objects in the response.</p>
-    async fn list_web_ac_ls(
+    /// Presigns the request `list_web_ac_ls` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_web_ac_ls(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWebACLsRequest,
-    ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_web_ac_ls_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_web_ac_ls_request(&self, input: ListWebACLsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListWebACLs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListWebACLs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_xss_match_sets`, also returning the metadata of the response.
+    pub async fn list_xss_match_sets_with_metadata(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> Result<(ListXssMatchSetsResponse, ResponseMetadata), RusotoError<ListXssMatchSetsError>>
+    {
+        let request = self.build_list_xss_match_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListWebACLsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListXssMatchSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWebACLsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListXssMatchSetsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <a>XssMatchSet</a> objects.</p>
-    async fn list_xss_match_sets(
+    /// Presigns the request `list_xss_match_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_xss_match_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListXssMatchSetsRequest,
-    ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_xss_match_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_xss_match_sets_request(&self, input: ListXssMatchSetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("ListXssMatchSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.ListXssMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_logging_configuration`, also returning the metadata of the response.
+    pub async fn put_logging_configuration_with_metadata(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> Result<
+        (PutLoggingConfigurationResponse, ResponseMetadata),
+        RusotoError<PutLoggingConfigurationError>,
+    > {
+        let request = self.build_put_logging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListXssMatchSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutLoggingConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListXssMatchSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutLoggingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Associates a <a>LoggingConfiguration</a> with a specified web ACL.</p> <p>You can access information about all traffic that AWS WAF inspects using the following steps:</p> <ol> <li> <p>Create an Amazon Kinesis Data Firehose. </p> <p>Create the data firehose with a PUT source and in the region that you are operating. However, if you are capturing logs for Amazon CloudFront, always create the firehose in US East (N. Virginia). </p> <note> <p>Do not create the data firehose using a <code>Kinesis stream</code> as your source.</p> </note> </li> <li> <p>Associate that firehose to your web ACL using a <code>PutLoggingConfiguration</code> request.</p> </li> </ol> <p>When you successfully enable logging using a <code>PutLoggingConfiguration</code> request, AWS WAF will create a service linked role with the necessary permissions to write logs to the Amazon Kinesis Data Firehose. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/logging.html">Logging Web ACL Traffic Information</a> in the <i>AWS WAF Developer Guide</i>.</p>
-    async fn put_logging_configuration(
+    /// Presigns the request `put_logging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_logging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLoggingConfigurationRequest,
-    ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_logging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_logging_configuration_request(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("PutLoggingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9662,28 +12390,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_permission_policy`, also returning the metadata of the response.
+    pub async fn put_permission_policy_with_metadata(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> Result<
+        (PutPermissionPolicyResponse, ResponseMetadata),
+        RusotoError<PutPermissionPolicyError>,
+    > {
+        let request = self.build_put_permission_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutLoggingConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutPermissionPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLoggingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutPermissionPolicyError::from_response(response))
         }
     }
 
-    /// <p>Attaches a IAM policy to the specified resource. The only supported use for this action is to share a RuleGroup across accounts.</p> <p>The <code>PutPermissionPolicy</code> is subject to the following restrictions:</p> <ul> <li> <p>You can attach only one policy with each <code>PutPermissionPolicy</code> request.</p> </li> <li> <p>The policy must include an <code>Effect</code>, <code>Action</code> and <code>Principal</code>. </p> </li> <li> <p> <code>Effect</code> must specify <code>Allow</code>.</p> </li> <li> <p>The <code>Action</code> in the policy must be <code>waf:UpdateWebACL</code>, <code>waf-regional:UpdateWebACL</code>, <code>waf:GetRuleGroup</code> and <code>waf-regional:GetRuleGroup</code> . Any extra or wildcard actions in the policy will be rejected.</p> </li> <li> <p>The policy cannot include a <code>Resource</code> parameter.</p> </li> <li> <p>The ARN in the request must be a valid WAF RuleGroup ARN and the RuleGroup must exist in the same region.</p> </li> <li> <p>The user making the request must be the owner of the RuleGroup.</p> </li> <li> <p>Your policy must be composed using IAM Policy version 2012-10-17.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html">IAM Policies</a>. </p> <p>An example of a valid policy parameter is shown in the Examples section below.</p>
-    async fn put_permission_policy(
+    /// Presigns the request `put_permission_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_permission_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPermissionPolicyRequest,
-    ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_permission_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_permission_policy_request(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("PutPermissionPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9693,80 +12451,160 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutPermissionPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPermissionPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_byte_match_set`, also returning the metadata of the response.
+    pub async fn update_byte_match_set_with_metadata(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> Result<(UpdateByteMatchSetResponse, ResponseMetadata), RusotoError<UpdateByteMatchSetError>>
+    {
+        let request = self.build_update_byte_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateByteMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateByteMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>ByteMatchTuple</a> objects (filters) in a <a>ByteMatchSet</a>. For each <code>ByteMatchTuple</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>ByteMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to look for. For more information, including how you specify the values for the AWS WAF API and the AWS CLI or SDKs, see <code>TargetString</code> in the <a>ByteMatchTuple</a> data type. </p> </li> <li> <p>Where to look, such as at the beginning or the end of a query string.</p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p>For example, you can add a <code>ByteMatchSetUpdate</code> object that matches web requests in which <code>User-Agent</code> headers contain the string <code>BadBot</code>. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>ByteMatchSet.</code> For more information, see <a>CreateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateByteMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_byte_match_set(
+    /// Presigns the request `update_byte_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_byte_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateByteMatchSetRequest,
-    ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_byte_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_byte_match_set_request(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateByteMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9776,83 +12614,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_geo_match_set`, also returning the metadata of the response.
+    pub async fn update_geo_match_set_with_metadata(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> Result<(UpdateGeoMatchSetResponse, ResponseMetadata), RusotoError<UpdateGeoMatchSetError>>
+    {
+        let request = self.build_update_geo_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateByteMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGeoMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateByteMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGeoMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>GeoMatchConstraint</a> objects in an <code>GeoMatchSet</code>. For each <code>GeoMatchConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>GeoMatchConstraint</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The <code>Type</code>. The only valid value for <code>Type</code> is <code>Country</code>.</p> </li> <li> <p>The <code>Value</code>, which is a two character code for the country to add to the <code>GeoMatchConstraint</code> object. Valid codes are listed in <a>GeoMatchConstraint$Value</a>.</p> </li> </ul> <p>To create and configure an <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateGeoMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSet</code> request to specify the country that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>GeoMatchSet</code>, you specify the country that you want to add and/or the country that you want to delete. If you want to change a country, you delete the existing country and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_geo_match_set(
+    /// Presigns the request `update_geo_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_geo_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGeoMatchSetRequest,
-    ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_geo_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_geo_match_set_request(&self, input: UpdateGeoMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateGeoMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_ip_set`, also returning the metadata of the response.
+    pub async fn update_ip_set_with_metadata(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<(UpdateIPSetResponse, ResponseMetadata), RusotoError<UpdateIPSetError>> {
+        let request = self.build_update_ip_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGeoMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateIPSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGeoMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateIPSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>IPSetDescriptor</a> objects in an <code>IPSet</code>. For each <code>IPSetDescriptor</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>IPSetDescriptor</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The IP address version, <code>IPv4</code> or <code>IPv6</code>. </p> </li> <li> <p>The IP address in CIDR notation, for example, <code>192.0.2.0/24</code> (for the range of IP addresses from <code>192.0.2.0</code> to <code>192.0.2.255</code>) or <code>192.0.2.44/32</code> (for the individual IP address <code>192.0.2.44</code>). </p> </li> </ul> <p>AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128. For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>IPv6 addresses can be represented using any of the following formats:</p> <ul> <li> <p>1111:0000:0000:0000:0000:0000:0000:0111/128</p> </li> <li> <p>1111:0:0:0:0:0:0:0111/128</p> </li> <li> <p>1111::0111/128</p> </li> <li> <p>1111::111/128</p> </li> </ul> <p>You use an <code>IPSet</code> to specify which web requests you want to allow or block based on the IP addresses that the requests originated from. For example, if you're receiving a lot of requests from one or a small number of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that specifies those IP addresses, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateIPSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>IPSet</code>, you specify the IP addresses that you want to add and/or the IP addresses that you want to delete. If you want to change an IP address, you delete the existing IP address and add the new one.</p> <p>You can insert a maximum of 1000 addresses in a single request.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_ip_set(
+    /// Presigns the request `update_ip_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_ip_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIPSetRequest,
-    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_ip_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_ip_set_request(&self, input: UpdateIPSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateIPSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rate_based_rule`, also returning the metadata of the response.
+    pub async fn update_rate_based_rule_with_metadata(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> Result<
+        (UpdateRateBasedRuleResponse, ResponseMetadata),
+        RusotoError<UpdateRateBasedRuleError>,
+    > {
+        let request = self.build_update_rate_based_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateIPSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRateBasedRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIPSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRateBasedRuleError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>Predicate</a> objects in a rule and updates the <code>RateLimit</code> in the rule. </p> <p>Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to block or count. The <code>RateLimit</code> specifies the number of requests every five minutes that triggers the rule.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request must match all the predicates and exceed the <code>RateLimit</code> to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions much be received at a rate of more than 15,000 every five minutes. If the rate drops below this limit, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p>
-    async fn update_rate_based_rule(
+    /// Presigns the request `update_rate_based_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rate_based_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRateBasedRuleRequest,
-    ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rate_based_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rate_based_rule_request(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateRateBasedRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9862,28 +12780,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_regex_match_set`, also returning the metadata of the response.
+    pub async fn update_regex_match_set_with_metadata(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> Result<
+        (UpdateRegexMatchSetResponse, ResponseMetadata),
+        RusotoError<UpdateRegexMatchSetError>,
+    > {
+        let request = self.build_update_regex_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRateBasedRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRegexMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRateBasedRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRegexMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>RegexMatchTuple</a> objects (filters) in a <a>RegexMatchSet</a>. For each <code>RegexMatchSetUpdate</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>RegexMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspectupdate, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p> For example, you can create a <code>RegexPatternSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexMatchSet.</code> For more information, see <a>CreateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the identifier of the <code>RegexPatternSet</code> that contain the regular expression patters you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_regex_match_set(
+    /// Presigns the request `update_regex_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_regex_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRegexMatchSetRequest,
-    ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_regex_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_regex_match_set_request(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateRegexMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9893,28 +12841,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_regex_pattern_set`, also returning the metadata of the response.
+    pub async fn update_regex_pattern_set_with_metadata(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> Result<
+        (UpdateRegexPatternSetResponse, ResponseMetadata),
+        RusotoError<UpdateRegexPatternSetError>,
+    > {
+        let request = self.build_update_regex_pattern_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRegexMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRegexPatternSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRegexMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRegexPatternSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <code>RegexPatternString</code> objects in a <a>RegexPatternSet</a>. For each <code>RegexPatternString</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the <code>RegexPatternString</code>.</p> </li> <li> <p>The regular expression pattern that you want to insert or delete. For more information, see <a>RegexPatternSet</a>. </p> </li> </ul> <p> For example, you can create a <code>RegexPatternString</code> such as <code>B[a@]dB[o0]t</code>. AWS WAF will match this <code>RegexPatternString</code> to:</p> <ul> <li> <p>BadBot</p> </li> <li> <p>BadB0t</p> </li> <li> <p>B@dBot</p> </li> <li> <p>B@dB0t</p> </li> </ul> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexPatternSet.</code> For more information, see <a>CreateRegexPatternSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexPatternSet</code> request to specify the regular expression pattern that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_regex_pattern_set(
+    /// Presigns the request `update_regex_pattern_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_regex_pattern_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRegexPatternSetRequest,
-    ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_regex_pattern_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_regex_pattern_set_request(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateRegexPatternSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9924,82 +12902,163 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rule`, also returning the metadata of the response.
+    pub async fn update_rule_with_metadata(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> Result<(UpdateRuleResponse, ResponseMetadata), RusotoError<UpdateRuleError>> {
+        let request = self.build_update_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRegexPatternSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRegexPatternSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRuleError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>Predicate</a> objects in a <code>Rule</code>. Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to allow, block, or count. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed, blocked, or counted. For example, suppose that you add the following to a <code>Rule</code>: </p> <ul> <li> <p>A <code>ByteMatchSet</code> that matches the value <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44</code> </p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the <code>Rule</code>. For a request to be blocked, the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code> <i>and</i> the request must originate from the IP address 192.0.2.44.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create the <code>Rule</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to add predicates to the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>ByteMatchSet</code> or <code>IPSet</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_rule(
+    /// Presigns the request `update_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRuleRequest,
-    ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rule_request(&self, input: UpdateRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rule_group`, also returning the metadata of the response.
+    pub async fn update_rule_group_with_metadata(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> Result<(UpdateRuleGroupResponse, ResponseMetadata), RusotoError<UpdateRuleGroupError>>
+    {
+        let request = self.build_update_rule_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRuleGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRuleGroupError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>RuleGroup</code>.</p> <p>You can only insert <code>REGULAR</code> rules into a rule group.</p> <p>You can have a maximum of ten rules per rule group.</p> <p>To create and configure a <code>RuleGroup</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>RuleGroup</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRuleGroup</a> request.</p> </li> <li> <p>Submit an <code>UpdateRuleGroup</code> request to add <code>Rules</code> to the <code>RuleGroup</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RuleGroup</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>Rule</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_rule_group(
+    /// Presigns the request `update_rule_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rule_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRuleGroupRequest,
-    ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rule_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rule_group_request(&self, input: UpdateRuleGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateRuleGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_size_constraint_set`, also returning the metadata of the response.
+    pub async fn update_size_constraint_set_with_metadata(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> Result<
+        (UpdateSizeConstraintSetResponse, ResponseMetadata),
+        RusotoError<UpdateSizeConstraintSetError>,
+    > {
+        let request = self.build_update_size_constraint_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSizeConstraintSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRuleGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSizeConstraintSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>SizeConstraint</a> objects (filters) in a <a>SizeConstraintSet</a>. For each <code>SizeConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>SizeConstraintSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to evaluate, such as the length of a query string or the length of the <code>User-Agent</code> header.</p> </li> <li> <p>Whether to perform any transformations on the request, such as converting it to lowercase, before checking its length. Note that transformations of the request body are not supported because the AWS resource forwards only the first <code>8192</code> bytes of your request to AWS WAF.</p> <p>You can only specify a single type of TextTransformation.</p> </li> <li> <p>A <code>ComparisonOperator</code> used for evaluating the selected part of the request against the specified <code>Size</code>, such as equals, greater than, less than, and so on.</p> </li> <li> <p>The length, in bytes, that you want AWS WAF to watch for in selected part of the request. The length is computed after applying the transformation.</p> </li> </ul> <p>For example, you can add a <code>SizeConstraintSetUpdate</code> object that matches web requests in which the length of the <code>User-Agent</code> header is greater than 100 bytes. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>SizeConstraintSet.</code> For more information, see <a>CreateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateSizeConstraintSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_size_constraint_set(
+    /// Presigns the request `update_size_constraint_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_size_constraint_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSizeConstraintSetRequest,
-    ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_size_constraint_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_size_constraint_set_request(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateSizeConstraintSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10009,29 +13068,58 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_sql_injection_match_set`, also returning the metadata of the response.
+    pub async fn update_sql_injection_match_set_with_metadata(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> Result<
+        (UpdateSqlInjectionMatchSetResponse, ResponseMetadata),
+        RusotoError<UpdateSqlInjectionMatchSetError>,
+    > {
+        let request = self.build_update_sql_injection_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSizeConstraintSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSqlInjectionMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSizeConstraintSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSqlInjectionMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>SqlInjectionMatchTuple</a> objects (filters) in a <a>SqlInjectionMatchSet</a>. For each <code>SqlInjectionMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change a <code>SqlInjectionMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for snippets of malicious SQL code.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>SqlInjectionMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain snippets of SQL code in the query string and you want to block the requests, you can create a <code>SqlInjectionMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateSqlInjectionMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for snippets of SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_sql_injection_match_set(
+    /// Presigns the request `update_sql_injection_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_sql_injection_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSqlInjectionMatchSetRequest,
-    ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_sql_injection_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_sql_injection_match_set_request(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateSqlInjectionMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10041,74 +13129,920 @@ impl WAFRegional for WAFRegionalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_web_acl`, also returning the metadata of the response.
+    pub async fn update_web_acl_with_metadata(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> Result<(UpdateWebACLResponse, ResponseMetadata), RusotoError<UpdateWebACLError>> {
+        let request = self.build_update_web_acl_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSqlInjectionMatchSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWebACLResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSqlInjectionMatchSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWebACLError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>WebACL</code>. Each <code>Rule</code> identifies web requests that you want to allow, block, or count. When you update a <code>WebACL</code>, you specify the following values:</p> <ul> <li> <p>A default action for the <code>WebACL</code>, either <code>ALLOW</code> or <code>BLOCK</code>. AWS WAF performs the default action if a request doesn't match the criteria in any of the <code>Rules</code> in a <code>WebACL</code>.</p> </li> <li> <p>The <code>Rules</code> that you want to add or delete. If you want to replace one <code>Rule</code> with another, you delete the existing <code>Rule</code> and add the new one.</p> </li> <li> <p>For each <code>Rule</code>, whether you want AWS WAF to allow requests, block requests, or count requests that match the conditions in the <code>Rule</code>.</p> </li> <li> <p>The order in which you want AWS WAF to evaluate the <code>Rules</code> in a <code>WebACL</code>. If you add more than one <code>Rule</code> to a <code>WebACL</code>, AWS WAF evaluates each request against the <code>Rules</code> in order based on the value of <code>Priority</code>. (The <code>Rule</code> that has the lowest value for <code>Priority</code> is evaluated first.) When a web request matches all the predicates (such as <code>ByteMatchSets</code> and <code>IPSets</code>) in a <code>Rule</code>, AWS WAF immediately takes the corresponding action, allow or block, and doesn't evaluate the request against the remaining <code>Rules</code> in the <code>WebACL</code>, if any. </p> </li> </ul> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Create a <code>WebACL</code>. See <a>CreateWebACL</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <code>UpdateWebACL</code> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution. </p> <p>The <code>ActivatedRule</code> can be a rule group. If you specify a rule group as your <code>ActivatedRule</code>, you can exclude specific rules from that rule group.</p> <p>If you already have a rule group associated with a web ACL and want to submit an <code>UpdateWebACL</code> request to exclude certain rules from that rule group, you must first remove the rule group from the web ACL, the re-insert it again, specifying the excluded rules. For details, see <a>ActivatedRule$ExcludedRules</a>. </p> </li> </ol> <p>Be aware that if you try to add a RATE_BASED rule to a web ACL without setting the rule type when first creating the rule, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule (the default rule type) with the specified ID, which does not exist. </p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_web_acl(
+    /// Presigns the request `update_web_acl` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_web_acl(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWebACLRequest,
-    ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_web_acl_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_web_acl_request(&self, input: UpdateWebACLRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateWebACL");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_xss_match_set`, also returning the metadata of the response.
+    pub async fn update_xss_match_set_with_metadata(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> Result<(UpdateXssMatchSetResponse, ResponseMetadata), RusotoError<UpdateXssMatchSetError>>
+    {
+        let request = self.build_update_xss_match_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebACLResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateXssMatchSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWebACLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateXssMatchSetError::from_response(response))
         }
     }
 
-    /// <p>Inserts or deletes <a>XssMatchTuple</a> objects (filters) in an <a>XssMatchSet</a>. For each <code>XssMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change an <code>XssMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for cross-site scripting attacks.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>XssMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain cross-site scripting attacks in the request body and you want to block the requests, you can create an <code>XssMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateXssMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateXssMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
-    async fn update_xss_match_set(
+    /// Presigns the request `update_xss_match_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_xss_match_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateXssMatchSetRequest,
-    ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_xss_match_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_xss_match_set_request(&self, input: UpdateXssMatchSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "waf-regional", &self.region, "/");
+        request.set_operation("UpdateXssMatchSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSWAF_Regional_20161128.UpdateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl WAFRegional for WAFRegionalClient {
+    /// <p>Associates a web ACL with a resource, either an application load balancer or Amazon API Gateway stage.</p>
+    async fn associate_web_acl(
+        &self,
+        input: AssociateWebACLRequest,
+    ) -> Result<AssociateWebACLResponse, RusotoError<AssociateWebACLError>> {
+        self.associate_web_acl_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateXssMatchSetResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateXssMatchSetError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>ByteMatchSet</code>. You then use <a>UpdateByteMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>ByteMatchSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>BadBot</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateByteMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
+        self.create_byte_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an <a>GeoMatchSet</a>, which you use to specify which web requests you want to allow or block based on the country that the requests originate from. For example, if you're receiving a lot of requests from one or more countries and you want to block the requests, you can create an <code>GeoMatchSet</code> that contains those countries and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSetSet</code> request to specify the countries that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
+        self.create_geo_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an <a>IPSet</a>, which you use to specify which web requests that you want to allow or block based on the IP addresses that the requests originate from. For example, if you're receiving a lot of requests from one or more individual IP addresses or one or more ranges of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that contains those IP addresses and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateIPSet</code> request.</p> </li> <li> <p>Submit a <code>CreateIPSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.create_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>RateBasedRule</a>. The <code>RateBasedRule</code> contains a <code>RateLimit</code>, which specifies the maximum number of requests that AWS WAF allows from a specified IP address in a five-minute period. The <code>RateBasedRule</code> also contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to count or block if these requests exceed the <code>RateLimit</code>.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request not only must exceed the <code>RateLimit</code>, but it also must match all the specifications to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that meet the conditions in the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions must be received at a rate of more than 15,000 requests every five minutes. If both conditions are met and the rate is exceeded, AWS WAF blocks the requests. If the rate drops below 15,000 for a five-minute period, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p> <p>To create and configure a <code>RateBasedRule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the rule. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRateBasedRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRateBasedRule</code> request to specify the predicates that you want to include in the rule.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RateBasedRule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
+        self.create_rate_based_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>RegexMatchSet</a>. You then use <a>UpdateRegexMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>RegexMatchSet</code> that contains a <code>RegexMatchTuple</code> that looks for any requests with <code>User-Agent</code> headers that match a <code>RegexPatternSet</code> with pattern <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value, using a <code>RegexPatternSet</code>, that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
+        self.create_regex_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>RegexPatternSet</code>. You then use <a>UpdateRegexPatternSet</a> to specify the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexPatternSet</a> request to specify the string that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
+        self.create_regex_pattern_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>Rule</code>, which contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to block. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed or blocked. For example, suppose that you add the following to a <code>Rule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to blocks requests that satisfy the <code>Rule</code>. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to specify the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
+        self.create_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>RuleGroup</code>. A rule group is a collection of predefined rules that you add to a web ACL. You use <a>UpdateRuleGroup</a> to add rules to the rule group.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
+        self.create_rule_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>SizeConstraintSet</code>. You then use <a>UpdateSizeConstraintSet</a> to identify the part of a web request that you want AWS WAF to check for length, such as the length of the <code>User-Agent</code> header or the length of the query string. For example, you can create a <code>SizeConstraintSet</code> that matches any requests that have a query string that is longer than 100 bytes. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateSizeConstraintSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
+        self.create_size_constraint_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>SqlInjectionMatchSet</a>, which you use to allow, block, or count requests that contain snippets of SQL code in a specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateSqlInjectionMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count malicious SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
+    {
+        self.create_sql_injection_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>WebACL</code>, which contains the <code>Rules</code> that identify the CloudFront web requests that you want to allow, block, or count. AWS WAF evaluates <code>Rules</code> in order based on the value of <code>Priority</code> for each <code>Rule</code>.</p> <p>You also specify a default action, either <code>ALLOW</code> or <code>BLOCK</code>. If a web request doesn't match any of the <code>Rules</code> in a <code>WebACL</code>, AWS WAF responds to the request with the default action. </p> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>ByteMatchSet</code> objects and other predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateWebACL</code> request.</p> </li> <li> <p>Submit a <code>CreateWebACL</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <a>UpdateWebACL</a> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution.</p> </li> </ol> <p>For more information about how to use the AWS WAF API, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
+        self.create_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an <a>XssMatchSet</a>, which you use to allow, block, or count requests that contain cross-site scripting attacks in the specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateXssMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateXssMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
+        self.create_xss_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>ByteMatchSet</a>. You can&#39;t delete a <code>ByteMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>ByteMatchTuple</a> objects (any filters).</p> <p>If you just want to remove a <code>ByteMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>ByteMatchSet</code> to remove filters, if any. For more information, see <a>UpdateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteByteMatchSet</code> request.</p> </li> </ol></p>
+    async fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
+        self.delete_byte_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>GeoMatchSet</a>. You can&#39;t delete a <code>GeoMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any countries.</p> <p>If you just want to remove a <code>GeoMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>GeoMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>GeoMatchSet</code> to remove any countries. For more information, see <a>UpdateGeoMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteGeoMatchSet</code> request.</p> </li> </ol></p>
+    async fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
+        self.delete_geo_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes an <a>IPSet</a>. You can&#39;t delete an <code>IPSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any IP addresses.</p> <p>If you just want to remove an <code>IPSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>IPSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>IPSet</code> to remove IP address ranges, if any. For more information, see <a>UpdateIPSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteIPSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteIPSet</code> request.</p> </li> </ol></p>
+    async fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.delete_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes the <a>LoggingConfiguration</a> from the specified web ACL.</p>
+    async fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
+    {
+        self.delete_logging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes an IAM policy from the specified RuleGroup.</p> <p>The user making the request must be the owner of the RuleGroup.</p>
+    async fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
+        self.delete_permission_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>RateBasedRule</a>. You can&#39;t delete a rule if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a rule from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RateBasedRule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RateBasedRule</code> to remove predicates, if any. For more information, see <a>UpdateRateBasedRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRateBasedRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRateBasedRule</code> request.</p> </li> </ol></p>
+    async fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
+        self.delete_rate_based_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>RegexMatchSet</a>. You can&#39;t delete a <code>RegexMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <code>RegexMatchTuples</code> objects (any filters).</p> <p>If you just want to remove a <code>RegexMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>RegexMatchSet</code> to remove filters, if any. For more information, see <a>UpdateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteRegexMatchSet</code> request.</p> </li> </ol></p>
+    async fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
+        self.delete_regex_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes a <a>RegexPatternSet</a>. You can't delete a <code>RegexPatternSet</code> if it's still used in any <code>RegexMatchSet</code> or if the <code>RegexPatternSet</code> is not empty. </p>
+    async fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
+        self.delete_regex_pattern_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>Rule</a>. You can&#39;t delete a <code>Rule</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a <code>Rule</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>Rule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>Rule</code> to remove predicates, if any. For more information, see <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRule</code> request.</p> </li> </ol></p>
+    async fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
+        self.delete_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>RuleGroup</a>. You can&#39;t delete a <code>RuleGroup</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any rules.</p> <p>If you just want to remove a <code>RuleGroup</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RuleGroup</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RuleGroup</code> to remove rules, if any. For more information, see <a>UpdateRuleGroup</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRuleGroup</code> request.</p> </li> <li> <p>Submit a <code>DeleteRuleGroup</code> request.</p> </li> </ol></p>
+    async fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
+        self.delete_rule_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>SizeConstraintSet</a>. You can&#39;t delete a <code>SizeConstraintSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>SizeConstraint</a> objects (any filters).</p> <p>If you just want to remove a <code>SizeConstraintSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>SizeConstraintSet</code> to remove filters, if any. For more information, see <a>UpdateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSizeConstraintSet</code> request.</p> </li> </ol></p>
+    async fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
+        self.delete_size_constraint_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>SqlInjectionMatchSet</a>. You can&#39;t delete a <code>SqlInjectionMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>SqlInjectionMatchTuple</a> objects.</p> <p>If you just want to remove a <code>SqlInjectionMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SqlInjectionMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>SqlInjectionMatchSet</code> to remove filters, if any. For more information, see <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> </ol></p>
+    async fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
+    {
+        self.delete_sql_injection_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes a <a>WebACL</a>. You can&#39;t delete a <code>WebACL</code> if it still contains any <code>Rules</code>.</p> <p>To delete a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>WebACL</code> to remove <code>Rules</code>, if any. For more information, see <a>UpdateWebACL</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteWebACL</code> request.</p> </li> <li> <p>Submit a <code>DeleteWebACL</code> request.</p> </li> </ol></p>
+    async fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
+        self.delete_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently deletes an <a>XssMatchSet</a>. You can&#39;t delete an <code>XssMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>XssMatchTuple</a> objects.</p> <p>If you just want to remove an <code>XssMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>XssMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>XssMatchSet</code> to remove filters, if any. For more information, see <a>UpdateXssMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteXssMatchSet</code> request.</p> </li> </ol></p>
+    async fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
+        self.delete_xss_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a web ACL from the specified resource, either an application load balancer or Amazon API Gateway stage.</p>
+    async fn disassociate_web_acl(
+        &self,
+        input: DisassociateWebACLRequest,
+    ) -> Result<DisassociateWebACLResponse, RusotoError<DisassociateWebACLError>> {
+        self.disassociate_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>ByteMatchSet</a> specified by <code>ByteMatchSetId</code>.</p>
+    async fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
+        self.get_byte_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>When you want to create, update, or delete AWS WAF objects, get a change token and include the change token in the create, update, or delete request. Change tokens ensure that your application doesn't submit conflicting requests to AWS WAF.</p> <p>Each create, update, or delete request must use a unique change token. If your application submits a <code>GetChangeToken</code> request and then submits a second <code>GetChangeToken</code> request before submitting a create, update, or delete request, the second <code>GetChangeToken</code> request returns the same value as the first <code>GetChangeToken</code> request.</p> <p>When you use a change token in a create, update, or delete request, the status of the change token changes to <code>PENDING</code>, which indicates that AWS WAF is propagating the change to all AWS WAF servers. Use <code>GetChangeTokenStatus</code> to determine the status of your change token.</p>
+    async fn get_change_token(
+        &self,
+    ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
+        self.get_change_token_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the status of a <code>ChangeToken</code> that you got by calling <a>GetChangeToken</a>. <code>ChangeTokenStatus</code> is one of the following values:</p> <ul> <li> <p> <code>PROVISIONED</code>: You requested the change token by calling <code>GetChangeToken</code>, but you haven&#39;t used it yet in a call to create, update, or delete an AWS WAF object.</p> </li> <li> <p> <code>PENDING</code>: AWS WAF is propagating the create, update, or delete request to all AWS WAF servers.</p> </li> <li> <p> <code>INSYNC</code>: Propagation is complete.</p> </li> </ul></p>
+    async fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
+        self.get_change_token_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>GeoMatchSet</a> that is specified by <code>GeoMatchSetId</code>.</p>
+    async fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
+        self.get_geo_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>IPSet</a> that is specified by <code>IPSetId</code>.</p>
+    async fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.get_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>LoggingConfiguration</a> for the specified web ACL.</p>
+    async fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
+        self.get_logging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the IAM policy attached to the RuleGroup.</p>
+    async fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
+        self.get_permission_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>RateBasedRule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRateBasedRule</code> request.</p>
+    async fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
+        self.get_rate_based_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of IP addresses currently being blocked by the <a>RateBasedRule</a> that is specified by the <code>RuleId</code>. The maximum number of managed keys that will be blocked is 10,000. If more than 10,000 addresses exceed the rate limit, the 10,000 addresses with the highest rates will be blocked.</p>
+    async fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
+    {
+        self.get_rate_based_rule_managed_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>RegexMatchSet</a> specified by <code>RegexMatchSetId</code>.</p>
+    async fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
+        self.get_regex_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>RegexPatternSet</a> specified by <code>RegexPatternSetId</code>.</p>
+    async fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
+        self.get_regex_pattern_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>Rule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRule</code> request.</p>
+    async fn get_rule(
+        &self,
+        input: GetRuleRequest,
+    ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
+        self.get_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>RuleGroup</a> that is specified by the <code>RuleGroupId</code> that you included in the <code>GetRuleGroup</code> request.</p> <p>To view the rules in a rule group, use <a>ListActivatedRulesInRuleGroup</a>.</p>
+    async fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
+        self.get_rule_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets detailed information about a specified number of requests--a sample--that AWS WAF randomly selects from among the first 5,000 requests that your AWS resource received during a time range that you choose. You can specify a sample size of up to 500 requests, and you can specify any time range in the previous three hours.</p> <p> <code>GetSampledRequests</code> returns a time range, which is usually the time range that you specified. However, if your resource (such as a CloudFront distribution) received 5,000 requests before the specified time range elapsed, <code>GetSampledRequests</code> returns an updated time range. This new time range indicates the actual period during which AWS WAF selected the requests in the sample.</p>
+    async fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
+        self.get_sampled_requests_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>SizeConstraintSet</a> specified by <code>SizeConstraintSetId</code>.</p>
+    async fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
+        self.get_size_constraint_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>SqlInjectionMatchSet</a> that is specified by <code>SqlInjectionMatchSetId</code>.</p>
+    async fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
+        self.get_sql_injection_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>WebACL</a> that is specified by <code>WebACLId</code>.</p>
+    async fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
+        self.get_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the web ACL for the specified resource, either an application load balancer or Amazon API Gateway stage.</p>
+    async fn get_web_acl_for_resource(
+        &self,
+        input: GetWebACLForResourceRequest,
+    ) -> Result<GetWebACLForResourceResponse, RusotoError<GetWebACLForResourceError>> {
+        self.get_web_acl_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <a>XssMatchSet</a> that is specified by <code>XssMatchSetId</code>.</p>
+    async fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
+        self.get_xss_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>ActivatedRule</a> objects.</p>
+    async fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> Result<
+        ListActivatedRulesInRuleGroupResponse,
+        RusotoError<ListActivatedRulesInRuleGroupError>,
+    > {
+        self.list_activated_rules_in_rule_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>ByteMatchSetSummary</a> objects.</p>
+    async fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
+        self.list_byte_match_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>GeoMatchSetSummary</a> objects in the response.</p>
+    async fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
+        self.list_geo_match_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>IPSetSummary</a> objects in the response.</p>
+    async fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.list_ip_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>LoggingConfiguration</a> objects.</p>
+    async fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
+    {
+        self.list_logging_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
+    async fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
+        self.list_rate_based_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RegexMatchSetSummary</a> objects.</p>
+    async fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
+        self.list_regex_match_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RegexPatternSetSummary</a> objects.</p>
+    async fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
+        self.list_regex_pattern_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of resources associated with the specified web ACL.</p>
+    async fn list_resources_for_web_acl(
+        &self,
+        input: ListResourcesForWebACLRequest,
+    ) -> Result<ListResourcesForWebACLResponse, RusotoError<ListResourcesForWebACLError>> {
+        self.list_resources_for_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RuleGroup</a> objects.</p>
+    async fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
+        self.list_rule_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
+    async fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.list_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>SizeConstraintSetSummary</a> objects.</p>
+    async fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
+        self.list_size_constraint_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>SqlInjectionMatchSet</a> objects.</p>
+    async fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
+    {
+        self.list_sql_injection_match_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>RuleGroup</a> objects that you are subscribed to.</p>
+    async fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
+        self.list_subscribed_rule_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>WebACLSummary</a> objects in the response.</p>
+    async fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
+        self.list_web_ac_ls_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <a>XssMatchSet</a> objects.</p>
+    async fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
+        self.list_xss_match_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a <a>LoggingConfiguration</a> with a specified web ACL.</p> <p>You can access information about all traffic that AWS WAF inspects using the following steps:</p> <ol> <li> <p>Create an Amazon Kinesis Data Firehose. </p> <p>Create the data firehose with a PUT source and in the region that you are operating. However, if you are capturing logs for Amazon CloudFront, always create the firehose in US East (N. Virginia). </p> <note> <p>Do not create the data firehose using a <code>Kinesis stream</code> as your source.</p> </note> </li> <li> <p>Associate that firehose to your web ACL using a <code>PutLoggingConfiguration</code> request.</p> </li> </ol> <p>When you successfully enable logging using a <code>PutLoggingConfiguration</code> request, AWS WAF will create a service linked role with the necessary permissions to write logs to the Amazon Kinesis Data Firehose. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/logging.html">Logging Web ACL Traffic Information</a> in the <i>AWS WAF Developer Guide</i>.</p>
+    async fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
+        self.put_logging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a IAM policy to the specified resource. The only supported use for this action is to share a RuleGroup across accounts.</p> <p>The <code>PutPermissionPolicy</code> is subject to the following restrictions:</p> <ul> <li> <p>You can attach only one policy with each <code>PutPermissionPolicy</code> request.</p> </li> <li> <p>The policy must include an <code>Effect</code>, <code>Action</code> and <code>Principal</code>. </p> </li> <li> <p> <code>Effect</code> must specify <code>Allow</code>.</p> </li> <li> <p>The <code>Action</code> in the policy must be <code>waf:UpdateWebACL</code>, <code>waf-regional:UpdateWebACL</code>, <code>waf:GetRuleGroup</code> and <code>waf-regional:GetRuleGroup</code> . Any extra or wildcard actions in the policy will be rejected.</p> </li> <li> <p>The policy cannot include a <code>Resource</code> parameter.</p> </li> <li> <p>The ARN in the request must be a valid WAF RuleGroup ARN and the RuleGroup must exist in the same region.</p> </li> <li> <p>The user making the request must be the owner of the RuleGroup.</p> </li> <li> <p>Your policy must be composed using IAM Policy version 2012-10-17.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html">IAM Policies</a>. </p> <p>An example of a valid policy parameter is shown in the Examples section below.</p>
+    async fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
+        self.put_permission_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>ByteMatchTuple</a> objects (filters) in a <a>ByteMatchSet</a>. For each <code>ByteMatchTuple</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>ByteMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to look for. For more information, including how you specify the values for the AWS WAF API and the AWS CLI or SDKs, see <code>TargetString</code> in the <a>ByteMatchTuple</a> data type. </p> </li> <li> <p>Where to look, such as at the beginning or the end of a query string.</p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p>For example, you can add a <code>ByteMatchSetUpdate</code> object that matches web requests in which <code>User-Agent</code> headers contain the string <code>BadBot</code>. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>ByteMatchSet.</code> For more information, see <a>CreateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateByteMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
+        self.update_byte_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>GeoMatchConstraint</a> objects in an <code>GeoMatchSet</code>. For each <code>GeoMatchConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>GeoMatchConstraint</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The <code>Type</code>. The only valid value for <code>Type</code> is <code>Country</code>.</p> </li> <li> <p>The <code>Value</code>, which is a two character code for the country to add to the <code>GeoMatchConstraint</code> object. Valid codes are listed in <a>GeoMatchConstraint$Value</a>.</p> </li> </ul> <p>To create and configure an <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateGeoMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSet</code> request to specify the country that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>GeoMatchSet</code>, you specify the country that you want to add and/or the country that you want to delete. If you want to change a country, you delete the existing country and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
+        self.update_geo_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>IPSetDescriptor</a> objects in an <code>IPSet</code>. For each <code>IPSetDescriptor</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>IPSetDescriptor</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The IP address version, <code>IPv4</code> or <code>IPv6</code>. </p> </li> <li> <p>The IP address in CIDR notation, for example, <code>192.0.2.0/24</code> (for the range of IP addresses from <code>192.0.2.0</code> to <code>192.0.2.255</code>) or <code>192.0.2.44/32</code> (for the individual IP address <code>192.0.2.44</code>). </p> </li> </ul> <p>AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128. For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>IPv6 addresses can be represented using any of the following formats:</p> <ul> <li> <p>1111:0000:0000:0000:0000:0000:0000:0111/128</p> </li> <li> <p>1111:0:0:0:0:0:0:0111/128</p> </li> <li> <p>1111::0111/128</p> </li> <li> <p>1111::111/128</p> </li> </ul> <p>You use an <code>IPSet</code> to specify which web requests you want to allow or block based on the IP addresses that the requests originated from. For example, if you're receiving a lot of requests from one or a small number of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that specifies those IP addresses, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateIPSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>IPSet</code>, you specify the IP addresses that you want to add and/or the IP addresses that you want to delete. If you want to change an IP address, you delete the existing IP address and add the new one.</p> <p>You can insert a maximum of 1000 addresses in a single request.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.update_ip_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>Predicate</a> objects in a rule and updates the <code>RateLimit</code> in the rule. </p> <p>Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to block or count. The <code>RateLimit</code> specifies the number of requests every five minutes that triggers the rule.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request must match all the predicates and exceed the <code>RateLimit</code> to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions much be received at a rate of more than 15,000 every five minutes. If the rate drops below this limit, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p>
+    async fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
+        self.update_rate_based_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>RegexMatchTuple</a> objects (filters) in a <a>RegexMatchSet</a>. For each <code>RegexMatchSetUpdate</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>RegexMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspectupdate, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p> For example, you can create a <code>RegexPatternSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexMatchSet.</code> For more information, see <a>CreateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the identifier of the <code>RegexPatternSet</code> that contain the regular expression patters you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
+        self.update_regex_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <code>RegexPatternString</code> objects in a <a>RegexPatternSet</a>. For each <code>RegexPatternString</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the <code>RegexPatternString</code>.</p> </li> <li> <p>The regular expression pattern that you want to insert or delete. For more information, see <a>RegexPatternSet</a>. </p> </li> </ul> <p> For example, you can create a <code>RegexPatternString</code> such as <code>B[a@]dB[o0]t</code>. AWS WAF will match this <code>RegexPatternString</code> to:</p> <ul> <li> <p>BadBot</p> </li> <li> <p>BadB0t</p> </li> <li> <p>B@dBot</p> </li> <li> <p>B@dB0t</p> </li> </ul> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexPatternSet.</code> For more information, see <a>CreateRegexPatternSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexPatternSet</code> request to specify the regular expression pattern that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
+        self.update_regex_pattern_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>Predicate</a> objects in a <code>Rule</code>. Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to allow, block, or count. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed, blocked, or counted. For example, suppose that you add the following to a <code>Rule</code>: </p> <ul> <li> <p>A <code>ByteMatchSet</code> that matches the value <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44</code> </p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the <code>Rule</code>. For a request to be blocked, the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code> <i>and</i> the request must originate from the IP address 192.0.2.44.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create the <code>Rule</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to add predicates to the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>ByteMatchSet</code> or <code>IPSet</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
+        self.update_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>RuleGroup</code>.</p> <p>You can only insert <code>REGULAR</code> rules into a rule group.</p> <p>You can have a maximum of ten rules per rule group.</p> <p>To create and configure a <code>RuleGroup</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>RuleGroup</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRuleGroup</a> request.</p> </li> <li> <p>Submit an <code>UpdateRuleGroup</code> request to add <code>Rules</code> to the <code>RuleGroup</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RuleGroup</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>Rule</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
+        self.update_rule_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>SizeConstraint</a> objects (filters) in a <a>SizeConstraintSet</a>. For each <code>SizeConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>SizeConstraintSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to evaluate, such as the length of a query string or the length of the <code>User-Agent</code> header.</p> </li> <li> <p>Whether to perform any transformations on the request, such as converting it to lowercase, before checking its length. Note that transformations of the request body are not supported because the AWS resource forwards only the first <code>8192</code> bytes of your request to AWS WAF.</p> <p>You can only specify a single type of TextTransformation.</p> </li> <li> <p>A <code>ComparisonOperator</code> used for evaluating the selected part of the request against the specified <code>Size</code>, such as equals, greater than, less than, and so on.</p> </li> <li> <p>The length, in bytes, that you want AWS WAF to watch for in selected part of the request. The length is computed after applying the transformation.</p> </li> </ul> <p>For example, you can add a <code>SizeConstraintSetUpdate</code> object that matches web requests in which the length of the <code>User-Agent</code> header is greater than 100 bytes. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>SizeConstraintSet.</code> For more information, see <a>CreateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateSizeConstraintSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
+        self.update_size_constraint_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>SqlInjectionMatchTuple</a> objects (filters) in a <a>SqlInjectionMatchSet</a>. For each <code>SqlInjectionMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change a <code>SqlInjectionMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for snippets of malicious SQL code.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>SqlInjectionMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain snippets of SQL code in the query string and you want to block the requests, you can create a <code>SqlInjectionMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateSqlInjectionMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for snippets of SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
+    {
+        self.update_sql_injection_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>WebACL</code>. Each <code>Rule</code> identifies web requests that you want to allow, block, or count. When you update a <code>WebACL</code>, you specify the following values:</p> <ul> <li> <p>A default action for the <code>WebACL</code>, either <code>ALLOW</code> or <code>BLOCK</code>. AWS WAF performs the default action if a request doesn't match the criteria in any of the <code>Rules</code> in a <code>WebACL</code>.</p> </li> <li> <p>The <code>Rules</code> that you want to add or delete. If you want to replace one <code>Rule</code> with another, you delete the existing <code>Rule</code> and add the new one.</p> </li> <li> <p>For each <code>Rule</code>, whether you want AWS WAF to allow requests, block requests, or count requests that match the conditions in the <code>Rule</code>.</p> </li> <li> <p>The order in which you want AWS WAF to evaluate the <code>Rules</code> in a <code>WebACL</code>. If you add more than one <code>Rule</code> to a <code>WebACL</code>, AWS WAF evaluates each request against the <code>Rules</code> in order based on the value of <code>Priority</code>. (The <code>Rule</code> that has the lowest value for <code>Priority</code> is evaluated first.) When a web request matches all the predicates (such as <code>ByteMatchSets</code> and <code>IPSets</code>) in a <code>Rule</code>, AWS WAF immediately takes the corresponding action, allow or block, and doesn't evaluate the request against the remaining <code>Rules</code> in the <code>WebACL</code>, if any. </p> </li> </ul> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Create a <code>WebACL</code>. See <a>CreateWebACL</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <code>UpdateWebACL</code> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution. </p> <p>The <code>ActivatedRule</code> can be a rule group. If you specify a rule group as your <code>ActivatedRule</code>, you can exclude specific rules from that rule group.</p> <p>If you already have a rule group associated with a web ACL and want to submit an <code>UpdateWebACL</code> request to exclude certain rules from that rule group, you must first remove the rule group from the web ACL, the re-insert it again, specifying the excluded rules. For details, see <a>ActivatedRule$ExcludedRules</a>. </p> </li> </ol> <p>Be aware that if you try to add a RATE_BASED rule to a web ACL without setting the rule type when first creating the rule, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule (the default rule type) with the specified ID, which does not exist. </p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
+        self.update_web_acl_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inserts or deletes <a>XssMatchTuple</a> objects (filters) in an <a>XssMatchSet</a>. For each <code>XssMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change an <code>XssMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for cross-site scripting attacks.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>XssMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain cross-site scripting attacks in the request body and you want to block the requests, you can create an <code>XssMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateXssMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateXssMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    async fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
+        self.update_xss_match_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }